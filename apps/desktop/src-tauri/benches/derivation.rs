@@ -0,0 +1,70 @@
+//! Bulk address derivation benchmarks
+//!
+//! Compares deriving every address from the seed (full path walk per index)
+//! with `WalletManager::derive_range`, which expands a cached account node.
+//!
+//! Run with `cargo bench --bench derivation`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use coinbox_lib::wallet::{mnemonic_to_seed, parse_mnemonic, WalletManager};
+
+const MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+/// Addresses per iteration (a typical gap-limit scan window)
+const RANGE: u32 = 100;
+
+fn bench_derive_range(c: &mut Criterion) {
+    let chains = ["bitcoin", "ethereum", "tron", "solana", "sui"];
+
+    let manager = WalletManager::new();
+    let chain_ids: Vec<String> = chains.iter().map(|c| c.to_string()).collect();
+    let wallet = manager
+        .import_hd_wallet("Bench", MNEMONIC, &chain_ids, "password")
+        .expect("import wallet");
+    let seed = mnemonic_to_seed(&parse_mnemonic(MNEMONIC).unwrap(), "").unwrap();
+
+    let mut group = c.benchmark_group("derive_range");
+    group.throughput(Throughput::Elements(RANGE as u64));
+
+    for chain_id in chains {
+        let module = manager.registry().get(chain_id).expect("registered chain");
+
+        group.bench_with_input(
+            BenchmarkId::new("seed_path", chain_id),
+            &chain_id,
+            |b, _| {
+                b.iter(|| {
+                    for index in 0..RANGE {
+                        black_box(module.derive_address(seed.as_bytes(), 0, index).unwrap());
+                    }
+                })
+            },
+        );
+
+        // Warm the session cache so only the per-index cost is measured
+        manager
+            .derive_range(&wallet.wallet_id, chain_id, 0, 0..1)
+            .unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("cached_node", chain_id),
+            &chain_id,
+            |b, chain_id| {
+                b.iter(|| {
+                    black_box(
+                        manager
+                            .derive_range(&wallet.wallet_id, chain_id, 0, 0..RANGE)
+                            .unwrap(),
+                    )
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_derive_range);
+criterion_main!(benches);
@@ -2,22 +2,40 @@
 //!
 //! Exposes BDK wallet functionality to the frontend.
 
+use bdk_wallet::bitcoin::ScriptBuf;
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
-use tauri::State;
-use tracing::{debug, error, info};
+use tauri::{AppHandle, Emitter, Manager, State};
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+use tokio::sync::Mutex as AsyncMutex;
+use tracing::{debug, error, info, warn};
 
 use crate::error::{Error, Result};
 use crate::wallet::bitcoin::{
-    BitcoinAdapter, BitcoinBalance, BitcoinConfig, BitcoinNetwork, BitcoinTransaction, FeeEstimate,
-    SendTransactionResult, SyncProgress, UtxoInfo,
+    transaction_events, tx_snapshot, BackendKind, BackendStatus, BitcoinAdapter, BitcoinBalance,
+    BitcoinConfig, BitcoinNetwork, BitcoinTransaction, ChainEvent, ChainWatcher, FeeEstimate,
+    SendTransactionResult, SyncMonitor, SyncProgress, SyncProgressEvent, TxSnapshot, UtxoInfo,
+    WalletCache, WalletDescriptors, WatchNotice, CHAIN_EVENT, SYNC_PROGRESS_EVENT,
 };
+use crate::wallet::chains::BitcoinScriptType;
 use super::wallet::get_wallet_manager;
 
 /// Bitcoin adapter state for Tauri
 pub struct BitcoinState {
     adapter: Arc<BitcoinAdapter>,
+    /// Loaded wallets, each behind its own lock
+    wallets: WalletCache,
+    /// Running syncs by wallet ID, for cancellation
+    syncs: Arc<Mutex<HashMap<String, SyncMonitor>>>,
+    /// Electrum subscriptions, connected when the first wallet is watched
+    watcher: Mutex<Option<Arc<ChainWatcher>>>,
+    /// Held while the watcher connects, so only one connection is made
+    watcher_connecting: AsyncMutex<()>,
+    /// Watched wallets by ID
+    watched: Mutex<HashMap<String, WatchedWallet>>,
 }
 
 impl BitcoinState {
@@ -26,9 +44,7 @@ impl BitcoinState {
         let bitcoin_db_dir = data_dir.join("bitcoin");
         std::fs::create_dir_all(&bitcoin_db_dir).ok();
 
-        Self {
-            adapter: Arc::new(BitcoinAdapter::mainnet(bitcoin_db_dir)),
-        }
+        Self::with_adapter(BitcoinAdapter::mainnet(bitcoin_db_dir))
     }
 
     /// Create with custom configuration
@@ -36,8 +52,18 @@ impl BitcoinState {
         let bitcoin_db_dir = data_dir.join("bitcoin");
         std::fs::create_dir_all(&bitcoin_db_dir).ok();
 
+        Self::with_adapter(BitcoinAdapter::new(config, bitcoin_db_dir))
+    }
+
+    fn with_adapter(adapter: BitcoinAdapter) -> Self {
+        let adapter = Arc::new(adapter);
         Self {
-            adapter: Arc::new(BitcoinAdapter::new(config, bitcoin_db_dir)),
+            wallets: WalletCache::new(adapter.clone()),
+            adapter,
+            syncs: Arc::default(),
+            watcher: Mutex::new(None),
+            watcher_connecting: AsyncMutex::new(()),
+            watched: Mutex::new(HashMap::new()),
         }
     }
 
@@ -45,6 +71,229 @@ impl BitcoinState {
     pub fn adapter(&self) -> &BitcoinAdapter {
         &self.adapter
     }
+
+    /// Run a server query of the adapter off the async runtime
+    ///
+    /// Backend calls block on the network and sleep between retry rounds.
+    async fn query<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&BitcoinAdapter) -> Result<T> + Send + 'static,
+    ) -> Result<T> {
+        let adapter = self.adapter.clone();
+        tokio::task::spawn_blocking(move || f(&adapter))
+            .await
+            .map_err(task_failed)?
+    }
+
+    /// Get the cache of loaded wallets
+    pub fn wallets(&self) -> &WalletCache {
+        &self.wallets
+    }
+
+    /// Register a running sync so it can be cancelled
+    ///
+    /// Fails if the wallet is already syncing. The sync is unregistered
+    /// when the returned guard drops.
+    fn start_sync(&self, wallet_id: &str, monitor: SyncMonitor) -> Result<SyncGuard> {
+        let mut syncs = self.syncs.lock();
+        if syncs.contains_key(wallet_id) {
+            return Err(Error::Bitcoin(format!(
+                "Wallet {} is already syncing",
+                wallet_id
+            )));
+        }
+        syncs.insert(wallet_id.to_string(), monitor);
+
+        Ok(SyncGuard {
+            syncs: self.syncs.clone(),
+            wallet_id: wallet_id.to_string(),
+        })
+    }
+
+    /// The chain watcher, connected on first use
+    ///
+    /// Its notices are handled by a task that lives as long as it does.
+    async fn watcher(&self, app: &AppHandle) -> Result<Arc<ChainWatcher>> {
+        let _connecting = self.watcher_connecting.lock().await;
+        if let Some(watcher) = self.watcher.lock().clone() {
+            return Ok(watcher);
+        }
+
+        let urls = self.adapter.backends().urls(BackendKind::Electrum);
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let watcher = tokio::task::spawn_blocking(move || {
+            ChainWatcher::connect(urls, move |notice| {
+                // Only fails once the handler task is gone
+                let _ = sender.send(notice);
+            })
+        })
+        .await
+        .map_err(task_failed)??;
+
+        let watcher = Arc::new(watcher);
+        tauri::async_runtime::spawn(handle_chain_notices(app.clone(), receiver));
+        *self.watcher.lock() = Some(watcher.clone());
+        Ok(watcher)
+    }
+
+    /// Subscribe more scripts of a watched wallet, e.g. newly revealed ones
+    async fn watch_more(&self, wallet_id: &str, scripts: Vec<ScriptBuf>) -> Result<()> {
+        if !self.watched.lock().contains_key(wallet_id) {
+            return Ok(());
+        }
+        let Some(watcher) = self.watcher.lock().clone() else {
+            return Ok(());
+        };
+        watch_scripts(watcher, wallet_id, scripts).await?;
+        Ok(())
+    }
+
+    /// Stop push updates for a wallet
+    ///
+    /// Unsubscribing talks to the server, so it runs off the async runtime.
+    /// Returns whether the wallet was watched.
+    pub async fn unwatch(&self, wallet_id: &str) -> Result<bool> {
+        let was_watched = self.watched.lock().remove(wallet_id).is_some();
+        let watcher = self.watcher.lock().clone();
+        if let Some(watcher) = watcher {
+            let wallet_id = wallet_id.to_string();
+            tokio::task::spawn_blocking(move || watcher.unwatch(&wallet_id))
+                .await
+                .map_err(task_failed)?;
+        }
+        Ok(was_watched)
+    }
+
+    /// Stop push updates for every wallet and close the connection
+    pub fn stop_watching(&self) {
+        self.watched.lock().clear();
+        if self.watcher.lock().take().is_some() {
+            info!("Stopped watching Bitcoin wallets");
+        }
+    }
+
+    /// Sync a watched wallet's changed scripts and describe what changed
+    async fn refresh_watched(
+        &self,
+        wallet_id: &str,
+        scripts: Vec<ScriptBuf>,
+    ) -> Result<Vec<ChainEvent>> {
+        let address = match self.watched.lock().get(wallet_id) {
+            Some(watched) => watched.address.clone(),
+            None => return Ok(Vec::new()),
+        };
+
+        let transactions = match address {
+            Some(address) => {
+                let adapter = self.adapter.clone();
+                tokio::task::spawn_blocking(move || adapter.get_address_transactions(&address))
+                    .await
+                    .map_err(task_failed)??
+            }
+            None => {
+                // A sync that is already running picks the change up
+                let monitor = SyncMonitor::new();
+                let Ok(_guard) = self.start_sync(wallet_id, monitor.clone()) else {
+                    debug!("Wallet {} is syncing; skipping push update", wallet_id);
+                    return Ok(Vec::new());
+                };
+                self.wallets
+                    .sync_scripts(wallet_id, scripts, &monitor)
+                    .await?;
+
+                let (transactions, revealed) = self
+                    .wallets
+                    .with_wallet(wallet_id, |adapter, wallet| {
+                        Ok((
+                            adapter.get_transactions(wallet)?,
+                            adapter.revealed_scripts(wallet),
+                        ))
+                    })
+                    .await?;
+                // Addresses revealed since watching started, e.g. change
+                self.watch_more(wallet_id, revealed).await?;
+                transactions
+            }
+        };
+
+        let mut watched = self.watched.lock();
+        let Some(entry) = watched.get_mut(wallet_id) else {
+            return Ok(Vec::new());
+        };
+        let mut events = transaction_events(wallet_id, &entry.snapshot, &transactions);
+        entry.snapshot = tx_snapshot(&transactions);
+        events.push(ChainEvent::WalletUpdated {
+            wallet_id: wallet_id.to_string(),
+        });
+        Ok(events)
+    }
+}
+
+/// A wallet receiving push updates
+struct WatchedWallet {
+    /// The address of a single-address watch wallet
+    address: Option<String>,
+    /// History at the last update, to tell what changed
+    snapshot: TxSnapshot,
+}
+
+fn task_failed(e: tokio::task::JoinError) -> Error {
+    Error::Bitcoin(format!("Bitcoin task failed: {}", e))
+}
+
+/// Subscribe scripts off the async runtime, as it talks to the server
+async fn watch_scripts(
+    watcher: Arc<ChainWatcher>,
+    wallet_id: &str,
+    scripts: Vec<ScriptBuf>,
+) -> Result<usize> {
+    let wallet_id = wallet_id.to_string();
+    tokio::task::spawn_blocking(move || watcher.watch(&wallet_id, scripts))
+        .await
+        .map_err(task_failed)
+}
+
+/// Turn watcher notices into targeted syncs and chain events
+///
+/// Runs until the watcher stops; notices are handled one at a time.
+async fn handle_chain_notices(app: AppHandle, mut notices: UnboundedReceiver<WatchNotice>) {
+    while let Some(notice) = notices.recv().await {
+        let state = app.state::<BitcoinState>();
+        let events = match notice {
+            WatchNotice::NewTip { height } => vec![ChainEvent::NewBlock { height }],
+            WatchNotice::Reorg { height } => {
+                warn!("Bitcoin chain reorganized; new tip at {}", height);
+                vec![ChainEvent::Reorg { height }]
+            }
+            WatchNotice::ScriptsChanged { wallet_id, scripts } => {
+                match state.refresh_watched(&wallet_id, scripts).await {
+                    Ok(events) => events,
+                    Err(e) => {
+                        warn!("Failed to update watched wallet {}: {}", wallet_id, e);
+                        continue;
+                    }
+                }
+            }
+        };
+
+        for event in events {
+            if let Err(e) = app.emit(CHAIN_EVENT, &event) {
+                warn!("Failed to emit chain event: {}", e);
+            }
+        }
+    }
+}
+
+/// Unregisters a running sync on drop
+struct SyncGuard {
+    syncs: Arc<Mutex<HashMap<String, SyncMonitor>>>,
+    wallet_id: String,
+}
+
+impl Drop for SyncGuard {
+    fn drop(&mut self) {
+        self.syncs.lock().remove(&self.wallet_id);
+    }
 }
 
 // ============================================================================
@@ -82,6 +331,7 @@ pub async fn bitcoin_create_wallet(
     wallet_id: String,
     seed: Vec<u8>,
     account: Option<u32>,
+    script_type: Option<BitcoinScriptType>,
 ) -> Result<String> {
     info!("Creating Bitcoin wallet: {}", wallet_id);
 
@@ -96,14 +346,21 @@ pub async fn bitcoin_create_wallet(
         Error::Bitcoin("Failed to convert seed to fixed array".to_string())
     })?;
 
-    state
-        .adapter()
-        .create_wallet_from_seed(&seed_array, &wallet_id, account.unwrap_or(0))?;
+    state.adapter().create_wallet_from_seed(
+        &seed_array,
+        &wallet_id,
+        account.unwrap_or(0),
+        script_type.unwrap_or_default(),
+    )?;
 
     Ok(wallet_id)
 }
 
-/// Create a watch-only Bitcoin wallet from xpub
+/// Create a watch-only Bitcoin wallet
+///
+/// `xpub` may be an address, an extended public key (xpub, ypub, zpub...)
+/// or output descriptors, including Sparrow, Specter and Bitcoin Core
+/// exports. Keys must be for the configured network.
 #[tauri::command]
 pub async fn bitcoin_create_watch_wallet(
     state: State<'_, BitcoinState>,
@@ -117,18 +374,145 @@ pub async fn bitcoin_create_watch_wallet(
     Ok(wallet_id)
 }
 
+/// Export a Bitcoin wallet's public descriptors
+///
+/// Watch-only wallets export the descriptors they were imported with.
+#[tauri::command]
+pub async fn bitcoin_export_descriptors(
+    state: State<'_, BitcoinState>,
+    wallet_id: String,
+) -> Result<WalletDescriptors> {
+    debug!("Exporting descriptors of wallet: {}", wallet_id);
+
+    state
+        .wallets()
+        .with_wallet(&wallet_id, |_, wallet| {
+            Ok(BitcoinAdapter::wallet_descriptors(wallet))
+        })
+        .await
+}
+
 /// Sync a Bitcoin wallet with the blockchain
+///
+/// The first sync is a full scan; later syncs only refresh revealed
+/// addresses. Pass `rescan` to force a full scan (e.g. to discover
+/// addresses used by another wallet with the same seed).
+///
+/// Emits `SYNC_PROGRESS_EVENT` as it goes; `bitcoin_cancel_sync` stops
+/// it. Other commands on the wallet keep answering from the last synced
+/// state while the scan runs.
 #[tauri::command]
 pub async fn bitcoin_sync_wallet(
+    app: AppHandle,
     state: State<'_, BitcoinState>,
     wallet_id: String,
+    rescan: Option<bool>,
 ) -> Result<SyncProgress> {
     info!("Syncing Bitcoin wallet: {}", wallet_id);
 
-    let mut wallet = state.adapter().load_wallet(&wallet_id)?;
-    let progress = state.adapter().sync_wallet(&mut wallet)?;
+    let monitor = SyncMonitor::with_reporter({
+        let wallet_id = wallet_id.clone();
+        move |progress| {
+            let event = SyncProgressEvent {
+                wallet_id: wallet_id.clone(),
+                progress: progress.clone(),
+            };
+            if let Err(e) = app.emit(SYNC_PROGRESS_EVENT, event) {
+                warn!("Failed to emit sync progress: {}", e);
+            }
+        }
+    });
+    let _guard = state.start_sync(&wallet_id, monitor.clone())?;
 
-    Ok(progress)
+    state
+        .wallets()
+        .sync(&wallet_id, rescan.unwrap_or(false), &monitor)
+        .await
+}
+
+/// Cancel a running sync
+///
+/// Returns whether the wallet was syncing. The sync command then fails
+/// with a cancellation error.
+#[tauri::command]
+pub async fn bitcoin_cancel_sync(
+    state: State<'_, BitcoinState>,
+    wallet_id: String,
+) -> Result<bool> {
+    match state.syncs.lock().get(&wallet_id) {
+        Some(monitor) => {
+            info!("Cancelling sync for wallet: {}", wallet_id);
+            monitor.cancel();
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Start push updates for a wallet
+///
+/// Subscribes to the wallet's revealed addresses (or to `address` for a
+/// single-address watch wallet) and to new blocks on the configured
+/// Electrum server. Changes are synced as they happen and reported as
+/// `CHAIN_EVENT` events, so the wallet needn't be refreshed with
+/// `bitcoin_sync_wallet`. Sync the wallet once before watching it.
+#[tauri::command]
+pub async fn bitcoin_watch_wallet(
+    app: AppHandle,
+    state: State<'_, BitcoinState>,
+    wallet_id: String,
+    address: Option<String>,
+) -> Result<()> {
+    info!("Watching Bitcoin wallet: {}", wallet_id);
+
+    let watcher = state.watcher(&app).await?;
+    let (scripts, transactions) = match &address {
+        Some(address) => {
+            let script = state.adapter().address_script(address)?;
+            let adapter = state.adapter.clone();
+            let address = address.clone();
+            let transactions =
+                tokio::task::spawn_blocking(move || adapter.get_address_transactions(&address))
+                    .await
+                    .map_err(task_failed)??;
+            (vec![script], transactions)
+        }
+        None => {
+            state
+                .wallets()
+                .with_wallet(&wallet_id, |adapter, wallet| {
+                    Ok((
+                        adapter.revealed_scripts(wallet),
+                        adapter.get_transactions(wallet)?,
+                    ))
+                })
+                .await?
+        }
+    };
+
+    state.watched.lock().insert(
+        wallet_id.clone(),
+        WatchedWallet {
+            address,
+            snapshot: tx_snapshot(&transactions),
+        },
+    );
+    let added = watch_scripts(watcher, &wallet_id, scripts).await?;
+    debug!("Subscribed {} scripts of wallet {}", added, wallet_id);
+
+    Ok(())
+}
+
+/// Stop push updates for a wallet
+///
+/// Returns whether the wallet was watched.
+#[tauri::command]
+pub async fn bitcoin_unwatch_wallet(
+    state: State<'_, BitcoinState>,
+    wallet_id: String,
+) -> Result<bool> {
+    info!("Unwatching Bitcoin wallet: {}", wallet_id);
+    state.unwatch(&wallet_id).await
 }
 
 /// Get Bitcoin wallet balance
@@ -139,8 +523,10 @@ pub async fn bitcoin_get_balance(
 ) -> Result<BitcoinBalance> {
     debug!("Getting balance for wallet: {}", wallet_id);
 
-    let wallet = state.adapter().load_wallet(&wallet_id)?;
-    let balance = state.adapter().get_balance(&wallet)?;
+    let balance = state
+        .wallets()
+        .with_wallet(&wallet_id, |adapter, wallet| adapter.get_balance(wallet))
+        .await?;
 
     Ok(balance)
 }
@@ -153,8 +539,12 @@ pub async fn bitcoin_get_transactions(
 ) -> Result<Vec<BitcoinTransaction>> {
     debug!("Getting transactions for wallet: {}", wallet_id);
 
-    let wallet = state.adapter().load_wallet(&wallet_id)?;
-    let transactions = state.adapter().get_transactions(&wallet)?;
+    let transactions = state
+        .wallets()
+        .with_wallet(&wallet_id, |adapter, wallet| {
+            adapter.get_transactions(wallet)
+        })
+        .await?;
 
     Ok(transactions)
 }
@@ -167,8 +557,10 @@ pub async fn bitcoin_get_utxos(
 ) -> Result<Vec<UtxoInfo>> {
     debug!("Getting UTXOs for wallet: {}", wallet_id);
 
-    let wallet = state.adapter().load_wallet(&wallet_id)?;
-    let utxos = state.adapter().get_utxos(&wallet)?;
+    let utxos = state
+        .wallets()
+        .with_wallet(&wallet_id, |adapter, wallet| adapter.get_utxos(wallet))
+        .await?;
 
     Ok(utxos)
 }
@@ -181,9 +573,10 @@ pub async fn bitcoin_estimate_fee(
 ) -> Result<FeeEstimate> {
     debug!("Estimating fee for {} blocks", target_blocks.unwrap_or(6));
 
+    let target_blocks = target_blocks.unwrap_or(6);
     let estimate = state
-        .adapter()
-        .estimate_fee(target_blocks.unwrap_or(6))?;
+        .query(move |adapter| adapter.estimate_fee(target_blocks))
+        .await?;
 
     Ok(estimate)
 }
@@ -196,8 +589,15 @@ pub async fn bitcoin_get_new_address(
 ) -> Result<String> {
     debug!("Getting new address for wallet: {}", wallet_id);
 
-    let mut wallet = state.adapter().load_wallet(&wallet_id)?;
-    let address = state.adapter().get_new_address(&mut wallet)?;
+    let address = state
+        .wallets()
+        .with_wallet(&wallet_id, |adapter, wallet| {
+            adapter.get_new_address(wallet)
+        })
+        .await?;
+
+    let script = state.adapter().address_script(&address)?;
+    state.watch_more(&wallet_id, vec![script]).await?;
 
     Ok(address)
 }
@@ -208,17 +608,34 @@ pub async fn bitcoin_get_network(state: State<'_, BitcoinState>) -> Result<Bitco
     Ok(state.adapter().network())
 }
 
+/// Get the status of the configured Bitcoin backend servers
+///
+/// With `refresh`, every server is health-checked first (tip height and
+/// latency); otherwise the status observed by recent calls is returned.
+#[tauri::command]
+pub async fn bitcoin_get_backend_status(
+    state: State<'_, BitcoinState>,
+    refresh: Option<bool>,
+) -> Result<Vec<BackendStatus>> {
+    let backends = state.adapter().backends();
+
+    if refresh.unwrap_or(false) {
+        debug!("Checking health of {} Bitcoin backends", backends.len());
+        state
+            .query(|adapter| Ok(adapter.backends().check_health()))
+            .await
+    } else {
+        Ok(backends.status())
+    }
+}
+
 /// Check if a Bitcoin wallet exists
 #[tauri::command]
 pub async fn bitcoin_wallet_exists(
     state: State<'_, BitcoinState>,
     wallet_id: String,
 ) -> Result<bool> {
-    match state.adapter().load_wallet(&wallet_id) {
-        Ok(_) => Ok(true),
-        Err(Error::Bitcoin(msg)) if msg.contains("not found") => Ok(false),
-        Err(e) => Err(e),
-    }
+    Ok(state.adapter().wallet_exists(&wallet_id))
 }
 
 /// Initialize a Bitcoin wallet from the cached seed in WalletManager
@@ -230,6 +647,7 @@ pub async fn bitcoin_init_from_cached_seed(
     state: State<'_, BitcoinState>,
     wallet_id: String,
     account: Option<u32>,
+    script_type: Option<BitcoinScriptType>,
 ) -> Result<String> {
     info!("Initializing Bitcoin wallet from cached seed: {}", wallet_id);
 
@@ -241,9 +659,12 @@ pub async fn bitcoin_init_from_cached_seed(
         .map_err(|e| Error::Bitcoin(format!("Failed to get cached seed: {}", e)))?;
 
     // Create the BDK wallet
-    state
-        .adapter()
-        .create_wallet_from_seed(&seed, &wallet_id, account.unwrap_or(0))?;
+    state.adapter().create_wallet_from_seed(
+        &seed,
+        &wallet_id,
+        account.unwrap_or(0),
+        script_type.unwrap_or_default(),
+    )?;
 
     info!("Bitcoin wallet initialized: {}", wallet_id);
     Ok(wallet_id)
@@ -259,7 +680,9 @@ pub async fn bitcoin_get_address_balance(
 ) -> Result<BitcoinBalance> {
     info!("Getting balance for address: {}", address);
 
-    let balance = state.adapter().get_address_balance(&address)?;
+    let balance = state
+        .query(move |adapter| adapter.get_address_balance(&address))
+        .await?;
 
     Ok(balance)
 }
@@ -274,7 +697,27 @@ pub async fn bitcoin_get_address_transactions(
 ) -> Result<Vec<BitcoinTransaction>> {
     info!("Getting transactions for address: {}", address);
 
-    let transactions = state.adapter().get_address_transactions(&address)?;
+    let transactions = state
+        .query(move |adapter| adapter.get_address_transactions(&address))
+        .await?;
+
+    Ok(transactions)
+}
+
+/// Get the combined transaction history of several watched addresses
+///
+/// Transactions shared by the addresses are listed once, and transfers
+/// between them are internal.
+#[tauri::command]
+pub async fn bitcoin_get_addresses_transactions(
+    state: State<'_, BitcoinState>,
+    addresses: Vec<String>,
+) -> Result<Vec<BitcoinTransaction>> {
+    info!("Getting transactions for {} addresses", addresses.len());
+
+    let transactions = state
+        .query(move |adapter| adapter.get_addresses_transactions(&addresses))
+        .await?;
 
     Ok(transactions)
 }
@@ -297,15 +740,18 @@ pub async fn bitcoin_send_transaction(
         amount_sats, wallet_id, recipient_address, fee_rate
     );
 
-    let mut wallet = state.adapter().load_wallet(&wallet_id)?;
-
-    let result = state.adapter().create_and_send_transaction(
-        &mut wallet,
-        &recipient_address,
-        amount_sats,
-        fee_rate,
-        broadcast.unwrap_or(true),
-    )?;
+    let result = state
+        .wallets()
+        .with_wallet(&wallet_id, move |adapter, wallet| {
+            adapter.create_and_send_transaction(
+                wallet,
+                &recipient_address,
+                amount_sats,
+                fee_rate,
+                broadcast.unwrap_or(true),
+            )
+        })
+        .await?;
 
     Ok(result)
 }
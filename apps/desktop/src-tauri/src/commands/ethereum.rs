@@ -5,14 +5,21 @@
 //!
 //! The frontend uses Viem for RPC calls (balances, gas estimation, etc.)
 //! and routes signing requests to these commands.
+//!
+//! Message signing goes through the chain-generic `ChainSigner` of the
+//! `ethereum` module; these commands only adapt the result to the
+//! `{signature, v, r, s}` shape Viem expects. EIP-712 and transaction hashes
+//! are computed by Viem and signed as pre-hashed digests.
 
-use k256::ecdsa::{Signature, SigningKey};
 use serde::{Deserialize, Serialize};
-use sha3::{Digest, Keccak256};
-use tauri::State;
+use zeroize::Zeroizing;
+
+use crate::wallet::chains::{EthereumModule, DEFAULT_SCHEME};
 
-use crate::wallet::chains::secp256k1::derive_key_from_seed;
-use crate::wallet::core::WalletManager;
+use super::wallet::get_wallet_manager;
+
+/// Registry ID of the module used for key derivation (all EVM chains share it)
+const ETHEREUM_CHAIN_ID: &str = "ethereum";
 
 /// Ethereum transaction request from frontend
 #[derive(Debug, Clone, Deserialize)]
@@ -67,40 +74,61 @@ pub struct MessageSignature {
     pub s: String,
 }
 
-/// Wallet state wrapper for Tauri
-pub struct EthereumState {
-    pub wallet_manager: WalletManager,
+impl MessageSignature {
+    /// Split a 65-byte `r || s || v` signature into its components
+    fn from_bytes(bytes: &[u8; 65]) -> Self {
+        Self {
+            signature: format!("0x{}", hex::encode(bytes)),
+            v: bytes[64],
+            r: format!("0x{}", hex::encode(&bytes[..32])),
+            s: format!("0x{}", hex::encode(&bytes[32..64])),
+        }
+    }
 }
 
-impl EthereumState {
-    pub fn new(wallet_manager: WalletManager) -> Self {
-        Self { wallet_manager }
-    }
+/// Parse a 0x-prefixed 32-byte hash
+fn parse_hash(hash: &str) -> Result<[u8; 32], String> {
+    let hash_bytes =
+        hex::decode(hash.trim_start_matches("0x")).map_err(|e| format!("Invalid hash: {}", e))?;
+
+    hash_bytes
+        .try_into()
+        .map_err(|_| "Hash must be 32 bytes".to_string())
 }
 
-/// Get the Ethereum private key for a wallet
-fn get_ethereum_private_key(
-    wallet_manager: &WalletManager,
+/// Sign a pre-computed hash with the wallet's Ethereum key
+///
+/// Returns `v` as 27/28, as used by both EIP-712 and legacy transactions.
+fn sign_hash(
     wallet_id: &str,
+    hash: &str,
     account_index: u32,
     address_index: u32,
-) -> Result<SigningKey, String> {
-    // Get the seed from storage
-    let storage = wallet_manager.storage();
-    let seed = storage
-        .get_seed(wallet_id)
-        .map_err(|e| format!("Failed to get seed: {}", e))?;
-
-    // Derive the Ethereum key using BIP44 path
-    // m/44'/60'/account'/0/index
-    let path = format!("m/44'/60'/{}'/0/{}", account_index, address_index);
-    let derived = derive_key_from_seed(&seed, &path)
-        .map_err(|e| format!("Failed to derive key: {}", e))?;
-
-    // Convert to k256 SigningKey
-    let private_bytes = derived.private_key().to_bytes();
-    SigningKey::from_bytes((&private_bytes).into())
-        .map_err(|e| format!("Failed to create signing key: {}", e))
+) -> Result<MessageSignature, String> {
+    let manager = get_wallet_manager();
+    let hash = parse_hash(hash)?;
+
+    let seed = Zeroizing::new(
+        manager
+            .storage()
+            .get_seed(wallet_id)
+            .map_err(|e| format!("Failed to get seed: {}", e))?,
+    );
+    let path = manager
+        .registry()
+        .scheme_path(
+            ETHEREUM_CHAIN_ID,
+            DEFAULT_SCHEME,
+            account_index,
+            address_index,
+        )
+        .map_err(|e| e.to_string())?;
+
+    let mut signature =
+        EthereumModule::sign_prehash(&seed, &path, &hash).map_err(|e| e.to_string())?;
+    signature[64] += 27;
+
+    Ok(MessageSignature::from_bytes(&signature))
 }
 
 /// Sign an Ethereum personal message (EIP-191)
@@ -109,7 +137,6 @@ fn get_ethereum_private_key(
 /// before hashing and signing.
 #[tauri::command]
 pub async fn ethereum_sign_message(
-    state: State<'_, EthereumState>,
     wallet_id: String,
     message: String,
     account_index: Option<u32>,
@@ -125,44 +152,23 @@ pub async fn ethereum_sign_message(
         address_idx
     );
 
-    // Get the signing key
-    let signing_key = get_ethereum_private_key(
-        &state.wallet_manager,
-        &wallet_id,
-        account_idx,
-        address_idx,
-    )?;
-
-    // Create the EIP-191 prefixed message
-    let prefix = format!("\x19Ethereum Signed Message:\n{}", message.len());
-    let prefixed_message = [prefix.as_bytes(), message.as_bytes()].concat();
-
-    // Hash with Keccak256
-    let mut hasher = Keccak256::new();
-    hasher.update(&prefixed_message);
-    let hash = hasher.finalize();
-
-    // Sign the hash
-    let (signature, recovery_id): (Signature, _) = signing_key
-        .sign_prehash_recoverable(&hash)
-        .map_err(|e| format!("Failed to sign: {}", e))?;
-
-    let r = signature.r();
-    let s = signature.s();
-    let v = recovery_id.to_byte() + 27; // Ethereum uses 27/28 for v
-
-    // Combine into 65-byte signature
-    let mut sig_bytes = [0u8; 65];
-    sig_bytes[0..32].copy_from_slice(&r.to_bytes());
-    sig_bytes[32..64].copy_from_slice(&s.to_bytes());
-    sig_bytes[64] = v;
-
-    Ok(MessageSignature {
-        signature: format!("0x{}", hex::encode(sig_bytes)),
-        v,
-        r: format!("0x{}", hex::encode(r.to_bytes())),
-        s: format!("0x{}", hex::encode(s.to_bytes())),
-    })
+    let signed = get_wallet_manager()
+        .sign_message(
+            &wallet_id,
+            ETHEREUM_CHAIN_ID,
+            DEFAULT_SCHEME,
+            account_idx,
+            address_idx,
+            message.as_bytes(),
+        )
+        .map_err(|e| e.to_string())?;
+
+    let signature: [u8; 65] = hex::decode(signed.signature.trim_start_matches("0x"))
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or("Unexpected signature length")?;
+
+    Ok(MessageSignature::from_bytes(&signature))
 }
 
 /// Sign typed data (EIP-712)
@@ -170,7 +176,6 @@ pub async fn ethereum_sign_message(
 /// The frontend should compute the EIP-712 hash and pass it here.
 #[tauri::command]
 pub async fn ethereum_sign_typed_data(
-    state: State<'_, EthereumState>,
     wallet_id: String,
     hash: String, // Pre-computed EIP-712 hash from frontend
     account_index: Option<u32>,
@@ -186,46 +191,7 @@ pub async fn ethereum_sign_typed_data(
         address_idx
     );
 
-    // Get the signing key
-    let signing_key = get_ethereum_private_key(
-        &state.wallet_manager,
-        &wallet_id,
-        account_idx,
-        address_idx,
-    )?;
-
-    // Parse the hash (should be 32 bytes)
-    let hash_bytes = hex::decode(hash.trim_start_matches("0x"))
-        .map_err(|e| format!("Invalid hash: {}", e))?;
-
-    if hash_bytes.len() != 32 {
-        return Err("Hash must be 32 bytes".to_string());
-    }
-
-    let hash_array: [u8; 32] = hash_bytes
-        .try_into()
-        .map_err(|_| "Failed to convert hash")?;
-
-    // Sign the hash
-    let (signature, recovery_id): (Signature, _) = signing_key
-        .sign_prehash_recoverable(&hash_array)
-        .map_err(|e| format!("Failed to sign: {}", e))?;
-
-    let r = signature.r();
-    let s = signature.s();
-    let v = recovery_id.to_byte() + 27;
-
-    let mut sig_bytes = [0u8; 65];
-    sig_bytes[0..32].copy_from_slice(&r.to_bytes());
-    sig_bytes[32..64].copy_from_slice(&s.to_bytes());
-    sig_bytes[64] = v;
-
-    Ok(MessageSignature {
-        signature: format!("0x{}", hex::encode(sig_bytes)),
-        v,
-        r: format!("0x{}", hex::encode(r.to_bytes())),
-        s: format!("0x{}", hex::encode(s.to_bytes())),
-    })
+    sign_hash(&wallet_id, &hash, account_idx, address_idx)
 }
 
 /// Sign a raw transaction hash
@@ -234,7 +200,6 @@ pub async fn ethereum_sign_typed_data(
 /// the hash. We sign the hash here and return the signature components.
 #[tauri::command]
 pub async fn ethereum_sign_transaction_hash(
-    state: State<'_, EthereumState>,
     wallet_id: String,
     hash: String, // Transaction hash to sign
     account_index: Option<u32>,
@@ -250,46 +215,7 @@ pub async fn ethereum_sign_transaction_hash(
         address_idx
     );
 
-    // Get the signing key
-    let signing_key = get_ethereum_private_key(
-        &state.wallet_manager,
-        &wallet_id,
-        account_idx,
-        address_idx,
-    )?;
-
-    // Parse the hash
-    let hash_bytes = hex::decode(hash.trim_start_matches("0x"))
-        .map_err(|e| format!("Invalid hash: {}", e))?;
-
-    if hash_bytes.len() != 32 {
-        return Err("Hash must be 32 bytes".to_string());
-    }
-
-    let hash_array: [u8; 32] = hash_bytes
-        .try_into()
-        .map_err(|_| "Failed to convert hash")?;
-
-    // Sign
-    let (signature, recovery_id): (Signature, _) = signing_key
-        .sign_prehash_recoverable(&hash_array)
-        .map_err(|e| format!("Failed to sign: {}", e))?;
-
-    let r = signature.r();
-    let s = signature.s();
-    let v = recovery_id.to_byte() + 27;
-
-    let mut sig_bytes = [0u8; 65];
-    sig_bytes[0..32].copy_from_slice(&r.to_bytes());
-    sig_bytes[32..64].copy_from_slice(&s.to_bytes());
-    sig_bytes[64] = v;
-
-    Ok(MessageSignature {
-        signature: format!("0x{}", hex::encode(sig_bytes)),
-        v,
-        r: format!("0x{}", hex::encode(r.to_bytes())),
-        s: format!("0x{}", hex::encode(s.to_bytes())),
-    })
+    sign_hash(&wallet_id, &hash, account_idx, address_idx)
 }
 
 /// Get the Ethereum address for a wallet
@@ -297,92 +223,25 @@ pub async fn ethereum_sign_transaction_hash(
 /// Returns the address derived from the wallet's seed.
 #[tauri::command]
 pub async fn ethereum_get_address(
-    state: State<'_, EthereumState>,
     wallet_id: String,
     account_index: Option<u32>,
     address_index: Option<u32>,
 ) -> Result<String, String> {
-    let account_idx = account_index.unwrap_or(0);
-    let address_idx = address_index.unwrap_or(0);
-
-    // Get the signing key
-    let signing_key = get_ethereum_private_key(
-        &state.wallet_manager,
-        &wallet_id,
-        account_idx,
-        address_idx,
-    )?;
-
-    // Get the public key
-    let verifying_key = signing_key.verifying_key();
-    let public_key_bytes = verifying_key.to_encoded_point(false);
-    let public_key_uncompressed = &public_key_bytes.as_bytes()[1..]; // Skip the 0x04 prefix
-
-    // Hash with Keccak256 and take last 20 bytes
-    let mut hasher = Keccak256::new();
-    hasher.update(public_key_uncompressed);
-    let hash = hasher.finalize();
-    let address_bytes = &hash[12..32];
-
-    // Convert to checksummed address
-    let address_hex = hex::encode(address_bytes);
-    let checksummed = to_checksum_address(&address_hex);
-
-    Ok(checksummed)
-}
-
-/// Convert address to EIP-55 checksum format
-fn to_checksum_address(address: &str) -> String {
-    let address_lower = address.to_lowercase();
-
-    // Hash the lowercase address
-    let mut hasher = Keccak256::new();
-    hasher.update(address_lower.as_bytes());
-    let hash = hasher.finalize();
-    let hash_hex = hex::encode(hash);
-
-    // Apply checksum
-    let mut result = String::with_capacity(42);
-    result.push_str("0x");
-
-    for (i, c) in address_lower.chars().enumerate() {
-        if c.is_ascii_hexdigit() && !c.is_ascii_digit() {
-            let hash_char = hash_hex.chars().nth(i).unwrap();
-            if hash_char.to_digit(16).unwrap() >= 8 {
-                result.push(c.to_ascii_uppercase());
-            } else {
-                result.push(c);
-            }
-        } else {
-            result.push(c);
-        }
-    }
-
-    result
+    get_wallet_manager()
+        .derive_address(
+            &wallet_id,
+            ETHEREUM_CHAIN_ID,
+            account_index.unwrap_or(0),
+            address_index.unwrap_or(0),
+        )
+        .map(|derived| derived.address)
+        .map_err(|e| e.to_string())
 }
 
 /// Validate an Ethereum address
 #[tauri::command]
 pub async fn ethereum_validate_address(address: String) -> Result<bool, String> {
-    // Basic format check
-    if !address.starts_with("0x") || address.len() != 42 {
-        return Ok(false);
-    }
-
-    // Check if it's valid hex
-    let addr_bytes = hex::decode(&address[2..]);
-    if addr_bytes.is_err() {
-        return Ok(false);
-    }
-
-    // If mixed case, verify checksum
-    let addr_without_prefix = &address[2..];
-    if addr_without_prefix != addr_without_prefix.to_lowercase()
-        && addr_without_prefix != addr_without_prefix.to_uppercase()
-    {
-        let checksummed = to_checksum_address(addr_without_prefix);
-        return Ok(checksummed == address);
-    }
-
-    Ok(true)
+    get_wallet_manager()
+        .validate_address(ETHEREUM_CHAIN_ID, &address)
+        .map_err(|e| e.to_string())
 }
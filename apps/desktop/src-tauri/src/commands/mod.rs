@@ -1,8 +1,10 @@
 pub mod bitcoin;
 pub mod ethereum;
 pub mod etherscan;
+pub mod networks;
 pub mod store_sync;
 pub mod wallet;
+pub mod watch_only;
 
 use crate::db::Database;
 use crate::Result;
@@ -15,15 +17,21 @@ pub use wallet::*;
 // Re-export bitcoin commands and state
 pub use bitcoin::*;
 
-// Re-export ethereum commands and state
+// Re-export ethereum commands
 pub use ethereum::*;
 
 // Re-export etherscan commands
 pub use etherscan::*;
 
+// Re-export custom network commands
+pub use networks::*;
+
 // Re-export store sync commands
 pub use store_sync::*;
 
+// Re-export watch-only key commands
+pub use watch_only::*;
+
 // ============================================================================
 // Types
 // ============================================================================
@@ -187,9 +195,21 @@ pub async fn add_wallet(db: State<'_, Database>, request: AddWalletRequest) -> R
 }
 
 #[tauri::command]
-pub async fn remove_wallet(db: State<'_, Database>, wallet_id: String) -> Result<()> {
+pub async fn remove_wallet(
+    db: State<'_, Database>,
+    bitcoin: State<'_, BitcoinState>,
+    wallet_id: String,
+) -> Result<()> {
+    bitcoin.unwatch(&wallet_id).await?;
+    bitcoin.wallets().evict(&wallet_id).await;
+    // Otherwise its xpubs would be registered again at startup
+    get_wallet_manager().remove_watch_wallet(&wallet_id);
     db.execute(|conn| {
         conn.execute("DELETE FROM wallets WHERE id = ?", [&wallet_id])?;
+        conn.execute(
+            "DELETE FROM watch_only_keys WHERE wallet_id = ?",
+            [&wallet_id],
+        )?;
         Ok(())
     })
 }
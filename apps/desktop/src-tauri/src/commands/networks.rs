@@ -0,0 +1,151 @@
+//! Custom EVM network commands
+//!
+//! User-defined EVM networks are persisted in SQLite and registered with the
+//! `ChainRegistry` at runtime, so new L2s can be added without a rebuild.
+
+use rusqlite::params;
+use tauri::State;
+use tracing::{debug, info};
+
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::wallet::chains::EvmNetwork;
+use crate::wallet::registry::ChainInfo;
+
+use super::wallet::get_wallet_manager;
+
+/// Read all custom EVM networks from SQLite
+fn read_custom_evm_networks(db: &Database) -> Result<Vec<EvmNetwork>> {
+    db.execute(|conn| {
+        let mut stmt = conn.prepare(
+            "SELECT id, name, symbol, chain_id, decimals, rpc_urls, explorer_url, is_testnet
+             FROM custom_evm_networks
+             ORDER BY created_at",
+        )?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, i64>(3)?,
+                    row.get::<_, i64>(4)?,
+                    row.get::<_, String>(5)?,
+                    row.get::<_, Option<String>>(6)?,
+                    row.get::<_, i32>(7)?,
+                ))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        rows.into_iter()
+            .map(
+                |(id, name, symbol, chain_id, decimals, rpc_urls, explorer_url, is_testnet)|
+                 -> Result<EvmNetwork> {
+                    Ok(EvmNetwork {
+                        id,
+                        name,
+                        symbol,
+                        chain_id: chain_id as u64,
+                        decimals: decimals as u8,
+                        rpc_urls: serde_json::from_str(&rpc_urls)?,
+                        explorer_url,
+                        is_testnet: is_testnet == 1,
+                        is_custom: true,
+                    })
+                },
+            )
+            .collect()
+    })
+}
+
+/// Load custom EVM networks from SQLite into the chain registry
+///
+/// Called once at startup, after the database is initialized.
+pub fn load_custom_evm_networks(db: &Database) -> Result<usize> {
+    let networks = read_custom_evm_networks(db)?;
+    let loaded = get_wallet_manager()
+        .registry()
+        .reload_evm_networks(networks);
+
+    info!("Registered {} custom EVM network(s)", loaded);
+    Ok(loaded)
+}
+
+/// List user-defined EVM networks
+#[tauri::command]
+pub async fn get_custom_evm_networks(db: State<'_, Database>) -> Result<Vec<EvmNetwork>> {
+    debug!("Loading custom EVM networks");
+    read_custom_evm_networks(&db)
+}
+
+/// Add or update a user-defined EVM network
+///
+/// The network is checked, persisted and only then registered, so a
+/// failed write leaves any previous definition of it in place.
+#[tauri::command]
+pub async fn add_custom_evm_network(
+    db: State<'_, Database>,
+    network: EvmNetwork,
+) -> Result<ChainInfo> {
+    info!(
+        "Adding custom EVM network: {} ({})",
+        network.id, network.chain_id
+    );
+
+    let registry = get_wallet_manager().registry();
+    registry
+        .check_custom_evm_network(&network)
+        .map_err(|e| Error::InvalidInput(e.to_string()))?;
+
+    let rpc_urls = serde_json::to_string(&network.rpc_urls)?;
+    db.execute(|conn| {
+        conn.execute(
+            "INSERT INTO custom_evm_networks
+                (id, name, symbol, chain_id, decimals, rpc_urls, explorer_url, is_testnet)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+             ON CONFLICT(id) DO UPDATE SET
+                name = excluded.name,
+                symbol = excluded.symbol,
+                chain_id = excluded.chain_id,
+                decimals = excluded.decimals,
+                rpc_urls = excluded.rpc_urls,
+                explorer_url = excluded.explorer_url,
+                is_testnet = excluded.is_testnet,
+                updated_at = datetime('now')",
+            params![
+                network.id,
+                network.name,
+                network.symbol,
+                network.chain_id as i64,
+                network.decimals,
+                rpc_urls,
+                network.explorer_url,
+                if network.is_testnet { 1 } else { 0 },
+            ],
+        )?;
+        Ok(())
+    })?;
+
+    registry
+        .register_evm_network(network)
+        .map_err(|e| Error::InvalidInput(e.to_string()))
+}
+
+/// Remove a user-defined EVM network
+#[tauri::command]
+pub async fn remove_custom_evm_network(db: State<'_, Database>, chain_id: String) -> Result<()> {
+    info!("Removing custom EVM network: {}", chain_id);
+
+    // Delete before unregistering, so a failed delete leaves the network
+    // registered as it will be after the next reload
+    db.execute(|conn| {
+        conn.execute("DELETE FROM custom_evm_networks WHERE id = ?1", [&chain_id])?;
+        Ok(())
+    })?;
+
+    get_wallet_manager()
+        .registry()
+        .remove_evm_network(&chain_id)
+        .map_err(|e| Error::NotFound(e.to_string()))
+}
@@ -7,10 +7,17 @@ use std::sync::OnceLock;
 
 use tauri::State;
 
+use super::bitcoin::BitcoinState;
+use crate::wallet::bip85::{Bip85Application, Bip85Child, Bip85Language};
+use crate::wallet::chains::{
+    ChainSignature, DerivationSchemeInfo, NostrEvent, UnsignedEvent, DEFAULT_SCHEME,
+};
 use crate::wallet::core::WalletManager;
+use crate::wallet::discovery::{self, ActivityProbe, SchemeScanResult, DEFAULT_SCAN_ACCOUNTS};
+use crate::wallet::inspect::AddressInspection;
 use crate::wallet::registry::ChainInfo;
 use crate::wallet::types::{
-    CreateHDWalletRequest, CreateHDWalletResponse, DerivedAddress,
+    AddressValidation, CreateHDWalletRequest, CreateHDWalletResponse, DerivedAddress,
     ImportHDWalletRequest, ValidateMnemonicResponse,
 };
 
@@ -46,6 +53,25 @@ pub fn validate_chain_address(chain_id: String, address: String) -> Result<bool,
         .map_err(|e| e.to_string())
 }
 
+/// Validate an address for a specific chain and report its address type
+#[tauri::command]
+pub fn validate_chain_address_details(
+    chain_id: String,
+    address: String,
+) -> Result<AddressValidation, String> {
+    get_wallet_manager()
+        .validate_address_details(&chain_id, &address)
+        .map_err(|e| e.to_string())
+}
+
+/// Detect the chains, networks and address types of a pasted string
+///
+/// Also recognizes extended public/private keys, output descriptors and ENS names.
+#[tauri::command]
+pub fn inspect_address(input: String) -> AddressInspection {
+    get_wallet_manager().inspect_address(&input)
+}
+
 // =============================================================================
 // Mnemonic Commands
 // =============================================================================
@@ -126,6 +152,254 @@ pub fn derive_wallet_address(
         .map_err(|e| e.to_string())
 }
 
+/// Most addresses a single range derivation may return
+const MAX_ADDRESS_RANGE: u32 = 1000;
+
+/// Derive the addresses at indices `start..end` of a wallet account
+///
+/// Uses the session's cached account node, so large ranges (gap-limit
+/// scans, address lists) stay fast. Ranges are capped at
+/// `MAX_ADDRESS_RANGE` addresses and run off the main thread.
+#[tauri::command(async)]
+pub fn derive_wallet_address_range(
+    wallet_id: String,
+    chain_id: String,
+    account: Option<u32>,
+    start: u32,
+    end: u32,
+) -> Result<Vec<DerivedAddress>, String> {
+    if end <= start {
+        return Err(format!("Invalid address range {}..{}", start, end));
+    }
+    if end - start > MAX_ADDRESS_RANGE {
+        return Err(format!(
+            "Address range too large: {} addresses (max {})",
+            end - start,
+            MAX_ADDRESS_RANGE
+        ));
+    }
+
+    get_wallet_manager()
+        .derive_range(&wallet_id, &chain_id, account.unwrap_or(0), start..end)
+        .map_err(|e| e.to_string())
+}
+
+// =============================================================================
+// Derivation Scheme Commands
+// =============================================================================
+
+/// List the derivation schemes available for a chain (default first)
+#[tauri::command]
+pub fn get_derivation_schemes(chain_id: String) -> Result<Vec<DerivationSchemeInfo>, String> {
+    get_wallet_manager()
+        .get_derivation_schemes(&chain_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Derive an address for an existing wallet using a named derivation scheme
+#[tauri::command]
+pub fn derive_wallet_address_with_scheme(
+    wallet_id: String,
+    chain_id: String,
+    scheme: String,
+    account: Option<u32>,
+    index: Option<u32>,
+) -> Result<DerivedAddress, String> {
+    get_wallet_manager()
+        .derive_address_with_scheme(
+            &wallet_id,
+            &chain_id,
+            &scheme,
+            account.unwrap_or(0),
+            index.unwrap_or(0),
+        )
+        .map_err(|e| e.to_string())
+}
+
+/// Most accounts a scheme scan may derive per scheme
+const MAX_SCAN_ACCOUNTS: u32 = 1000;
+
+/// Scan every derivation scheme of the given chains for on-chain activity
+///
+/// Intended to run right after import: derives the first `accounts`
+/// accounts of each scheme and reports which addresses hold funds or
+/// have history, so the user can pick the scheme their old wallet used.
+/// `accounts` is capped at `MAX_SCAN_ACCOUNTS`.
+#[tauri::command]
+pub async fn scan_derivation_schemes(
+    wallet_id: String,
+    chain_ids: Vec<String>,
+    accounts: Option<u32>,
+) -> Result<Vec<SchemeScanResult>, String> {
+    let manager = get_wallet_manager();
+    let accounts = accounts.unwrap_or(DEFAULT_SCAN_ACCOUNTS);
+    if accounts > MAX_SCAN_ACCOUNTS {
+        return Err(format!(
+            "Too many accounts to scan: {} (max {})",
+            accounts, MAX_SCAN_ACCOUNTS
+        ));
+    }
+    let client = discovery::scan_client().map_err(|e| e.to_string())?;
+
+    let mut results = Vec::new();
+    for chain_id in &chain_ids {
+        // Reads the seed and derives every scheme, so keep it off the runtime
+        let candidates = {
+            let (wallet_id, chain_id) = (wallet_id.clone(), chain_id.clone());
+            tokio::task::spawn_blocking(move || {
+                manager.scheme_candidates(&wallet_id, &chain_id, accounts)
+            })
+            .await
+            .map_err(|e| format!("Scheme scan failed: {}", e))?
+            .map_err(|e| e.to_string())?
+        };
+        let probe = manager
+            .registry()
+            .get(chain_id)
+            .and_then(|module| ActivityProbe::for_module(module.as_ref()));
+
+        results.extend(discovery::scan_candidates(&client, probe, candidates).await);
+    }
+
+    Ok(results)
+}
+
+// =============================================================================
+// Signing Commands
+// =============================================================================
+
+/// Sign a message with a wallet address using the chain's native scheme
+///
+/// EVM chains use EIP-191, Bitcoin uses BIP-322 (BIP-137 for legacy
+/// addresses) and Solana uses off-chain message signing.
+#[tauri::command]
+pub fn sign_chain_message(
+    wallet_id: String,
+    chain_id: String,
+    message: String,
+    account: Option<u32>,
+    index: Option<u32>,
+    scheme: Option<String>,
+) -> Result<ChainSignature, String> {
+    get_wallet_manager()
+        .sign_message(
+            &wallet_id,
+            &chain_id,
+            scheme.as_deref().unwrap_or(DEFAULT_SCHEME),
+            account.unwrap_or(0),
+            index.unwrap_or(0),
+            message.as_bytes(),
+        )
+        .map_err(|e| e.to_string())
+}
+
+/// Sign a hex-encoded transaction payload
+///
+/// The payload is the unsigned transaction for EVM chains, a serialized
+/// PSBT for Bitcoin and the message bytes for Solana.
+#[tauri::command]
+pub fn sign_chain_transaction(
+    wallet_id: String,
+    chain_id: String,
+    payload: String,
+    account: Option<u32>,
+    index: Option<u32>,
+    scheme: Option<String>,
+) -> Result<ChainSignature, String> {
+    let payload = hex::decode(payload.trim_start_matches("0x"))
+        .map_err(|e| format!("Invalid payload hex: {}", e))?;
+
+    get_wallet_manager()
+        .sign_transaction(
+            &wallet_id,
+            &chain_id,
+            scheme.as_deref().unwrap_or(DEFAULT_SCHEME),
+            account.unwrap_or(0),
+            index.unwrap_or(0),
+            &payload,
+        )
+        .map_err(|e| e.to_string())
+}
+
+/// Verify a message signature produced by `sign_chain_message`
+#[tauri::command]
+pub fn verify_chain_message(
+    chain_id: String,
+    address: String,
+    message: String,
+    signature: String,
+) -> Result<bool, String> {
+    get_wallet_manager()
+        .verify_message(&chain_id, &address, message.as_bytes(), &signature)
+        .map_err(|e| e.to_string())
+}
+
+// =============================================================================
+// Nostr Commands
+// =============================================================================
+
+/// Sign a Nostr event with the wallet's NIP-06 identity
+///
+/// The identity's `npub` is the wallet's address on the `nostr` chain.
+#[tauri::command]
+pub fn sign_nostr_event(
+    wallet_id: String,
+    event: UnsignedEvent,
+    account: Option<u32>,
+) -> Result<NostrEvent, String> {
+    get_wallet_manager()
+        .sign_nostr_event(&wallet_id, account.unwrap_or(0), &event)
+        .map_err(|e| e.to_string())
+}
+
+/// Export the wallet's NIP-06 identity key as `nsec` (for other Nostr clients)
+#[tauri::command]
+pub fn export_nostr_nsec(wallet_id: String, account: Option<u32>) -> Result<String, String> {
+    get_wallet_manager()
+        .export_nostr_nsec(&wallet_id, account.unwrap_or(0))
+        .map(|nsec| nsec.as_str().to_string())
+        .map_err(|e| e.to_string())
+}
+
+// =============================================================================
+// BIP-85 Commands
+// =============================================================================
+
+/// Derive a BIP-85 child secret from an unlocked wallet
+///
+/// The value (mnemonic, WIF, xprv, hex or password) is returned for display
+/// and is never stored.
+#[tauri::command]
+pub fn derive_bip85_child(
+    wallet_id: String,
+    application: Bip85Application,
+    index: Option<u32>,
+) -> Result<Bip85Child, String> {
+    get_wallet_manager()
+        .derive_bip85_child(&wallet_id, application, index.unwrap_or(0))
+        .map_err(|e| e.to_string())
+}
+
+/// Create a new HD wallet from a BIP-85 child mnemonic of an unlocked wallet
+#[tauri::command]
+pub fn import_bip85_wallet(
+    parent_wallet_id: String,
+    language: Option<Bip85Language>,
+    words: Option<u32>,
+    index: u32,
+    chains: Vec<String>,
+) -> Result<CreateHDWalletResponse, String> {
+    get_wallet_manager()
+        .import_bip85_wallet(
+            &parent_wallet_id,
+            language.unwrap_or_default(),
+            words.unwrap_or(12),
+            index,
+            &chains,
+        )
+        .map_err(|e| e.to_string())
+}
+
 // =============================================================================
 // Session Management Commands
 // =============================================================================
@@ -137,9 +411,14 @@ pub fn is_wallet_unlocked() -> bool {
 }
 
 /// Lock the wallet (clear cached secrets)
+///
+/// Also drops the loaded Bitcoin wallets, which hold signing keys.
 #[tauri::command]
-pub fn lock_wallet() {
+pub async fn lock_wallet(bitcoin: State<'_, BitcoinState>) -> Result<(), String> {
     get_wallet_manager().lock();
+    bitcoin.stop_watching();
+    bitcoin.wallets().clear().await;
+    Ok(())
 }
 
 /// Unlock a wallet with password
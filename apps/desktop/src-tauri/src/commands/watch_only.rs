@@ -0,0 +1,121 @@
+//! Watch-only account key commands
+//!
+//! Account-level xpubs of watch-only wallets are persisted in SQLite and
+//! attached to the `WalletManager` at runtime, so receive addresses can be
+//! derived for hardware or external wallets without any secrets.
+
+use rusqlite::{params, OptionalExtension};
+use tauri::State;
+use tracing::{info, warn};
+
+use crate::db::Database;
+use crate::error::{Error, Result};
+use crate::wallet::types::DerivedAddress;
+
+use super::wallet::get_wallet_manager;
+
+/// Load watch-only account keys from SQLite into the wallet manager
+///
+/// Called once at startup, after the database is initialized. Keys that no
+/// longer parse (e.g. for a removed custom chain) are skipped.
+pub fn load_watch_only_keys(db: &Database) -> Result<usize> {
+    let rows = db.execute(|conn| {
+        let mut stmt = conn.prepare("SELECT wallet_id, chain, xpub FROM watch_only_keys")?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(rows)
+    })?;
+
+    let manager = get_wallet_manager();
+    let mut loaded = 0;
+    for (wallet_id, chain_id, xpub) in rows {
+        match manager.add_watch_xpub(&wallet_id, &chain_id, &xpub) {
+            Ok(_) => loaded += 1,
+            Err(e) => warn!(
+                "Skipping watch-only key for {} on {}: {}",
+                wallet_id, chain_id, e
+            ),
+        }
+    }
+
+    info!("Registered {} watch-only account key(s)", loaded);
+    Ok(loaded)
+}
+
+/// Attach an account xpub to a watch-only wallet
+///
+/// Only wallets stored as watch-only accept keys. The key is validated
+/// against the chain (network and SLIP-132 script type), stored, and
+/// only then registered with the wallet manager, so the session never
+/// holds a key the database lacks. Returns the first receive address.
+#[tauri::command]
+pub async fn add_watch_only_xpub(
+    db: State<'_, Database>,
+    wallet_id: String,
+    chain_id: String,
+    xpub: String,
+) -> Result<DerivedAddress> {
+    info!("Adding watch-only xpub for {} on {}", wallet_id, chain_id);
+
+    let is_watch_only = db.execute(|conn| {
+        let flag = conn
+            .query_row(
+                "SELECT is_watch_only FROM wallets WHERE id = ?1",
+                [&wallet_id],
+                |row| row.get::<_, i32>(0),
+            )
+            .optional()?;
+        Ok(flag)
+    })?;
+    match is_watch_only {
+        Some(1) => {}
+        Some(_) => {
+            return Err(Error::InvalidInput(format!(
+                "Wallet {} is not watch-only",
+                wallet_id
+            )))
+        }
+        None => return Err(Error::NotFound(format!("Wallet {}", wallet_id))),
+    }
+
+    let xpub = xpub.trim().to_string();
+    let manager = get_wallet_manager();
+    manager
+        .check_watch_xpub(&wallet_id, &chain_id, &xpub)
+        .map_err(|e| Error::InvalidInput(e.to_string()))?;
+
+    db.execute(|conn| {
+        conn.execute(
+            "INSERT OR REPLACE INTO watch_only_keys (wallet_id, chain, xpub) VALUES (?1, ?2, ?3)",
+            params![wallet_id, chain_id, xpub],
+        )?;
+        Ok(())
+    })?;
+
+    manager
+        .add_watch_xpub(&wallet_id, &chain_id, &xpub)
+        .map_err(|e| Error::InvalidInput(e.to_string()))
+}
+
+/// Forget the account xpubs of a watch-only wallet
+#[tauri::command]
+pub async fn remove_watch_only_keys(db: State<'_, Database>, wallet_id: String) -> Result<()> {
+    info!("Removing watch-only keys for {}", wallet_id);
+
+    get_wallet_manager().remove_watch_wallet(&wallet_id);
+
+    db.execute(|conn| {
+        conn.execute(
+            "DELETE FROM watch_only_keys WHERE wallet_id = ?1",
+            [&wallet_id],
+        )?;
+        Ok(())
+    })
+}
@@ -2,7 +2,7 @@ use crate::Result;
 use rusqlite::Connection;
 
 /// Current schema version
-const SCHEMA_VERSION: i32 = 1;
+const SCHEMA_VERSION: i32 = 3;
 
 pub fn run_migrations(conn: &Connection) -> Result<()> {
     // Create migrations table if not exists
@@ -29,6 +29,14 @@ pub fn run_migrations(conn: &Connection) -> Result<()> {
         migrate_v1(conn)?;
     }
 
+    if current_version < 2 {
+        migrate_v2(conn)?;
+    }
+
+    if current_version < 3 {
+        migrate_v3(conn)?;
+    }
+
     Ok(())
 }
 
@@ -210,3 +218,57 @@ fn migrate_v1(conn: &Connection) -> Result<()> {
     Ok(())
 }
 
+fn migrate_v2(conn: &Connection) -> Result<()> {
+    tracing::info!("Applying migration v2");
+
+    conn.execute_batch(
+        r#"
+        -- =======================================================================
+        -- Custom EVM Networks (user-defined, registered with ChainRegistry)
+        -- =======================================================================
+
+        CREATE TABLE IF NOT EXISTS custom_evm_networks (
+            id TEXT PRIMARY KEY,                -- registry chain id, e.g. "linea"
+            name TEXT NOT NULL,
+            symbol TEXT NOT NULL,
+            chain_id INTEGER NOT NULL UNIQUE,   -- EIP-155 chain id
+            decimals INTEGER NOT NULL DEFAULT 18,
+            rpc_urls TEXT NOT NULL DEFAULT '[]', -- JSON array of URLs
+            explorer_url TEXT,
+            is_testnet INTEGER NOT NULL DEFAULT 0,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        );
+
+        -- Record migration
+        INSERT INTO migrations (version) VALUES (2);
+        "#,
+    )?;
+
+    Ok(())
+}
+
+fn migrate_v3(conn: &Connection) -> Result<()> {
+    tracing::info!("Applying migration v3");
+
+    conn.execute_batch(
+        r#"
+        -- =======================================================================
+        -- Watch-only account keys (one xpub per wallet and chain)
+        -- =======================================================================
+
+        CREATE TABLE IF NOT EXISTS watch_only_keys (
+            wallet_id TEXT NOT NULL,
+            chain TEXT NOT NULL,
+            xpub TEXT NOT NULL,                 -- account-level key as entered (xpub/ypub/zpub/tpub...)
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            PRIMARY KEY (wallet_id, chain)
+        );
+
+        -- Record migration
+        INSERT INTO migrations (version) VALUES (3);
+        "#,
+    )?;
+
+    Ok(())
+}
@@ -25,6 +25,15 @@ pub enum Error {
 
     #[error("Bitcoin error: {0}")]
     Bitcoin(String),
+
+    #[error("Cancelled: {0}")]
+    Cancelled(String),
+
+    #[error("Unsupported: {0}")]
+    Unsupported(String),
+
+    #[error("Rejected: {0}")]
+    Rejected(String),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
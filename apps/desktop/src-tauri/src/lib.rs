@@ -1,7 +1,7 @@
 mod commands;
 mod db;
 mod error;
-mod wallet;
+pub mod wallet;
 
 use tauri::Manager;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -32,15 +32,23 @@ pub fn run() {
                 }
             });
 
+            // Register user-defined EVM networks with the chain registry
+            if let Some(db) = app.try_state::<db::Database>() {
+                if let Err(e) = commands::load_custom_evm_networks(&db) {
+                    tracing::error!("Failed to load custom EVM networks: {}", e);
+                }
+
+                // Attach persisted xpubs to their watch-only wallets
+                if let Err(e) = commands::load_watch_only_keys(&db) {
+                    tracing::error!("Failed to load watch-only keys: {}", e);
+                }
+            }
+
             // Initialize Bitcoin state
             let data_dir = app.path().app_data_dir().expect("Failed to get app data dir");
             let bitcoin_state = commands::BitcoinState::new(data_dir);
             app.manage(bitcoin_state);
 
-            // Initialize Ethereum state (uses WalletManager for signing)
-            let ethereum_state = commands::EthereumState::new(wallet::WalletManager::new());
-            app.manage(ethereum_state);
-
             // Open devtools in debug builds
             #[cfg(debug_assertions)]
             {
@@ -66,29 +74,55 @@ pub fn run() {
             commands::get_supported_chains,
             commands::get_mainnet_chains,
             commands::validate_chain_address,
+            commands::validate_chain_address_details,
+            commands::inspect_address,
+            // Custom EVM network commands
+            commands::get_custom_evm_networks,
+            commands::add_custom_evm_network,
+            commands::remove_custom_evm_network,
+            // Watch-only account key commands
+            commands::add_watch_only_xpub,
+            commands::remove_watch_only_keys,
             commands::generate_mnemonic,
             commands::validate_mnemonic,
             commands::create_hd_wallet,
             commands::import_hd_wallet,
             commands::derive_wallet_address,
+            commands::derive_wallet_address_range,
+            commands::get_derivation_schemes,
+            commands::derive_wallet_address_with_scheme,
+            commands::scan_derivation_schemes,
+            commands::sign_chain_message,
+            commands::sign_chain_transaction,
+            commands::verify_chain_message,
+            commands::sign_nostr_event,
+            commands::export_nostr_nsec,
+            commands::derive_bip85_child,
+            commands::import_bip85_wallet,
             commands::is_wallet_unlocked,
             commands::lock_wallet,
             commands::unlock_wallet,
             // Bitcoin commands (Sprint 5-6)
             commands::bitcoin_create_wallet,
             commands::bitcoin_create_watch_wallet,
+            commands::bitcoin_export_descriptors,
             commands::bitcoin_init_from_cached_seed,
             commands::bitcoin_sync_wallet,
+            commands::bitcoin_cancel_sync,
+            commands::bitcoin_watch_wallet,
+            commands::bitcoin_unwatch_wallet,
             commands::bitcoin_get_balance,
             commands::bitcoin_get_transactions,
             commands::bitcoin_get_utxos,
             commands::bitcoin_estimate_fee,
             commands::bitcoin_get_new_address,
             commands::bitcoin_get_network,
+            commands::bitcoin_get_backend_status,
             commands::bitcoin_wallet_exists,
             // Bitcoin single-address commands (for watch-only addresses)
             commands::bitcoin_get_address_balance,
             commands::bitcoin_get_address_transactions,
+            commands::bitcoin_get_addresses_transactions,
             // Bitcoin transaction commands
             commands::bitcoin_send_transaction,
             commands::bitcoin_validate_address,
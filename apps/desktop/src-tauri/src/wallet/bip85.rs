@@ -0,0 +1,467 @@
+//! BIP-85 deterministic entropy
+//!
+//! Derives independent child secrets from a master wallet, so hot-wallet
+//! seeds, keys and passwords can all be recovered from a single backed-up
+//! root. Each application has its own hardened path below `m/83696968'`:
+//!
+//! | Application     | Path                                          |
+//! |-----------------|-----------------------------------------------|
+//! | BIP39 mnemonic  | `m/83696968'/39'/{language}'/{words}'/{index}'` |
+//! | HD-Seed WIF     | `m/83696968'/2'/{index}'`                      |
+//! | XPRV            | `m/83696968'/32'/{index}'`                     |
+//! | HEX             | `m/83696968'/128169'/{num_bytes}'/{index}'`    |
+//! | PWD BASE64      | `m/83696968'/707764'/{length}'/{index}'`       |
+//! | PWD BASE85      | `m/83696968'/707785'/{length}'/{index}'`       |
+//!
+//! The child entropy is `HMAC-SHA512(key = "bip-entropy-from-k", msg = k)`
+//! where `k` is the private key at the application path. Knowing a child
+//! reveals nothing about the master or its siblings.
+
+use bip32::XPrv;
+use bip39::{Language, Mnemonic};
+use bitcoin::base64::prelude::{Engine as _, BASE64_STANDARD};
+use bitcoin::bip32::{ChainCode, ChildNumber, Fingerprint, Xpriv};
+use bitcoin::secp256k1::SecretKey;
+use bitcoin::{NetworkKind, PrivateKey};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha512;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use crate::wallet::chains::secp256k1::derive_key_from_master;
+use crate::wallet::error::{WalletError, WalletResult};
+use crate::wallet::types::{MasterSecret, SecretEntropy, SecretMnemonic, SecretSeed};
+
+/// BIP-85 purpose ("BIPENTROPY" as a number)
+pub const BIP85_PURPOSE: u32 = 83696968;
+
+/// HMAC key applied to the derived private key
+const ENTROPY_HMAC_KEY: &[u8] = b"bip-entropy-from-k";
+
+/// RFC 1924 base85 alphabet used by the BASE85 password application
+const BASE85_ALPHABET: &[u8; 85] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+
+// =============================================================================
+// Applications
+// =============================================================================
+
+/// BIP39 wordlist of a child mnemonic, with its BIP-85 language code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Bip85Language {
+    #[default]
+    English,
+    Japanese,
+    Korean,
+    Spanish,
+    ChineseSimplified,
+    ChineseTraditional,
+    French,
+    Italian,
+    Czech,
+    Portuguese,
+}
+
+impl Bip85Language {
+    /// Language code used in the derivation path
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::English => 0,
+            Self::Japanese => 1,
+            Self::Korean => 2,
+            Self::Spanish => 3,
+            Self::ChineseSimplified => 4,
+            Self::ChineseTraditional => 5,
+            Self::French => 6,
+            Self::Italian => 7,
+            Self::Czech => 8,
+            Self::Portuguese => 9,
+        }
+    }
+
+    fn wordlist(&self) -> Language {
+        match self {
+            Self::English => Language::English,
+            Self::Japanese => Language::Japanese,
+            Self::Korean => Language::Korean,
+            Self::Spanish => Language::Spanish,
+            Self::ChineseSimplified => Language::SimplifiedChinese,
+            Self::ChineseTraditional => Language::TraditionalChinese,
+            Self::French => Language::French,
+            Self::Italian => Language::Italian,
+            Self::Czech => Language::Czech,
+            Self::Portuguese => Language::Portuguese,
+        }
+    }
+}
+
+/// What to derive from the child entropy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Bip85Application {
+    /// BIP39 mnemonic of 12, 18 or 24 words
+    Mnemonic { language: Bip85Language, words: u32 },
+    /// Compressed mainnet WIF private key (for an HD-seed wallet like Core)
+    Wif,
+    /// Master extended private key
+    Xprv,
+    /// Raw entropy, 16 to 64 bytes
+    Hex { num_bytes: u32 },
+    /// Base64 password, 20 to 86 characters
+    PasswordBase64 { length: u32 },
+    /// Base85 password, 10 to 80 characters
+    PasswordBase85 { length: u32 },
+}
+
+impl Bip85Application {
+    /// Application name as shown to the user
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Mnemonic { .. } => "BIP39",
+            Self::Wif => "HD-Seed WIF",
+            Self::Xprv => "XPRV",
+            Self::Hex { .. } => "HEX",
+            Self::PasswordBase64 { .. } => "PWD BASE64",
+            Self::PasswordBase85 { .. } => "PWD BASE85",
+        }
+    }
+
+    /// Derivation path of the child at `index`
+    ///
+    /// Fails if the application parameters are out of range.
+    pub fn path(&self, index: u32) -> WalletResult<String> {
+        let invalid = |message: String| Err(WalletError::DerivationError(message));
+        let components = match *self {
+            Self::Mnemonic { language, words } => {
+                if !matches!(words, 12 | 18 | 24) {
+                    return invalid(format!(
+                        "BIP-85 mnemonics have 12, 18 or 24 words, not {}",
+                        words
+                    ));
+                }
+                vec![39, language.code(), words]
+            }
+            Self::Wif => vec![2],
+            Self::Xprv => vec![32],
+            Self::Hex { num_bytes } => {
+                if !(16..=64).contains(&num_bytes) {
+                    return invalid(format!(
+                        "BIP-85 hex entropy is 16 to 64 bytes, not {}",
+                        num_bytes
+                    ));
+                }
+                vec![128169, num_bytes]
+            }
+            Self::PasswordBase64 { length } => {
+                if !(20..=86).contains(&length) {
+                    return invalid(format!(
+                        "BIP-85 base64 passwords are 20 to 86 characters, not {}",
+                        length
+                    ));
+                }
+                vec![707764, length]
+            }
+            Self::PasswordBase85 { length } => {
+                if !(10..=80).contains(&length) {
+                    return invalid(format!(
+                        "BIP-85 base85 passwords are 10 to 80 characters, not {}",
+                        length
+                    ));
+                }
+                vec![707785, length]
+            }
+        };
+
+        let mut path = format!("m/{}'", BIP85_PURPOSE);
+        for component in components.into_iter().chain([index]) {
+            path.push_str(&format!("/{}'", component));
+        }
+        Ok(path)
+    }
+}
+
+/// A derived child secret
+///
+/// `value` is secret: shown to the user once, never logged. Zeroized on drop.
+#[derive(Clone, Serialize, Zeroize, ZeroizeOnDrop)]
+pub struct Bip85Child {
+    /// Application name (e.g., "BIP39", "XPRV")
+    #[zeroize(skip)]
+    pub application: &'static str,
+    /// Derivation path below the master key
+    pub path: String,
+    /// Encoded child (mnemonic, WIF, xprv, hex or password)
+    pub value: String,
+}
+
+impl std::fmt::Debug for Bip85Child {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Bip85Child({} at {}, [REDACTED])",
+            self.application, self.path
+        )
+    }
+}
+
+// =============================================================================
+// Derivation
+// =============================================================================
+
+/// Master key of a wallet seed
+pub fn master_key(seed: &[u8; 64]) -> WalletResult<XPrv> {
+    XPrv::new(seed)
+        .map_err(|e| WalletError::DerivationError(format!("Failed to create master key: {}", e)))
+}
+
+/// Derive the 64 bytes of child entropy at a BIP-85 path
+pub fn derive_entropy(master: &XPrv, path: &str) -> WalletResult<Zeroizing<[u8; 64]>> {
+    let derived = derive_key_from_master(master, path)?;
+    let k = Zeroizing::new(derived.private_key().to_bytes());
+
+    let mut mac = Hmac::<Sha512>::new_from_slice(ENTROPY_HMAC_KEY)
+        .map_err(|e| WalletError::DerivationError(format!("HMAC init failed: {}", e)))?;
+    mac.update(&k);
+
+    let mut entropy = Zeroizing::new([0u8; 64]);
+    entropy.copy_from_slice(&mac.finalize().into_bytes());
+    Ok(entropy)
+}
+
+/// Derive a child mnemonic
+pub fn derive_mnemonic(
+    master: &XPrv,
+    language: Bip85Language,
+    words: u32,
+    index: u32,
+) -> WalletResult<Mnemonic> {
+    let path = Bip85Application::Mnemonic { language, words }.path(index)?;
+    let entropy = derive_entropy(master, &path)?;
+
+    // 12 words = 128 bits, 18 = 192, 24 = 256
+    let length = (words as usize) * 4 / 3;
+    Mnemonic::from_entropy_in(language.wordlist(), &entropy[..length])
+        .map_err(|e| WalletError::DerivationError(format!("Invalid child entropy: {}", e)))
+}
+
+/// Seed and entropy of a child mnemonic, ready to import as a new HD wallet
+///
+/// Works for every language; the mnemonic itself is returned for backup.
+pub fn derive_mnemonic_secret(
+    master: &XPrv,
+    language: Bip85Language,
+    words: u32,
+    index: u32,
+) -> WalletResult<(SecretMnemonic, MasterSecret)> {
+    let mnemonic = derive_mnemonic(master, language, words, index)?;
+    let secret = MasterSecret::new(
+        SecretSeed::new(mnemonic.to_seed("")),
+        Some(SecretEntropy::new(mnemonic.to_entropy())),
+    );
+    Ok((SecretMnemonic::new(mnemonic.to_string()), secret))
+}
+
+/// Derive the child of an application at `index`
+pub fn derive_child(
+    master: &XPrv,
+    application: Bip85Application,
+    index: u32,
+) -> WalletResult<Bip85Child> {
+    let path = application.path(index)?;
+
+    let value = match application {
+        Bip85Application::Mnemonic { language, words } => {
+            derive_mnemonic(master, language, words, index)?.to_string()
+        }
+        Bip85Application::Wif => {
+            let entropy = derive_entropy(master, &path)?;
+            PrivateKey::new(secret_key(&entropy[..32])?, NetworkKind::Main).to_wif()
+        }
+        Bip85Application::Xprv => {
+            // Chain code first, then the private key
+            let entropy = derive_entropy(master, &path)?;
+            let mut chain_code = [0u8; 32];
+            chain_code.copy_from_slice(&entropy[..32]);
+
+            Xpriv {
+                network: NetworkKind::Main,
+                depth: 0,
+                parent_fingerprint: Fingerprint::default(),
+                child_number: ChildNumber::from_normal_idx(0).expect("zero is a normal index"),
+                private_key: secret_key(&entropy[32..])?,
+                chain_code: ChainCode::from(chain_code),
+            }
+            .to_string()
+        }
+        Bip85Application::Hex { num_bytes } => {
+            let entropy = derive_entropy(master, &path)?;
+            hex::encode(&entropy[..num_bytes as usize])
+        }
+        Bip85Application::PasswordBase64 { length } => {
+            let entropy = derive_entropy(master, &path)?;
+            let mut encoded = BASE64_STANDARD.encode(&entropy[..]);
+            encoded.truncate(length as usize);
+            encoded
+        }
+        Bip85Application::PasswordBase85 { length } => {
+            let entropy = derive_entropy(master, &path)?;
+            let mut encoded = base85_encode(&entropy[..]);
+            encoded.truncate(length as usize);
+            encoded
+        }
+    };
+
+    Ok(Bip85Child {
+        application: application.name(),
+        path,
+        value,
+    })
+}
+
+fn secret_key(bytes: &[u8]) -> WalletResult<SecretKey> {
+    SecretKey::from_slice(bytes)
+        .map_err(|e| WalletError::DerivationError(format!("Invalid child private key: {}", e)))
+}
+
+/// RFC 1924 base85 encoding of a multiple of 4 bytes
+fn base85_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len() / 4 * 5);
+    for chunk in bytes.chunks_exact(4) {
+        let mut value = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        let mut digits = [0u8; 5];
+        for digit in digits.iter_mut().rev() {
+            *digit = BASE85_ALPHABET[(value % 85) as usize];
+            value /= 85;
+        }
+        encoded.extend(digits.iter().map(|&d| d as char));
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    // Master key of the BIP-85 test vectors
+    fn test_master() -> XPrv {
+        XPrv::from_str("xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb").unwrap()
+    }
+
+    fn child(application: Bip85Application) -> String {
+        derive_child(&test_master(), application, 0)
+            .unwrap()
+            .value
+            .clone()
+    }
+
+    #[test]
+    fn test_derive_entropy_vectors() {
+        let master = test_master();
+
+        let entropy = derive_entropy(&master, "m/83696968'/0'/0'").unwrap();
+        assert_eq!(
+            hex::encode(&entropy[..]),
+            "efecfbccffea313214232d29e71563d941229afb4338c21f9517c41aaa0d16f00b83d2a09ef747e7a64e8e2bd5a14869e693da66ce94ac2da570ab7ee48618f7"
+        );
+
+        let entropy = derive_entropy(&master, "m/83696968'/0'/1'").unwrap();
+        assert_eq!(
+            hex::encode(&entropy[..]),
+            "70c6e3e8ebee8dc4c0dbba66076819bb8c09672527c4277ca8729532ad711872218f826919f6b67218adde99018a6df9095ab2b58d803b5b93ec9802085a690e"
+        );
+    }
+
+    #[test]
+    fn test_mnemonic_vectors() {
+        let english = Bip85Language::English;
+        assert_eq!(
+            child(Bip85Application::Mnemonic {
+                language: english,
+                words: 12
+            }),
+            "girl mad pet galaxy egg matter matrix prison refuse sense ordinary nose"
+        );
+        assert_eq!(
+            child(Bip85Application::Mnemonic { language: english, words: 18 }),
+            "near account window bike charge season chef number sketch tomorrow excuse sniff circle vital hockey outdoor supply token"
+        );
+        assert_eq!(
+            child(Bip85Application::Mnemonic { language: english, words: 24 }),
+            "puppy ocean match cereal symbol another shed magic wrap hammer bulb intact gadget divorce twin tonight reason outdoor destroy simple truth cigar social volcano"
+        );
+    }
+
+    #[test]
+    fn test_key_and_password_vectors() {
+        assert_eq!(
+            child(Bip85Application::Wif),
+            "Kzyv4uF39d4Jrw2W7UryTHwZr1zQVNk4dAFyqE6BuMrMh1Za7uhp"
+        );
+        assert_eq!(
+            child(Bip85Application::Xprv),
+            "xprv9s21ZrQH143K2srSbCSg4m4kLvPMzcWydgmKEnMmoZUurYuBuYG46c6P71UGXMzmriLzCCBvKQWBUv3vPB3m1SATMhp3uEjXHJ42jFg7myX"
+        );
+        assert_eq!(
+            child(Bip85Application::Hex { num_bytes: 64 }),
+            "492db4698cf3b73a5a24998aa3e9d7fa96275d85724a91e71aa2d645442f878555d078fd1f1f67e368976f04137b1f7a0d19232136ca50c44614af72b5582a5c"
+        );
+        assert_eq!(
+            child(Bip85Application::PasswordBase64 { length: 21 }),
+            "dKLoepugzdVJvdL56ogNV"
+        );
+        assert_eq!(
+            child(Bip85Application::PasswordBase85 { length: 12 }),
+            "_s`{TW89)i4`"
+        );
+    }
+
+    #[test]
+    fn test_application_paths() {
+        let mnemonic = Bip85Application::Mnemonic {
+            language: Bip85Language::Japanese,
+            words: 18,
+        };
+        assert_eq!(mnemonic.path(5).unwrap(), "m/83696968'/39'/1'/18'/5'");
+        assert_eq!(
+            Bip85Application::Xprv.path(0).unwrap(),
+            "m/83696968'/32'/0'"
+        );
+
+        assert!(Bip85Application::Mnemonic {
+            language: Bip85Language::English,
+            words: 15
+        }
+        .path(0)
+        .is_err());
+        assert!(Bip85Application::Hex { num_bytes: 8 }.path(0).is_err());
+        assert!(Bip85Application::PasswordBase64 { length: 87 }
+            .path(0)
+            .is_err());
+        assert!(Bip85Application::PasswordBase85 { length: 9 }
+            .path(0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_mnemonic_languages() {
+        let master = test_master();
+        let english = derive_mnemonic(&master, Bip85Language::English, 12, 0).unwrap();
+        let spanish = derive_mnemonic(&master, Bip85Language::Spanish, 12, 0).unwrap();
+
+        // Different language code in the path: different entropy, not a translation
+        assert_eq!(spanish.language(), Language::Spanish);
+        assert_ne!(english.to_entropy(), spanish.to_entropy());
+    }
+
+    #[test]
+    fn test_mnemonic_secret_matches_phrase() {
+        let (mnemonic, secret) =
+            derive_mnemonic_secret(&test_master(), Bip85Language::English, 12, 0).unwrap();
+        let parsed = Mnemonic::parse_in(Language::English, mnemonic.as_str()).unwrap();
+
+        assert_eq!(secret.seed(), &parsed.to_seed(""));
+        assert_eq!(secret.entropy(), Some(parsed.to_entropy().as_slice()));
+    }
+}
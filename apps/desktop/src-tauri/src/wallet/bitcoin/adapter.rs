@@ -2,36 +2,230 @@
 //!
 //! Provides high-level wallet functionality using Bitcoin Dev Kit (BDK):
 //! - Wallet creation from descriptors
-//! - Blockchain synchronization via Electrum/Esplora
+//! - Blockchain synchronization via Electrum/Esplora/Bitcoin Core
 //! - Balance fetching
 //! - Transaction history
 //! - UTXO management
 //!
-//! For single-address watch-only wallets, uses direct Electrum queries
+//! For single-address watch-only wallets, queries the backend directly
 //! since BDK descriptors don't support arbitrary addresses.
+//!
+//! All network access goes through a `BackendPool`, which fails over
+//! between the configured servers.
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ops::Deref;
 use std::path::PathBuf;
 
-use bdk_electrum::electrum_client::{self, ElectrumApi};
-use bdk_electrum::BdkElectrumClient;
 use bdk_wallet::bitcoin::bip32::{DerivationPath, Xpriv};
 use bdk_wallet::bitcoin::secp256k1::Secp256k1;
-use bdk_wallet::bitcoin::Network;
-use bdk_wallet::chain::ChainPosition;
+use bdk_wallet::bitcoin::{Network, OutPoint, Script, ScriptBuf, Transaction, Txid};
+use bdk_wallet::chain::spk_client::{FullScanRequest, SyncRequest};
+use bdk_wallet::chain::{ChainPosition, CheckPoint, SpkIterator};
+use bdk_wallet::descriptor::ExtendedDescriptor;
 use bdk_wallet::rusqlite::Connection;
-use bdk_wallet::{KeychainKind, PersistedWallet, Wallet};
+use bdk_wallet::{AddressInfo, KeychainKind, PersistedWallet, Update, Wallet, WalletTx};
 use bitcoin::Address;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info};
 
+use super::backend::BackendPool;
+use super::descriptor::WalletDescriptors;
+use super::sync::{SyncMonitor, PROGRESS_CONNECTING, PROGRESS_UPDATING};
 use super::types::*;
 use crate::error::{Error, Result};
+use crate::wallet::chains::{BitcoinModule, BitcoinScriptType};
+
+/// Table recording when unconfirmed transactions were first seen
+///
+/// BDK only keeps the last time a transaction was seen in the mempool.
+const FIRST_SEEN_SCHEMA: &str = "CREATE TABLE IF NOT EXISTS coinbox_first_seen (
+    txid TEXT PRIMARY KEY NOT NULL,
+    first_seen INTEGER NOT NULL
+)";
+
+fn first_seen_error(e: impl std::fmt::Display) -> Error {
+    error!("First-seen times unavailable: {}", e);
+    Error::Bitcoin(format!("Failed to access first-seen times: {}", e))
+}
+
+/// A BDK wallet with its open database connection
+///
+/// Changes (sync results, revealed addresses) are staged in the wallet and
+/// written by `persist`. The wallet derefs to the BDK wallet for reads
+/// only; the methods that change it persist before returning.
+pub struct BitcoinWallet {
+    wallet: PersistedWallet<Connection>,
+    conn: Connection,
+    /// When each transaction was first seen unconfirmed
+    first_seen: HashMap<Txid, u64>,
+}
+
+impl BitcoinWallet {
+    fn new(wallet: PersistedWallet<Connection>, conn: Connection) -> Result<Self> {
+        conn.execute_batch(FIRST_SEEN_SCHEMA)
+            .map_err(first_seen_error)?;
+
+        let first_seen = conn
+            .prepare("SELECT txid, first_seen FROM coinbox_first_seen")
+            .and_then(|mut stmt| {
+                stmt.query_map([], |row| {
+                    Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+                })?
+                .collect::<std::result::Result<Vec<_>, _>>()
+            })
+            .map_err(first_seen_error)?
+            .into_iter()
+            .filter_map(|(txid, seen)| Some((txid.parse().ok()?, seen as u64)))
+            .collect();
+
+        Ok(Self {
+            wallet,
+            conn,
+            first_seen,
+        })
+    }
+
+    /// Write staged changes to the database
+    ///
+    /// Also records the first-seen time of newly seen unconfirmed
+    /// transactions. Returns whether there were wallet changes to write.
+    pub fn persist(&mut self) -> Result<bool> {
+        let changed = self.wallet.persist(&mut self.conn).map_err(|e| {
+            error!("Failed to persist wallet: {}", e);
+            Error::Bitcoin(format!("Failed to persist wallet: {}", e))
+        })?;
+        self.record_first_seen()?;
+        Ok(changed)
+    }
+
+    /// When a transaction was first seen unconfirmed
+    ///
+    /// `None` for transactions that confirmed before a sync saw them in
+    /// the mempool.
+    pub fn first_seen(&self, txid: Txid) -> Option<u64> {
+        self.first_seen.get(&txid).copied()
+    }
+
+    fn record_first_seen(&mut self) -> Result<()> {
+        let new: Vec<(Txid, u64)> = self
+            .wallet
+            .transactions()
+            .filter_map(|tx| match tx.chain_position {
+                ChainPosition::Unconfirmed {
+                    last_seen: Some(seen),
+                } if !self.first_seen.contains_key(&tx.tx_node.txid) => {
+                    Some((tx.tx_node.txid, seen))
+                }
+                _ => None,
+            })
+            .collect();
+        if new.is_empty() {
+            return Ok(());
+        }
+
+        let db_tx = self.conn.transaction().map_err(first_seen_error)?;
+        for (txid, seen) in &new {
+            db_tx
+                .execute(
+                    "INSERT OR IGNORE INTO coinbox_first_seen (txid, first_seen) VALUES (?1, ?2)",
+                    (txid.to_string(), *seen as i64),
+                )
+                .map_err(first_seen_error)?;
+        }
+        db_tx.commit().map_err(first_seen_error)?;
+
+        self.first_seen.extend(new);
+        Ok(())
+    }
+
+    /// Whether the initial full scan has completed
+    ///
+    /// A new wallet's chain only holds the genesis block; every scan moves
+    /// the checkpoint to the backend's tip.
+    pub fn is_scanned(&self) -> bool {
+        self.wallet.latest_checkpoint().height() > 0
+    }
+
+    /// Apply a chain update and persist it
+    fn apply_update(&mut self, update: impl Into<Update>) -> Result<()> {
+        self.wallet.apply_update(update).map_err(|e| {
+            error!("Failed to apply sync update: {}", e);
+            Error::Bitcoin(format!("Failed to apply sync: {}", e))
+        })?;
+        self.persist()?;
+        Ok(())
+    }
+
+    /// Reveal the next address of a keychain and persist it
+    pub fn reveal_next_address(&mut self, keychain: KeychainKind) -> Result<AddressInfo> {
+        let address = self.wallet.reveal_next_address(keychain);
+        self.persist()?;
+        Ok(address)
+    }
+
+    /// Reveal an address without persisting it, as a failed write would
+    #[cfg(test)]
+    pub(super) fn reveal_unpersisted(&mut self, keychain: KeychainKind) -> AddressInfo {
+        self.wallet.reveal_next_address(keychain)
+    }
+}
+
+impl Deref for BitcoinWallet {
+    type Target = PersistedWallet<Connection>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.wallet
+    }
+}
+
+/// What a sync scans, captured from the wallet by `prepare_sync`
+pub enum SyncPlan {
+    /// Every script of each keychain until the gap limit
+    Full {
+        tip: CheckPoint,
+        spks: BTreeMap<KeychainKind, SpkIterator<ExtendedDescriptor>>,
+        expected: u32,
+    },
+    /// Scripts already revealed, plus outputs to watch for spends and
+    /// unconfirmed transactions to check again
+    Revealed {
+        tip: CheckPoint,
+        spks: Vec<((KeychainKind, u32), ScriptBuf)>,
+        outpoints: Vec<OutPoint>,
+        txids: Vec<Txid>,
+    },
+}
+
+/// Unconfirmed transactions of a wallet to check again, and their inputs
+///
+/// Checking a transaction refreshes it while it stays in the mempool or
+/// anchors it once mined; watching its inputs finds a replacement that
+/// spends them. bdk_wallet 1.2 can't record evictions, so a transaction
+/// dropped without a replacement stays pending.
+fn unconfirmed_checks(
+    wallet: &BitcoinWallet,
+    relevant: impl Fn(&Transaction) -> bool,
+) -> (Vec<Txid>, Vec<OutPoint>) {
+    let mut txids = Vec::new();
+    let mut inputs = Vec::new();
+    for tx in wallet.transactions() {
+        if tx.chain_position.is_confirmed() || !relevant(tx.tx_node.tx.as_ref()) {
+            continue;
+        }
+        txids.push(tx.tx_node.txid);
+        for input in &tx.tx_node.tx.input {
+            inputs.push(input.previous_output);
+        }
+    }
+    (txids, inputs)
+}
 
 /// Bitcoin wallet adapter for BDK integration
 pub struct BitcoinAdapter {
     /// Network configuration
     network: Network,
-    /// Electrum server URL
-    electrum_url: String,
+    /// Chain sources for sync, queries and broadcast, in order of preference
+    backends: BackendPool,
     /// Gap limit for address discovery
     gap_limit: u32,
     /// Database path for wallet persistence
@@ -41,22 +235,13 @@ pub struct BitcoinAdapter {
 impl BitcoinAdapter {
     /// Create a new Bitcoin adapter with configuration
     pub fn new(config: BitcoinConfig, db_path: PathBuf) -> Self {
-        let (network, electrum_url) = match config.backend {
-            BlockchainBackend::Electrum { url } => (config.network.into(), url),
-            BlockchainBackend::Esplora { url: _ } => {
-                // For Esplora, we'll still use Electrum as primary
-                // Esplora support can be added as fallback later
-                warn!("Esplora backend requested but using Electrum - Esplora support coming soon");
-                (
-                    config.network.into(),
-                    get_default_electrum_url(config.network),
-                )
-            }
-        };
+        for backend in config.backends() {
+            info!("Using {:?} backend: {}", backend.kind(), backend.url());
+        }
 
         Self {
-            network,
-            electrum_url,
+            network: config.network.into(),
+            backends: BackendPool::from_config(&config),
             gap_limit: config.gap_limit,
             db_path,
         }
@@ -69,68 +254,73 @@ impl BitcoinAdapter {
 
     /// Create adapter for testnet
     pub fn testnet(db_path: PathBuf) -> Self {
-        Self::new(
-            BitcoinConfig {
-                network: BitcoinNetwork::Testnet,
-                backend: BlockchainBackend::Electrum {
-                    url: "ssl://electrum.blockstream.info:60002".to_string(),
-                },
-                gap_limit: 20,
-            },
-            db_path,
-        )
+        Self::new(BitcoinConfig::for_network(BitcoinNetwork::Testnet), db_path)
     }
 
-    /// Create a new Electrum client connection
-    fn create_electrum_client(&self) -> Result<BdkElectrumClient<electrum_client::Client>> {
-        info!("Connecting to Electrum server: {}", self.electrum_url);
-        let client = electrum_client::Client::new(&self.electrum_url).map_err(|e| {
-            error!("Failed to connect to Electrum: {}", e);
-            Error::Bitcoin(format!("Electrum connection failed: {}", e))
-        })?;
-
-        Ok(BdkElectrumClient::new(client))
+    /// Configured blockchain backends
+    pub fn backends(&self) -> &BackendPool {
+        &self.backends
     }
 
     /// Check if input is a single address (vs xpub)
+    ///
+    /// The input must parse as an address of any network; network checks
+    /// happen where the address is used.
     pub fn is_single_address(address_or_xpub: &str) -> bool {
-        address_or_xpub.starts_with("bc1")
-            || address_or_xpub.starts_with("tb1")
-            || address_or_xpub.starts_with("1")
-            || address_or_xpub.starts_with("3")
-            || address_or_xpub.starts_with("m")
-            || address_or_xpub.starts_with("n")
-            || address_or_xpub.starts_with("2")
+        address_or_xpub
+            .parse::<Address<bitcoin::address::NetworkUnchecked>>()
+            .is_ok()
     }
 
-    /// Create a watch-only wallet from an xpub
+    /// Output script of an address on the adapter's network
+    pub fn address_script(&self, address: &str) -> Result<ScriptBuf> {
+        let addr = address
+            .parse::<Address<bitcoin::address::NetworkUnchecked>>()
+            .map_err(|e| Error::Bitcoin(format!("Invalid address: {}", e)))?
+            .require_network(self.network)
+            .map_err(|e| Error::Bitcoin(format!("Address network mismatch: {}", e)))?;
+        Ok(addr.script_pubkey())
+    }
+
+    /// Create a watch-only wallet from an address, extended public key or
+    /// output descriptors
     ///
-    /// For xpub: Uses BIP84 (Native SegWit) descriptors with BDK
-    /// For single addresses: Use get_address_balance/get_address_transactions directly
-    pub fn create_watch_wallet(&self, address_or_xpub: &str, wallet_id: &str) -> Result<()> {
-        // Single addresses don't need a BDK wallet - query Electrum directly
-        if Self::is_single_address(address_or_xpub) {
-            info!("Single address watch wallet created for: {} (no BDK wallet needed)", address_or_xpub);
-            // Validate the address format
-            address_or_xpub.parse::<Address<bitcoin::address::NetworkUnchecked>>()
+    /// Keys and descriptors get a BDK wallet (see `WalletDescriptors::parse`
+    /// for the accepted formats); single addresses need none and are
+    /// queried with get_address_balance/get_address_transactions directly.
+    pub fn create_watch_wallet(&self, input: &str, wallet_id: &str) -> Result<()> {
+        // Single addresses don't need a BDK wallet - query the backend directly
+        if let Ok(address) = input.parse::<Address<bitcoin::address::NetworkUnchecked>>() {
+            address
+                .require_network(self.network)
                 .map_err(|e| Error::Bitcoin(format!("Invalid address: {}", e)))?;
+            info!(
+                "Single address watch wallet created for: {} (no BDK wallet needed)",
+                input
+            );
             return Ok(());
         }
 
-        // xpub - use BDK with wpkh descriptor
-        let db_path = self.db_path.join(format!("{}.sqlite", wallet_id));
-        let descriptor = format!("wpkh({}/<0;1>/*)", address_or_xpub);
+        let descriptors = WalletDescriptors::parse(input, self.network)?;
+        let db_path = self.wallet_path(wallet_id);
 
-        info!("Creating xpub watch wallet");
-        debug!("Creating watch wallet with descriptor: {}", descriptor);
+        info!("Creating descriptor watch wallet");
+        debug!(
+            "Creating watch wallet with descriptors: {} / {:?}",
+            descriptors.external, descriptors.internal
+        );
 
         // Create database connection
         let mut conn = Connection::open(&db_path).map_err(|e| {
             Error::Bitcoin(format!("Failed to open wallet database: {}", e))
         })?;
 
-        // Create wallet with descriptor
-        let wallet = Wallet::create_single(descriptor.clone())
+        // BDK persists the descriptors, so the wallet can be exported later
+        let params = match descriptors.internal {
+            Some(internal) => Wallet::create(descriptors.external, internal),
+            None => Wallet::create_single(descriptors.external),
+        };
+        let wallet = params
             .network(self.network)
             .create_wallet(&mut conn)
             .map_err(|e| {
@@ -139,172 +329,90 @@ impl BitcoinAdapter {
             })?;
 
         info!(
-            "Created watch wallet {} with {} addresses pre-derived",
+            "Created watch wallet {} with {} keychains",
             wallet_id,
-            wallet.spk_index().last_revealed_indices().len()
+            wallet.keychains().count()
         );
 
         Ok(())
     }
 
-    /// Get balance for a single address directly from Electrum
+    /// Public descriptors of a wallet, for export to other wallets
+    ///
+    /// Keeps key origins as imported; wallets created from a seed export
+    /// their account xpubs.
+    pub fn wallet_descriptors(wallet: &BitcoinWallet) -> WalletDescriptors {
+        let mut external = String::new();
+        let mut internal = None;
+        for (keychain, descriptor) in wallet.keychains() {
+            match keychain {
+                KeychainKind::External => external = descriptor.to_string(),
+                KeychainKind::Internal => internal = Some(descriptor.to_string()),
+            }
+        }
+        WalletDescriptors { external, internal }
+    }
+
+    /// Get balance for a single address directly from the backend
     pub fn get_address_balance(&self, address: &str) -> Result<BitcoinBalance> {
         info!("Fetching balance for address: {}", address);
 
-        // Parse address
-        let addr = address.parse::<Address<bitcoin::address::NetworkUnchecked>>()
-            .map_err(|e| Error::Bitcoin(format!("Invalid address: {}", e)))?
-            .assume_checked();
+        let script = self.address_script(address)?;
 
-        let script = addr.script_pubkey();
-
-        // Connect to Electrum
-        let client = electrum_client::Client::new(&self.electrum_url).map_err(|e| {
-            error!("Failed to connect to Electrum: {}", e);
-            Error::Bitcoin(format!("Electrum connection failed: {}", e))
-        })?;
+        let balance = self
+            .backends
+            .call("Balance query", |backend| backend.script_balance(&script))?;
 
-        // Query balance
-        let balance = client.script_get_balance(&script).map_err(|e| {
-            error!("Failed to get balance: {}", e);
-            Error::Bitcoin(format!("Balance query failed: {}", e))
-        })?;
-
-        info!("Balance for {}: confirmed={}, unconfirmed={}",
-              address, balance.confirmed, balance.unconfirmed);
+        info!(
+            "Balance for {}: confirmed={}, unconfirmed={}",
+            address, balance.confirmed, balance.unconfirmed
+        );
 
-        Ok(BitcoinBalance {
-            confirmed: balance.confirmed,
-            unconfirmed: balance.unconfirmed as u64,
-            immature: 0,
-            trusted_spendable: balance.confirmed,
-        })
+        Ok(balance)
     }
 
-    /// Get transaction history for a single address directly from Electrum
+    /// Get transaction history for a single address directly from the backend
     pub fn get_address_transactions(&self, address: &str) -> Result<Vec<BitcoinTransaction>> {
-        info!("Fetching transactions for address: {}", address);
-
-        // Parse address
-        let addr = address.parse::<Address<bitcoin::address::NetworkUnchecked>>()
-            .map_err(|e| Error::Bitcoin(format!("Invalid address: {}", e)))?
-            .assume_checked();
-
-        let script = addr.script_pubkey();
-
-        // Connect to Electrum
-        let client = electrum_client::Client::new(&self.electrum_url).map_err(|e| {
-            error!("Failed to connect to Electrum: {}", e);
-            Error::Bitcoin(format!("Electrum connection failed: {}", e))
-        })?;
-
-        // Get current tip height for confirmation count
-        let tip_height = client.block_headers_subscribe()
-            .map(|h| h.height as u32)
-            .unwrap_or(0);
-
-        // Get transaction history
-        let history = client.script_get_history(&script).map_err(|e| {
-            error!("Failed to get history: {}", e);
-            Error::Bitcoin(format!("History query failed: {}", e))
-        })?;
-
-        info!("Found {} transactions for {}", history.len(), address);
-
-        // Cache block timestamps to avoid redundant fetches
-        let mut block_timestamps: std::collections::HashMap<usize, u64> = std::collections::HashMap::new();
-
-        let mut transactions = Vec::new();
-
-        for item in history {
-            let txid = item.tx_hash.to_string();
-
-            // Get full transaction to calculate amount
-            let tx = client.transaction_get(&item.tx_hash).map_err(|e| {
-                warn!("Failed to get transaction {}: {}", txid, e);
-                Error::Bitcoin(format!("Transaction fetch failed: {}", e))
-            })?;
+        self.get_addresses_transactions(&[address.to_string()])
+    }
 
-            // Calculate received amount for this address
-            let mut received: i64 = 0;
-            for output in &tx.output {
-                if output.script_pubkey == script {
-                    received += output.value.to_sat() as i64;
-                }
+    /// Get the combined transaction history of a list of watched addresses
+    ///
+    /// The addresses are treated as one wallet: a transaction touching
+    /// several of them is listed once with its net amount, and a transfer
+    /// between two of them is internal.
+    pub fn get_addresses_transactions(
+        &self,
+        addresses: &[String],
+    ) -> Result<Vec<BitcoinTransaction>> {
+        info!("Fetching transactions for {} addresses", addresses.len());
+
+        let mut scripts = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            let script = self.address_script(address)?;
+            if !scripts.contains(&script) {
+                scripts.push(script);
             }
-
-            // Check if any inputs are from this address (sent)
-            // This requires fetching previous transactions, which is expensive
-            // For simplicity, we'll just track receives for now
-            let direction = if received > 0 {
-                TransactionDirection::Received
-            } else {
-                TransactionDirection::Sent
-            };
-
-            let (status, timestamp) = if item.height > 0 {
-                let height = item.height as u32;
-                let height_usize = item.height as usize;
-                let confirmations = if tip_height > height {
-                    tip_height - height + 1
-                } else {
-                    1
-                };
-
-                // Get block timestamp (cached)
-                let block_time = if let Some(&cached_time) = block_timestamps.get(&height_usize) {
-                    cached_time
-                } else {
-                    // Fetch block header to get timestamp
-                    let block_time = client.block_header(height_usize)
-                        .map(|header| header.time as u64)
-                        .unwrap_or(0);
-                    block_timestamps.insert(height_usize, block_time);
-                    block_time
-                };
-
-                (
-                    ConfirmationStatus::Confirmed {
-                        block_height: height,
-                        block_time,
-                        confirmations,
-                    },
-                    Some(block_time),
-                )
-            } else {
-                (ConfirmationStatus::Unconfirmed, None)
-            };
-
-            transactions.push(BitcoinTransaction {
-                txid,
-                direction,
-                amount_sats: received,
-                fee_sats: None,
-                status,
-                timestamp,
-                addresses: vec![address.to_string()],
-                size: Some(tx.total_size() as u32),
-                vsize: Some(tx.vsize() as u32),
-            });
         }
 
-        // Sort by timestamp (most recent first), then by block height
-        transactions.sort_by(|a, b| {
-            match (&b.timestamp, &a.timestamp) {
-                (Some(t_b), Some(t_a)) => t_b.cmp(t_a),
-                (Some(_), None) => std::cmp::Ordering::Less,
-                (None, Some(_)) => std::cmp::Ordering::Greater,
-                (None, None) => std::cmp::Ordering::Equal,
-            }
-        });
-
-        debug!("Retrieved {} transactions for {}", transactions.len(), address);
+        let history = self
+            .backends
+            .call("History query", |backend| backend.scripts_history(&scripts))?;
+        let watched: HashSet<ScriptBuf> = scripts.into_iter().collect();
+        // Already ordered unconfirmed first, then by height
+        let transactions = history.transactions(&watched, self.network);
+
+        debug!(
+            "Retrieved {} transactions for {} addresses",
+            transactions.len(),
+            addresses.len()
+        );
         Ok(transactions)
     }
 
     /// Create a full wallet from master seed
     ///
-    /// Derives BIP84 keys from the seed:
+    /// Derives keys for the script type's BIP purpose, e.g. for BIP84:
     /// - External: m/84'/0'/0'/0/*
     /// - Internal: m/84'/0'/0'/1/*
     pub fn create_wallet_from_seed(
@@ -312,8 +420,9 @@ impl BitcoinAdapter {
         seed: &[u8; 64],
         wallet_id: &str,
         account: u32,
+        script_type: BitcoinScriptType,
     ) -> Result<()> {
-        let db_path = self.db_path.join(format!("{}.sqlite", wallet_id));
+        let db_path = self.wallet_path(wallet_id);
 
         let secp = Secp256k1::new();
 
@@ -322,12 +431,11 @@ impl BitcoinAdapter {
             Error::Bitcoin(format!("Failed to derive master key: {}", e))
         })?;
 
-        // BIP84 derivation path for account
-        let coin_type = match self.network {
-            Network::Bitcoin => 0,
-            _ => 1, // Testnet uses coin type 1
-        };
-        let account_path: DerivationPath = format!("m/84'/{}'/{}'", coin_type, account)
+        // BIP44/49/84/86 account path, same as the chain module uses
+        // (coin type 1 on test networks)
+        let account_path: DerivationPath = BitcoinModule::for_network(self.network)
+            .with_script_type(script_type)
+            .account_path(account)
             .parse()
             .map_err(|e| Error::Bitcoin(format!("Invalid derivation path: {}", e)))?;
 
@@ -336,16 +444,13 @@ impl BitcoinAdapter {
             .map_err(|e| Error::Bitcoin(format!("Failed to derive account key: {}", e)))?;
 
         // Create descriptors with private keys
-        let external_desc = format!(
-            "wpkh({}/0/*)",
-            account_xpriv
-        );
-        let internal_desc = format!(
-            "wpkh({}/1/*)",
-            account_xpriv
-        );
+        let external_desc = script_type.descriptor(&format!("{}/0/*", account_xpriv));
+        let internal_desc = script_type.descriptor(&format!("{}/1/*", account_xpriv));
 
-        debug!("Creating full wallet for account {}", account);
+        debug!(
+            "Creating full {} wallet for account {}",
+            script_type, account
+        );
 
         // Create database connection
         let mut conn = Connection::open(&db_path).map_err(|e| {
@@ -361,17 +466,24 @@ impl BitcoinAdapter {
                 Error::Bitcoin(format!("Wallet creation failed: {}", e))
             })?;
 
-        info!(
-            "Created full wallet {} for account {}",
-            wallet_id, account
-        );
+        info!("Created full wallet {} for account {}", wallet_id, account);
 
         Ok(())
     }
 
+    /// Path of a wallet's database
+    fn wallet_path(&self, wallet_id: &str) -> PathBuf {
+        self.db_path.join(format!("{}.sqlite", wallet_id))
+    }
+
+    /// Whether a wallet has a database, without loading it
+    pub fn wallet_exists(&self, wallet_id: &str) -> bool {
+        self.wallet_path(wallet_id).exists()
+    }
+
     /// Load an existing wallet from database
-    pub fn load_wallet(&self, wallet_id: &str) -> Result<PersistedWallet<Connection>> {
-        let db_path = self.db_path.join(format!("{}.sqlite", wallet_id));
+    pub fn load_wallet(&self, wallet_id: &str) -> Result<BitcoinWallet> {
+        let db_path = self.wallet_path(wallet_id);
 
         if !db_path.exists() {
             return Err(Error::Bitcoin(format!(
@@ -393,51 +505,221 @@ impl BitcoinAdapter {
             .ok_or_else(|| Error::Bitcoin("Wallet not found in database".to_string()))?;
 
         debug!("Loaded wallet {}", wallet_id);
-        Ok(wallet)
+        BitcoinWallet::new(wallet, conn)
     }
 
     /// Sync wallet with blockchain
-    pub fn sync_wallet(&self, wallet: &mut PersistedWallet<Connection>) -> Result<SyncProgress> {
-        info!("Starting wallet sync...");
-
-        let client = self.create_electrum_client()?;
-
-        // Get the sync request from wallet
-        let request = wallet.start_full_scan().inspect({
-            let mut count = 0;
-            move |_keychain, _spk_i, _script| {
-                count += 1;
-                if count % 10 == 0 {
-                    debug!("Scanned {} scripts", count);
-                }
+    ///
+    /// Runs the full scan on the first sync, then only syncs revealed
+    /// addresses from the last persisted state.
+    pub fn sync_wallet(
+        &self,
+        wallet: &mut BitcoinWallet,
+        monitor: &SyncMonitor,
+    ) -> Result<SyncProgress> {
+        let plan = self.prepare_sync(wallet, false);
+        let result = self
+            .scan(plan, monitor)
+            .and_then(|update| self.apply_sync(wallet, update, monitor));
+        monitor.finish(result)
+    }
+
+    /// Full scan of both keychains up to the gap limit
+    ///
+    /// Also discovers addresses used elsewhere (e.g. by another wallet
+    /// sharing the seed). Used for the first sync and explicit rescans.
+    pub fn rescan_wallet(
+        &self,
+        wallet: &mut BitcoinWallet,
+        monitor: &SyncMonitor,
+    ) -> Result<SyncProgress> {
+        let plan = self.prepare_sync(wallet, true);
+        let result = self
+            .scan(plan, monitor)
+            .and_then(|update| self.apply_sync(wallet, update, monitor));
+        monitor.finish(result)
+    }
+
+    /// Capture what a sync needs to scan
+    ///
+    /// A full scan runs if `rescan` is set or the wallet has never been
+    /// scanned; otherwise only revealed addresses (and unspent outputs,
+    /// so block-scanning backends see their spends) are synced, along with
+    /// the wallet's unconfirmed transactions. The plan doesn't borrow the
+    /// wallet, so the scan can run without holding it.
+    pub fn prepare_sync(&self, wallet: &BitcoinWallet, rescan: bool) -> SyncPlan {
+        let tip = wallet.latest_checkpoint();
+
+        if rescan || !wallet.is_scanned() {
+            // Scripts a scan is expected to check: everything revealed
+            // plus one gap per keychain
+            let spks = wallet.spk_index().all_unbounded_spk_iters();
+            let expected = spks
+                .keys()
+                .map(|&keychain| wallet.derivation_index(keychain).map_or(0, |i| i + 1))
+                .sum::<u32>()
+                + self.gap_limit * spks.len() as u32;
+
+            SyncPlan::Full {
+                tip,
+                spks,
+                expected,
+            }
+        } else {
+            let (txids, inputs) = unconfirmed_checks(wallet, |_| true);
+            SyncPlan::Revealed {
+                tip,
+                spks: wallet.spk_index().revealed_spks(..).collect(),
+                outpoints: wallet
+                    .list_unspent()
+                    .map(|utxo| utxo.outpoint)
+                    .chain(inputs)
+                    .collect(),
+                txids,
             }
+        }
+    }
+
+    /// Capture a sync of some revealed scripts only
+    ///
+    /// Used for push updates, where a subscription says which scripts
+    /// changed: those scripts are synced along with the unspent outputs
+    /// they hold and the unconfirmed transactions paying them. A wallet
+    /// that was never scanned gets its first full scan.
+    pub fn prepare_script_sync(&self, wallet: &BitcoinWallet, scripts: &[ScriptBuf]) -> SyncPlan {
+        if !wallet.is_scanned() {
+            return self.prepare_sync(wallet, false);
+        }
+
+        let (txids, inputs) = unconfirmed_checks(wallet, |tx| {
+            tx.output
+                .iter()
+                .any(|output| scripts.contains(&output.script_pubkey))
         });
+        SyncPlan::Revealed {
+            tip: wallet.latest_checkpoint(),
+            spks: wallet
+                .spk_index()
+                .revealed_spks(..)
+                .filter(|(_, spk)| scripts.contains(spk))
+                .collect(),
+            outpoints: wallet
+                .list_unspent()
+                .filter(|utxo| scripts.contains(&utxo.txout.script_pubkey))
+                .map(|utxo| utxo.outpoint)
+                .chain(inputs)
+                .collect(),
+            txids,
+        }
+    }
 
-        // Perform the sync
-        let update = client
-            .full_scan(request, 5, self.gap_limit as usize, false)
-            .map_err(|e| {
-                error!("Sync failed: {}", e);
-                Error::Bitcoin(format!("Blockchain sync failed: {}", e))
-            })?;
+    /// Every revealed script of the wallet, e.g. to subscribe to
+    pub fn revealed_scripts(&self, wallet: &BitcoinWallet) -> Vec<ScriptBuf> {
+        wallet
+            .spk_index()
+            .revealed_spks(..)
+            .map(|(_, spk)| spk)
+            .collect()
+    }
 
-        // Apply the update to wallet
-        wallet.apply_update(update).map_err(|e| {
-            error!("Failed to apply sync update: {}", e);
-            Error::Bitcoin(format!("Failed to apply sync: {}", e))
-        })?;
+    /// Fetch the chain data for a sync plan
+    ///
+    /// Talks to the backends only; the wallet isn't needed until the
+    /// update is applied.
+    pub fn scan(&self, plan: SyncPlan, monitor: &SyncMonitor) -> Result<Update> {
+        monitor.check()?;
+        monitor.report(&SyncProgress::new(
+            SyncStage::Connecting,
+            PROGRESS_CONNECTING,
+            "Connecting to Bitcoin backend",
+        ));
+
+        // A failed attempt restarts the scan on the next server; a
+        // cancelled scan returns early with partial results, which are
+        // discarded by the second check
+        let update = match plan {
+            SyncPlan::Full {
+                tip,
+                spks,
+                expected,
+            } => {
+                info!("Starting wallet full scan...");
+
+                // Script iterators end once cancelled, so the backend
+                // stops requesting history
+                let request = || {
+                    let mut builder = FullScanRequest::builder().chain_tip(tip.clone());
+                    for (keychain, keychain_spks) in spks.clone() {
+                        let monitor = monitor.clone();
+                        builder = builder.spks_for_keychain(
+                            keychain,
+                            keychain_spks.take_while(move |_| !monitor.is_cancelled()),
+                        );
+                    }
+                    builder
+                        .inspect(monitor.full_scan_inspector(expected))
+                        .build()
+                };
 
-        info!("Wallet sync complete");
+                self.backends
+                    .call("Full scan", |backend| {
+                        monitor.check()?;
+                        let response = backend.full_scan(request(), self.gap_limit as usize)?;
+                        monitor.check()?;
+                        Ok(response)
+                    })?
+                    .into()
+            }
+            SyncPlan::Revealed {
+                tip,
+                spks,
+                outpoints,
+                txids,
+            } => {
+                info!("Starting revealed-address sync...");
+
+                // A request's scripts are taken up front, so a cancelled
+                // sync stops once the request returns
+                self.backends
+                    .call("Sync", |backend| {
+                        monitor.check()?;
+                        let request = SyncRequest::builder()
+                            .chain_tip(tip.clone())
+                            .spks_with_indexes(spks.iter().cloned())
+                            .outpoints(outpoints.iter().copied())
+                            .txids(txids.iter().copied())
+                            .inspect(monitor.sync_inspector())
+                            .build();
+                        let response = backend.sync(request)?;
+                        monitor.check()?;
+                        Ok(response)
+                    })?
+                    .into()
+            }
+        };
 
-        Ok(SyncProgress {
-            stage: SyncStage::Complete,
-            progress: 100.0,
-            message: Some("Sync complete".to_string()),
-        })
+        info!("Wallet scan complete");
+        Ok(update)
+    }
+
+    /// Apply a scanned update to the wallet and persist it
+    pub fn apply_sync(
+        &self,
+        wallet: &mut BitcoinWallet,
+        update: Update,
+        monitor: &SyncMonitor,
+    ) -> Result<()> {
+        monitor.check()?;
+        monitor.report(&SyncProgress::new(
+            SyncStage::Updating,
+            PROGRESS_UPDATING,
+            "Saving wallet state",
+        ));
+        wallet.apply_update(update)
     }
 
     /// Get wallet balance
-    pub fn get_balance(&self, wallet: &PersistedWallet<Connection>) -> Result<BitcoinBalance> {
+    pub fn get_balance(&self, wallet: &BitcoinWallet) -> Result<BitcoinBalance> {
         let balance = wallet.balance();
 
         Ok(BitcoinBalance {
@@ -449,91 +731,167 @@ impl BitcoinAdapter {
     }
 
     /// Get transaction history
-    pub fn get_transactions(&self, wallet: &PersistedWallet<Connection>) -> Result<Vec<BitcoinTransaction>> {
-        let mut transactions = Vec::new();
+    pub fn get_transactions(&self, wallet: &BitcoinWallet) -> Result<Vec<BitcoinTransaction>> {
+        let tip_height = wallet.latest_checkpoint().height();
 
-        // Get all transactions from wallet
-        for tx in wallet.transactions() {
-            let txid = tx.tx_node.txid.to_string();
+        let mut transactions: Vec<BitcoinTransaction> = wallet
+            .transactions()
+            .map(|tx| self.wallet_transaction(wallet, &tx, tip_height))
+            .collect();
 
-            // Calculate amount (positive = received, negative = sent)
-            let mut received: i64 = 0;
-            let sent: i64 = 0;
+        // Sort by timestamp (most recent first)
+        transactions.sort_by_key(|t| std::cmp::Reverse(t.timestamp));
 
-            for output in tx.tx_node.tx.output.iter() {
-                if wallet.is_mine(output.script_pubkey.clone()) {
-                    received += output.value.to_sat() as i64;
-                }
-            }
+        debug!("Retrieved {} transactions", transactions.len());
+        Ok(transactions)
+    }
 
-            // For sent transactions, we need to check inputs
-            // This is a simplified calculation
-            let amount_sats = received - sent;
-
-            let direction = if amount_sats > 0 {
-                TransactionDirection::Received
-            } else if amount_sats < 0 {
-                TransactionDirection::Sent
-            } else {
-                TransactionDirection::Internal
-            };
-
-            // Get confirmation status using struct pattern matching
-            let status = match &tx.chain_position {
-                ChainPosition::Confirmed { anchor, .. } => {
-                    ConfirmationStatus::Confirmed {
-                        block_height: anchor.block_id.height,
-                        block_time: anchor.confirmation_time,
-                        confirmations: 0, // Would need current height to calculate
-                    }
+    /// History entry for one wallet transaction
+    fn wallet_transaction(
+        &self,
+        wallet: &BitcoinWallet,
+        tx: &WalletTx,
+        tip_height: u32,
+    ) -> BitcoinTransaction {
+        let txid = tx.tx_node.txid;
+        let transaction = &tx.tx_node.tx;
+
+        // Net effect on the wallet: positive = received, negative = sent
+        let (sent, received) = wallet.sent_and_received(transaction);
+        let amount_sats = received.to_sat() as i64 - sent.to_sat() as i64;
+
+        // Prevouts are known for our own inputs, and for others' when the
+        // backend fetched them
+        let inputs: Vec<TransactionInput> = transaction
+            .input
+            .iter()
+            .map(|input| {
+                let prevout = wallet.tx_graph().get_txout(input.previous_output);
+                TransactionInput {
+                    previous_output: input.previous_output.to_string(),
+                    address: prevout.and_then(|o| self.script_address(&o.script_pubkey)),
+                    value_sats: prevout.map(|o| o.value.to_sat()),
+                    is_mine: prevout.is_some_and(|o| wallet.is_mine(o.script_pubkey.clone())),
                 }
-                ChainPosition::Unconfirmed { .. } => {
-                    ConfirmationStatus::Unconfirmed
+            })
+            .collect();
+
+        let outputs: Vec<TransactionOutput> = transaction
+            .output
+            .iter()
+            .enumerate()
+            .map(|(vout, output)| {
+                let keychain = wallet
+                    .derivation_of_spk(output.script_pubkey.clone())
+                    .map(|(keychain, _)| keychain);
+                TransactionOutput {
+                    vout: vout as u32,
+                    address: self.script_address(&output.script_pubkey),
+                    value_sats: output.value.to_sat(),
+                    is_mine: keychain.is_some(),
+                    is_change: keychain == Some(KeychainKind::Internal),
                 }
-            };
-
-            let timestamp = match &status {
-                ConfirmationStatus::Confirmed { block_time, .. } => Some(*block_time),
-                ConfirmationStatus::Unconfirmed => None,
-            };
-
-            // Collect addresses
-            let addresses: Vec<String> = tx
-                .tx_node
-                .tx
-                .output
+            })
+            .collect();
+
+        // Spending our coins only to ourselves is a self-transfer, whatever
+        // the fee makes the net amount
+        let direction = if sent.to_sat() > 0 && outputs.iter().all(|o| o.is_mine) {
+            TransactionDirection::Internal
+        } else if amount_sats > 0 {
+            TransactionDirection::Received
+        } else if amount_sats < 0 {
+            TransactionDirection::Sent
+        } else {
+            TransactionDirection::Internal
+        };
+
+        let own_addresses = unique_addresses(
+            inputs
                 .iter()
-                .filter_map(|o| {
-                    bitcoin::Address::from_script(&o.script_pubkey, self.network)
-                        .ok()
-                        .map(|a| a.to_string())
-                })
-                .collect();
-
-            let fee_sats = wallet.calculate_fee(&tx.tx_node.tx).ok().map(|f| f.to_sat());
-
-            transactions.push(BitcoinTransaction {
-                txid,
-                direction,
-                amount_sats,
-                fee_sats,
-                status,
-                timestamp,
-                addresses,
-                size: Some(tx.tx_node.tx.total_size() as u32),
-                vsize: Some(tx.tx_node.tx.vsize() as u32),
-            });
-        }
+                .filter(|i| i.is_mine)
+                .filter_map(|i| i.address.as_ref())
+                .chain(
+                    outputs
+                        .iter()
+                        .filter(|o| o.is_mine)
+                        .filter_map(|o| o.address.as_ref()),
+                ),
+        );
+        let counterparty_addresses = unique_addresses(
+            inputs
+                .iter()
+                .filter(|i| !i.is_mine)
+                .filter_map(|i| i.address.as_ref())
+                .chain(
+                    outputs
+                        .iter()
+                        .filter(|o| !o.is_mine)
+                        .filter_map(|o| o.address.as_ref()),
+                ),
+        );
 
-        // Sort by timestamp (most recent first)
-        transactions.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        // Recipients for payments, our receiving outputs otherwise
+        let show_ours = direction != TransactionDirection::Sent;
+        let addresses = unique_addresses(
+            outputs
+                .iter()
+                .filter(|o| o.is_mine == show_ours)
+                .filter_map(|o| o.address.as_ref()),
+        );
 
-        debug!("Retrieved {} transactions", transactions.len());
-        Ok(transactions)
+        // Only the sender pays (and can compute) the fee
+        let fee_sats = if sent.to_sat() > 0 {
+            wallet.calculate_fee(transaction).ok().map(|f| f.to_sat())
+        } else {
+            None
+        };
+
+        let first_seen = wallet.first_seen(txid);
+        let (status, timestamp) = match &tx.chain_position {
+            ChainPosition::Confirmed { anchor, .. } => {
+                let block_height = anchor.block_id.height;
+                (
+                    ConfirmationStatus::Confirmed {
+                        block_height,
+                        block_time: anchor.confirmation_time,
+                        confirmations: tip_height.saturating_sub(block_height).saturating_add(1),
+                    },
+                    Some(anchor.confirmation_time),
+                )
+            }
+            ChainPosition::Unconfirmed { last_seen } => {
+                (ConfirmationStatus::Unconfirmed, first_seen.or(*last_seen))
+            }
+        };
+
+        BitcoinTransaction {
+            txid: txid.to_string(),
+            direction,
+            amount_sats,
+            fee_sats,
+            status,
+            timestamp,
+            first_seen,
+            addresses,
+            own_addresses,
+            counterparty_addresses,
+            inputs,
+            outputs,
+            size: Some(transaction.total_size() as u32),
+            vsize: Some(transaction.vsize() as u32),
+        }
+    }
+
+    /// Address of an output script on this network
+    fn script_address(&self, script: &Script) -> Option<String> {
+        Address::from_script(script, self.network)
+            .ok()
+            .map(|address| address.to_string())
     }
 
     /// Get list of UTXOs
-    pub fn get_utxos(&self, wallet: &PersistedWallet<Connection>) -> Result<Vec<UtxoInfo>> {
+    pub fn get_utxos(&self, wallet: &BitcoinWallet) -> Result<Vec<UtxoInfo>> {
         let utxos = wallet
             .list_unspent()
             .map(|utxo| {
@@ -567,25 +925,16 @@ impl BitcoinAdapter {
 
     /// Get fee estimate for target confirmation blocks
     pub fn estimate_fee(&self, target_blocks: u32) -> Result<FeeEstimate> {
-        let client = self.create_electrum_client()?;
-
-        let fee_rate = client
-            .inner
-            .estimate_fee(target_blocks as usize)
-            .map_err(|e| Error::Bitcoin(format!("Fee estimation failed: {}", e)))?;
-
-        // Convert from BTC/kB to sat/vB
-        let sat_per_vbyte = (fee_rate * 100_000.0) as f32;
-
-        Ok(FeeEstimate {
-            sat_per_vbyte,
-            target_blocks,
+        self.backends.call("Fee estimation", |backend| {
+            backend.estimate_fee(target_blocks)
         })
     }
 
     /// Get a new receiving address
-    pub fn get_new_address(&self, wallet: &mut PersistedWallet<Connection>) -> Result<String> {
-        let address = wallet.reveal_next_address(KeychainKind::External);
+    ///
+    /// The revealed index is persisted so the address isn't handed out again.
+    pub fn get_new_address(&self, wallet: &mut BitcoinWallet) -> Result<String> {
+        let address = wallet.reveal_next_address(KeychainKind::External)?;
         Ok(address.address.to_string())
     }
 
@@ -599,7 +948,7 @@ impl BitcoinAdapter {
     /// Returns the signed transaction hex and txid
     pub fn create_and_send_transaction(
         &self,
-        wallet: &mut PersistedWallet<Connection>,
+        wallet: &mut BitcoinWallet,
         recipient_address: &str,
         amount_sats: u64,
         fee_rate: f32, // sat/vB
@@ -621,7 +970,7 @@ impl BitcoinAdapter {
             .map_err(|e| Error::Bitcoin(format!("Address network mismatch: {}", e)))?;
 
         // Build the transaction
-        let mut tx_builder = wallet.build_tx();
+        let mut tx_builder = wallet.wallet.build_tx();
         tx_builder
             .add_recipient(address.script_pubkey(), Amount::from_sat(amount_sats))
             .fee_rate(bdk_wallet::bitcoin::FeeRate::from_sat_per_vb(fee_rate as u64).unwrap());
@@ -653,18 +1002,29 @@ impl BitcoinAdapter {
         let fee_sats = wallet.calculate_fee(&tx).ok().map(|f| f.to_sat());
         let vsize = tx.vsize() as u32;
 
-        info!("Transaction created: {} (fee: {:?} sats, vsize: {})", txid, fee_sats, vsize);
+        info!(
+            "Transaction created: {} (fee: {:?} sats, vsize: {})",
+            txid, fee_sats, vsize
+        );
 
         // Optionally broadcast
         if broadcast {
-            let client = self.create_electrum_client()?;
-            client.inner.transaction_broadcast(&tx).map_err(|e| {
-                error!("Failed to broadcast transaction: {}", e);
-                Error::Bitcoin(format!("Transaction broadcast failed: {}", e))
-            })?;
+            self.backends
+                .call("Broadcast", |backend| backend.broadcast(&tx))?;
             info!("Transaction broadcast: {}", txid);
+
+            // Track it as unconfirmed so the balance reflects the spend
+            // before the next sync
+            let seen_at = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            wallet.wallet.apply_unconfirmed_txs([(tx, seen_at)]);
         }
 
+        // Persist the revealed change address and any applied transaction
+        wallet.persist()?;
+
         Ok(SendTransactionResult {
             txid,
             tx_hex,
@@ -675,14 +1035,15 @@ impl BitcoinAdapter {
     }
 }
 
-/// Get default Electrum URL for a network
-fn get_default_electrum_url(network: BitcoinNetwork) -> String {
-    match network {
-        BitcoinNetwork::Mainnet => "ssl://electrum.blockstream.info:50002".to_string(),
-        BitcoinNetwork::Testnet => "ssl://electrum.blockstream.info:60002".to_string(),
-        BitcoinNetwork::Signet => "ssl://mempool.space:60602".to_string(),
-        BitcoinNetwork::Regtest => "tcp://127.0.0.1:50001".to_string(),
+/// Addresses in first-seen order without repeats
+fn unique_addresses<'a>(addresses: impl Iterator<Item = &'a String>) -> Vec<String> {
+    let mut unique: Vec<String> = Vec::new();
+    for address in addresses {
+        if !unique.contains(address) {
+            unique.push(address.clone());
+        }
     }
+    unique
 }
 
 #[cfg(test)]
@@ -703,4 +1064,278 @@ mod tests {
         let adapter = BitcoinAdapter::testnet(temp_dir.path().to_path_buf());
         assert_eq!(adapter.network, Network::Testnet);
     }
+
+    #[test]
+    fn test_esplora_adapter() {
+        let temp_dir = TempDir::new().unwrap();
+        let adapter = BitcoinAdapter::new(
+            BitcoinConfig {
+                network: BitcoinNetwork::Regtest,
+                backend: BlockchainBackend::Esplora {
+                    url: "http://127.0.0.1:3002".to_string(),
+                },
+                fallback_backends: vec![BlockchainBackend::Electrum {
+                    url: "tcp://127.0.0.1:50001".to_string(),
+                }],
+                gap_limit: 20,
+            },
+            temp_dir.path().to_path_buf(),
+        );
+
+        assert_eq!(adapter.network, Network::Regtest);
+
+        let status = adapter.backends().status();
+        assert_eq!(status.len(), 2);
+        assert_eq!(status[0].kind, BackendKind::Esplora);
+        assert_eq!(status[0].url, "http://127.0.0.1:3002");
+        assert_eq!(status[1].kind, BackendKind::Electrum);
+        assert!(status.iter().all(|s| s.health == BackendHealth::Unknown));
+    }
+
+    #[test]
+    fn test_revealed_addresses_persist() {
+        // "abandon ... about" seed
+        let seed_hex = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+        let mut seed = [0u8; 64];
+        hex::decode_to_slice(seed_hex, &mut seed).unwrap();
+
+        let temp_dir = TempDir::new().unwrap();
+        let adapter = BitcoinAdapter::mainnet(temp_dir.path().to_path_buf());
+        adapter
+            .create_wallet_from_seed(&seed, "btc", 0, BitcoinScriptType::P2wpkh)
+            .unwrap();
+
+        let mut wallet = adapter.load_wallet("btc").unwrap();
+        assert!(!wallet.is_scanned());
+        let first = adapter.get_new_address(&mut wallet).unwrap();
+        assert_eq!(first, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        drop(wallet);
+
+        // A reload must not hand out the same address again
+        let mut wallet = adapter.load_wallet("btc").unwrap();
+        let second = adapter.get_new_address(&mut wallet).unwrap();
+        assert_ne!(first, second);
+        assert!(!wallet.persist().unwrap());
+    }
+
+    #[test]
+    fn test_descriptor_watch_wallet_exports() {
+        // BIP84 account 0 of the "abandon ... about" seed
+        let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+        let temp_dir = TempDir::new().unwrap();
+        let adapter = BitcoinAdapter::mainnet(temp_dir.path().to_path_buf());
+
+        adapter.create_watch_wallet(zpub, "zpub").unwrap();
+        let mut wallet = adapter.load_wallet("zpub").unwrap();
+        assert_eq!(
+            adapter.get_new_address(&mut wallet).unwrap(),
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        );
+        let converted = BitcoinAdapter::wallet_descriptors(&wallet);
+        assert!(converted.internal.is_some());
+
+        // A descriptor with key origins is exported as imported
+        let multipath = converted
+            .multipath()
+            .unwrap()
+            .replace("wpkh(", "wpkh([73c5da0a/84'/0'/0']");
+        let multipath = multipath.split('#').next().unwrap();
+        adapter.create_watch_wallet(multipath, "sparrow").unwrap();
+        let wallet = adapter.load_wallet("sparrow").unwrap();
+        let exported = BitcoinAdapter::wallet_descriptors(&wallet);
+        assert!(exported
+            .external
+            .starts_with("wpkh([73c5da0a/84'/0'/0']xpub"));
+        assert_eq!(
+            exported,
+            WalletDescriptors::parse(multipath, Network::Bitcoin).unwrap()
+        );
+
+        let testnet = BitcoinAdapter::testnet(temp_dir.path().to_path_buf());
+        assert!(testnet.create_watch_wallet(zpub, "wrong-network").is_err());
+    }
+
+    #[test]
+    fn test_single_address_watch_wallet_network() {
+        let temp_dir = TempDir::new().unwrap();
+        let adapter = BitcoinAdapter::mainnet(temp_dir.path().to_path_buf());
+
+        assert!(adapter
+            .create_watch_wallet("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq", "mainnet")
+            .is_ok());
+        assert!(adapter
+            .create_watch_wallet("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx", "testnet")
+            .is_err());
+
+        // Queries and subscriptions refuse addresses of other networks too
+        assert!(adapter
+            .address_script("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq")
+            .is_ok());
+        assert!(adapter
+            .address_script("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx")
+            .is_err());
+        assert!(adapter
+            .get_address_balance("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx")
+            .is_err());
+    }
+
+    #[test]
+    fn test_transaction_history_details() {
+        use bdk_wallet::bitcoin::hashes::Hash;
+        use bdk_wallet::bitcoin::{
+            absolute, transaction, Amount, BlockHash, Transaction, TxIn, TxOut,
+        };
+        use bdk_wallet::chain::{BlockId, ConfirmationBlockTime, TxUpdate};
+        use std::sync::Arc;
+
+        let seed_hex = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+        let mut seed = [0u8; 64];
+        hex::decode_to_slice(seed_hex, &mut seed).unwrap();
+
+        let temp_dir = TempDir::new().unwrap();
+        let adapter = BitcoinAdapter::mainnet(temp_dir.path().to_path_buf());
+        adapter
+            .create_wallet_from_seed(&seed, "btc", 0, BitcoinScriptType::P2wpkh)
+            .unwrap();
+        let mut wallet = adapter.load_wallet("btc").unwrap();
+
+        let ours = wallet
+            .reveal_next_address(KeychainKind::External)
+            .unwrap()
+            .address;
+        let change = wallet
+            .reveal_next_address(KeychainKind::Internal)
+            .unwrap()
+            .address;
+        let foreign = |address: &str| {
+            address
+                .parse::<Address<bitcoin::address::NetworkUnchecked>>()
+                .unwrap()
+                .assume_checked()
+        };
+        let sender_change = foreign("1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA");
+        let recipient = foreign("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq");
+
+        let tx = |input: OutPoint, outputs: &[(&Address, u64)]| Transaction {
+            version: transaction::Version::TWO,
+            lock_time: absolute::LockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: input,
+                ..Default::default()
+            }],
+            output: outputs
+                .iter()
+                .map(|(address, sats)| TxOut {
+                    value: Amount::from_sat(*sats),
+                    script_pubkey: address.script_pubkey(),
+                })
+                .collect(),
+        };
+
+        // Someone pays us, then we pay a recipient with change back
+        let funding = tx(
+            OutPoint::new(Txid::from_byte_array([7; 32]), 0),
+            &[(&ours, 100_000), (&sender_change, 50_000)],
+        );
+        let funding_txid = funding.compute_txid();
+        let payment = tx(
+            OutPoint::new(funding_txid, 0),
+            &[(&recipient, 30_000), (&change, 69_000)],
+        );
+        let payment_txid = payment.compute_txid();
+
+        // Funding confirmed at height 100, tip at 105; the wallet stamps
+        // the unconfirmed payment as seen now
+        let block = BlockId {
+            height: 100,
+            hash: BlockHash::from_byte_array([1; 32]),
+        };
+        let tip = wallet.latest_checkpoint().insert(block).insert(BlockId {
+            height: 105,
+            hash: BlockHash::from_byte_array([2; 32]),
+        });
+        let mut tx_update = TxUpdate {
+            txs: vec![Arc::new(funding), Arc::new(payment)],
+            ..Default::default()
+        };
+        tx_update.anchors.insert((
+            ConfirmationBlockTime {
+                block_id: block,
+                confirmation_time: 1_700_000_000,
+            },
+            funding_txid,
+        ));
+        wallet
+            .apply_update(Update {
+                last_active_indices: BTreeMap::new(),
+                tx_update,
+                chain: Some(tip),
+            })
+            .unwrap();
+
+        let history = adapter.get_transactions(&wallet).unwrap();
+        assert_eq!(history.len(), 2);
+
+        // Unconfirmed payment sorts first by its first-seen time
+        let seen_now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let sent = &history[0];
+        assert_eq!(sent.txid, payment_txid.to_string());
+        assert_eq!(sent.direction, TransactionDirection::Sent);
+        assert_eq!(sent.amount_sats, -31_000);
+        assert_eq!(sent.fee_sats, Some(1_000));
+        assert!(!sent.is_confirmed());
+        let first_seen = sent.first_seen.unwrap();
+        assert!(first_seen > 1_700_000_000 && first_seen <= seen_now);
+        assert_eq!(sent.timestamp, Some(first_seen));
+        assert_eq!(sent.addresses, [recipient.to_string()]);
+        assert_eq!(sent.own_addresses, [ours.to_string(), change.to_string()]);
+        assert_eq!(sent.counterparty_addresses, [recipient.to_string()]);
+        assert!(sent.inputs[0].is_mine);
+        assert_eq!(sent.inputs[0].value_sats, Some(100_000));
+        assert!(sent.outputs[1].is_mine && sent.outputs[1].is_change);
+        assert!(!sent.outputs[0].is_mine);
+
+        let received = &history[1];
+        assert_eq!(received.direction, TransactionDirection::Received);
+        assert_eq!(received.amount_sats, 100_000);
+        assert_eq!(received.fee_sats, None);
+        assert_eq!(received.confirmations(), 6);
+        assert_eq!(received.timestamp, Some(1_700_000_000));
+        assert_eq!(received.first_seen, None);
+        assert_eq!(received.addresses, [ours.to_string()]);
+        assert_eq!(received.counterparty_addresses, [sender_change.to_string()]);
+        assert_eq!(received.inputs[0].address, None);
+        assert_eq!(received.inputs[0].value_sats, None);
+        assert!(received.outputs[0].is_mine && !received.outputs[0].is_change);
+        drop(wallet);
+
+        // First-seen times survive a reload
+        let wallet = adapter.load_wallet("btc").unwrap();
+        assert_eq!(wallet.first_seen(payment_txid), Some(first_seen));
+    }
+
+    #[test]
+    fn test_is_single_address() {
+        assert!(BitcoinAdapter::is_single_address(
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        ));
+        assert!(BitcoinAdapter::is_single_address(
+            "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"
+        ));
+        assert!(BitcoinAdapter::is_single_address(
+            "tb1q6rz28mcfaxtmd6v789l9rrlrusdprr9pqcpvkl"
+        ));
+
+        // Prefixes alone are not enough
+        assert!(!BitcoinAdapter::is_single_address(
+            "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabB"
+        ));
+        assert!(!BitcoinAdapter::is_single_address("mainnet"));
+        assert!(!BitcoinAdapter::is_single_address(
+            "tpubD6NzVbkrYhZ4XgiXtGrdW5XDAPFCL9h7we1vwNCpn8tGbBcgfVYjXyhWo4E1xkh56hjod1RhGjxbaTLV3X4FyWuejifB9jusQ46QzG87VKp"
+        ));
+    }
 }
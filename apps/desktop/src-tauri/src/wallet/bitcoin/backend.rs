@@ -0,0 +1,739 @@
+//! Chain backends and multi-server failover
+//!
+//! `BitcoinBackend` is the interface the adapter uses for everything that
+//! needs the network: wallet scans, single-address queries, fee estimates
+//! and broadcast. Electrum, Esplora and Bitcoin Core RPC implement it.
+//!
+//! `BackendPool` holds the configured servers in order of preference and
+//! runs each call against the first one that answers:
+//!
+//! - servers are tried in configured order, so the preferred server is used
+//!   whenever it is up
+//! - a failing call moves on to the next server; when every server failed,
+//!   the whole round is retried after an exponential backoff
+//! - a server that cannot answer a kind of call (`Error::Unsupported`) is
+//!   skipped without counting as a failure
+//! - a request the server refused on its merits (`Error::Rejected`, e.g. a
+//!   broadcast with too low a fee) is returned as is; other servers would
+//!   refuse it too
+//! - after `DOWN_AFTER_FAILURES` consecutive failures a server is marked
+//!   down and only tried as a last resort until `DOWN_COOLDOWN` passes
+//! - health checks (`check_health`) query the tip height and track a
+//!   smoothed latency per server
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use bdk_wallet::bitcoin::{Script, ScriptBuf, Transaction};
+use bdk_wallet::chain::spk_client::{FullScanRequest, FullScanResponse, SyncRequest, SyncResponse};
+use bdk_wallet::KeychainKind;
+use parking_lot::Mutex;
+use tracing::{debug, info, warn};
+
+use super::electrum::ElectrumBackend;
+use super::esplora::EsploraClient;
+use super::history::ScriptHistory;
+use super::rpc::BitcoindBackend;
+use super::types::*;
+use crate::error::{Error, Result};
+
+/// Consecutive failures after which a server is considered down
+const DOWN_AFTER_FAILURES: u32 = 3;
+
+/// How long a down server is skipped before it is tried in order again
+const DOWN_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Weight of the newest sample in the smoothed latency
+const LATENCY_SMOOTHING: f64 = 0.3;
+
+/// A chain source the adapter can query
+///
+/// Implementations are blocking and must be safe to share between threads;
+/// connection state is kept inside the backend and reused across calls.
+pub trait BitcoinBackend: Send + Sync {
+    /// Protocol spoken by this backend
+    fn kind(&self) -> BackendKind;
+
+    /// Server URL
+    fn url(&self) -> &str;
+
+    /// Current chain tip height; also used as the health check
+    fn tip_height(&self) -> Result<u32>;
+
+    /// Scan both keychains until `stop_gap` consecutive unused scripts
+    fn full_scan(
+        &self,
+        request: FullScanRequest<KeychainKind>,
+        stop_gap: usize,
+    ) -> Result<FullScanResponse<KeychainKind>>;
+
+    /// Sync the scripts, txids and outpoints of a sync request
+    fn sync(&self, request: SyncRequest<(KeychainKind, u32)>) -> Result<SyncResponse>;
+
+    /// Balance of a single script
+    fn script_balance(&self, script: &Script) -> Result<BitcoinBalance>;
+
+    /// Transactions touching any of `scripts`, with the previous outputs
+    /// needed to value their inputs
+    fn scripts_history(&self, scripts: &[ScriptBuf]) -> Result<ScriptHistory>;
+
+    /// Fee rate for confirmation within `target_blocks`
+    fn estimate_fee(&self, target_blocks: u32) -> Result<FeeEstimate>;
+
+    /// Broadcast a signed transaction
+    fn broadcast(&self, tx: &Transaction) -> Result<()>;
+
+    /// Drop any cached connection so the next call reconnects
+    fn reset(&self) {}
+}
+
+/// Create the backend for a configured server
+pub fn connect(backend: &BlockchainBackend) -> Box<dyn BitcoinBackend> {
+    match backend {
+        BlockchainBackend::Electrum { url } => Box::new(ElectrumBackend::new(url)),
+        BlockchainBackend::Esplora { url } => Box::new(EsploraClient::new(url)),
+        BlockchainBackend::Rpc {
+            url,
+            auth,
+            start_height,
+        } => Box::new(BitcoindBackend::new(url, auth.clone(), *start_height)),
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// ============================================================================
+// Retry Policy
+// ============================================================================
+
+/// How often a call is retried once every server has failed
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Rounds over all servers (1 = no retry)
+    pub max_rounds: u32,
+    /// Delay before the second round; doubles each round
+    pub initial_backoff: Duration,
+    /// Upper bound for the delay between rounds
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Delay before `round` (0-based; round 0 starts immediately)
+    pub fn backoff(&self, round: u32) -> Duration {
+        if round == 0 {
+            return Duration::ZERO;
+        }
+        let factor = 1u32.checked_shl(round - 1).unwrap_or(u32::MAX);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_rounds: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+// ============================================================================
+// Server Health
+// ============================================================================
+
+#[derive(Debug, Default)]
+struct ServerHealth {
+    latency_ms: Option<f64>,
+    tip_height: Option<u32>,
+    consecutive_failures: u32,
+    last_error: Option<String>,
+    last_success: Option<u64>,
+    last_failure: Option<u64>,
+    down_until: Option<Instant>,
+}
+
+impl ServerHealth {
+    fn health(&self) -> BackendHealth {
+        match (self.consecutive_failures, self.last_success) {
+            (0, None) if self.last_failure.is_none() => BackendHealth::Unknown,
+            (0, _) => BackendHealth::Healthy,
+            (n, _) if n >= DOWN_AFTER_FAILURES => BackendHealth::Down,
+            _ => BackendHealth::Degraded,
+        }
+    }
+
+    fn is_cooling_down(&self, now: Instant) -> bool {
+        self.down_until.is_some_and(|until| now < until)
+    }
+
+    fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.last_error = None;
+        self.last_success = Some(unix_now());
+        self.down_until = None;
+    }
+
+    fn record_latency(&mut self, elapsed: Duration) {
+        let sample = elapsed.as_secs_f64() * 1000.0;
+        self.latency_ms = Some(match self.latency_ms {
+            Some(previous) => previous + LATENCY_SMOOTHING * (sample - previous),
+            None => sample,
+        });
+    }
+
+    fn record_failure(&mut self, error: &Error) {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        self.last_error = Some(error.to_string());
+        self.last_failure = Some(unix_now());
+        if self.consecutive_failures >= DOWN_AFTER_FAILURES {
+            self.down_until = Some(Instant::now() + DOWN_COOLDOWN);
+        }
+    }
+}
+
+struct Server {
+    backend: Box<dyn BitcoinBackend>,
+    health: Mutex<ServerHealth>,
+}
+
+// ============================================================================
+// Backend Pool
+// ============================================================================
+
+/// Ordered set of backends with failover and health tracking
+pub struct BackendPool {
+    servers: Vec<Server>,
+    /// Index of the server that answered the last successful call
+    active: AtomicUsize,
+    retry: RetryPolicy,
+}
+
+impl BackendPool {
+    /// Create a pool over backends in order of preference
+    pub fn new(backends: Vec<Box<dyn BitcoinBackend>>, retry: RetryPolicy) -> Self {
+        let servers = backends
+            .into_iter()
+            .map(|backend| Server {
+                backend,
+                health: Mutex::new(ServerHealth::default()),
+            })
+            .collect();
+
+        Self {
+            servers,
+            active: AtomicUsize::new(0),
+            retry,
+        }
+    }
+
+    /// Create a pool for every backend in a configuration
+    pub fn from_config(config: &BitcoinConfig) -> Self {
+        let backends = config.backends().map(connect).collect();
+        Self::new(backends, RetryPolicy::default())
+    }
+
+    /// Number of configured servers
+    pub fn len(&self) -> usize {
+        self.servers.len()
+    }
+
+    /// Whether no servers are configured
+    pub fn is_empty(&self) -> bool {
+        self.servers.is_empty()
+    }
+
+    /// URLs of the servers speaking `kind`, in order of preference
+    pub fn urls(&self, kind: BackendKind) -> Vec<String> {
+        self.servers
+            .iter()
+            .filter(|server| server.backend.kind() == kind)
+            .map(|server| server.backend.url().to_string())
+            .collect()
+    }
+
+    /// The server that answered the last successful call
+    pub fn active(&self) -> Option<&dyn BitcoinBackend> {
+        self.servers
+            .get(self.active.load(Ordering::Relaxed))
+            .map(|server| server.backend.as_ref())
+    }
+
+    /// Server indices in the order they should be tried
+    ///
+    /// Configured order, with servers in their down cooldown moved to the
+    /// end so they are only used when nothing else answers.
+    fn candidates(&self) -> Vec<usize> {
+        let now = Instant::now();
+        let (mut available, cooling_down): (Vec<usize>, Vec<usize>) = (0..self.servers.len())
+            .partition(|&i| !self.servers[i].health.lock().is_cooling_down(now));
+        available.extend(cooling_down);
+        available
+    }
+
+    /// Run `operation` against the first server that succeeds
+    ///
+    /// Failed servers are reset (dropping their connection) and recorded
+    /// as unhealthy. If every server fails, the round is retried after the
+    /// policy's backoff; the last error is returned once rounds run out.
+    /// Servers that don't support the call are skipped, and it is not
+    /// retried when none does. Rejected requests are returned right away.
+    pub fn call<T>(
+        &self,
+        operation: &str,
+        mut f: impl FnMut(&dyn BitcoinBackend) -> Result<T>,
+    ) -> Result<T> {
+        let mut last_error = None;
+        let mut unsupported = None;
+
+        for round in 0..self.retry.max_rounds.max(1) {
+            let delay = self.retry.backoff(round);
+            if !delay.is_zero() {
+                debug!(
+                    "Retrying {} in {:?} (round {})",
+                    operation,
+                    delay,
+                    round + 1
+                );
+                std::thread::sleep(delay);
+            }
+
+            let mut attempted = false;
+            for index in self.candidates() {
+                let server = &self.servers[index];
+                match f(server.backend.as_ref()) {
+                    Ok(value) => {
+                        server.health.lock().record_success();
+                        let previous = self.active.swap(index, Ordering::Relaxed);
+                        if previous != index {
+                            info!("Bitcoin backend switched to {}", server.backend.url());
+                        }
+                        return Ok(value);
+                    }
+                    // Not the server's fault; don't retry elsewhere
+                    Err(e @ (Error::Cancelled(_) | Error::Rejected(_))) => return Err(e),
+                    Err(e @ Error::Unsupported(_)) => {
+                        debug!("{} skipped {}: {}", operation, server.backend.url(), e);
+                        unsupported = Some(e);
+                    }
+                    Err(e) => {
+                        attempted = true;
+                        warn!("{} via {} failed: {}", operation, server.backend.url(), e);
+                        server.backend.reset();
+                        server.health.lock().record_failure(&e);
+                        last_error = Some(e);
+                    }
+                }
+            }
+
+            if !attempted {
+                break;
+            }
+        }
+
+        Err(last_error
+            .or(unsupported)
+            .unwrap_or_else(|| Error::Bitcoin("No Bitcoin backends configured".to_string())))
+    }
+
+    /// Query every server's tip height and update its health and latency
+    pub fn check_health(&self) -> Vec<BackendStatus> {
+        for server in &self.servers {
+            let started = Instant::now();
+            let result = server.backend.tip_height();
+            let elapsed = started.elapsed();
+
+            let mut health = server.health.lock();
+            match result {
+                Ok(height) => {
+                    health.record_success();
+                    health.record_latency(elapsed);
+                    health.tip_height = Some(height);
+                }
+                Err(e) => {
+                    server.backend.reset();
+                    health.record_failure(&e);
+                }
+            }
+        }
+
+        self.status()
+    }
+
+    /// Current status of every server, in configured order
+    pub fn status(&self) -> Vec<BackendStatus> {
+        let active = self.active.load(Ordering::Relaxed);
+
+        self.servers
+            .iter()
+            .enumerate()
+            .map(|(index, server)| {
+                let health = server.health.lock();
+                BackendStatus {
+                    kind: server.backend.kind(),
+                    url: server.backend.url().to_string(),
+                    health: health.health(),
+                    active: index == active,
+                    latency_ms: health.latency_ms.map(|ms| ms.round() as u64),
+                    tip_height: health.tip_height,
+                    consecutive_failures: health.consecutive_failures,
+                    last_error: health.last_error.clone(),
+                    last_success: health.last_success,
+                    last_failure: health.last_failure,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+    use std::sync::Arc;
+
+    /// Backend that fails a fixed number of calls, then answers
+    struct MockBackend {
+        url: String,
+        failures_left: AtomicU32,
+        calls: Arc<AtomicU32>,
+        resets: Arc<AtomicU32>,
+    }
+
+    impl MockBackend {
+        fn new(url: &str, failures: u32) -> Self {
+            Self {
+                url: url.to_string(),
+                failures_left: AtomicU32::new(failures),
+                calls: Arc::new(AtomicU32::new(0)),
+                resets: Arc::new(AtomicU32::new(0)),
+            }
+        }
+
+        fn answer(&self) -> Result<u32> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let failing = self
+                .failures_left
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+            if failing {
+                Err(Error::Bitcoin(format!("{} unavailable", self.url)))
+            } else {
+                Ok(800_000)
+            }
+        }
+
+        /// Only `tip_height` is answered; other calls take the error path
+        fn unsupported<T>(&self, call: &str) -> Result<T> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Err(Error::Unsupported(format!(
+                "{} cannot answer {}",
+                self.url, call
+            )))
+        }
+    }
+
+    impl BitcoinBackend for MockBackend {
+        fn kind(&self) -> BackendKind {
+            BackendKind::Electrum
+        }
+
+        fn url(&self) -> &str {
+            &self.url
+        }
+
+        fn tip_height(&self) -> Result<u32> {
+            self.answer()
+        }
+
+        fn full_scan(
+            &self,
+            _request: FullScanRequest<KeychainKind>,
+            _stop_gap: usize,
+        ) -> Result<FullScanResponse<KeychainKind>> {
+            self.unsupported("full scan")
+        }
+
+        fn sync(&self, _request: SyncRequest<(KeychainKind, u32)>) -> Result<SyncResponse> {
+            self.unsupported("sync")
+        }
+
+        fn script_balance(&self, _script: &Script) -> Result<BitcoinBalance> {
+            self.unsupported("script balance")
+        }
+
+        fn scripts_history(&self, _scripts: &[ScriptBuf]) -> Result<ScriptHistory> {
+            self.unsupported("scripts history")
+        }
+
+        fn estimate_fee(&self, _target_blocks: u32) -> Result<FeeEstimate> {
+            self.unsupported("fee estimate")
+        }
+
+        fn broadcast(&self, _tx: &Transaction) -> Result<()> {
+            self.unsupported("broadcast")
+        }
+
+        fn reset(&self) {
+            self.resets.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn no_delay(max_rounds: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_rounds,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        }
+    }
+
+    fn pool(failures: &[u32], retry: RetryPolicy) -> BackendPool {
+        let backends = failures
+            .iter()
+            .enumerate()
+            .map(|(i, &n)| {
+                Box::new(MockBackend::new(&format!("server-{}", i), n)) as Box<dyn BitcoinBackend>
+            })
+            .collect();
+        BackendPool::new(backends, retry)
+    }
+
+    #[test]
+    fn test_prefers_first_server() {
+        let fallback = MockBackend::new("server-1", 0);
+        let fallback_calls = fallback.calls.clone();
+        let pool = BackendPool::new(
+            vec![
+                Box::new(MockBackend::new("server-0", 0)),
+                Box::new(fallback),
+            ],
+            no_delay(1),
+        );
+
+        assert_eq!(pool.call("tip", |b| b.tip_height()).unwrap(), 800_000);
+        assert_eq!(pool.active().unwrap().url(), "server-0");
+        assert_eq!(fallback_calls.load(Ordering::SeqCst), 0);
+
+        let status = pool.status();
+        assert_eq!(status[0].health, BackendHealth::Healthy);
+        assert_eq!(status[1].health, BackendHealth::Unknown);
+        assert!(status[0].active);
+        assert!(!status[1].active);
+    }
+
+    #[test]
+    fn test_fails_over_to_next_server() {
+        let pool = pool(&[1, 0], no_delay(1));
+
+        assert!(pool.call("tip", |b| b.tip_height()).is_ok());
+        assert_eq!(pool.active().unwrap().url(), "server-1");
+
+        let status = pool.status();
+        assert_eq!(status[0].health, BackendHealth::Degraded);
+        assert_eq!(status[0].consecutive_failures, 1);
+        assert_eq!(
+            status[0].last_error.as_deref(),
+            Some("Bitcoin error: server-0 unavailable")
+        );
+        assert_eq!(status[1].health, BackendHealth::Healthy);
+
+        // The preferred server is tried first again and recovers
+        assert!(pool.call("tip", |b| b.tip_height()).is_ok());
+        assert_eq!(pool.active().unwrap().url(), "server-0");
+        assert_eq!(pool.status()[0].health, BackendHealth::Healthy);
+    }
+
+    #[test]
+    fn test_failed_server_is_reset() {
+        let mock = MockBackend::new("server-0", 1);
+        let (calls, resets) = (mock.calls.clone(), mock.resets.clone());
+        let pool = BackendPool::new(vec![Box::new(mock)], no_delay(2));
+
+        // Fails in the first round, answers in the retry
+        assert!(pool.call("tip", |b| b.tip_height()).is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(resets.load(Ordering::SeqCst), 1);
+
+        let status = pool.status();
+        assert_eq!(status[0].health, BackendHealth::Healthy);
+        assert!(status[0].last_error.is_none());
+    }
+
+    #[test]
+    fn test_retries_rounds_then_gives_up() {
+        let pool = pool(&[u32::MAX, u32::MAX], no_delay(2));
+
+        let err = pool.call("tip", |b| b.tip_height()).unwrap_err();
+        assert!(err.to_string().contains("server-1 unavailable"));
+
+        let status = pool.status();
+        assert_eq!(status[0].consecutive_failures, 2);
+        assert_eq!(status[1].consecutive_failures, 2);
+        assert_eq!(status[0].health, BackendHealth::Degraded);
+    }
+
+    #[test]
+    fn test_cancellation_stops_without_failover() {
+        let pool = pool(&[0, 0], no_delay(3));
+
+        let mut calls = 0;
+        let err = pool
+            .call("tip", |_| -> Result<u32> {
+                calls += 1;
+                Err(Error::Cancelled("Sync cancelled".to_string()))
+            })
+            .unwrap_err();
+        assert!(matches!(err, Error::Cancelled(_)));
+        assert_eq!(calls, 1);
+        assert!(pool
+            .status()
+            .iter()
+            .all(|s| s.consecutive_failures == 0 && s.health == BackendHealth::Unknown));
+    }
+
+    #[test]
+    fn test_rejection_is_not_retried() {
+        let mock = MockBackend::new("server-0", 0);
+        let resets = mock.resets.clone();
+        let pool = BackendPool::new(
+            vec![Box::new(mock), Box::new(MockBackend::new("server-1", 0))],
+            no_delay(3),
+        );
+
+        let mut calls = 0;
+        let err = pool
+            .call("Broadcast", |_| -> Result<()> {
+                calls += 1;
+                Err(Error::Rejected("min relay fee not met".to_string()))
+            })
+            .unwrap_err();
+        assert!(matches!(err, Error::Rejected(_)));
+        assert_eq!(calls, 1);
+        assert_eq!(resets.load(Ordering::SeqCst), 0);
+        assert!(pool
+            .status()
+            .iter()
+            .all(|s| s.consecutive_failures == 0 && s.last_error.is_none()));
+    }
+
+    #[test]
+    fn test_unsupported_server_is_skipped() {
+        let pool = pool(&[0, 0], no_delay(3));
+
+        let mut calls = Vec::new();
+        let height = pool
+            .call("history", |b| {
+                calls.push(b.url().to_string());
+                if b.url() == "server-0" {
+                    return Err(Error::Unsupported("no address index".to_string()));
+                }
+                b.tip_height()
+            })
+            .unwrap();
+        assert_eq!(height, 800_000);
+        assert_eq!(calls, ["server-0", "server-1"]);
+
+        let status = pool.status();
+        assert_eq!(status[0].consecutive_failures, 0);
+        assert_eq!(status[0].health, BackendHealth::Unknown);
+        assert!(status[0].last_error.is_none());
+    }
+
+    #[test]
+    fn test_unsupported_everywhere_is_not_retried() {
+        let mock = MockBackend::new("server-0", 0);
+        let calls = mock.calls.clone();
+        let resets = mock.resets.clone();
+        let pool = BackendPool::new(vec![Box::new(mock)], no_delay(3));
+
+        let err = pool.call("fee", |b| b.estimate_fee(6)).unwrap_err();
+        assert!(matches!(err, Error::Unsupported(_)));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(resets.load(Ordering::SeqCst), 0);
+        assert_eq!(pool.status()[0].consecutive_failures, 0);
+    }
+
+    #[test]
+    fn test_down_server_is_tried_last() {
+        let pool = pool(&[DOWN_AFTER_FAILURES, 0], no_delay(1));
+
+        for _ in 0..DOWN_AFTER_FAILURES {
+            assert!(pool.call("tip", |b| b.tip_height()).is_ok());
+        }
+        assert_eq!(pool.status()[0].health, BackendHealth::Down);
+
+        // Server 0 would answer now, but it is cooling down
+        assert_eq!(pool.candidates(), vec![1, 0]);
+        assert!(pool.call("tip", |b| b.tip_height()).is_ok());
+        assert_eq!(pool.active().unwrap().url(), "server-1");
+    }
+
+    #[test]
+    fn test_down_server_used_as_last_resort() {
+        let pool = pool(
+            &[DOWN_AFTER_FAILURES, u32::MAX],
+            no_delay(DOWN_AFTER_FAILURES + 1),
+        );
+
+        // Both fail for three rounds, then only the down server recovers
+        assert!(pool.call("tip", |b| b.tip_height()).is_ok());
+        assert_eq!(pool.active().unwrap().url(), "server-0");
+        assert_eq!(pool.status()[0].health, BackendHealth::Healthy);
+    }
+
+    #[test]
+    fn test_check_health_tracks_latency() {
+        let pool = pool(&[0, 1], no_delay(1));
+
+        let status = pool.check_health();
+        assert_eq!(status[0].health, BackendHealth::Healthy);
+        assert_eq!(status[0].tip_height, Some(800_000));
+        assert!(status[0].latency_ms.is_some());
+        assert!(status[0].last_success.is_some());
+
+        assert_eq!(status[1].health, BackendHealth::Degraded);
+        assert!(status[1].latency_ms.is_none());
+        assert!(status[1].last_failure.is_some());
+    }
+
+    #[test]
+    fn test_empty_pool() {
+        let pool = BackendPool::new(Vec::new(), RetryPolicy::default());
+        assert!(pool.is_empty());
+        assert!(pool.active().is_none());
+        assert!(pool.call("tip", |b| b.tip_height()).is_err());
+    }
+
+    #[test]
+    fn test_backoff() {
+        let retry = RetryPolicy {
+            max_rounds: 10,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(5),
+        };
+
+        assert_eq!(retry.backoff(0), Duration::ZERO);
+        assert_eq!(retry.backoff(1), Duration::from_millis(500));
+        assert_eq!(retry.backoff(2), Duration::from_secs(1));
+        assert_eq!(retry.backoff(3), Duration::from_secs(2));
+        assert_eq!(retry.backoff(5), Duration::from_secs(5));
+        assert_eq!(retry.backoff(40), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_latency_smoothing() {
+        let mut health = ServerHealth::default();
+        health.record_latency(Duration::from_millis(100));
+        assert_eq!(health.latency_ms, Some(100.0));
+
+        health.record_latency(Duration::from_millis(200));
+        assert!((health.latency_ms.unwrap() - 130.0).abs() < 1e-6);
+    }
+}
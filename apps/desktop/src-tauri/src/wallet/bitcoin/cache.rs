@@ -0,0 +1,310 @@
+//! Wallet cache - loaded BDK wallets shared between commands
+//!
+//! Loading a wallet reopens its SQLite database and rebuilds the BDK
+//! wallet from the stored changesets, so wallets are loaded once and
+//! kept. Each wallet sits behind its own async lock: commands on one
+//! wallet run one at a time (a send can't race a sync on the same file),
+//! while different wallets proceed in parallel.
+//!
+//! Syncs only hold the lock to capture the scan plan and to apply the
+//! result; the network scan runs unlocked, so balance and history
+//! queries answer from the last synced state while a sync is running.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use bdk_wallet::bitcoin::ScriptBuf;
+use parking_lot::Mutex;
+use tokio::sync::Mutex as AsyncMutex;
+use tracing::debug;
+
+use super::adapter::{BitcoinAdapter, BitcoinWallet, SyncPlan};
+use super::sync::SyncMonitor;
+use super::types::SyncProgress;
+use crate::error::{Error, Result};
+
+/// One cached wallet and its lock
+#[derive(Default)]
+struct WalletSlot {
+    /// Set once the slot leaves the cache; holders must look it up again
+    evicted: AtomicBool,
+    /// Shared so an owned guard can move to a blocking worker
+    wallet: Arc<AsyncMutex<Option<BitcoinWallet>>>,
+}
+
+/// Loaded wallets by ID, each behind its own async lock
+pub struct WalletCache {
+    adapter: Arc<BitcoinAdapter>,
+    slots: Mutex<HashMap<String, Arc<WalletSlot>>>,
+}
+
+impl WalletCache {
+    /// Create an empty cache loading wallets through `adapter`
+    pub fn new(adapter: Arc<BitcoinAdapter>) -> Self {
+        Self {
+            adapter,
+            slots: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The adapter wallets are loaded with
+    pub fn adapter(&self) -> &Arc<BitcoinAdapter> {
+        &self.adapter
+    }
+
+    /// Run `f` with exclusive access to a wallet
+    ///
+    /// Loads the wallet on first use. `f` runs on a blocking worker, as
+    /// loading, persisting and signing all touch the disk. A wallet that
+    /// fails to load leaves no slot behind.
+    pub async fn with_wallet<T, F>(&self, wallet_id: &str, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&BitcoinAdapter, &mut BitcoinWallet) -> Result<T> + Send + 'static,
+    {
+        let (slot, mut wallet) = loop {
+            let slot = self.slot(wallet_id);
+            let wallet = slot.wallet.clone().lock_owned().await;
+            // Evicted while we waited; a fresh slot may already be loading
+            if !slot.evicted.load(Ordering::Acquire) {
+                break (slot, wallet);
+            }
+        };
+
+        let adapter = self.adapter.clone();
+        let task_slot = slot.clone();
+        let task_wallet_id = wallet_id.to_string();
+        let result = tokio::task::spawn_blocking(move || {
+            if wallet.is_none() {
+                match adapter.load_wallet(&task_wallet_id) {
+                    Ok(loaded) => *wallet = Some(loaded),
+                    Err(e) => {
+                        // Waiters look the wallet up again
+                        task_slot.evicted.store(true, Ordering::Release);
+                        return Err(e);
+                    }
+                }
+                debug!("Cached wallet {}", task_wallet_id);
+            }
+            let wallet = wallet.as_mut().expect("wallet loaded above");
+            f(&adapter, wallet)
+        })
+        .await
+        .map_err(|e| Error::Bitcoin(format!("Wallet task failed: {}", e)))?;
+
+        if slot.evicted.load(Ordering::Acquire) {
+            let mut slots = self.slots.lock();
+            if slots.get(wallet_id).is_some_and(|s| Arc::ptr_eq(s, &slot)) {
+                slots.remove(wallet_id);
+            }
+        }
+        result
+    }
+
+    /// Whether a wallet is in the cache (loaded or loading)
+    #[cfg(test)]
+    fn is_cached(&self, wallet_id: &str) -> bool {
+        self.slots.lock().contains_key(wallet_id)
+    }
+
+    /// Sync a wallet without blocking readers during the scan
+    ///
+    /// The wallet is locked to plan the sync and again to apply the
+    /// result. With `rescan` (or on the first sync) the full gap limit is
+    /// scanned.
+    pub async fn sync(
+        &self,
+        wallet_id: &str,
+        rescan: bool,
+        monitor: &SyncMonitor,
+    ) -> Result<SyncProgress> {
+        let plan = move |adapter: &BitcoinAdapter, wallet: &mut BitcoinWallet| {
+            Ok(adapter.prepare_sync(wallet, rescan))
+        };
+        let result = self.run_sync(wallet_id, plan, monitor).await;
+        monitor.finish(result)
+    }
+
+    /// Sync only some of a wallet's scripts, e.g. ones a subscription
+    /// reported as changed
+    pub async fn sync_scripts(
+        &self,
+        wallet_id: &str,
+        scripts: Vec<ScriptBuf>,
+        monitor: &SyncMonitor,
+    ) -> Result<SyncProgress> {
+        let plan = move |adapter: &BitcoinAdapter, wallet: &mut BitcoinWallet| {
+            Ok(adapter.prepare_script_sync(wallet, &scripts))
+        };
+        let result = self.run_sync(wallet_id, plan, monitor).await;
+        monitor.finish(result)
+    }
+
+    async fn run_sync<P>(&self, wallet_id: &str, plan: P, monitor: &SyncMonitor) -> Result<()>
+    where
+        P: FnOnce(&BitcoinAdapter, &mut BitcoinWallet) -> Result<SyncPlan> + Send + 'static,
+    {
+        let plan = self.with_wallet(wallet_id, plan).await?;
+
+        let update = {
+            let adapter = self.adapter.clone();
+            let monitor = monitor.clone();
+            tokio::task::spawn_blocking(move || adapter.scan(plan, &monitor))
+                .await
+                .map_err(|e| Error::Bitcoin(format!("Sync task failed: {}", e)))??
+        };
+
+        let monitor = monitor.clone();
+        self.with_wallet(wallet_id, move |adapter, wallet| {
+            adapter.apply_sync(wallet, update, &monitor)
+        })
+        .await
+    }
+
+    /// Drop a wallet from the cache
+    ///
+    /// Waits for running commands on the wallet to finish, so nothing is
+    /// still writing to its database afterwards. The next use reloads it
+    /// from disk.
+    pub async fn evict(&self, wallet_id: &str) {
+        let slot = self.slots.lock().remove(wallet_id);
+        if let Some(slot) = slot {
+            Self::close(wallet_id, &slot).await;
+        }
+    }
+
+    /// Drop every wallet from the cache (e.g. when the session locks)
+    pub async fn clear(&self) {
+        let slots: Vec<_> = self.slots.lock().drain().collect();
+        for (wallet_id, slot) in &slots {
+            Self::close(wallet_id, slot).await;
+        }
+    }
+
+    /// The wallet's slot, created on first use
+    fn slot(&self, wallet_id: &str) -> Arc<WalletSlot> {
+        self.slots
+            .lock()
+            .entry(wallet_id.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Mark a removed slot evicted and drop its wallet once it's free
+    async fn close(wallet_id: &str, slot: &WalletSlot) {
+        slot.evicted.store(true, Ordering::Release);
+        if slot.wallet.lock().await.take().is_some() {
+            debug!("Evicted wallet {}", wallet_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::chains::BitcoinScriptType;
+    use bdk_wallet::KeychainKind;
+    use tempfile::TempDir;
+
+    fn cache_with_wallet(temp_dir: &TempDir) -> WalletCache {
+        // "abandon ... about" seed
+        let seed_hex = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+        let mut seed = [0u8; 64];
+        hex::decode_to_slice(seed_hex, &mut seed).unwrap();
+
+        let adapter = BitcoinAdapter::mainnet(temp_dir.path().to_path_buf());
+        adapter
+            .create_wallet_from_seed(&seed, "btc", 0, BitcoinScriptType::P2wpkh)
+            .unwrap();
+        WalletCache::new(Arc::new(adapter))
+    }
+
+    /// Reveal an address without persisting it, so only the cached
+    /// wallet knows about it
+    async fn reveal_unpersisted(cache: &WalletCache) {
+        cache
+            .with_wallet("btc", |_, wallet| {
+                wallet.reveal_unpersisted(KeychainKind::External);
+                Ok(())
+            })
+            .await
+            .unwrap();
+    }
+
+    async fn revealed_index(cache: &WalletCache) -> Option<u32> {
+        cache
+            .with_wallet("btc", |_, wallet| {
+                Ok(wallet.derivation_index(KeychainKind::External))
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_wallet_is_loaded_once() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = cache_with_wallet(&temp_dir);
+
+        reveal_unpersisted(&cache).await;
+        assert_eq!(revealed_index(&cache).await, Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_evicted_wallet_reloads_from_disk() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = cache_with_wallet(&temp_dir);
+
+        reveal_unpersisted(&cache).await;
+        cache.evict("btc").await;
+        assert_eq!(revealed_index(&cache).await, None);
+
+        reveal_unpersisted(&cache).await;
+        cache.clear().await;
+        assert_eq!(revealed_index(&cache).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_missing_wallet() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = cache_with_wallet(&temp_dir);
+
+        let err = cache
+            .with_wallet("missing", |_, _| Ok(()))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not found"));
+        assert!(!cache.is_cached("missing"));
+        assert!(!cache.adapter().wallet_exists("missing"));
+        assert!(cache.adapter().wallet_exists("btc"));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_commands_on_a_wallet_are_serialized() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Arc::new(cache_with_wallet(&temp_dir));
+
+        // Each task reveals and persists one address; interleaving would
+        // hand two of them the same index
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let cache = cache.clone();
+                tokio::spawn(async move {
+                    cache
+                        .with_wallet("btc", |adapter, wallet| adapter.get_new_address(wallet))
+                        .await
+                        .unwrap()
+                })
+            })
+            .collect();
+
+        let mut addresses = Vec::new();
+        for task in tasks {
+            addresses.push(task.await.unwrap());
+        }
+        addresses.sort();
+        addresses.dedup();
+        assert_eq!(addresses.len(), 4);
+        assert_eq!(revealed_index(&cache).await, Some(3));
+    }
+}
@@ -0,0 +1,383 @@
+//! Watch-only wallet descriptors
+//!
+//! Turns whatever the user imports into the receive and change descriptors
+//! of a BDK wallet:
+//!
+//! - Extended public keys: xpub/tpub watch BIP84 (P2WPKH); SLIP-132 versions
+//!   (ypub, zpub, ...) pick their own script type
+//! - Output descriptors, with or without key origins and checksum: a
+//!   multipath descriptor (`/<0;1>/*`), a receive and change pair, or a
+//!   lone receive descriptor (`/0/*`, whose change descriptor is inferred)
+//! - Wallet exports: Sparrow's text file (`#` comment lines), Specter's
+//!   JSON (`descriptor`) and Bitcoin Core's `listdescriptors` output
+//!
+//! Every extended key must belong to the configured network, and private
+//! keys are refused. BDK persists the descriptors with the wallet, so they
+//! can be exported again as given.
+
+use bdk_wallet::bitcoin::secp256k1::Secp256k1;
+use bdk_wallet::bitcoin::{Network, NetworkKind};
+use bdk_wallet::miniscript::descriptor::{DescriptorPublicKey, Wildcard};
+use bdk_wallet::miniscript::{Descriptor, ForEachKey};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+use crate::wallet::chains::secp256k1::slip132::{self, ExtendedKey};
+use crate::wallet::chains::BitcoinScriptType;
+
+/// Receive and change descriptors of a wallet, with checksums
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WalletDescriptors {
+    /// Descriptor of receive addresses
+    pub external: String,
+    /// Descriptor of change addresses; `None` if the wallet has no change keychain
+    pub internal: Option<String>,
+}
+
+type PublicDescriptor = Descriptor<DescriptorPublicKey>;
+
+impl WalletDescriptors {
+    /// Descriptors for an imported extended key, descriptor or wallet export
+    pub fn parse(input: &str, network: Network) -> Result<Self> {
+        let input = input.trim();
+
+        if let Some((version, key)) = slip132::decode(input) {
+            let ExtendedKey::Public(xpub) = key else {
+                return Err(Error::Bitcoin(format!(
+                    "{} is a private key; provide the matching public key",
+                    version.prefix
+                )));
+            };
+            let script_type = match version.script_type {
+                None | Some("p2wpkh") => BitcoinScriptType::P2wpkh,
+                Some("p2sh-p2wpkh") => BitcoinScriptType::P2shP2wpkh,
+                Some(_) => {
+                    return Err(Error::Bitcoin(format!(
+                        "{} is a multisig key; import the wallet's output descriptor instead",
+                        version.prefix
+                    )))
+                }
+            };
+            let descriptor = script_type.descriptor(&format!("{}/<0;1>/*", xpub));
+            return Self::from_entries(vec![(descriptor, None)], network);
+        }
+
+        let entries = if input.starts_with('{') || input.starts_with('[') {
+            json_entries(input)?
+        } else {
+            input
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .flat_map(str::split_whitespace)
+                .map(|descriptor| (descriptor.to_string(), None))
+                .collect()
+        };
+        Self::from_entries(entries, network)
+    }
+
+    /// Pair up descriptors, each optionally flagged as change (`true`)
+    fn from_entries(entries: Vec<(String, Option<bool>)>, network: Network) -> Result<Self> {
+        let mut descriptors = Vec::new();
+        for (input, internal) in entries {
+            let descriptor = parse_public(&input, network)?;
+            if !descriptor.is_multipath() {
+                descriptors.push((descriptor, internal));
+                continue;
+            }
+
+            let paths = descriptor
+                .into_single_descriptors()
+                .map_err(|e| Error::Bitcoin(format!("Invalid descriptor: {}", e)))?;
+            let [external, change] = <[_; 2]>::try_from(paths).map_err(|paths| {
+                Error::Bitcoin(format!(
+                    "Multipath descriptor has {} paths; expected receive and change",
+                    paths.len()
+                ))
+            })?;
+            descriptors.push((external, Some(false)));
+            descriptors.push((change, Some(true)));
+        }
+
+        let (external, internal) = match descriptors.as_slice() {
+            [] => return Err(Error::Bitcoin("No descriptor found".to_string())),
+            [(_, Some(true))] => {
+                return Err(Error::Bitcoin(
+                    "Only a change descriptor was given; import the receive descriptor too"
+                        .to_string(),
+                ))
+            }
+            [(external, _)] => (external.clone(), change_descriptor(external, network)?),
+            [(first, first_internal), (second, second_internal)] => {
+                if *first_internal == Some(true) || *second_internal == Some(false) {
+                    (second.clone(), Some(first.clone()))
+                } else {
+                    (first.clone(), Some(second.clone()))
+                }
+            }
+            _ => {
+                return Err(Error::Bitcoin(format!(
+                    "Found {} descriptors; import a single receive and change pair",
+                    descriptors.len()
+                )))
+            }
+        };
+
+        if internal.as_ref() == Some(&external) {
+            return Err(Error::Bitcoin(
+                "Receive and change descriptors are the same".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            external: external.to_string(),
+            internal: internal.map(|d| d.to_string()),
+        })
+    }
+
+    /// Receive and change descriptors combined as one multipath descriptor
+    /// when they differ only in the receive/change step
+    pub fn multipath(&self) -> Option<String> {
+        let internal = self.internal.as_deref()?;
+        let body = |descriptor: &str| descriptor.split('#').next().unwrap_or_default().to_string();
+        let (external, internal) = (body(&self.external), body(internal));
+
+        let combined = external.replace("/0/*", "/<0;1>/*");
+        (combined != external && external.replace("/0/*", "/1/*") == internal)
+            .then(|| combined.parse::<PublicDescriptor>().ok())
+            .flatten()
+            .map(|descriptor| descriptor.to_string())
+    }
+}
+
+/// Parse a public descriptor, checking its checksum and keys' network
+fn parse_public(input: &str, network: Network) -> Result<PublicDescriptor> {
+    let secp = Secp256k1::new();
+    let (descriptor, key_map) = Descriptor::parse_descriptor(&secp, input)
+        .map_err(|e| Error::Bitcoin(format!("Invalid descriptor: {}", e)))?;
+
+    if !key_map.is_empty() {
+        return Err(Error::Bitcoin(
+            "Descriptor contains private keys; import its public version for a watch-only wallet"
+                .to_string(),
+        ));
+    }
+
+    let expected = NetworkKind::from(network);
+    let wrong_network = !descriptor.for_each_key(|key| match key {
+        DescriptorPublicKey::XPub(xkey) => xkey.xkey.network == expected,
+        DescriptorPublicKey::MultiXPub(xkey) => xkey.xkey.network == expected,
+        DescriptorPublicKey::Single(_) => true,
+    });
+    if wrong_network {
+        return Err(Error::Bitcoin(format!(
+            "Descriptor keys are not for the configured network ({})",
+            network
+        )));
+    }
+
+    Ok(descriptor)
+}
+
+/// Change descriptor of a lone receive descriptor
+///
+/// Inferred, as Sparrow and Specter do, when every key derives its
+/// addresses at `.../0/*`; otherwise the wallet gets no change keychain.
+fn change_descriptor(
+    external: &PublicDescriptor,
+    network: Network,
+) -> Result<Option<PublicDescriptor>> {
+    let receive_path = external.for_each_key(|key| match key {
+        DescriptorPublicKey::XPub(xkey) => {
+            xkey.wildcard != Wildcard::None
+                && xkey
+                    .derivation_path
+                    .as_ref()
+                    .last()
+                    .is_some_and(|step| u32::from(*step) == 0)
+        }
+        _ => false,
+    });
+    if !receive_path {
+        return Ok(None);
+    }
+
+    let body = external.to_string();
+    let body = body.split('#').next().unwrap_or_default();
+    parse_public(&body.replace("/0/*", "/1/*"), network).map(Some)
+}
+
+/// Descriptors of a Specter or Bitcoin Core JSON export
+///
+/// Specter stores one receive descriptor under `descriptor`; Core lists
+/// `{desc, internal}` entries under `descriptors` (`listdescriptors`) or at
+/// the top level (`importdescriptors`).
+fn json_entries(input: &str) -> Result<Vec<(String, Option<bool>)>> {
+    let json: serde_json::Value = serde_json::from_str(input)
+        .map_err(|e| Error::Bitcoin(format!("Invalid wallet export: {}", e)))?;
+
+    if let Some(descriptor) = json.get("descriptor").and_then(|d| d.as_str()) {
+        return Ok(vec![(descriptor.to_string(), None)]);
+    }
+
+    let entries = json
+        .get("descriptors")
+        .unwrap_or(&json)
+        .as_array()
+        .ok_or_else(|| Error::Bitcoin("Wallet export has no descriptors".to_string()))?;
+
+    entries
+        .iter()
+        .map(|entry| match entry {
+            serde_json::Value::String(descriptor) => Ok((descriptor.clone(), None)),
+            _ => {
+                let descriptor = entry.get("desc").and_then(|d| d.as_str()).ok_or_else(|| {
+                    Error::Bitcoin("Wallet export entry has no descriptor".to_string())
+                })?;
+                let internal = entry.get("internal").and_then(|i| i.as_bool());
+                Ok((descriptor.to_string(), internal))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bdk_wallet::bitcoin::bip32::Xpub;
+    use bdk_wallet::miniscript::descriptor::checksum::desc_checksum;
+
+    const ZPUB: &str = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+    const YPUB: &str = "ypub6Ww3ibxVfGzLrAH1PNcjyAWenMTbbAosGNB6VvmSEgytSER9azLDWCxoJwW7Ke7icmizBMXrzBx9979FfaHxHcrArf3zbeJJJUZPf663zsP";
+
+    /// Plain xpub encoding of a SLIP-132 key
+    fn xpub(slip132: &str) -> Xpub {
+        match slip132::decode(slip132) {
+            Some((_, ExtendedKey::Public(xpub))) => xpub.to_string().parse().unwrap(),
+            _ => panic!("{} should decode", slip132),
+        }
+    }
+
+    fn with_checksum(descriptor: &str) -> String {
+        format!("{}#{}", descriptor, desc_checksum(descriptor).unwrap())
+    }
+
+    #[test]
+    fn test_slip132_keys() {
+        let zpub = WalletDescriptors::parse(ZPUB, Network::Bitcoin).unwrap();
+        assert_eq!(
+            zpub.external,
+            with_checksum(&format!("wpkh({}/0/*)", xpub(ZPUB)))
+        );
+        assert_eq!(
+            zpub.internal,
+            Some(with_checksum(&format!("wpkh({}/1/*)", xpub(ZPUB))))
+        );
+
+        let ypub = WalletDescriptors::parse(YPUB, Network::Bitcoin).unwrap();
+        assert_eq!(
+            ypub.external,
+            with_checksum(&format!("sh(wpkh({}/0/*))", xpub(YPUB)))
+        );
+
+        // Plain xpubs keep the BIP84 default
+        let plain = WalletDescriptors::parse(&xpub(ZPUB).to_string(), Network::Bitcoin).unwrap();
+        assert_eq!(plain, zpub);
+
+        let err = WalletDescriptors::parse(ZPUB, Network::Testnet).unwrap_err();
+        assert!(err.to_string().contains("network"));
+    }
+
+    #[test]
+    fn test_multipath_descriptor_with_origin() {
+        let descriptor = format!("tr([73c5da0a/86'/0'/0']{}/<0;1>/*)", xpub(ZPUB));
+        let parsed =
+            WalletDescriptors::parse(&with_checksum(&descriptor), Network::Bitcoin).unwrap();
+
+        assert_eq!(
+            parsed.external,
+            with_checksum(&format!("tr([73c5da0a/86'/0'/0']{}/0/*)", xpub(ZPUB)))
+        );
+        assert_eq!(
+            parsed.internal,
+            Some(with_checksum(&format!(
+                "tr([73c5da0a/86'/0'/0']{}/1/*)",
+                xpub(ZPUB)
+            )))
+        );
+        assert_eq!(parsed.multipath(), Some(with_checksum(&descriptor)));
+
+        let bad_checksum = format!("{}#aaaaaaaa", descriptor);
+        assert!(WalletDescriptors::parse(&bad_checksum, Network::Bitcoin).is_err());
+    }
+
+    #[test]
+    fn test_receive_descriptor_infers_change() {
+        let multisig = format!(
+            "wsh(sortedmulti(2,[73c5da0a/48'/0'/0'/2']{}/0/*,[73c5da0a/48'/0'/1'/2']{}/0/*))",
+            xpub(ZPUB),
+            xpub(YPUB)
+        );
+        let parsed = WalletDescriptors::parse(&multisig, Network::Bitcoin).unwrap();
+        assert_eq!(parsed.external, with_checksum(&multisig));
+        assert_eq!(
+            parsed.internal,
+            Some(with_checksum(&multisig.replace("/0/*", "/1/*")))
+        );
+
+        // Without a receive step there is nothing to infer
+        let legacy = format!("pkh({}/*)", xpub(ZPUB));
+        let parsed = WalletDescriptors::parse(&legacy, Network::Bitcoin).unwrap();
+        assert_eq!(parsed.internal, None);
+        assert_eq!(parsed.multipath(), None);
+    }
+
+    #[test]
+    fn test_wallet_exports() {
+        let receive = with_checksum(&format!("wpkh([73c5da0a/84'/0'/0']{}/0/*)", xpub(ZPUB)));
+        let change = with_checksum(&format!("wpkh([73c5da0a/84'/0'/0']{}/1/*)", xpub(ZPUB)));
+
+        // Sparrow: comments, then the descriptor
+        let sparrow = format!("# Receive and change descriptor\n{}\n", receive);
+        let parsed = WalletDescriptors::parse(&sparrow, Network::Bitcoin).unwrap();
+        assert_eq!(parsed.external, receive);
+        assert_eq!(parsed.internal.as_ref(), Some(&change));
+
+        // Core: flagged entries, change listed first
+        let core = serde_json::json!({
+            "wallet_name": "watch",
+            "descriptors": [
+                { "desc": change, "internal": true, "active": true },
+                { "desc": receive, "internal": false, "active": true },
+            ],
+        });
+        let parsed = WalletDescriptors::parse(&core.to_string(), Network::Bitcoin).unwrap();
+        assert_eq!(parsed.external, receive);
+        assert_eq!(parsed.internal.as_ref(), Some(&change));
+
+        // Specter: receive descriptor only
+        let specter = serde_json::json!({ "label": "watch", "descriptor": receive });
+        let parsed = WalletDescriptors::parse(&specter.to_string(), Network::Bitcoin).unwrap();
+        assert_eq!(parsed.internal.as_ref(), Some(&change));
+    }
+
+    #[test]
+    fn test_rejected_inputs() {
+        let xprv = "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi";
+        let err =
+            WalletDescriptors::parse(&format!("wpkh({}/0/*)", xprv), Network::Bitcoin).unwrap_err();
+        assert!(err.to_string().contains("private keys"));
+        assert!(WalletDescriptors::parse(xprv, Network::Bitcoin).is_err());
+
+        let mut tpub = xpub(ZPUB);
+        tpub.network = NetworkKind::Test;
+        let testnet = format!("wpkh({}/<0;1>/*)", tpub);
+        assert!(WalletDescriptors::parse(&testnet, Network::Bitcoin).is_err());
+        assert!(WalletDescriptors::parse(&testnet, Network::Signet).is_ok());
+
+        let three = format!("{0} {0} {0}", format!("pkh({}/*)", xpub(ZPUB)));
+        assert!(WalletDescriptors::parse(&three, Network::Bitcoin).is_err());
+        assert!(WalletDescriptors::parse("", Network::Bitcoin).is_err());
+        assert!(WalletDescriptors::parse("wpkh(notakey)", Network::Bitcoin).is_err());
+    }
+}
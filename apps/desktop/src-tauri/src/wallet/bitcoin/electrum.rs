@@ -0,0 +1,381 @@
+//! Electrum backend - chain source over the Electrum protocol
+//!
+//! Keeps one connection per server and reuses it across calls, so the
+//! `BdkElectrumClient` transaction and header caches survive between
+//! syncs. A failed call drops the connection; the next one reconnects.
+//!
+//! Address history is fetched in batches: the histories of all watched
+//! scripts, then the transactions, then the parents needed to work out
+//! fees. Fetched transactions are immutable and cached for the life of
+//! the backend.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use bdk_electrum::electrum_client::{self, ConfigBuilder, ElectrumApi};
+use bdk_electrum::BdkElectrumClient;
+use bdk_wallet::bitcoin::{OutPoint, Script, ScriptBuf, Transaction, Txid};
+use bdk_wallet::chain::spk_client::{FullScanRequest, FullScanResponse, SyncRequest, SyncResponse};
+use bdk_wallet::KeychainKind;
+use parking_lot::Mutex;
+use tracing::{debug, error, info};
+
+use super::backend::BitcoinBackend;
+use super::history::{ScriptHistory, ScriptTx};
+use super::types::*;
+use crate::error::{Error, Result};
+
+/// Scripts per Electrum batch request
+const ELECTRUM_BATCH_SIZE: usize = 5;
+
+/// Transactions or headers per Electrum batch request
+const ELECTRUM_TX_BATCH_SIZE: usize = 50;
+
+/// Confirmations after which a block time is cached
+const BLOCK_TIME_CACHE_DEPTH: u32 = 6;
+
+/// Socket timeout in seconds
+const ELECTRUM_TIMEOUT_SECS: u8 = 30;
+
+fn electrum_error(context: &str, e: electrum_client::Error) -> Error {
+    error!("Electrum {} failed: {}", context, e);
+    Error::Bitcoin(format!("Electrum {} failed: {}", context, e))
+}
+
+/// Electrum server with a reusable connection
+pub struct ElectrumBackend {
+    url: String,
+    client: Mutex<Option<Arc<BdkElectrumClient<electrum_client::Client>>>>,
+    /// Transactions fetched for address history; kept across reconnects
+    tx_cache: Mutex<HashMap<Txid, Arc<Transaction>>>,
+    /// Block times of buried blocks by height
+    block_time_cache: Mutex<HashMap<u32, u64>>,
+}
+
+impl ElectrumBackend {
+    /// Create a backend for an Electrum URL (e.g. `ssl://host:50002`)
+    ///
+    /// Connects lazily on the first call.
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            client: Mutex::new(None),
+            tx_cache: Mutex::new(HashMap::new()),
+            block_time_cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Shared connection, opened on first use
+    fn client(&self) -> Result<Arc<BdkElectrumClient<electrum_client::Client>>> {
+        let mut client = self.client.lock();
+        if let Some(client) = client.as_ref() {
+            return Ok(client.clone());
+        }
+
+        info!("Connecting to Electrum server: {}", self.url);
+        let config = ConfigBuilder::new()
+            .timeout(Some(ELECTRUM_TIMEOUT_SECS))
+            .build();
+        let connected = electrum_client::Client::from_config(&self.url, config)
+            .map_err(|e| electrum_error("connection", e))?;
+
+        let connected = Arc::new(BdkElectrumClient::new(connected));
+        *client = Some(connected.clone());
+        Ok(connected)
+    }
+
+    /// Transactions in `txids` order, fetching uncached ones in batches
+    fn transactions(
+        &self,
+        client: &BdkElectrumClient<electrum_client::Client>,
+        txids: &[Txid],
+    ) -> Result<Vec<Arc<Transaction>>> {
+        let missing: Vec<Txid> = {
+            let cache = self.tx_cache.lock();
+            txids
+                .iter()
+                .filter(|&txid| !cache.contains_key(txid))
+                .copied()
+                .collect()
+        };
+
+        for chunk in missing.chunks(ELECTRUM_TX_BATCH_SIZE) {
+            let fetched = client
+                .inner
+                .batch_transaction_get(chunk)
+                .map_err(|e| electrum_error("transaction fetch", e))?;
+            let mut cache = self.tx_cache.lock();
+            for (txid, tx) in chunk.iter().zip(fetched) {
+                if tx.compute_txid() != *txid {
+                    return Err(Error::Bitcoin(format!(
+                        "Electrum server returned the wrong transaction for {}",
+                        txid
+                    )));
+                }
+                cache.insert(*txid, Arc::new(tx));
+            }
+        }
+
+        let cache = self.tx_cache.lock();
+        txids
+            .iter()
+            .map(|txid| {
+                cache.get(txid).cloned().ok_or_else(|| {
+                    Error::Bitcoin(format!("Transaction {} missing from cache", txid))
+                })
+            })
+            .collect()
+    }
+
+    /// Block times by height, fetching uncached headers in batches
+    ///
+    /// Only blocks buried `BLOCK_TIME_CACHE_DEPTH` deep are cached, so a
+    /// reorg near the tip can't leave a stale time behind.
+    fn block_times(
+        &self,
+        client: &BdkElectrumClient<electrum_client::Client>,
+        heights: &[u32],
+        tip_height: u32,
+    ) -> Result<HashMap<u32, u64>> {
+        let mut times = HashMap::new();
+        let mut missing = Vec::new();
+        {
+            let cache = self.block_time_cache.lock();
+            for &height in heights {
+                match cache.get(&height) {
+                    Some(&time) => {
+                        times.insert(height, time);
+                    }
+                    None => missing.push(height),
+                }
+            }
+        }
+
+        for chunk in missing.chunks(ELECTRUM_TX_BATCH_SIZE) {
+            let headers = client
+                .inner
+                .batch_block_header(chunk.iter().copied())
+                .map_err(|e| electrum_error("header fetch", e))?;
+            let mut cache = self.block_time_cache.lock();
+            for (&height, header) in chunk.iter().zip(headers) {
+                let time = header.time as u64;
+                if tip_height.saturating_sub(height) + 1 >= BLOCK_TIME_CACHE_DEPTH {
+                    cache.insert(height, time);
+                }
+                times.insert(height, time);
+            }
+        }
+        Ok(times)
+    }
+}
+
+impl BitcoinBackend for ElectrumBackend {
+    fn kind(&self) -> BackendKind {
+        BackendKind::Electrum
+    }
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn tip_height(&self) -> Result<u32> {
+        let header = self
+            .client()?
+            .inner
+            .block_headers_subscribe()
+            .map_err(|e| electrum_error("tip query", e))?;
+        Ok(header.height as u32)
+    }
+
+    fn full_scan(
+        &self,
+        request: FullScanRequest<KeychainKind>,
+        stop_gap: usize,
+    ) -> Result<FullScanResponse<KeychainKind>> {
+        self.client()?
+            .full_scan(request, stop_gap, ELECTRUM_BATCH_SIZE, false)
+            .map_err(|e| electrum_error("full scan", e))
+    }
+
+    fn sync(&self, request: SyncRequest<(KeychainKind, u32)>) -> Result<SyncResponse> {
+        self.client()?
+            .sync(request, ELECTRUM_BATCH_SIZE, false)
+            .map_err(|e| electrum_error("sync", e))
+    }
+
+    fn script_balance(&self, script: &Script) -> Result<BitcoinBalance> {
+        let balance = self
+            .client()?
+            .inner
+            .script_get_balance(script)
+            .map_err(|e| electrum_error("balance query", e))?;
+
+        Ok(BitcoinBalance {
+            confirmed: balance.confirmed,
+            unconfirmed: balance.unconfirmed as u64,
+            immature: 0,
+            trusted_spendable: balance.confirmed,
+        })
+    }
+
+    fn scripts_history(&self, scripts: &[ScriptBuf]) -> Result<ScriptHistory> {
+        let client = self.client()?;
+        let tip_height = client
+            .inner
+            .block_headers_subscribe()
+            .map_err(|e| electrum_error("tip query", e))?
+            .height as u32;
+
+        // Watched scripts can share transactions; keep the first entry
+        let mut heights: HashMap<Txid, i32> = HashMap::new();
+        let mut txids = Vec::new();
+        for chunk in scripts.chunks(ELECTRUM_BATCH_SIZE) {
+            let histories = client
+                .inner
+                .batch_script_get_history(chunk.iter().map(|s| s.as_script()))
+                .map_err(|e| electrum_error("history query", e))?;
+            for entry in histories.into_iter().flatten() {
+                if heights.insert(entry.tx_hash, entry.height).is_none() {
+                    txids.push(entry.tx_hash);
+                }
+            }
+        }
+        let txs = self.transactions(&client, &txids)?;
+
+        // Inputs spending a watched script need every parent for the fee
+        let watched: HashSet<&ScriptBuf> = scripts.iter().collect();
+        let by_txid: HashMap<Txid, &Arc<Transaction>> =
+            txids.iter().copied().zip(txs.iter()).collect();
+        let spends_watched = |tx: &Transaction| {
+            tx.input.iter().any(|input| {
+                by_txid
+                    .get(&input.previous_output.txid)
+                    .and_then(|parent| parent.output.get(input.previous_output.vout as usize))
+                    .is_some_and(|output| watched.contains(&output.script_pubkey))
+            })
+        };
+        let outpoints: Vec<OutPoint> = txs
+            .iter()
+            .filter(|tx| spends_watched(tx))
+            .flat_map(|tx| tx.input.iter().map(|input| input.previous_output))
+            .filter(|outpoint| !by_txid.contains_key(&outpoint.txid))
+            .collect();
+        let mut parent_txids: Vec<Txid> = outpoints.iter().map(|o| o.txid).collect();
+        parent_txids.sort();
+        parent_txids.dedup();
+        let parents: HashMap<Txid, Arc<Transaction>> = parent_txids
+            .iter()
+            .copied()
+            .zip(self.transactions(&client, &parent_txids)?)
+            .collect();
+        let prevouts = outpoints
+            .into_iter()
+            .filter_map(|outpoint| {
+                let output = parents
+                    .get(&outpoint.txid)?
+                    .output
+                    .get(outpoint.vout as usize)?;
+                Some((outpoint, output.clone()))
+            })
+            .collect();
+
+        // Mempool entries report a height of 0 or -1
+        let mut confirmed_heights: Vec<u32> = heights
+            .values()
+            .filter(|&&height| height > 0)
+            .map(|&height| height as u32)
+            .collect();
+        confirmed_heights.sort();
+        confirmed_heights.dedup();
+        let block_times = self.block_times(&client, &confirmed_heights, tip_height)?;
+
+        let txs = txids
+            .iter()
+            .zip(txs)
+            .map(|(txid, tx)| {
+                let height = heights[txid];
+                let confirmed = (height > 0).then(|| {
+                    let height = height as u32;
+                    (height, block_times.get(&height).copied().unwrap_or(0))
+                });
+                ScriptTx { tx, confirmed }
+            })
+            .collect::<Vec<_>>();
+
+        debug!(
+            "Fetched {} transactions for {} scripts",
+            txs.len(),
+            scripts.len()
+        );
+        Ok(ScriptHistory {
+            tip_height,
+            txs,
+            prevouts,
+        })
+    }
+
+    fn estimate_fee(&self, target_blocks: u32) -> Result<FeeEstimate> {
+        let fee_rate = self
+            .client()?
+            .inner
+            .estimate_fee(target_blocks as usize)
+            .map_err(|e| electrum_error("fee estimation", e))?;
+
+        // Convert from BTC/kB to sat/vB
+        let sat_per_vbyte = (fee_rate * 100_000.0) as f32;
+
+        Ok(FeeEstimate {
+            sat_per_vbyte,
+            target_blocks,
+        })
+    }
+
+    fn broadcast(&self, tx: &Transaction) -> Result<()> {
+        self.client()?
+            .inner
+            .transaction_broadcast(tx)
+            .map_err(|e| match e {
+                // The server answered with an error: the node refused the transaction
+                electrum_client::Error::Protocol(_) => {
+                    error!("Electrum server rejected the transaction: {}", e);
+                    Error::Rejected(format!("Electrum server rejected the transaction: {}", e))
+                }
+                e => electrum_error("broadcast", e),
+            })?;
+        Ok(())
+    }
+
+    fn reset(&self) {
+        if self.client.lock().take().is_some() {
+            debug!("Dropped Electrum connection to {}", self.url);
+        }
+    }
+}
+
+/// Get default Electrum URL for a network
+pub fn get_default_electrum_url(network: BitcoinNetwork) -> String {
+    match network {
+        BitcoinNetwork::Mainnet => "ssl://electrum.blockstream.info:50002".to_string(),
+        BitcoinNetwork::Testnet => "ssl://electrum.blockstream.info:60002".to_string(),
+        BitcoinNetwork::Testnet4 => "ssl://mempool.space:40002".to_string(),
+        BitcoinNetwork::Signet => "ssl://mempool.space:60602".to_string(),
+        BitcoinNetwork::Regtest => "tcp://127.0.0.1:50001".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connects_lazily() {
+        // Nothing listens here; construction must not touch the network
+        let backend = ElectrumBackend::new("tcp://127.0.0.1:1");
+        assert_eq!(backend.kind(), BackendKind::Electrum);
+        assert_eq!(backend.url(), "tcp://127.0.0.1:1");
+        assert!(backend.client.lock().is_none());
+
+        assert!(backend.tip_height().is_err());
+        assert!(backend.client.lock().is_none());
+    }
+}
@@ -0,0 +1,421 @@
+//! Esplora backend - chain source over the Esplora HTTP API
+//!
+//! Works against any Esplora-compatible server: mempool.space,
+//! blockstream.info, or a self-hosted electrs/esplora instance (e.g. on
+//! regtest for local testing).
+//!
+//! Wallet scans go through `bdk_esplora`'s `EsploraExt`; single-address
+//! queries, fees and broadcast use its re-exported `esplora_client`.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use bdk_esplora::esplora_client::{self, AddressTxsSummary, BlockingClient, Builder, Tx};
+use bdk_esplora::EsploraExt;
+use bdk_wallet::bitcoin::hashes::{sha256, Hash};
+use bdk_wallet::bitcoin::{Amount, OutPoint, Script, ScriptBuf, Transaction, TxOut};
+use bdk_wallet::chain::spk_client::{FullScanRequest, FullScanResponse, SyncRequest, SyncResponse};
+use bdk_wallet::KeychainKind;
+use serde::Deserialize;
+use tracing::{debug, error};
+
+use super::backend::BitcoinBackend;
+use super::history::{ScriptHistory, ScriptTx};
+use super::types::*;
+use crate::error::{Error, Result};
+
+/// Maximum number of HTTP requests in flight during a scan
+const ESPLORA_PARALLEL_REQUESTS: usize = 5;
+
+/// Request timeout in seconds
+const ESPLORA_TIMEOUT_SECS: u64 = 30;
+
+/// Retries for rate-limited or unavailable responses (429, 500, 503)
+const ESPLORA_MAX_RETRIES: usize = 3;
+
+/// Confirmed transactions per page of `/scripthash/:hash/txs`
+const CONFIRMED_TXS_PER_PAGE: usize = 25;
+
+fn esplora_error(context: &str, e: esplora_client::Error) -> Error {
+    error!("Esplora {} failed: {}", context, e);
+    Error::Bitcoin(format!("Esplora {} failed: {}", context, e))
+}
+
+/// Confirmed and mempool totals of a script
+#[derive(Debug, Deserialize)]
+struct ScriptHashStats {
+    chain_stats: AddressTxsSummary,
+    mempool_stats: AddressTxsSummary,
+}
+
+/// Blocking client for an Esplora server
+#[derive(Debug, Clone)]
+pub struct EsploraClient {
+    client: BlockingClient,
+}
+
+impl EsploraClient {
+    /// Create a client for an Esplora base URL (e.g. `https://mempool.space/api`)
+    pub fn new(url: &str) -> Self {
+        let client = Builder::new(url.trim_end_matches('/'))
+            .timeout(ESPLORA_TIMEOUT_SECS)
+            .max_retries(ESPLORA_MAX_RETRIES)
+            .build_blocking();
+
+        Self { client }
+    }
+
+    /// Base URL of the server
+    pub fn url(&self) -> &str {
+        self.client.url()
+    }
+
+    /// Current chain tip height
+    pub fn tip_height(&self) -> Result<u32> {
+        self.client
+            .get_height()
+            .map_err(|e| esplora_error("tip query", e))
+    }
+
+    // =========================================================================
+    // Wallet Scans
+    // =========================================================================
+
+    /// Scan keychain scripts until `stop_gap` consecutive unused scripts
+    pub fn full_scan<K: Ord + Clone>(
+        &self,
+        request: impl Into<FullScanRequest<K>>,
+        stop_gap: usize,
+        parallel_requests: usize,
+    ) -> Result<FullScanResponse<K>> {
+        EsploraExt::full_scan(&self.client, request, stop_gap, parallel_requests)
+            .map_err(|e| esplora_error("full scan", *e))
+    }
+
+    /// Sync a bounded set of scripts, txids and outpoints
+    pub fn sync<I: 'static>(
+        &self,
+        request: impl Into<SyncRequest<I>>,
+        parallel_requests: usize,
+    ) -> Result<SyncResponse> {
+        EsploraExt::sync(&self.client, request, parallel_requests)
+            .map_err(|e| esplora_error("sync", *e))
+    }
+
+    /// Full history of a script, mempool first then confirmed newest first
+    fn script_txs(&self, script: &Script) -> Result<Vec<Tx>> {
+        let mut txs = Vec::new();
+        let mut last_seen = None;
+        loop {
+            let page = self
+                .client
+                .scripthash_txs(script, last_seen)
+                .map_err(|e| esplora_error("history query", e))?;
+            let page_len = page.len();
+            last_seen = page.last().map(|tx| tx.txid);
+            txs.extend(page);
+            if page_len < CONFIRMED_TXS_PER_PAGE {
+                break;
+            }
+        }
+        Ok(txs)
+    }
+
+    // =========================================================================
+    // Single-Address Queries
+    // =========================================================================
+
+    /// Confirmed and mempool balance of a script
+    pub fn script_balance(&self, script: &Script) -> Result<BitcoinBalance> {
+        let stats = self
+            .script_stats(script)
+            .map_err(|e| esplora_error("balance query", e))?;
+
+        let confirmed = stats
+            .chain_stats
+            .funded_txo_sum
+            .saturating_sub(stats.chain_stats.spent_txo_sum);
+        let unconfirmed = stats
+            .mempool_stats
+            .funded_txo_sum
+            .saturating_sub(stats.mempool_stats.spent_txo_sum);
+
+        Ok(BitcoinBalance {
+            confirmed,
+            unconfirmed,
+            immature: 0,
+            trusted_spendable: confirmed,
+        })
+    }
+
+    /// `/scripthash/:hash` summary
+    ///
+    /// `esplora_client` 0.11 only exposes this endpoint per address, and a
+    /// script alone does not say which network its address is on.
+    fn script_stats(
+        &self,
+        script: &Script,
+    ) -> std::result::Result<ScriptHashStats, esplora_client::Error> {
+        let script_hash = sha256::Hash::hash(script.as_bytes());
+        let response = self
+            .client
+            .get_request(&format!("/scripthash/{:x}", script_hash))?
+            .send()?;
+
+        if !(200..300).contains(&response.status_code) {
+            return Err(esplora_client::Error::HttpResponse {
+                status: response.status_code as u16,
+                message: response.as_str().unwrap_or_default().to_string(),
+            });
+        }
+        Ok(response.json()?)
+    }
+
+    /// Transactions touching any of `scripts`, with their inputs' prevouts
+    ///
+    /// Esplora includes the previous output of every input, so no parent
+    /// transactions need fetching for fees.
+    pub fn scripts_history(&self, scripts: &[ScriptBuf]) -> Result<ScriptHistory> {
+        let mut history = ScriptHistory {
+            tip_height: self.tip_height()?,
+            ..Default::default()
+        };
+
+        // Watched scripts can share transactions
+        let mut seen = HashSet::new();
+        for script in scripts {
+            for tx in self.script_txs(script)? {
+                if seen.insert(tx.txid) {
+                    add_script_tx(&mut history, tx);
+                }
+            }
+        }
+
+        debug!(
+            "Esplora returned {} transactions for {} scripts",
+            history.txs.len(),
+            scripts.len()
+        );
+        Ok(history)
+    }
+
+    // =========================================================================
+    // Fees and Broadcast
+    // =========================================================================
+
+    /// Fee rate for confirmation within `target_blocks`
+    ///
+    /// Uses the estimate for the largest server target not above
+    /// `target_blocks`.
+    pub fn estimate_fee(&self, target_blocks: u32) -> Result<FeeEstimate> {
+        let estimates: HashMap<u16, f64> = self
+            .client
+            .get_fee_estimates()
+            .map_err(|e| esplora_error("fee estimation", e))?;
+
+        let sat_per_vbyte = esplora_client::convert_fee_rate(target_blocks as usize, estimates)
+            .ok_or_else(|| {
+                Error::Bitcoin(format!(
+                    "No Esplora fee estimate for {} blocks",
+                    target_blocks
+                ))
+            })?;
+
+        Ok(FeeEstimate {
+            sat_per_vbyte,
+            target_blocks,
+        })
+    }
+
+    /// Broadcast a signed transaction
+    pub fn broadcast(&self, tx: &Transaction) -> Result<()> {
+        self.client.broadcast(tx).map_err(|e| match e {
+            // Esplora relays the node's refusal as a 400
+            esplora_client::Error::HttpResponse {
+                status: 400,
+                message,
+            } => {
+                error!("Esplora rejected the transaction: {}", message);
+                Error::Rejected(format!("Esplora rejected the transaction: {}", message))
+            }
+            e => esplora_error("broadcast", e),
+        })
+    }
+}
+
+impl BitcoinBackend for EsploraClient {
+    fn kind(&self) -> BackendKind {
+        BackendKind::Esplora
+    }
+
+    fn url(&self) -> &str {
+        EsploraClient::url(self)
+    }
+
+    fn tip_height(&self) -> Result<u32> {
+        EsploraClient::tip_height(self)
+    }
+
+    fn full_scan(
+        &self,
+        request: FullScanRequest<KeychainKind>,
+        stop_gap: usize,
+    ) -> Result<FullScanResponse<KeychainKind>> {
+        EsploraClient::full_scan(self, request, stop_gap, ESPLORA_PARALLEL_REQUESTS)
+    }
+
+    fn sync(&self, request: SyncRequest<(KeychainKind, u32)>) -> Result<SyncResponse> {
+        EsploraClient::sync(self, request, ESPLORA_PARALLEL_REQUESTS)
+    }
+
+    fn script_balance(&self, script: &Script) -> Result<BitcoinBalance> {
+        EsploraClient::script_balance(self, script)
+    }
+
+    fn scripts_history(&self, scripts: &[ScriptBuf]) -> Result<ScriptHistory> {
+        EsploraClient::scripts_history(self, scripts)
+    }
+
+    fn estimate_fee(&self, target_blocks: u32) -> Result<FeeEstimate> {
+        EsploraClient::estimate_fee(self, target_blocks)
+    }
+
+    fn broadcast(&self, tx: &Transaction) -> Result<()> {
+        EsploraClient::broadcast(self, tx)
+    }
+}
+
+/// Add an Esplora transaction and its inputs' prevouts to an address history
+fn add_script_tx(history: &mut ScriptHistory, tx: Tx) {
+    for vin in &tx.vin {
+        if let Some(prevout) = &vin.prevout {
+            history.prevouts.insert(
+                OutPoint::new(vin.txid, vin.vout),
+                TxOut {
+                    script_pubkey: prevout.scriptpubkey.clone(),
+                    value: Amount::from_sat(prevout.value),
+                },
+            );
+        }
+    }
+
+    let confirmed = tx.status.block_height.zip(tx.status.block_time);
+    history.txs.push(ScriptTx {
+        tx: Arc::new(tx.to_tx()),
+        confirmed,
+    });
+}
+
+/// Default Esplora API URL for a network
+pub fn get_default_esplora_url(network: BitcoinNetwork) -> String {
+    match network {
+        BitcoinNetwork::Mainnet => "https://mempool.space/api".to_string(),
+        BitcoinNetwork::Testnet => "https://mempool.space/testnet/api".to_string(),
+        BitcoinNetwork::Testnet4 => "https://mempool.space/testnet4/api".to_string(),
+        BitcoinNetwork::Signet => "https://mempool.space/signet/api".to_string(),
+        // electrs/esplora's default regtest HTTP port
+        BitcoinNetwork::Regtest => "http://127.0.0.1:3002".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bdk_wallet::bitcoin::Network;
+
+    const ADDRESS: &str = "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu";
+
+    fn script() -> ScriptBuf {
+        ADDRESS
+            .parse::<bitcoin::Address<bitcoin::address::NetworkUnchecked>>()
+            .unwrap()
+            .assume_checked()
+            .script_pubkey()
+    }
+
+    /// Esplora `/tx` JSON with one input and two outputs
+    fn esplora_tx(input_script: &Script, output_script: &Script, confirmed: bool) -> Tx {
+        let status = if confirmed {
+            serde_json::json!({
+                "confirmed": true,
+                "block_height": 800_000,
+                "block_hash": "00000000000000000002a7c4c1e48d76c5a37902165a270156b7a8d72728a054",
+                "block_time": 1_690_168_629,
+            })
+        } else {
+            serde_json::json!({ "confirmed": false })
+        };
+
+        serde_json::from_value(serde_json::json!({
+            "txid": "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b",
+            "version": 2,
+            "locktime": 0,
+            "vin": [{
+                "txid": "0e3e2357e806b6cdb1f70b54c3a3a17b6714ee1f0e68bebb44a74b1efd512098",
+                "vout": 0,
+                "prevout": { "scriptpubkey": input_script.to_hex_string(), "value": 150_000 },
+                "scriptsig": "",
+                // P2WPKH-sized: 73-byte signature, 33-byte public key
+                "witness": ["30".repeat(73), "02".repeat(33)],
+                "sequence": 4_294_967_293u32,
+                "is_coinbase": false,
+            }],
+            "vout": [
+                { "scriptpubkey": output_script.to_hex_string(), "value": 100_000 },
+                { "scriptpubkey": "0014e8df018c7e326cc253faac7e46cdc51e68542c42", "value": 49_000 },
+            ],
+            "size": 222,
+            "weight": 561,
+            "status": status,
+            "fee": 1_000,
+        }))
+        .unwrap()
+    }
+
+    /// History entry of ADDRESS for one Esplora transaction
+    fn address_entry(tx: Tx) -> BitcoinTransaction {
+        let mut history = ScriptHistory {
+            tip_height: 800_005,
+            ..Default::default()
+        };
+        add_script_tx(&mut history, tx);
+        history
+            .transactions(&HashSet::from([script()]), Network::Bitcoin)
+            .remove(0)
+    }
+
+    #[test]
+    fn test_received_transaction() {
+        let other = ScriptBuf::from_hex("0014751e76e8199196d454941c45d1b3a323f1433bd6").unwrap();
+        let entry = address_entry(esplora_tx(&other, &script(), true));
+
+        assert_eq!(entry.direction, TransactionDirection::Received);
+        assert_eq!(entry.amount_sats, 100_000);
+        assert_eq!(entry.fee_sats, None);
+        assert_eq!(entry.confirmations(), 6);
+        assert_eq!(entry.timestamp, Some(1_690_168_629));
+        assert_eq!(entry.vsize, Some(141));
+    }
+
+    #[test]
+    fn test_sent_transaction() {
+        let other = ScriptBuf::from_hex("0014751e76e8199196d454941c45d1b3a323f1433bd6").unwrap();
+        let entry = address_entry(esplora_tx(&script(), &other, false));
+
+        assert_eq!(entry.direction, TransactionDirection::Sent);
+        assert_eq!(entry.amount_sats, -150_000);
+        assert_eq!(entry.fee_sats, Some(1_000));
+        assert!(!entry.is_confirmed());
+        assert_eq!(entry.timestamp, None);
+    }
+
+    #[test]
+    fn test_client_url() {
+        let client = EsploraClient::new("https://mempool.space/api/");
+        assert_eq!(client.url(), "https://mempool.space/api");
+        assert_eq!(
+            get_default_esplora_url(BitcoinNetwork::Signet),
+            "https://mempool.space/signet/api"
+        );
+    }
+}
@@ -0,0 +1,369 @@
+//! Address history - transactions of watched addresses outside BDK
+//!
+//! Single-address and multi-address watch lists have no BDK wallet.
+//! Backends report the raw transactions touching the watched scripts,
+//! plus the previous outputs needed to value their inputs; this module
+//! turns them into history entries. The scripts are treated as one
+//! wallet, so a transaction shared by several of them appears once, and
+//! a transfer between two of them is internal.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use bdk_wallet::bitcoin::{Address, Network, OutPoint, ScriptBuf, Transaction, TxOut, Txid};
+
+use super::types::*;
+
+/// A transaction touching at least one watched script
+#[derive(Debug, Clone)]
+pub struct ScriptTx {
+    /// The full transaction
+    pub tx: Arc<Transaction>,
+    /// Block height and block time, if confirmed
+    pub confirmed: Option<(u32, u64)>,
+}
+
+/// Transactions of a set of scripts, as reported by a backend
+#[derive(Debug, Clone, Default)]
+pub struct ScriptHistory {
+    /// Chain tip height when the history was fetched
+    pub tip_height: u32,
+    /// Every transaction touching a watched script, without repeats
+    pub txs: Vec<ScriptTx>,
+    /// Outputs spent by the transactions' inputs, where known
+    ///
+    /// Outputs created by transactions in `txs` needn't be included.
+    pub prevouts: HashMap<OutPoint, TxOut>,
+}
+
+impl ScriptHistory {
+    /// History entries for the watched scripts, newest first
+    pub fn transactions(
+        &self,
+        watched: &HashSet<ScriptBuf>,
+        network: Network,
+    ) -> Vec<BitcoinTransaction> {
+        let by_txid: HashMap<Txid, &Transaction> = self
+            .txs
+            .iter()
+            .map(|entry| (entry.tx.compute_txid(), entry.tx.as_ref()))
+            .collect();
+        let prevout = |outpoint: &OutPoint| -> Option<TxOut> {
+            self.prevouts.get(outpoint).cloned().or_else(|| {
+                by_txid
+                    .get(&outpoint.txid)
+                    .and_then(|tx| tx.output.get(outpoint.vout as usize))
+                    .cloned()
+            })
+        };
+
+        let mut transactions: Vec<_> = self
+            .txs
+            .iter()
+            .map(|entry| self.transaction(entry, watched, network, &prevout))
+            .collect();
+
+        // Unconfirmed first, then by height
+        transactions.sort_by_key(|tx| match tx.status {
+            ConfirmationStatus::Confirmed { block_height, .. } => u32::MAX - block_height,
+            ConfirmationStatus::Unconfirmed => 0,
+        });
+        transactions
+    }
+
+    fn transaction(
+        &self,
+        entry: &ScriptTx,
+        watched: &HashSet<ScriptBuf>,
+        network: Network,
+        prevout: &impl Fn(&OutPoint) -> Option<TxOut>,
+    ) -> BitcoinTransaction {
+        let tx = entry.tx.as_ref();
+        let address = |script: &ScriptBuf| {
+            Address::from_script(script, network)
+                .ok()
+                .map(|address| address.to_string())
+        };
+
+        let inputs: Vec<TransactionInput> = tx
+            .input
+            .iter()
+            .map(|input| {
+                let spent = prevout(&input.previous_output);
+                TransactionInput {
+                    previous_output: input.previous_output.to_string(),
+                    address: spent.as_ref().and_then(|o| address(&o.script_pubkey)),
+                    value_sats: spent.as_ref().map(|o| o.value.to_sat()),
+                    is_mine: spent.is_some_and(|o| watched.contains(&o.script_pubkey)),
+                }
+            })
+            .collect();
+
+        let outputs: Vec<TransactionOutput> = tx
+            .output
+            .iter()
+            .enumerate()
+            .map(|(vout, output)| TransactionOutput {
+                vout: vout as u32,
+                address: address(&output.script_pubkey),
+                value_sats: output.value.to_sat(),
+                is_mine: watched.contains(&output.script_pubkey),
+                is_change: false,
+            })
+            .collect();
+
+        let sent: u64 = inputs
+            .iter()
+            .filter(|i| i.is_mine)
+            .filter_map(|i| i.value_sats)
+            .sum();
+        let received: u64 = outputs
+            .iter()
+            .filter(|o| o.is_mine)
+            .map(|o| o.value_sats)
+            .sum();
+        let amount_sats = received as i64 - sent as i64;
+
+        let direction = if sent > 0 && outputs.iter().all(|o| o.is_mine) {
+            TransactionDirection::Internal
+        } else if amount_sats > 0 {
+            TransactionDirection::Received
+        } else if amount_sats < 0 {
+            TransactionDirection::Sent
+        } else {
+            TransactionDirection::Internal
+        };
+
+        // Only the sender pays the fee; it needs every input's value
+        let fee_sats = if sent > 0 {
+            inputs
+                .iter()
+                .map(|i| i.value_sats)
+                .sum::<Option<u64>>()
+                .and_then(|total_in| {
+                    total_in.checked_sub(tx.output.iter().map(|o| o.value.to_sat()).sum())
+                })
+        } else {
+            None
+        };
+
+        let own_addresses = unique(
+            inputs
+                .iter()
+                .filter(|i| i.is_mine)
+                .filter_map(|i| i.address.as_ref())
+                .chain(
+                    outputs
+                        .iter()
+                        .filter(|o| o.is_mine)
+                        .filter_map(|o| o.address.as_ref()),
+                ),
+        );
+        let counterparty_addresses = unique(
+            inputs
+                .iter()
+                .filter(|i| !i.is_mine)
+                .filter_map(|i| i.address.as_ref())
+                .chain(
+                    outputs
+                        .iter()
+                        .filter(|o| !o.is_mine)
+                        .filter_map(|o| o.address.as_ref()),
+                ),
+        );
+
+        // Recipients for payments, our own addresses otherwise
+        let addresses = match direction {
+            TransactionDirection::Sent => unique(
+                outputs
+                    .iter()
+                    .filter(|o| !o.is_mine)
+                    .filter_map(|o| o.address.as_ref()),
+            ),
+            _ => own_addresses.clone(),
+        };
+
+        let (status, timestamp) = match entry.confirmed {
+            Some((block_height, block_time)) => (
+                ConfirmationStatus::Confirmed {
+                    block_height,
+                    block_time,
+                    confirmations: self
+                        .tip_height
+                        .saturating_sub(block_height)
+                        .saturating_add(1),
+                },
+                Some(block_time),
+            ),
+            None => (ConfirmationStatus::Unconfirmed, None),
+        };
+
+        BitcoinTransaction {
+            txid: tx.compute_txid().to_string(),
+            direction,
+            amount_sats,
+            fee_sats,
+            status,
+            timestamp,
+            first_seen: None,
+            addresses,
+            own_addresses,
+            counterparty_addresses,
+            inputs,
+            outputs,
+            size: Some(tx.total_size() as u32),
+            vsize: Some(tx.vsize() as u32),
+        }
+    }
+}
+
+/// Addresses in first-seen order without repeats
+fn unique<'a>(addresses: impl Iterator<Item = &'a String>) -> Vec<String> {
+    let mut unique: Vec<String> = Vec::new();
+    for address in addresses {
+        if !unique.contains(address) {
+            unique.push(address.clone());
+        }
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bdk_wallet::bitcoin::address::NetworkUnchecked;
+    use bdk_wallet::bitcoin::hashes::Hash;
+    use bdk_wallet::bitcoin::{absolute, transaction, Amount, TxIn};
+
+    const ADDRESS: &str = "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu";
+    const OTHER: &str = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+    const THIRD: &str = "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA";
+
+    fn script(address: &str) -> ScriptBuf {
+        address
+            .parse::<Address<NetworkUnchecked>>()
+            .unwrap()
+            .assume_checked()
+            .script_pubkey()
+    }
+
+    fn tx(inputs: &[OutPoint], outputs: &[(&str, u64)]) -> Arc<Transaction> {
+        Arc::new(Transaction {
+            version: transaction::Version::TWO,
+            lock_time: absolute::LockTime::ZERO,
+            input: inputs
+                .iter()
+                .map(|&previous_output| TxIn {
+                    previous_output,
+                    ..Default::default()
+                })
+                .collect(),
+            output: outputs
+                .iter()
+                .map(|&(address, sats)| TxOut {
+                    value: Amount::from_sat(sats),
+                    script_pubkey: script(address),
+                })
+                .collect(),
+        })
+    }
+
+    fn external(n: u8) -> OutPoint {
+        OutPoint::new(Txid::from_byte_array([n; 32]), 0)
+    }
+
+    fn watching(addresses: &[&str]) -> HashSet<ScriptBuf> {
+        addresses.iter().map(|a| script(a)).collect()
+    }
+
+    /// Someone pays ADDRESS, which then pays OTHER with change back
+    fn receive_then_spend() -> ScriptHistory {
+        let funding = tx(&[external(1)], &[(ADDRESS, 150_000), (THIRD, 20_000)]);
+        let spend = tx(
+            &[OutPoint::new(funding.compute_txid(), 0)],
+            &[(OTHER, 100_000), (ADDRESS, 49_000)],
+        );
+
+        ScriptHistory {
+            tip_height: 800_005,
+            txs: vec![
+                ScriptTx {
+                    tx: funding,
+                    confirmed: Some((800_000, 1_690_168_629)),
+                },
+                ScriptTx {
+                    tx: spend,
+                    confirmed: None,
+                },
+            ],
+            prevouts: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_received_and_spent() {
+        let history = receive_then_spend().transactions(&watching(&[ADDRESS]), Network::Bitcoin);
+        assert_eq!(history.len(), 2);
+
+        let spent = &history[0];
+        assert_eq!(spent.direction, TransactionDirection::Sent);
+        assert_eq!(spent.amount_sats, -101_000);
+        assert_eq!(spent.fee_sats, Some(1_000));
+        assert!(!spent.is_confirmed());
+        assert_eq!(spent.timestamp, None);
+        assert_eq!(spent.addresses, [OTHER]);
+        assert_eq!(spent.own_addresses, [ADDRESS]);
+        assert_eq!(spent.counterparty_addresses, [OTHER]);
+        assert!(spent.inputs[0].is_mine);
+        assert_eq!(spent.inputs[0].value_sats, Some(150_000));
+
+        let received = &history[1];
+        assert_eq!(received.direction, TransactionDirection::Received);
+        assert_eq!(received.amount_sats, 150_000);
+        assert_eq!(received.fee_sats, None);
+        assert_eq!(received.confirmations(), 6);
+        assert_eq!(received.timestamp, Some(1_690_168_629));
+        assert_eq!(received.addresses, [ADDRESS]);
+        assert_eq!(received.counterparty_addresses, [THIRD]);
+        assert_eq!(received.inputs[0].value_sats, None);
+    }
+
+    #[test]
+    fn test_fee_needs_every_prevout() {
+        let mut history = receive_then_spend();
+        let funding_txid = history.txs[0].tx.compute_txid();
+        history.txs[1].tx = tx(
+            &[OutPoint::new(funding_txid, 0), external(2)],
+            &[(OTHER, 150_000), (ADDRESS, 49_000)],
+        );
+
+        let entries = history.transactions(&watching(&[ADDRESS]), Network::Bitcoin);
+        assert_eq!(entries[0].amount_sats, -101_000);
+        assert_eq!(entries[0].fee_sats, None);
+
+        // With the other parent's output fetched, the fee is known
+        history.prevouts.insert(
+            external(2),
+            TxOut {
+                value: Amount::from_sat(51_000),
+                script_pubkey: script(THIRD),
+            },
+        );
+        let entries = history.transactions(&watching(&[ADDRESS]), Network::Bitcoin);
+        assert_eq!(entries[0].fee_sats, Some(2_000));
+        assert_eq!(entries[0].counterparty_addresses, [THIRD, OTHER]);
+    }
+
+    #[test]
+    fn test_transfer_within_watch_list_is_internal() {
+        let history = receive_then_spend();
+        let entries = history.transactions(&watching(&[ADDRESS, OTHER]), Network::Bitcoin);
+
+        let transfer = &entries[0];
+        assert_eq!(transfer.direction, TransactionDirection::Internal);
+        assert_eq!(transfer.amount_sats, -1_000);
+        assert_eq!(transfer.fee_sats, Some(1_000));
+        assert_eq!(transfer.own_addresses, [ADDRESS, OTHER]);
+        assert!(transfer.counterparty_addresses.is_empty());
+    }
+}
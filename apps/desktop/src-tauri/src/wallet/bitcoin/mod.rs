@@ -1,15 +1,36 @@
 //! Bitcoin module - BDK integration for Bitcoin wallet operations
 //!
 //! This module provides full Bitcoin wallet functionality using BDK:
-//! - Wallet creation and management
-//! - Blockchain synchronization (Electrum/Esplora)
+//! - Wallet creation and management, including watch-only wallets
+//!   imported from extended keys or output descriptors
+//! - Blockchain synchronization (Electrum/Esplora/Bitcoin Core) with
+//!   multi-server failover
 //! - Balance fetching
 //! - Transaction history
 //! - UTXO management
 //! - Fee estimation
+//! - Push updates over Electrum subscriptions
 
 mod adapter;
+mod backend;
+mod cache;
+mod descriptor;
+mod electrum;
+mod esplora;
+mod history;
+mod rpc;
+mod sync;
 mod types;
+mod watch;
 
-pub use adapter::BitcoinAdapter;
+pub use adapter::{BitcoinAdapter, BitcoinWallet, SyncPlan};
+pub use backend::{BackendPool, BitcoinBackend, RetryPolicy};
+pub use cache::WalletCache;
+pub use descriptor::WalletDescriptors;
+pub use electrum::{get_default_electrum_url, ElectrumBackend};
+pub use esplora::{get_default_esplora_url, EsploraClient};
+pub use history::{ScriptHistory, ScriptTx};
+pub use rpc::BitcoindBackend;
+pub use sync::{ProgressReporter, SyncMonitor};
 pub use types::*;
+pub use watch::{transaction_events, tx_snapshot, ChainWatcher, TxSnapshot, WatchNotice};
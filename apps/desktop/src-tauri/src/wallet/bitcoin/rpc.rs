@@ -0,0 +1,914 @@
+//! Bitcoin Core backend - chain source over bitcoind's JSON-RPC
+//!
+//! Talks to a self-hosted node directly, without an Electrum or Esplora
+//! indexer and without loading a wallet in bitcoind. Wallet scans use
+//! block-by-block emission, as `bdk_bitcoind_rpc` does: every block from
+//! the scan start to the tip is fetched (in JSON-RPC batches) and the
+//! transactions paying to wallet scripts or spending wallet outputs are
+//! kept, followed by the same pass over the mempool.
+//!
+//! - Full scans start at the configured `start_height` (the wallet
+//!   birthday). Scripts are loaded up to the gap limit past the last used
+//!   one as blocks are scanned; like `bdk_bitcoind_rpc`, a script first
+//!   used beyond that window in an earlier block is not found.
+//! - Syncs continue from the wallet's last checkpoint that is still in the
+//!   node's chain, so reorgs are rescanned.
+//!
+//! Single-address balances use `scantxoutset`. bitcoind has no address
+//! index, so single-address history is not available from this backend.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bdk_wallet::bitcoin::consensus::encode::{deserialize_hex, serialize_hex};
+use bdk_wallet::bitcoin::{
+    Amount, Block, BlockHash, OutPoint, Script, ScriptBuf, Transaction, Txid,
+};
+use bdk_wallet::chain::spk_client::{FullScanRequest, FullScanResponse, SyncRequest, SyncResponse};
+use bdk_wallet::chain::{BlockId, CheckPoint, ConfirmationBlockTime, TxUpdate};
+use bdk_wallet::KeychainKind;
+use bitcoin::base64::prelude::{Engine as _, BASE64_STANDARD};
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tracing::{debug, error, info};
+
+use super::backend::BitcoinBackend;
+use super::history::ScriptHistory;
+use super::types::*;
+use crate::error::{Error, Result};
+
+/// Request timeout in seconds (`scantxoutset` walks the whole UTXO set)
+const RPC_TIMEOUT_SECS: u64 = 120;
+
+/// Calls per JSON-RPC batch request
+const RPC_BATCH_SIZE: usize = 50;
+
+/// Highest confirmation target `estimatesmartfee` accepts
+const MAX_FEE_TARGET: u32 = 1008;
+
+/// JSON-RPC error code of a node that is still starting up
+const RPC_IN_WARMUP: i64 = -28;
+
+fn rpc_error(context: &str, e: impl std::fmt::Display) -> Error {
+    error!("Bitcoin Core {} failed: {}", context, e);
+    Error::Bitcoin(format!("Bitcoin Core {} failed: {}", context, e))
+}
+
+fn rpc_rejected(context: &str, e: impl std::fmt::Display) -> Error {
+    error!("Bitcoin Core rejected {}: {}", context, e);
+    Error::Rejected(format!("Bitcoin Core rejected {}: {}", context, e))
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// ============================================================================
+// JSON-RPC Types
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+struct RpcResponse {
+    #[serde(default)]
+    result: Value,
+    error: Option<RpcErrorBody>,
+    id: Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcErrorBody {
+    code: i64,
+    message: String,
+}
+
+impl RpcResponse {
+    fn into_result<T: DeserializeOwned>(self, method: &str) -> Result<T> {
+        if let Some(e) = self.error {
+            let message = format!("{} (code {})", e.message, e.code);
+            // A node still loading answers nothing yet, but another may
+            if e.code == RPC_IN_WARMUP {
+                return Err(rpc_error(method, message));
+            }
+            return Err(rpc_rejected(method, message));
+        }
+        serde_json::from_value(self.result).map_err(|e| rpc_error(method, e))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SmartFeeEstimate {
+    /// BTC/kvB
+    feerate: Option<f64>,
+    #[serde(default)]
+    errors: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MempoolInfo {
+    /// BTC/kvB
+    mempoolminfee: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct UtxoSetScan {
+    total_amount: f64,
+}
+
+/// Convert a BTC/kvB fee rate to sat/vB
+fn btc_per_kvb_to_sat_per_vb(rate: f64) -> f32 {
+    (rate * 100_000.0) as f32
+}
+
+/// `Authorization` header value for the configured credentials
+///
+/// Cookie files hold `__cookie__:<password>` and are rewritten on every
+/// bitcoind start, so they are read when the header is built.
+fn authorization_header(auth: &RpcAuth) -> Result<Option<String>> {
+    let credentials = match auth {
+        RpcAuth::None => return Ok(None),
+        RpcAuth::Cookie { path } => std::fs::read_to_string(path)
+            .map_err(|e| {
+                Error::Bitcoin(format!(
+                    "Failed to read bitcoind cookie file {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?
+            .trim()
+            .to_string(),
+        RpcAuth::UserPass { username, password } => format!("{}:{}", username, password),
+    };
+
+    Ok(Some(format!(
+        "Basic {}",
+        BASE64_STANDARD.encode(credentials)
+    )))
+}
+
+// ============================================================================
+// Block Scanning
+// ============================================================================
+
+/// Scripts, outpoints and txids a scan looks for
+#[derive(Default)]
+struct Watch<'r> {
+    /// Watched scripts, with their keychain index in full scans
+    spks: HashMap<ScriptBuf, Option<(KeychainKind, u32)>>,
+    /// Wallet outputs; a transaction spending one is a wallet transaction
+    outpoints: HashSet<OutPoint>,
+    /// Transactions of interest regardless of scripts
+    txids: HashSet<Txid>,
+    /// Transactions already added to the update
+    inserted: HashSet<Txid>,
+    /// Full scans: source of further scripts and the gap to keep loaded
+    keychains: Option<(&'r mut FullScanRequest<KeychainKind>, u32)>,
+    /// Scripts loaded per keychain (`u32::MAX` once exhausted)
+    loaded: BTreeMap<KeychainKind, u32>,
+    /// Highest used index per keychain
+    last_active: BTreeMap<KeychainKind, u32>,
+}
+
+impl<'r> Watch<'r> {
+    /// Watch keychain scripts up to `stop_gap` past the last used one
+    fn full_scan(request: &'r mut FullScanRequest<KeychainKind>, stop_gap: u32) -> Self {
+        let mut watch = Self {
+            keychains: Some((request, stop_gap.max(1))),
+            ..Default::default()
+        };
+        watch.load_scripts();
+        watch
+    }
+
+    /// Watch the scripts, txids and outpoints of a sync request
+    fn sync(request: &mut SyncRequest<(KeychainKind, u32)>) -> Self {
+        Self {
+            spks: request.iter_spks().map(|spk| (spk, None)).collect(),
+            txids: request.iter_txids().collect(),
+            outpoints: request.iter_outpoints().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Load keychain scripts until each keychain's gap is covered
+    fn load_scripts(&mut self) {
+        let Some((request, stop_gap)) = self.keychains.as_mut() else {
+            return;
+        };
+
+        for keychain in request.keychains() {
+            let target = match self.last_active.get(&keychain) {
+                Some(&index) => index.saturating_add(1).saturating_add(*stop_gap),
+                None => *stop_gap,
+            };
+            let loaded = self.loaded.entry(keychain).or_insert(0);
+            while *loaded < target {
+                match request.next_spk(keychain) {
+                    Some((index, spk)) => {
+                        self.spks.insert(spk, Some((keychain, index)));
+                        *loaded = index.saturating_add(1);
+                    }
+                    None => *loaded = u32::MAX,
+                }
+            }
+        }
+    }
+
+    /// Track a transaction's wallet outputs; returns its txid if it is ours
+    fn track(&mut self, tx: &Transaction) -> Option<Txid> {
+        let txid = tx.compute_txid();
+        let mut relevant = self.txids.contains(&txid)
+            || tx
+                .input
+                .iter()
+                .any(|input| self.outpoints.contains(&input.previous_output));
+
+        let mut used = false;
+        for (vout, output) in tx.output.iter().enumerate() {
+            let Some(&origin) = self.spks.get(&output.script_pubkey) else {
+                continue;
+            };
+            relevant = true;
+            self.outpoints.insert(OutPoint::new(txid, vout as u32));
+            if let Some((keychain, index)) = origin {
+                let last = self.last_active.entry(keychain).or_insert(index);
+                *last = (*last).max(index);
+                used = true;
+            }
+        }
+        if used {
+            self.load_scripts();
+        }
+
+        relevant.then_some(txid)
+    }
+
+    fn insert(
+        &mut self,
+        update: &mut TxUpdate<ConfirmationBlockTime>,
+        txid: Txid,
+        tx: Transaction,
+    ) {
+        if self.inserted.insert(txid) {
+            update.txs.push(Arc::new(tx));
+        }
+    }
+
+    /// Add the wallet transactions of a block to an update
+    fn scan_block(
+        &mut self,
+        update: &mut TxUpdate<ConfirmationBlockTime>,
+        block_id: BlockId,
+        block: &Block,
+    ) {
+        let anchor = ConfirmationBlockTime {
+            block_id,
+            confirmation_time: block.header.time as u64,
+        };
+        for tx in &block.txdata {
+            if let Some(txid) = self.track(tx) {
+                self.insert(update, txid, tx.clone());
+                update.anchors.insert((anchor, txid));
+            }
+        }
+    }
+
+    /// Add a mempool transaction to an update if it is ours
+    fn scan_mempool_tx(
+        &mut self,
+        update: &mut TxUpdate<ConfirmationBlockTime>,
+        tx: Transaction,
+        seen_at: u64,
+    ) {
+        if let Some(txid) = self.track(&tx) {
+            self.insert(update, txid, tx);
+            update.seen_ats.insert(txid, seen_at);
+        }
+    }
+}
+
+// ============================================================================
+// Bitcoin Core Backend
+// ============================================================================
+
+/// bitcoind JSON-RPC server
+pub struct BitcoindBackend {
+    url: String,
+    auth: RpcAuth,
+    start_height: u32,
+    /// Cached `Authorization` header, dropped on auth failures and resets
+    authorization: Mutex<Option<String>>,
+}
+
+impl BitcoindBackend {
+    /// Create a backend for a bitcoind RPC URL (e.g. `http://127.0.0.1:8332`)
+    ///
+    /// Full scans start at `start_height`.
+    pub fn new(url: &str, auth: RpcAuth, start_height: u32) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            auth,
+            start_height,
+            authorization: Mutex::new(None),
+        }
+    }
+
+    fn authorization(&self) -> Result<Option<String>> {
+        let mut cached = self.authorization.lock();
+        if cached.is_none() {
+            *cached = authorization_header(&self.auth)?;
+        }
+        Ok(cached.clone())
+    }
+
+    /// POST a JSON-RPC request or batch and return the parsed body
+    fn post(&self, body: &Value) -> Result<Value> {
+        let mut request = minreq::post(&self.url)
+            .with_timeout(RPC_TIMEOUT_SECS)
+            .with_json(body)
+            .map_err(|e| rpc_error("request", e))?;
+        if let Some(authorization) = self.authorization()? {
+            request = request.with_header("Authorization", authorization);
+        }
+
+        let response = request.send().map_err(|e| rpc_error("request", e))?;
+        if matches!(response.status_code, 401 | 403) {
+            // Re-read the cookie next time; bitcoind may have restarted
+            self.authorization.lock().take();
+            return Err(Error::Bitcoin(
+                "Bitcoin Core RPC authentication failed".to_string(),
+            ));
+        }
+
+        // RPC errors come with HTTP 500/404 and a JSON-RPC error body
+        response
+            .json()
+            .map_err(|e| rpc_error("request", format!("HTTP {} ({})", response.status_code, e)))
+    }
+
+    /// Call one RPC method
+    fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T> {
+        let body = json!({ "jsonrpc": "1.0", "id": 0, "method": method, "params": params });
+        let response: RpcResponse =
+            serde_json::from_value(self.post(&body)?).map_err(|e| rpc_error(method, e))?;
+        response.into_result(method)
+    }
+
+    /// Call one RPC method per parameter list, `RPC_BATCH_SIZE` per request
+    ///
+    /// Returns a result per call, in order.
+    fn batch<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Vec<Value>,
+    ) -> Result<Vec<Result<T>>> {
+        let mut results = Vec::with_capacity(params.len());
+
+        for chunk in params.chunks(RPC_BATCH_SIZE) {
+            let body: Vec<Value> = chunk
+                .iter()
+                .enumerate()
+                .map(|(id, params)| {
+                    json!({ "jsonrpc": "1.0", "id": id, "method": method, "params": params })
+                })
+                .collect();
+
+            let responses: Vec<RpcResponse> =
+                serde_json::from_value(self.post(&Value::Array(body))?)
+                    .map_err(|e| rpc_error(method, e))?;
+
+            let mut by_id: HashMap<u64, RpcResponse> = responses
+                .into_iter()
+                .filter_map(|response| Some((response.id.as_u64()?, response)))
+                .collect();
+            for id in 0..chunk.len() as u64 {
+                results.push(match by_id.remove(&id) {
+                    Some(response) => response.into_result(method),
+                    None => Err(rpc_error(method, "missing response in batch")),
+                });
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Block hashes at consecutive heights
+    fn block_hashes(&self, heights: impl Iterator<Item = u32>) -> Result<Vec<BlockHash>> {
+        self.batch(
+            "getblockhash",
+            heights.map(|height| json!([height])).collect(),
+        )?
+        .into_iter()
+        .collect()
+    }
+
+    /// Full blocks by hash
+    fn blocks(&self, hashes: &[BlockHash]) -> Result<Vec<Block>> {
+        let params = hashes.iter().map(|hash| json!([hash, 0])).collect();
+        self.batch::<String>("getblock", params)?
+            .into_iter()
+            .map(|hex| deserialize_hex(&hex?).map_err(|e| rpc_error("block decoding", e)))
+            .collect()
+    }
+
+    /// Scan blocks and the mempool for the watched scripts and outpoints
+    ///
+    /// Full scans start at the birthday; syncs at the block after the last
+    /// local checkpoint still in the node's chain. Returns the transactions
+    /// found and a checkpoint chain connecting `local_tip` to the node's tip.
+    fn scan(
+        &self,
+        watch: &mut Watch<'_>,
+        local_tip: Option<CheckPoint>,
+        full_scan: bool,
+    ) -> Result<(TxUpdate<ConfirmationBlockTime>, Option<CheckPoint>)> {
+        let tip_height = BitcoinBackend::tip_height(self)?;
+        let tip_hash: BlockHash = self.call("getblockhash", json!([tip_height]))?;
+
+        // Walk back from the local tip to the point of agreement
+        let mut agreement = None;
+        let mut conflicts = Vec::new();
+        if let Some(local_tip) = &local_tip {
+            for local_cp in local_tip.iter() {
+                if local_cp.height() > tip_height {
+                    continue;
+                }
+                let hash: BlockHash = self.call("getblockhash", json!([local_cp.height()]))?;
+                if hash == local_cp.hash() {
+                    agreement = Some(local_cp);
+                    break;
+                }
+                conflicts.push(BlockId {
+                    height: local_cp.height(),
+                    hash,
+                });
+            }
+            if agreement.is_none() {
+                return Err(Error::Bitcoin(
+                    "Bitcoin Core node is on a different chain than the wallet".to_string(),
+                ));
+            }
+        }
+
+        let start_height = match (&agreement, full_scan) {
+            (Some(cp), false) => self.start_height.max(cp.height() + 1),
+            _ => self.start_height,
+        };
+        info!(
+            "Scanning blocks {}..={} via Bitcoin Core",
+            start_height, tip_height
+        );
+
+        let mut update = TxUpdate::default();
+        let mut scanned = BTreeMap::new();
+        let mut prev_hash = None;
+        let heights: Vec<u32> = (start_height..=tip_height).collect();
+        for chunk in heights.chunks(RPC_BATCH_SIZE) {
+            let hashes = self.block_hashes(chunk.iter().copied())?;
+            let blocks = self.blocks(&hashes)?;
+
+            for ((&height, hash), block) in chunk.iter().zip(hashes).zip(blocks) {
+                // Blocks must chain; anything else means a reorg mid-scan
+                let linked = prev_hash.is_none_or(|prev| block.header.prev_blockhash == prev);
+                if block.block_hash() != hash || !linked {
+                    return Err(Error::Bitcoin(
+                        "Chain reorganized during Bitcoin Core scan".to_string(),
+                    ));
+                }
+                watch.scan_block(&mut update, BlockId { height, hash }, &block);
+                scanned.insert(height, hash);
+                prev_hash = Some(hash);
+            }
+            debug!("Scanned up to block {}", chunk[chunk.len() - 1]);
+        }
+        if prev_hash.is_some_and(|hash| hash != tip_hash) {
+            return Err(Error::Bitcoin(
+                "Chain reorganized during Bitcoin Core scan".to_string(),
+            ));
+        }
+
+        // Mempool transactions evicted between the two calls are skipped
+        let mempool: Vec<Txid> = self.call("getrawmempool", json!([]))?;
+        let params = mempool.iter().map(|txid| json!([txid])).collect();
+        let seen_at = unix_now();
+        for hex in self
+            .batch::<String>("getrawtransaction", params)?
+            .into_iter()
+            .flatten()
+        {
+            let tx: Transaction =
+                deserialize_hex(&hex).map_err(|e| rpc_error("transaction decoding", e))?;
+            watch.scan_mempool_tx(&mut update, tx, seen_at);
+        }
+
+        let chain_update = match agreement {
+            Some(agreement) => {
+                let mut tip = agreement.extend(conflicts.into_iter().rev()).map_err(|_| {
+                    Error::Bitcoin("Bitcoin Core returned out-of-order blocks".to_string())
+                })?;
+                let anchor_heights: Vec<u32> = update
+                    .anchors
+                    .iter()
+                    .map(|(anchor, _)| anchor.block_id.height)
+                    .collect();
+                for height in anchor_heights {
+                    if let Some(&hash) = scanned.get(&height) {
+                        tip = tip.insert(BlockId { height, hash });
+                    }
+                }
+                Some(tip.insert(BlockId {
+                    height: tip_height,
+                    hash: tip_hash,
+                }))
+            }
+            None => None,
+        };
+
+        Ok((update, chain_update))
+    }
+
+    /// Mine `count` blocks to `address` (regtest only)
+    #[cfg(test)]
+    fn generate_to_address(&self, count: u32, address: &str) -> Result<Vec<BlockHash>> {
+        self.call("generatetoaddress", json!([count, address]))
+    }
+}
+
+impl BitcoinBackend for BitcoindBackend {
+    fn kind(&self) -> BackendKind {
+        BackendKind::Rpc
+    }
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn tip_height(&self) -> Result<u32> {
+        self.call("getblockcount", json!([]))
+    }
+
+    fn full_scan(
+        &self,
+        mut request: FullScanRequest<KeychainKind>,
+        stop_gap: usize,
+    ) -> Result<FullScanResponse<KeychainKind>> {
+        let local_tip = request.chain_tip();
+        let mut watch = Watch::full_scan(&mut request, stop_gap as u32);
+        let (tx_update, chain_update) = self.scan(&mut watch, local_tip, true)?;
+
+        Ok(FullScanResponse {
+            tx_update,
+            last_active_indices: watch.last_active,
+            chain_update,
+        })
+    }
+
+    fn sync(&self, mut request: SyncRequest<(KeychainKind, u32)>) -> Result<SyncResponse> {
+        let local_tip = request.chain_tip();
+        let mut watch = Watch::sync(&mut request);
+        let (tx_update, chain_update) = self.scan(&mut watch, local_tip, false)?;
+
+        Ok(SyncResponse {
+            tx_update,
+            chain_update,
+        })
+    }
+
+    fn script_balance(&self, script: &Script) -> Result<BitcoinBalance> {
+        let descriptor = format!("raw({})", script.to_hex_string());
+        let scan: UtxoSetScan = self.call("scantxoutset", json!(["start", [descriptor]]))?;
+        let confirmed = Amount::from_btc(scan.total_amount)
+            .map_err(|e| rpc_error("balance query", e))?
+            .to_sat();
+
+        // The UTXO set holds confirmed outputs only
+        Ok(BitcoinBalance {
+            confirmed,
+            unconfirmed: 0,
+            immature: 0,
+            trusted_spendable: confirmed,
+        })
+    }
+
+    fn scripts_history(&self, _scripts: &[ScriptBuf]) -> Result<ScriptHistory> {
+        Err(Error::Unsupported(
+            "Bitcoin Core has no address index; address history needs an Electrum or Esplora server"
+                .to_string(),
+        ))
+    }
+
+    fn estimate_fee(&self, target_blocks: u32) -> Result<FeeEstimate> {
+        let target = target_blocks.clamp(1, MAX_FEE_TARGET);
+        let estimate: SmartFeeEstimate = self.call("estimatesmartfee", json!([target]))?;
+
+        // Fresh nodes and regtest have no fee data; fall back to the
+        // mempool's minimum relay rate
+        let rate = match estimate.feerate {
+            Some(rate) => rate,
+            None => {
+                debug!("No smart fee estimate: {}", estimate.errors.join("; "));
+                self.call::<MempoolInfo>("getmempoolinfo", json!([]))?
+                    .mempoolminfee
+            }
+        };
+
+        Ok(FeeEstimate {
+            sat_per_vbyte: btc_per_kvb_to_sat_per_vb(rate),
+            target_blocks,
+        })
+    }
+
+    fn broadcast(&self, tx: &Transaction) -> Result<()> {
+        let _txid: Txid = self.call("sendrawtransaction", json!([serialize_hex(tx)]))?;
+        Ok(())
+    }
+
+    fn reset(&self) {
+        self.authorization.lock().take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bdk_wallet::bitcoin::blockdata::constants::genesis_block;
+    use bdk_wallet::bitcoin::hashes::Hash;
+    use bdk_wallet::bitcoin::{absolute, transaction, Network, Sequence, TxIn, TxOut, Witness};
+
+    /// Distinct P2WPKH-shaped script
+    fn spk(n: u8) -> ScriptBuf {
+        let mut bytes = vec![0x00, 0x14];
+        bytes.extend([n; 20]);
+        ScriptBuf::from_bytes(bytes)
+    }
+
+    fn tx(inputs: &[OutPoint], outputs: &[ScriptBuf]) -> Transaction {
+        Transaction {
+            version: transaction::Version::TWO,
+            lock_time: absolute::LockTime::ZERO,
+            input: inputs
+                .iter()
+                .map(|&previous_output| TxIn {
+                    previous_output,
+                    script_sig: ScriptBuf::new(),
+                    sequence: Sequence::MAX,
+                    witness: Witness::new(),
+                })
+                .collect(),
+            output: outputs
+                .iter()
+                .map(|script_pubkey| TxOut {
+                    value: Amount::from_sat(10_000),
+                    script_pubkey: script_pubkey.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    fn block(height: u32, txdata: Vec<Transaction>) -> (BlockId, Block) {
+        let mut block = genesis_block(Network::Regtest);
+        block.header.time = 1_700_000_000 + height;
+        block.txdata = txdata;
+        let id = BlockId {
+            height,
+            hash: block.block_hash(),
+        };
+        (id, block)
+    }
+
+    fn foreign_input(n: u8) -> OutPoint {
+        OutPoint::new(Txid::from_byte_array([n; 32]), 0)
+    }
+
+    #[test]
+    fn test_authorization_header() {
+        assert_eq!(authorization_header(&RpcAuth::None).unwrap(), None);
+
+        let auth = RpcAuth::UserPass {
+            username: "alice".to_string(),
+            password: "hunter2".to_string(),
+        };
+        assert_eq!(
+            authorization_header(&auth).unwrap().unwrap(),
+            "Basic YWxpY2U6aHVudGVyMg=="
+        );
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(".cookie");
+        std::fs::write(&path, "__cookie__:abc\n").unwrap();
+        assert_eq!(
+            authorization_header(&RpcAuth::Cookie { path })
+                .unwrap()
+                .unwrap(),
+            format!("Basic {}", BASE64_STANDARD.encode("__cookie__:abc"))
+        );
+
+        let missing = RpcAuth::Cookie {
+            path: dir.path().join("missing"),
+        };
+        assert!(authorization_header(&missing).is_err());
+    }
+
+    #[test]
+    fn test_rpc_response() {
+        let ok: RpcResponse =
+            serde_json::from_str(r#"{"result":812345,"error":null,"id":0}"#).unwrap();
+        assert_eq!(ok.into_result::<u32>("getblockcount").unwrap(), 812345);
+
+        let failed: RpcResponse = serde_json::from_str(
+            r#"{"result":null,"error":{"code":-8,"message":"Block height out of range"},"id":3}"#,
+        )
+        .unwrap();
+        assert_eq!(failed.id.as_u64(), Some(3));
+        let err = failed.into_result::<String>("getblockhash").unwrap_err();
+        assert!(matches!(err, Error::Rejected(_)));
+        assert!(err
+            .to_string()
+            .contains("Block height out of range (code -8)"));
+
+        let warming_up: RpcResponse = serde_json::from_str(
+            r#"{"result":null,"error":{"code":-28,"message":"Loading block index..."},"id":0}"#,
+        )
+        .unwrap();
+        let err = warming_up.into_result::<u32>("getblockcount").unwrap_err();
+        assert!(matches!(err, Error::Bitcoin(_)));
+    }
+
+    #[test]
+    fn test_fee_rate_conversion() {
+        assert_eq!(btc_per_kvb_to_sat_per_vb(0.00001), 1.0);
+        assert_eq!(btc_per_kvb_to_sat_per_vb(0.00025), 25.0);
+    }
+
+    #[test]
+    fn test_full_scan_watch_follows_gap() {
+        let mut request = FullScanRequest::builder()
+            .spks_for_keychain(
+                KeychainKind::External,
+                (0..50u32).map(|i| (i, spk(i as u8))),
+            )
+            .spks_for_keychain(
+                KeychainKind::Internal,
+                (0..50u32).map(|i| (i, spk(100 + i as u8))),
+            )
+            .build();
+        let mut watch = Watch::full_scan(&mut request, 2);
+        assert_eq!(watch.spks.len(), 4);
+
+        let mut update = TxUpdate::default();
+
+        // Index 1 is within the gap, which then extends to index 3
+        let receive = tx(&[foreign_input(1)], &[spk(1)]);
+        let (id1, block1) = block(
+            1,
+            vec![receive.clone(), tx(&[foreign_input(2)], &[spk(200)])],
+        );
+        watch.scan_block(&mut update, id1, &block1);
+        assert_eq!(watch.spks.len(), 6);
+
+        // Index 3 is found; a spend of the first receive is ours too, and
+        // its change lands on the internal keychain
+        let spend = tx(
+            &[OutPoint::new(receive.compute_txid(), 0)],
+            &[spk(250), spk(100)],
+        );
+        let (id2, block2) = block(2, vec![tx(&[foreign_input(3)], &[spk(3)]), spend.clone()]);
+        watch.scan_block(&mut update, id2, &block2);
+
+        // Index 10 is beyond the gap of anything used
+        let (id3, block3) = block(3, vec![tx(&[foreign_input(4)], &[spk(10)])]);
+        watch.scan_block(&mut update, id3, &block3);
+
+        assert_eq!(update.txs.len(), 3);
+        assert_eq!(update.anchors.len(), 3);
+        assert!(update
+            .anchors
+            .iter()
+            .any(|(anchor, txid)| *txid == spend.compute_txid()
+                && anchor.block_id == id2
+                && anchor.confirmation_time == 1_700_000_002));
+        assert_eq!(
+            watch.last_active,
+            BTreeMap::from([(KeychainKind::External, 3), (KeychainKind::Internal, 0)])
+        );
+
+        // A mempool transaction spending change is ours; one already
+        // confirmed is not added twice
+        let pending = tx(&[OutPoint::new(spend.compute_txid(), 1)], &[spk(251)]);
+        watch.scan_mempool_tx(&mut update, pending.clone(), 42);
+        watch.scan_mempool_tx(&mut update, spend, 42);
+        assert_eq!(update.txs.len(), 4);
+        assert_eq!(update.seen_ats.get(&pending.compute_txid()), Some(&42));
+    }
+
+    #[test]
+    fn test_full_scan_watch_exhausted_keychain() {
+        let mut request = FullScanRequest::builder()
+            .spks_for_keychain(KeychainKind::External, (0..3u32).map(|i| (i, spk(i as u8))))
+            .build();
+        let watch = Watch::full_scan(&mut request, 20);
+        assert_eq!(watch.spks.len(), 3);
+        assert_eq!(watch.loaded.get(&KeychainKind::External), Some(&u32::MAX));
+    }
+
+    #[test]
+    fn test_sync_watch() {
+        let known = tx(&[foreign_input(1)], &[spk(250)]);
+        let utxo = foreign_input(9);
+        let mut request = SyncRequest::<(KeychainKind, u32)>::builder()
+            .spks_with_indexes([((KeychainKind::External, 0), spk(1))])
+            .txids([known.compute_txid()])
+            .outpoints([utxo])
+            .build();
+        let mut watch = Watch::sync(&mut request);
+
+        let mut update = TxUpdate::default();
+        let spend = tx(&[utxo], &[spk(251)]);
+        let (id, block) = block(7, vec![known, spend, tx(&[foreign_input(2)], &[spk(252)])]);
+        watch.scan_block(&mut update, id, &block);
+
+        assert_eq!(update.txs.len(), 2);
+        assert!(watch.last_active.is_empty());
+    }
+
+    /// Round trip against a local regtest node:
+    ///
+    /// ```text
+    /// bitcoind -regtest
+    /// BITCOIND_RPC_URL=http://127.0.0.1:18443 \
+    /// BITCOIND_RPC_COOKIE=~/.bitcoin/regtest/.cookie \
+    ///     cargo test bitcoind_regtest -- --ignored
+    /// ```
+    #[test]
+    #[ignore = "needs a regtest bitcoind (BITCOIND_RPC_URL, BITCOIND_RPC_COOKIE)"]
+    fn test_bitcoind_regtest() {
+        use bdk_wallet::bitcoin::bip32::Xpriv;
+        use bdk_wallet::{SignOptions, Wallet};
+
+        let url = std::env::var("BITCOIND_RPC_URL").unwrap();
+        let auth = match std::env::var("BITCOIND_RPC_COOKIE") {
+            Ok(path) => RpcAuth::Cookie { path: path.into() },
+            Err(_) => RpcAuth::None,
+        };
+        let backend = BitcoindBackend::new(&url, auth, 0);
+
+        let seed = [7u8; 64];
+        let xprv = Xpriv::new_master(Network::Regtest, &seed).unwrap();
+        let mut wallet = Wallet::create(
+            format!("wpkh({}/84'/1'/0'/0/*)", xprv),
+            format!("wpkh({}/84'/1'/0'/1/*)", xprv),
+        )
+        .network(Network::Regtest)
+        .create_wallet_no_persist()
+        .unwrap();
+
+        // Mine a coinbase to the wallet and let it mature
+        let address = wallet.reveal_next_address(KeychainKind::External).address;
+        backend
+            .generate_to_address(101, &address.to_string())
+            .unwrap();
+
+        let response = backend
+            .full_scan(wallet.start_full_scan().build(), 20)
+            .unwrap();
+        wallet.apply_update(response).unwrap();
+        assert!(wallet.balance().confirmed > Amount::ZERO);
+        assert_eq!(
+            wallet.latest_checkpoint().height(),
+            backend.tip_height().unwrap()
+        );
+
+        let fee = backend.estimate_fee(6).unwrap();
+        assert!(fee.sat_per_vbyte > 0.0);
+
+        // Send to ourselves and pick the transaction up from the mempool
+        let recipient = wallet.reveal_next_address(KeychainKind::External).address;
+        let mut builder = wallet.build_tx();
+        builder.add_recipient(recipient.script_pubkey(), Amount::from_sat(100_000));
+        let mut psbt = builder.finish().unwrap();
+        assert!(wallet.sign(&mut psbt, SignOptions::default()).unwrap());
+        let sent = psbt.extract_tx().unwrap();
+        backend.broadcast(&sent).unwrap();
+
+        let request = wallet
+            .start_sync_with_revealed_spks()
+            .outpoints(wallet.list_unspent().map(|utxo| utxo.outpoint))
+            .build();
+        wallet.apply_update(backend.sync(request).unwrap()).unwrap();
+        assert!(wallet.get_tx(sent.compute_txid()).is_some());
+
+        // Confirm it; the next sync only scans the new block
+        backend
+            .generate_to_address(1, &address.to_string())
+            .unwrap();
+        let request = wallet.start_sync_with_revealed_spks().build();
+        wallet.apply_update(backend.sync(request).unwrap()).unwrap();
+        let confirmed = wallet.get_tx(sent.compute_txid()).unwrap();
+        assert!(confirmed.chain_position.is_confirmed());
+    }
+}
@@ -0,0 +1,268 @@
+//! Sync monitoring - progress reporting and cancellation
+//!
+//! The adapter's sync methods take a `SyncMonitor`, which forwards each
+//! progress update to a reporter (the sync command emits Tauri events)
+//! and carries the flag used to cancel the sync from another thread.
+//! A cancelled full scan stops requesting scripts and its partial result
+//! is discarded; a cancelled revealed-script sync is discarded once its
+//! request returns.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use bdk_wallet::bitcoin::Script;
+use bdk_wallet::chain::spk_client::{SyncItem, SyncProgress as ScriptSyncProgress};
+use bdk_wallet::KeychainKind;
+use tracing::{info, warn};
+
+use super::types::{SyncProgress, SyncStage};
+use crate::error::{Error, Result};
+
+/// Progress while connecting to the backend
+pub(super) const PROGRESS_CONNECTING: f32 = 0.0;
+
+/// Progress when the first script is checked
+const PROGRESS_SCAN_START: f32 = 5.0;
+
+/// Progress once the scan is done and the update is being saved
+pub(super) const PROGRESS_UPDATING: f32 = 90.0;
+
+/// Callback receiving every progress update
+pub type ProgressReporter = Arc<dyn Fn(&SyncProgress) + Send + Sync>;
+
+/// Progress reporting and cancellation for one sync
+///
+/// Clones share the cancellation flag, so a clone kept by the caller can
+/// cancel a sync running on another thread.
+#[derive(Clone, Default)]
+pub struct SyncMonitor {
+    cancelled: Arc<AtomicBool>,
+    reporter: Option<ProgressReporter>,
+}
+
+impl SyncMonitor {
+    /// Monitor that only tracks cancellation
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Monitor that passes every progress update to `reporter`
+    pub fn with_reporter(reporter: impl Fn(&SyncProgress) + Send + Sync + 'static) -> Self {
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            reporter: Some(Arc::new(reporter)),
+        }
+    }
+
+    /// Ask the sync to stop at the next script
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether `cancel` has been called
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Fail with `Error::Cancelled` once cancelled
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(Error::Cancelled("Sync cancelled".to_string()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Pass a progress update to the reporter
+    pub fn report(&self, progress: &SyncProgress) {
+        if let Some(reporter) = &self.reporter {
+            reporter(progress);
+        }
+    }
+
+    /// Report the outcome of a sync and turn it into its final progress
+    pub(super) fn finish(&self, result: Result<()>) -> Result<SyncProgress> {
+        match result {
+            Ok(()) => {
+                let progress = SyncProgress::new(SyncStage::Complete, 100.0, "Sync complete");
+                self.report(&progress);
+                Ok(progress)
+            }
+            Err(e @ Error::Cancelled(_)) => {
+                info!("Wallet sync cancelled");
+                self.report(&SyncProgress::new(
+                    SyncStage::Cancelled,
+                    0.0,
+                    "Sync cancelled",
+                ));
+                Err(e)
+            }
+            Err(e) => {
+                warn!("Wallet sync failed: {}", e);
+                self.report(&SyncProgress::new(SyncStage::Failed, 0.0, e.to_string()));
+                Err(e)
+            }
+        }
+    }
+
+    /// Inspector for a full scan request
+    ///
+    /// The number of scripts a full scan checks isn't known up front, so
+    /// progress is measured against `expected`: the revealed scripts plus
+    /// one gap per keychain.
+    pub(super) fn full_scan_inspector(
+        &self,
+        expected: u32,
+    ) -> impl FnMut(KeychainKind, u32, &Script) + Send + 'static {
+        let mut counter = ScriptCounter::new(self.clone());
+        move |keychain, _index, _script| {
+            counter.add(keychain);
+            let fraction = counter.total() as f32 / expected.max(1) as f32;
+            counter.report(fraction);
+        }
+    }
+
+    /// Inspector for a revealed-script sync request
+    pub(super) fn sync_inspector(
+        &self,
+    ) -> impl FnMut(SyncItem<(KeychainKind, u32)>, ScriptSyncProgress) + Send + 'static {
+        let mut counter = ScriptCounter::new(self.clone());
+        move |item, progress| {
+            if let SyncItem::Spk((keychain, _), _) = item {
+                counter.add(keychain);
+            }
+            let fraction = progress.consumed() as f32 / progress.total().max(1) as f32;
+            counter.report(fraction);
+        }
+    }
+}
+
+/// Scripts checked per keychain during one scan attempt
+struct ScriptCounter {
+    monitor: SyncMonitor,
+    external: u32,
+    internal: u32,
+}
+
+impl ScriptCounter {
+    fn new(monitor: SyncMonitor) -> Self {
+        Self {
+            monitor,
+            external: 0,
+            internal: 0,
+        }
+    }
+
+    fn add(&mut self, keychain: KeychainKind) {
+        match keychain {
+            KeychainKind::External => self.external += 1,
+            KeychainKind::Internal => self.internal += 1,
+        }
+    }
+
+    fn total(&self) -> u32 {
+        self.external + self.internal
+    }
+
+    fn report(&self, fraction: f32) {
+        let progress = PROGRESS_SCAN_START
+            + (PROGRESS_UPDATING - PROGRESS_SCAN_START) * fraction.clamp(0.0, 1.0);
+        let message = format!(
+            "Checked {} receive and {} change scripts",
+            self.external, self.internal
+        );
+        self.monitor.report(
+            &SyncProgress::new(SyncStage::Scanning, progress, message)
+                .with_scripts(self.external, self.internal),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bdk_wallet::bitcoin::ScriptBuf;
+    use parking_lot::Mutex;
+
+    fn recording() -> (SyncMonitor, Arc<Mutex<Vec<SyncProgress>>>) {
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let monitor = SyncMonitor::with_reporter({
+            let updates = updates.clone();
+            move |progress| updates.lock().push(progress.clone())
+        });
+        (monitor, updates)
+    }
+
+    #[test]
+    fn test_cancel_is_shared_between_clones() {
+        let monitor = SyncMonitor::new();
+        let handle = monitor.clone();
+        assert!(monitor.check().is_ok());
+
+        handle.cancel();
+        assert!(monitor.is_cancelled());
+        assert!(matches!(monitor.check(), Err(Error::Cancelled(_))));
+    }
+
+    #[test]
+    fn test_full_scan_progress_counts_keychains() {
+        let (monitor, updates) = recording();
+        let script = ScriptBuf::new();
+        let mut inspect = monitor.full_scan_inspector(4);
+
+        inspect(KeychainKind::External, 0, &script);
+        inspect(KeychainKind::External, 1, &script);
+        inspect(KeychainKind::Internal, 0, &script);
+
+        let updates = updates.lock();
+        assert_eq!(updates.len(), 3);
+        assert!(updates.iter().all(|p| p.stage == SyncStage::Scanning));
+        assert!(updates.windows(2).all(|w| w[0].progress < w[1].progress));
+
+        let last = updates.last().unwrap();
+        assert_eq!((last.external_scripts, last.internal_scripts), (2, 1));
+        assert!(last.progress > PROGRESS_SCAN_START && last.progress < PROGRESS_UPDATING);
+        assert_eq!(
+            last.message.as_deref(),
+            Some("Checked 2 receive and 1 change scripts")
+        );
+    }
+
+    #[test]
+    fn test_full_scan_progress_is_capped() {
+        let (monitor, updates) = recording();
+        let mut inspect = monitor.full_scan_inspector(1);
+
+        // Scans can run past the estimate when used addresses are found
+        for index in 0..3 {
+            inspect(KeychainKind::External, index, &ScriptBuf::new());
+        }
+        assert_eq!(updates.lock().last().unwrap().progress, PROGRESS_UPDATING);
+    }
+
+    #[test]
+    fn test_finish_reports_outcome() {
+        let (monitor, updates) = recording();
+
+        let progress = monitor.finish(Ok(())).unwrap();
+        assert_eq!(progress.stage, SyncStage::Complete);
+        assert_eq!(progress.progress, 100.0);
+
+        assert!(monitor
+            .finish(Err(Error::Bitcoin("unreachable".to_string())))
+            .is_err());
+        assert!(monitor
+            .finish(Err(Error::Cancelled("Sync cancelled".to_string())))
+            .is_err());
+
+        let stages: Vec<_> = updates.lock().iter().map(|p| p.stage).collect();
+        assert_eq!(
+            stages,
+            [SyncStage::Complete, SyncStage::Failed, SyncStage::Cancelled]
+        );
+        assert_eq!(
+            updates.lock()[1].message.as_deref(),
+            Some("Bitcoin error: unreachable")
+        );
+    }
+}
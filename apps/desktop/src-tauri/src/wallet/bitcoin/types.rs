@@ -3,16 +3,23 @@
 //! Defines the data structures used for Bitcoin wallet operations,
 //! balance tracking, and transaction history.
 
+use std::fmt;
+use std::path::PathBuf;
+
 use serde::{Deserialize, Serialize};
 
+use super::{get_default_electrum_url, get_default_esplora_url};
+
 /// Bitcoin network configuration
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum BitcoinNetwork {
     /// Bitcoin mainnet
     Mainnet,
-    /// Bitcoin testnet
+    /// Bitcoin testnet3
     Testnet,
+    /// Bitcoin testnet4 (BIP94)
+    Testnet4,
     /// Bitcoin signet (testing network)
     Signet,
     /// Bitcoin regtest (local testing)
@@ -30,6 +37,7 @@ impl From<BitcoinNetwork> for bitcoin::Network {
         match network {
             BitcoinNetwork::Mainnet => bitcoin::Network::Bitcoin,
             BitcoinNetwork::Testnet => bitcoin::Network::Testnet,
+            BitcoinNetwork::Testnet4 => bitcoin::Network::Testnet4,
             BitcoinNetwork::Signet => bitcoin::Network::Signet,
             BitcoinNetwork::Regtest => bitcoin::Network::Regtest,
         }
@@ -41,9 +49,9 @@ impl From<bitcoin::Network> for BitcoinNetwork {
         match network {
             bitcoin::Network::Bitcoin => BitcoinNetwork::Mainnet,
             bitcoin::Network::Testnet => BitcoinNetwork::Testnet,
+            bitcoin::Network::Testnet4 => BitcoinNetwork::Testnet4,
             bitcoin::Network::Signet => BitcoinNetwork::Signet,
             bitcoin::Network::Regtest => BitcoinNetwork::Regtest,
-            _ => BitcoinNetwork::Mainnet,
         }
     }
 }
@@ -60,6 +68,34 @@ pub enum BlockchainBackend {
     Esplora {
         url: String,
     },
+    /// Bitcoin Core JSON-RPC (self-hosted node, no indexer needed)
+    Rpc {
+        url: String,
+        /// RPC credentials
+        #[serde(default)]
+        auth: RpcAuth,
+        /// First block height scanned by full scans (wallet birthday)
+        #[serde(default)]
+        start_height: u32,
+    },
+}
+
+impl BlockchainBackend {
+    /// Protocol spoken by this backend
+    pub fn kind(&self) -> BackendKind {
+        match self {
+            Self::Electrum { .. } => BackendKind::Electrum,
+            Self::Esplora { .. } => BackendKind::Esplora,
+            Self::Rpc { .. } => BackendKind::Rpc,
+        }
+    }
+
+    /// Server URL
+    pub fn url(&self) -> &str {
+        match self {
+            Self::Electrum { url } | Self::Esplora { url } | Self::Rpc { url, .. } => url,
+        }
+    }
 }
 
 impl Default for BlockchainBackend {
@@ -71,6 +107,84 @@ impl Default for BlockchainBackend {
     }
 }
 
+/// Backend protocol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackendKind {
+    /// Electrum protocol
+    Electrum,
+    /// Esplora HTTP API
+    Esplora,
+    /// Bitcoin Core JSON-RPC
+    Rpc,
+}
+
+/// Bitcoin Core RPC authentication
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum RpcAuth {
+    /// No credentials (e.g. behind an authenticating proxy)
+    #[default]
+    None,
+    /// Cookie file written by bitcoind (`<datadir>/.cookie`)
+    Cookie { path: PathBuf },
+    /// `rpcuser`/`rpcpassword` or `rpcauth` credentials
+    UserPass { username: String, password: String },
+}
+
+impl fmt::Debug for RpcAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Cookie { path } => f.debug_struct("Cookie").field("path", path).finish(),
+            Self::UserPass { username, .. } => f
+                .debug_struct("UserPass")
+                .field("username", username)
+                .field("password", &"<redacted>")
+                .finish(),
+        }
+    }
+}
+
+/// Health of a configured backend server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackendHealth {
+    /// Not contacted yet
+    Unknown,
+    /// Last request succeeded
+    Healthy,
+    /// Recent requests failed, but the server is still tried in order
+    Degraded,
+    /// Repeated failures; skipped until its cooldown expires
+    Down,
+}
+
+/// Status of one backend server, as reported to the UI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackendStatus {
+    /// Backend protocol
+    pub kind: BackendKind,
+    /// Server URL
+    pub url: String,
+    /// Current health
+    pub health: BackendHealth,
+    /// Whether this server handled the most recent successful request
+    pub active: bool,
+    /// Smoothed round-trip time of health checks in milliseconds
+    pub latency_ms: Option<u64>,
+    /// Chain tip height reported by the last health check
+    pub tip_height: Option<u32>,
+    /// Failures since the last success
+    pub consecutive_failures: u32,
+    /// Most recent error message
+    pub last_error: Option<String>,
+    /// Time of the last success (Unix timestamp)
+    pub last_success: Option<u64>,
+    /// Time of the last failure (Unix timestamp)
+    pub last_failure: Option<u64>,
+}
+
 /// Bitcoin balance breakdown
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BitcoinBalance {
@@ -145,14 +259,58 @@ pub struct BitcoinTransaction {
     pub status: ConfirmationStatus,
     /// Transaction timestamp (block time if confirmed, first seen if unconfirmed)
     pub timestamp: Option<u64>,
+    /// When the transaction was first seen unconfirmed, if it was
+    #[serde(default)]
+    pub first_seen: Option<u64>,
     /// Addresses involved (receiving addresses for sent, our addresses for received)
     pub addresses: Vec<String>,
+    /// Our addresses among the inputs and outputs
+    #[serde(default)]
+    pub own_addresses: Vec<String>,
+    /// Other parties' addresses among the inputs and outputs
+    #[serde(default)]
+    pub counterparty_addresses: Vec<String>,
+    /// Inputs, in transaction order
+    #[serde(default)]
+    pub inputs: Vec<TransactionInput>,
+    /// Outputs, in transaction order
+    #[serde(default)]
+    pub outputs: Vec<TransactionOutput>,
     /// Raw transaction size in bytes
     pub size: Option<u32>,
     /// Virtual size in vbytes (for fee calculation)
     pub vsize: Option<u32>,
 }
 
+/// Transaction input
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionInput {
+    /// Spent output (`txid:vout`)
+    pub previous_output: String,
+    /// Address of the spent output, if known
+    pub address: Option<String>,
+    /// Value of the spent output in satoshis, if known
+    pub value_sats: Option<u64>,
+    /// Whether the spent output was ours
+    pub is_mine: bool,
+}
+
+/// Transaction output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionOutput {
+    /// Output index
+    pub vout: u32,
+    /// Address, if the script has one (not OP_RETURN)
+    pub address: Option<String>,
+    /// Value in satoshis
+    pub value_sats: u64,
+    /// Whether the output is ours
+    pub is_mine: bool,
+    /// Whether the output is ours and on the change keychain
+    #[serde(default)]
+    pub is_change: bool,
+}
+
 impl BitcoinTransaction {
     /// Get amount in BTC (positive for received, negative for sent)
     pub fn amount_btc(&self) -> f64 {
@@ -204,8 +362,86 @@ pub struct SyncProgress {
     pub progress: f32,
     /// Optional message describing current operation
     pub message: Option<String>,
+    /// External (receive) scripts checked so far
+    #[serde(default)]
+    pub external_scripts: u32,
+    /// Internal (change) scripts checked so far
+    #[serde(default)]
+    pub internal_scripts: u32,
+}
+
+impl SyncProgress {
+    /// Progress at a stage, with no scripts checked yet
+    pub fn new(stage: SyncStage, progress: f32, message: impl Into<String>) -> Self {
+        Self {
+            stage,
+            progress,
+            message: Some(message.into()),
+            external_scripts: 0,
+            internal_scripts: 0,
+        }
+    }
+
+    /// Set the per-keychain script counts
+    pub fn with_scripts(mut self, external: u32, internal: u32) -> Self {
+        self.external_scripts = external;
+        self.internal_scripts = internal;
+        self
+    }
+}
+
+/// Sync progress event for one wallet
+///
+/// Emitted on [`SYNC_PROGRESS_EVENT`] while a sync runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncProgressEvent {
+    /// Wallet being synced
+    pub wallet_id: String,
+    /// Progress of the sync
+    #[serde(flatten)]
+    pub progress: SyncProgress,
+}
+
+/// Tauri event name for [`SyncProgressEvent`]
+pub const SYNC_PROGRESS_EVENT: &str = "bitcoin-sync-progress";
+
+/// Push update for watched wallets
+///
+/// Emitted on [`CHAIN_EVENT`] while wallets are watched over Electrum
+/// subscriptions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChainEvent {
+    /// A new block extended the chain
+    NewBlock { height: u32 },
+    /// Blocks were replaced; the new chain ends at `height`
+    Reorg { height: u32 },
+    /// A transaction paying the wallet appeared
+    TransactionReceived {
+        wallet_id: String,
+        txid: String,
+        amount_sats: i64,
+        confirmed: bool,
+    },
+    /// A wallet transaction was included in a block
+    TransactionConfirmed {
+        wallet_id: String,
+        txid: String,
+        block_height: u32,
+    },
+    /// A confirmed wallet transaction lost its block in a reorg
+    TransactionReorged {
+        wallet_id: String,
+        txid: String,
+        previous_height: u32,
+    },
+    /// The wallet's balance or history may have changed
+    WalletUpdated { wallet_id: String },
 }
 
+/// Tauri event name for [`ChainEvent`]
+pub const CHAIN_EVENT: &str = "bitcoin-chain-event";
+
 /// Sync stage enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -220,6 +456,8 @@ pub enum SyncStage {
     Complete,
     /// Sync failed
     Failed,
+    /// Sync cancelled by the user
+    Cancelled,
 }
 
 /// Fee rate estimation
@@ -251,20 +489,43 @@ pub struct SendTransactionResult {
 pub struct BitcoinConfig {
     /// Network to use
     pub network: BitcoinNetwork,
-    /// Blockchain backend configuration
+    /// Preferred blockchain backend
     pub backend: BlockchainBackend,
+    /// Servers to fail over to, in order, when the preferred one is unavailable
+    #[serde(default)]
+    pub fallback_backends: Vec<BlockchainBackend>,
     /// Gap limit for address discovery
     pub gap_limit: u32,
 }
 
-impl Default for BitcoinConfig {
-    fn default() -> Self {
+impl BitcoinConfig {
+    /// Default configuration for a network
+    ///
+    /// Uses the network's default Electrum server with its default Esplora
+    /// server as fallback.
+    pub fn for_network(network: BitcoinNetwork) -> Self {
         Self {
-            network: BitcoinNetwork::Mainnet,
-            backend: BlockchainBackend::default(),
+            network,
+            backend: BlockchainBackend::Electrum {
+                url: get_default_electrum_url(network),
+            },
+            fallback_backends: vec![BlockchainBackend::Esplora {
+                url: get_default_esplora_url(network),
+            }],
             gap_limit: 20,
         }
     }
+
+    /// All configured backends, preferred first
+    pub fn backends(&self) -> impl Iterator<Item = &BlockchainBackend> {
+        std::iter::once(&self.backend).chain(&self.fallback_backends)
+    }
+}
+
+impl Default for BitcoinConfig {
+    fn default() -> Self {
+        Self::for_network(BitcoinNetwork::Mainnet)
+    }
 }
 
 #[cfg(test)]
@@ -298,7 +559,12 @@ mod tests {
                 confirmations: 6,
             },
             timestamp: Some(1700000000),
+            first_seen: None,
             addresses: vec!["bc1q...".to_string()],
+            own_addresses: vec!["bc1q...".to_string()],
+            counterparty_addresses: Vec::new(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
             size: Some(250),
             vsize: Some(140),
         };
@@ -318,5 +584,70 @@ mod tests {
             BitcoinNetwork::from(bitcoin::Network::Testnet),
             BitcoinNetwork::Testnet
         );
+        assert_eq!(
+            BitcoinNetwork::from(bitcoin::Network::Testnet4),
+            BitcoinNetwork::Testnet4
+        );
+    }
+
+    #[test]
+    fn test_config_backends() {
+        let config = BitcoinConfig::for_network(BitcoinNetwork::Signet);
+        let backends: Vec<_> = config.backends().collect();
+
+        assert_eq!(backends.len(), 2);
+        assert_eq!(backends[0].kind(), BackendKind::Electrum);
+        assert_eq!(backends[0].url(), "ssl://mempool.space:60602");
+        assert_eq!(backends[1].kind(), BackendKind::Esplora);
+        assert_eq!(backends[1].url(), "https://mempool.space/signet/api");
+
+        // Configs without fallbacks still deserialize
+        let config: BitcoinConfig = serde_json::from_str(
+            r#"{"network":"regtest","backend":{"esplora":{"url":"http://127.0.0.1:3002"}},"gap_limit":20}"#,
+        )
+        .unwrap();
+        assert!(config.fallback_backends.is_empty());
+        assert_eq!(config.backends().count(), 1);
+    }
+
+    #[test]
+    fn test_rpc_backend_config() {
+        let backend: BlockchainBackend = serde_json::from_str(
+            r#"{"rpc":{"url":"http://127.0.0.1:18443","auth":{"type":"userpass","username":"alice","password":"hunter2"}}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(backend.kind(), BackendKind::Rpc);
+        assert_eq!(backend.url(), "http://127.0.0.1:18443");
+        let BlockchainBackend::Rpc {
+            auth, start_height, ..
+        } = &backend
+        else {
+            panic!("expected an RPC backend");
+        };
+        assert_eq!(*start_height, 0);
+        assert_eq!(
+            *auth,
+            RpcAuth::UserPass {
+                username: "alice".to_string(),
+                password: "hunter2".to_string(),
+            }
+        );
+
+        // Passwords stay out of logs
+        let debug = format!("{:?}", backend);
+        assert!(debug.contains("alice"));
+        assert!(!debug.contains("hunter2"));
+
+        // Auth defaults to none
+        let backend: BlockchainBackend =
+            serde_json::from_str(r#"{"rpc":{"url":"http://127.0.0.1:18443"}}"#).unwrap();
+        assert!(matches!(
+            backend,
+            BlockchainBackend::Rpc {
+                auth: RpcAuth::None,
+                ..
+            }
+        ));
     }
 }
@@ -0,0 +1,727 @@
+//! Chain watcher - push updates over Electrum subscriptions
+//!
+//! `ChainWatcher` keeps its own Electrum connection (subscriptions belong
+//! to a connection, so it can't share the sync backend's) and subscribes
+//! to block headers and to every watched script. A background thread
+//! polls the connection for notifications and hands them to a callback:
+//!
+//! - a script whose status hash changed produces `ScriptsChanged` for each
+//!   wallet watching it, so the caller can sync just those scripts
+//! - a new header produces `NewTip`, preceded by `Reorg` when it doesn't
+//!   build on the previous tip
+//!
+//! A dropped connection is reopened on the next poll, trying the servers
+//! in order. Statuses and the tip are compared with the last ones seen,
+//! so changes missed while disconnected are still reported.
+//!
+//! `transaction_events` turns a wallet's history before and after such a
+//! sync into the `ChainEvent`s shown to the user.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use bdk_electrum::electrum_client::{self, Client, ConfigBuilder, ElectrumApi, ScriptStatus};
+use bdk_wallet::bitcoin::block::Header;
+use bdk_wallet::bitcoin::{BlockHash, ScriptBuf};
+use parking_lot::Mutex;
+use tracing::{debug, info, warn};
+
+use super::types::*;
+use crate::error::{Error, Result};
+
+/// Time between polls for notifications
+const WATCH_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Socket timeout in seconds
+const WATCH_TIMEOUT_SECS: u8 = 30;
+
+fn watch_error(context: &str, e: electrum_client::Error) -> Error {
+    Error::Bitcoin(format!("Electrum {} failed: {}", context, e))
+}
+
+/// Change reported by the watcher
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WatchNotice {
+    /// Scripts of a watched wallet have new history
+    ScriptsChanged {
+        wallet_id: String,
+        scripts: Vec<ScriptBuf>,
+    },
+    /// The chain tip moved to `height`
+    NewTip { height: u32 },
+    /// The previous tip was replaced by a chain ending at `height`
+    Reorg { height: u32 },
+}
+
+/// A subscribed script and the wallets watching it
+///
+/// Wallets can share scripts, e.g. a watch-only copy of an HD wallet.
+#[derive(Default)]
+struct WatchedScript {
+    wallet_ids: BTreeSet<String>,
+    /// Last status hash seen; `None` while the script has no history
+    status: Option<ScriptStatus>,
+}
+
+/// Connection state shared with the poll thread
+#[derive(Default)]
+struct WatchState {
+    client: Option<Arc<Client>>,
+    scripts: HashMap<ScriptBuf, WatchedScript>,
+    tip: Option<(u32, BlockHash)>,
+}
+
+impl WatchState {
+    /// Record that a wallet watches a script
+    ///
+    /// Returns `None` if the wallet already watched it, otherwise whether
+    /// the script is new and needs subscribing.
+    fn add(&mut self, wallet_id: &str, script: &ScriptBuf) -> Option<bool> {
+        let is_new = !self.scripts.contains_key(script);
+        let watched = self.scripts.entry(script.clone()).or_default();
+        watched
+            .wallet_ids
+            .insert(wallet_id.to_string())
+            .then_some(is_new)
+    }
+
+    /// Forget a wallet's scripts
+    ///
+    /// Returns whether the wallet watched any, and the scripts no other
+    /// wallet watches, which can be unsubscribed.
+    fn remove(&mut self, wallet_id: &str) -> (bool, Vec<ScriptBuf>) {
+        let mut was_watching = false;
+        let mut unwatched = Vec::new();
+        self.scripts.retain(|script, watched| {
+            if !watched.wallet_ids.remove(wallet_id) {
+                return true;
+            }
+            was_watching = true;
+            if watched.wallet_ids.is_empty() {
+                unwatched.push(script.clone());
+                return false;
+            }
+            true
+        });
+        (was_watching, unwatched)
+    }
+}
+
+struct Shared {
+    urls: Vec<String>,
+    state: Mutex<WatchState>,
+    stopped: AtomicBool,
+}
+
+/// Electrum subscriptions for watched wallets
+///
+/// Stops its thread when dropped.
+pub struct ChainWatcher {
+    shared: Arc<Shared>,
+    thread: JoinHandle<()>,
+}
+
+impl ChainWatcher {
+    /// Connect to the first reachable server in `urls` and start polling
+    ///
+    /// `on_notice` runs on the poll thread, so it should hand work off
+    /// rather than sync inline.
+    pub fn connect(
+        urls: Vec<String>,
+        on_notice: impl Fn(WatchNotice) + Send + 'static,
+    ) -> Result<Self> {
+        let shared = Arc::new(Shared {
+            urls,
+            state: Mutex::new(WatchState::default()),
+            stopped: AtomicBool::new(false),
+        });
+        shared.connect()?;
+
+        let thread = {
+            let shared = shared.clone();
+            thread::Builder::new()
+                .name("bitcoin-watcher".to_string())
+                .spawn(move || shared.run(on_notice))
+                .map_err(|e| Error::Bitcoin(format!("Failed to start chain watcher: {}", e)))?
+        };
+
+        Ok(Self { shared, thread })
+    }
+
+    /// Subscribe to scripts on behalf of a wallet
+    ///
+    /// Scripts the wallet already watches are skipped; returns how many
+    /// were added. Scripts other wallets watch are shared rather than
+    /// subscribed again. While disconnected, scripts are subscribed on
+    /// reconnect.
+    pub fn watch(&self, wallet_id: &str, scripts: impl IntoIterator<Item = ScriptBuf>) -> usize {
+        // Only record the scripts under the lock; the server may be slow
+        let (client, subscribe, added) = {
+            let mut state = self.shared.state.lock();
+            let mut subscribe = Vec::new();
+            let mut added = 0;
+            for script in scripts {
+                let Some(is_new) = state.add(wallet_id, &script) else {
+                    continue;
+                };
+                added += 1;
+                if is_new {
+                    subscribe.push(script);
+                }
+            }
+            (state.client.clone(), subscribe, added)
+        };
+
+        if let Some(client) = client {
+            let mut statuses = Vec::new();
+            let mut failed = false;
+            for script in subscribe {
+                match client.script_subscribe(&script) {
+                    Ok(status) => statuses.push((script, status)),
+                    Err(e) => {
+                        warn!("Electrum subscription failed, reconnecting: {}", e);
+                        failed = true;
+                        break;
+                    }
+                }
+            }
+
+            // Scripts left unsubscribed are picked up on reconnect
+            let mut state = self.shared.state.lock();
+            for (script, status) in statuses {
+                if let Some(watched) = state.scripts.get_mut(&script) {
+                    watched.status = status;
+                }
+            }
+            if failed
+                && state
+                    .client
+                    .as_ref()
+                    .is_some_and(|c| Arc::ptr_eq(c, &client))
+            {
+                state.client = None;
+            }
+        }
+
+        if added > 0 {
+            debug!("Watching {} more scripts of wallet {}", added, wallet_id);
+        }
+        added
+    }
+
+    /// Stop watching a wallet's scripts
+    ///
+    /// Scripts other wallets still watch stay subscribed. Returns whether
+    /// the wallet was watched.
+    pub fn unwatch(&self, wallet_id: &str) -> bool {
+        let (was_watching, unwatched, client) = {
+            let mut state = self.shared.state.lock();
+            let (was_watching, unwatched) = state.remove(wallet_id);
+            (was_watching, unwatched, state.client.clone())
+        };
+
+        if let Some(client) = client {
+            for script in &unwatched {
+                if let Err(e) = client.script_unsubscribe(script) {
+                    debug!("Electrum unsubscribe failed: {}", e);
+                }
+            }
+        }
+        was_watching
+    }
+
+    /// Whether any of a wallet's scripts are watched
+    pub fn is_watching(&self, wallet_id: &str) -> bool {
+        self.shared
+            .state
+            .lock()
+            .scripts
+            .values()
+            .any(|watched| watched.wallet_ids.contains(wallet_id))
+    }
+}
+
+impl Drop for ChainWatcher {
+    fn drop(&mut self) {
+        self.shared.stopped.store(true, Ordering::Release);
+        self.thread.thread().unpark();
+    }
+}
+
+impl Shared {
+    fn run(&self, on_notice: impl Fn(WatchNotice)) {
+        info!("Chain watcher started");
+        loop {
+            thread::park_timeout(WATCH_POLL_INTERVAL);
+            if self.stopped.load(Ordering::Acquire) {
+                break;
+            }
+
+            match self.poll() {
+                Ok(notices) => notices.into_iter().for_each(&on_notice),
+                Err(e) => {
+                    warn!("Chain watcher poll failed: {}", e);
+                    self.state.lock().client = None;
+                }
+            }
+        }
+        info!("Chain watcher stopped");
+    }
+
+    /// Open a connection and (re)subscribe the header and every script
+    ///
+    /// The state is only locked to read or record scripts, never during a
+    /// request. Returns the changes since the last connection.
+    fn connect(&self) -> Result<Vec<WatchNotice>> {
+        let mut last_error = None;
+        for url in &self.urls {
+            match Self::open(url) {
+                Ok(client) => return self.subscribe_all(client),
+                Err(e) => {
+                    warn!("Chain watcher could not connect to {}: {}", url, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+        Err(last_error
+            .unwrap_or_else(|| Error::Bitcoin("Push updates need an Electrum server".to_string())))
+    }
+
+    fn open(url: &str) -> Result<Client> {
+        info!("Chain watcher connecting to {}", url);
+        // Reconnects are handled here, as they drop every subscription
+        let config = ConfigBuilder::new()
+            .timeout(Some(WATCH_TIMEOUT_SECS))
+            .retry(0)
+            .build();
+        Client::from_config(url, config).map_err(|e| watch_error("connection", e))
+    }
+
+    fn subscribe_all(&self, client: Client) -> Result<Vec<WatchNotice>> {
+        let header = client
+            .block_headers_subscribe()
+            .map_err(|e| watch_error("header subscription", e))?;
+
+        // Scripts watched while subscribing are subscribed in another
+        // round; the client is only published once none are left, so
+        // `watch` subscribes any added later itself
+        let mut statuses: HashMap<ScriptBuf, Option<ScriptStatus>> = HashMap::new();
+        let mut state = loop {
+            let pending: Vec<ScriptBuf> = {
+                let state = self.state.lock();
+                let pending: Vec<_> = state
+                    .scripts
+                    .keys()
+                    .filter(|script| !statuses.contains_key(*script))
+                    .cloned()
+                    .collect();
+                if pending.is_empty() {
+                    break state;
+                }
+                pending
+            };
+            for script in pending {
+                let status = client
+                    .script_subscribe(&script)
+                    .map_err(|e| watch_error("script subscription", e))?;
+                statuses.insert(script, status);
+            }
+        };
+
+        let mut notices = tip_notices(state.tip, header.height as u32, &header.header);
+        state.tip = Some((header.height as u32, header.header.block_hash()));
+
+        let mut changed = Vec::new();
+        for (script, watched) in state.scripts.iter_mut() {
+            // Every watched script was subscribed above
+            let status = statuses.remove(script).flatten();
+            if status != watched.status {
+                watched.status = status;
+                changed.push(script.clone());
+            }
+        }
+        notices.extend(scripts_changed(&state.scripts, changed));
+
+        state.client = Some(Arc::new(client));
+        Ok(notices)
+    }
+
+    /// Collect pending notifications, reconnecting first if needed
+    fn poll(&self) -> Result<Vec<WatchNotice>> {
+        let Some(client) = self.state.lock().client.clone() else {
+            return self.connect();
+        };
+
+        // Any request reads the notifications queued on the socket. The
+        // state stays unlocked meanwhile, as the server may be slow.
+        client.ping().map_err(|e| watch_error("ping", e))?;
+
+        // Popping only reads what the ping queued
+        let mut state = self.state.lock();
+        let mut notices = Vec::new();
+        while let Some(header) = client
+            .block_headers_pop()
+            .map_err(|e| watch_error("header notification", e))?
+        {
+            let height = header.height as u32;
+            notices.extend(tip_notices(state.tip, height, &header.header));
+            state.tip = Some((height, header.header.block_hash()));
+        }
+
+        let mut changed = Vec::new();
+        for (script, watched) in state.scripts.iter_mut() {
+            while let Some(status) = client
+                .script_pop(script)
+                .map_err(|e| watch_error("script notification", e))?
+            {
+                if Some(status) != watched.status {
+                    watched.status = Some(status);
+                    if changed.last() != Some(script) {
+                        changed.push(script.clone());
+                    }
+                }
+            }
+        }
+        notices.extend(scripts_changed(&state.scripts, changed));
+        Ok(notices)
+    }
+}
+
+/// Group changed scripts by the wallets watching them
+fn scripts_changed(
+    scripts: &HashMap<ScriptBuf, WatchedScript>,
+    changed: Vec<ScriptBuf>,
+) -> Vec<WatchNotice> {
+    let mut by_wallet: BTreeMap<&str, Vec<ScriptBuf>> = BTreeMap::new();
+    for script in changed {
+        let Some(watched) = scripts.get(&script) else {
+            continue;
+        };
+        for wallet_id in &watched.wallet_ids {
+            by_wallet
+                .entry(wallet_id.as_str())
+                .or_default()
+                .push(script.clone());
+        }
+    }
+    by_wallet
+        .into_iter()
+        .map(|(wallet_id, scripts)| WatchNotice::ScriptsChanged {
+            wallet_id: wallet_id.to_string(),
+            scripts,
+        })
+        .collect()
+}
+
+/// Notices for a new tip header, given the previous tip
+fn tip_notices(
+    previous: Option<(u32, BlockHash)>,
+    height: u32,
+    header: &Header,
+) -> Vec<WatchNotice> {
+    let Some((previous_height, previous_hash)) = previous else {
+        return Vec::new();
+    };
+    if height == previous_height && header.block_hash() == previous_hash {
+        return Vec::new();
+    }
+
+    // A header at or below the old tip, or a child of a different block,
+    // means the old tip was replaced. Skipped heights can't be checked.
+    let reorged = height <= previous_height
+        || (height == previous_height + 1 && header.prev_blockhash != previous_hash);
+
+    let mut notices = Vec::new();
+    if reorged {
+        notices.push(WatchNotice::Reorg { height });
+    }
+    notices.push(WatchNotice::NewTip { height });
+    notices
+}
+
+// ============================================================================
+// Transaction Events
+// ============================================================================
+
+/// Confirmation height of each transaction in a history, by txid
+pub type TxSnapshot = HashMap<String, Option<u32>>;
+
+/// Snapshot of a wallet's history for later comparison
+pub fn tx_snapshot(transactions: &[BitcoinTransaction]) -> TxSnapshot {
+    transactions
+        .iter()
+        .map(|tx| (tx.txid.clone(), confirmation_height(tx)))
+        .collect()
+}
+
+fn confirmation_height(tx: &BitcoinTransaction) -> Option<u32> {
+    match tx.status {
+        ConfirmationStatus::Confirmed { block_height, .. } => Some(block_height),
+        ConfirmationStatus::Unconfirmed => None,
+    }
+}
+
+/// Events for the differences between a snapshot and the new history
+pub fn transaction_events(
+    wallet_id: &str,
+    before: &TxSnapshot,
+    after: &[BitcoinTransaction],
+) -> Vec<ChainEvent> {
+    let reorged = |txid: &str, previous_height: u32| ChainEvent::TransactionReorged {
+        wallet_id: wallet_id.to_string(),
+        txid: txid.to_string(),
+        previous_height,
+    };
+    let confirmed = |txid: &str, block_height: u32| ChainEvent::TransactionConfirmed {
+        wallet_id: wallet_id.to_string(),
+        txid: txid.to_string(),
+        block_height,
+    };
+
+    let mut events = Vec::new();
+    for tx in after {
+        let height = confirmation_height(tx);
+        match (before.get(&tx.txid), height) {
+            (None, _) if tx.direction == TransactionDirection::Received => {
+                events.push(ChainEvent::TransactionReceived {
+                    wallet_id: wallet_id.to_string(),
+                    txid: tx.txid.clone(),
+                    amount_sats: tx.amount_sats,
+                    confirmed: height.is_some(),
+                });
+            }
+            (Some(None), Some(block_height)) => events.push(confirmed(&tx.txid, block_height)),
+            (Some(Some(previous)), None) => events.push(reorged(&tx.txid, *previous)),
+            (Some(Some(previous)), Some(block_height)) if *previous != block_height => {
+                events.push(reorged(&tx.txid, *previous));
+                events.push(confirmed(&tx.txid, block_height));
+            }
+            _ => {}
+        }
+    }
+
+    // Confirmed transactions that vanished were reorged out and conflicted
+    let mut vanished: Vec<_> = before
+        .iter()
+        .filter_map(|(txid, height)| Some((txid, (*height)?)))
+        .filter(|(txid, _)| !after.iter().any(|tx| &tx.txid == *txid))
+        .collect();
+    vanished.sort();
+    events.extend(
+        vanished
+            .into_iter()
+            .map(|(txid, previous_height)| reorged(txid, previous_height)),
+    );
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bdk_wallet::bitcoin::hashes::Hash;
+    use bdk_wallet::bitcoin::{CompactTarget, TxMerkleNode};
+
+    fn header(prev_blockhash: BlockHash, nonce: u32) -> Header {
+        Header {
+            version: Default::default(),
+            prev_blockhash,
+            merkle_root: TxMerkleNode::all_zeros(),
+            time: 1_690_168_629,
+            bits: CompactTarget::from_consensus(0x1d00ffff),
+            nonce,
+        }
+    }
+
+    #[test]
+    fn test_tip_extends_chain() {
+        let tip = header(BlockHash::all_zeros(), 0);
+        let next = header(tip.block_hash(), 1);
+
+        assert!(tip_notices(None, 100, &tip).is_empty());
+        assert!(tip_notices(Some((100, tip.block_hash())), 100, &tip).is_empty());
+        assert_eq!(
+            tip_notices(Some((100, tip.block_hash())), 101, &next),
+            [WatchNotice::NewTip { height: 101 }]
+        );
+    }
+
+    #[test]
+    fn test_tip_replaced() {
+        let tip = header(BlockHash::all_zeros(), 0);
+        let sibling = header(BlockHash::all_zeros(), 1);
+        let stale_child = header(BlockHash::from_byte_array([7; 32]), 2);
+        let previous = Some((100, tip.block_hash()));
+
+        let reorg = |height| {
+            [
+                WatchNotice::Reorg { height },
+                WatchNotice::NewTip { height },
+            ]
+        };
+        assert_eq!(tip_notices(previous, 100, &sibling), reorg(100));
+        assert_eq!(tip_notices(previous, 99, &sibling), reorg(99));
+        assert_eq!(tip_notices(previous, 101, &stale_child), reorg(101));
+
+        // A jump over several blocks can't be checked against the old tip
+        assert_eq!(
+            tip_notices(previous, 103, &stale_child),
+            [WatchNotice::NewTip { height: 103 }]
+        );
+    }
+
+    #[test]
+    fn test_scripts_grouped_by_wallet() {
+        let script = |byte| ScriptBuf::from_bytes(vec![byte]);
+        let watched = |wallet_ids: &[&str]| WatchedScript {
+            wallet_ids: wallet_ids.iter().map(|id| id.to_string()).collect(),
+            status: None,
+        };
+        let scripts = HashMap::from([
+            (script(1), watched(&["a"])),
+            (script(2), watched(&["a", "b"])),
+            (script(3), watched(&["a"])),
+        ]);
+
+        let notices = scripts_changed(&scripts, vec![script(3), script(2), script(1)]);
+        assert_eq!(
+            notices,
+            [
+                WatchNotice::ScriptsChanged {
+                    wallet_id: "a".to_string(),
+                    scripts: vec![script(3), script(2), script(1)],
+                },
+                WatchNotice::ScriptsChanged {
+                    wallet_id: "b".to_string(),
+                    scripts: vec![script(2)],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shared_script_stays_watched() {
+        let script = ScriptBuf::from_bytes(vec![1]);
+        let mut state = WatchState::default();
+
+        assert_eq!(state.add("a", &script), Some(true));
+        assert_eq!(state.add("b", &script), Some(false));
+        assert_eq!(state.add("b", &script), None);
+
+        // Still watched by "b", so not unsubscribed
+        assert_eq!(state.remove("a"), (true, Vec::new()));
+        assert_eq!(state.remove("a"), (false, Vec::new()));
+        assert_eq!(
+            state.scripts[&script].wallet_ids,
+            BTreeSet::from(["b".to_string()])
+        );
+
+        assert_eq!(state.remove("b"), (true, vec![script]));
+        assert!(state.scripts.is_empty());
+    }
+
+    fn transaction(
+        txid: &str,
+        direction: TransactionDirection,
+        height: Option<u32>,
+    ) -> BitcoinTransaction {
+        let status = match height {
+            Some(block_height) => ConfirmationStatus::Confirmed {
+                block_height,
+                block_time: 1_690_168_629,
+                confirmations: 1,
+            },
+            None => ConfirmationStatus::Unconfirmed,
+        };
+        BitcoinTransaction {
+            txid: txid.to_string(),
+            direction,
+            amount_sats: 50_000,
+            fee_sats: None,
+            status,
+            timestamp: None,
+            first_seen: None,
+            addresses: Vec::new(),
+            own_addresses: Vec::new(),
+            counterparty_addresses: Vec::new(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            size: None,
+            vsize: None,
+        }
+    }
+
+    #[test]
+    fn test_received_then_confirmed() {
+        let pending = [transaction("a", TransactionDirection::Received, None)];
+        let events = transaction_events("w", &TxSnapshot::new(), &pending);
+        assert_eq!(
+            events,
+            [ChainEvent::TransactionReceived {
+                wallet_id: "w".to_string(),
+                txid: "a".to_string(),
+                amount_sats: 50_000,
+                confirmed: false,
+            }]
+        );
+
+        let mined = [transaction(
+            "a",
+            TransactionDirection::Received,
+            Some(800_000),
+        )];
+        let events = transaction_events("w", &tx_snapshot(&pending), &mined);
+        assert_eq!(
+            events,
+            [ChainEvent::TransactionConfirmed {
+                wallet_id: "w".to_string(),
+                txid: "a".to_string(),
+                block_height: 800_000,
+            }]
+        );
+
+        // Nothing changed, nothing to report
+        assert!(transaction_events("w", &tx_snapshot(&mined), &mined).is_empty());
+    }
+
+    #[test]
+    fn test_outgoing_transactions_are_not_received() {
+        let sent = [transaction("a", TransactionDirection::Sent, None)];
+        assert!(transaction_events("w", &TxSnapshot::new(), &sent).is_empty());
+    }
+
+    #[test]
+    fn test_reorged_transactions() {
+        let before = tx_snapshot(&[
+            transaction("a", TransactionDirection::Received, Some(800_000)),
+            transaction("b", TransactionDirection::Sent, Some(800_000)),
+            transaction("c", TransactionDirection::Received, Some(800_000)),
+        ]);
+        let after = [
+            transaction("a", TransactionDirection::Received, None),
+            transaction("b", TransactionDirection::Sent, Some(800_001)),
+        ];
+
+        let reorged = |txid: &str| ChainEvent::TransactionReorged {
+            wallet_id: "w".to_string(),
+            txid: txid.to_string(),
+            previous_height: 800_000,
+        };
+        assert_eq!(
+            transaction_events("w", &before, &after),
+            [
+                reorged("a"),
+                reorged("b"),
+                ChainEvent::TransactionConfirmed {
+                    wallet_id: "w".to_string(),
+                    txid: "b".to_string(),
+                    block_height: 800_001,
+                },
+                reorged("c"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Aptos chain module (SLIP-0010 Ed25519)
+//!
+//! Generates 0x... addresses using SLIP-0010 derivation.
+//! Path: m/44'/637'/account'/0'/index' (all hardened)
+//!
+//! The authentication key of a single-signer Ed25519 account is
+//! SHA3-256(public key || 0x00); a fresh account's address equals it.
+
+use sha3::{Digest, Sha3_256};
+
+use crate::wallet::chains::{coin_types, ChainModule, DerivationNode, DEFAULT_SCHEME};
+use crate::wallet::error::{WalletError, WalletResult};
+use crate::wallet::types::{ChainFamily, DerivedAddress};
+
+use super::{create_signing_key, decode_hex_address, slip10_derive_path};
+
+/// Authentication key scheme byte for single Ed25519 signers
+const ED25519_SCHEME: u8 = 0x00;
+
+/// Aptos chain module
+pub struct AptosModule;
+
+impl AptosModule {
+    /// Create a new Aptos mainnet module
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Build the address for the derived private key at `account`/`index`
+    fn address_from_private_key(
+        &self,
+        private_key: &[u8; 32],
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        let signing_key = create_signing_key(private_key)?;
+        let public_key = signing_key.verifying_key();
+        let public_key_bytes = public_key.as_bytes();
+
+        // Address is SHA3-256(public key || scheme)
+        let mut hasher = Sha3_256::new();
+        hasher.update(public_key_bytes);
+        hasher.update([ED25519_SCHEME]);
+        let address = format!("0x{}", hex::encode(hasher.finalize()));
+
+        Ok(DerivedAddress {
+            chain: self.chain_id().to_string(),
+            chain_family: self.chain_family(),
+            address,
+            derivation_path: self.derivation_path(account, index),
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key_bytes.to_vec(),
+        })
+    }
+}
+
+impl Default for AptosModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChainModule for AptosModule {
+    fn chain_id(&self) -> &str {
+        "aptos"
+    }
+
+    fn display_name(&self) -> &str {
+        "Aptos"
+    }
+
+    fn chain_family(&self) -> ChainFamily {
+        ChainFamily::Ed25519
+    }
+
+    fn coin_type(&self) -> u32 {
+        coin_types::APTOS
+    }
+
+    fn symbol(&self) -> &str {
+        "APT"
+    }
+
+    fn decimals(&self) -> u8 {
+        8
+    }
+
+    fn rpc_urls(&self) -> Vec<String> {
+        vec!["https://fullnode.mainnet.aptoslabs.com/v1".to_string()]
+    }
+
+    fn explorer_url(&self) -> Option<&str> {
+        Some("https://explorer.aptoslabs.com")
+    }
+
+    fn derive_address(
+        &self,
+        seed: &[u8; 64],
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        // SLIP-0010 path for Aptos: m/44'/637'/account'/0'/index'
+        let path = [44, coin_types::APTOS, account, 0, index];
+        let private_key = slip10_derive_path(seed, &path)?;
+        self.address_from_private_key(&private_key, account, index)
+    }
+
+    fn account_node_path(&self, account: u32) -> Option<String> {
+        Some(format!("m/44'/{}'/{account}'/0'", self.coin_type()))
+    }
+
+    fn derive_address_from_node(
+        &self,
+        node: &DerivationNode,
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        let DerivationNode::Slip10(account_node) = node else {
+            return Err(WalletError::DerivationError(format!(
+                "{} derives from SLIP-0010 nodes",
+                self.display_name()
+            )));
+        };
+
+        let child = account_node.derive_child(index)?;
+        self.address_from_private_key(child.private_key(), account, index)
+    }
+
+    fn validate_address(&self, address: &str) -> bool {
+        // Only the AIP-40 long form is accepted; short forms are reserved
+        // for special framework addresses (0x1, 0x3, ...)
+        decode_hex_address(address).is_some()
+    }
+
+    fn derivation_path(&self, account: u32, index: u32) -> String {
+        format!("m/44'/{}'/{account}'/0'/{index}'", self.coin_type())
+    }
+
+    fn address_prefix(&self) -> Option<&str> {
+        Some("0x")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::mnemonic::{mnemonic_to_seed, parse_mnemonic};
+
+    // Test seed from mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    fn test_seed() -> [u8; 64] {
+        let seed_hex = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+        let mut seed = [0u8; 64];
+        hex::decode_to_slice(seed_hex, &mut seed).unwrap();
+        seed
+    }
+
+    #[test]
+    fn test_aptos_module_chain_id() {
+        let module = AptosModule::new();
+        assert_eq!(module.chain_id(), "aptos");
+        assert_eq!(module.chain_family(), ChainFamily::Ed25519);
+        assert_eq!(module.coin_type(), 637);
+        assert_eq!(module.symbol(), "APT");
+        assert_eq!(module.decimals(), 8);
+        assert_eq!(module.derivation_path(0, 0), "m/44'/637'/0'/0'/0'");
+    }
+
+    #[test]
+    fn test_aptos_derive_address() {
+        let module = AptosModule::new();
+        let derived = module.derive_address(&test_seed(), 0, 0).unwrap();
+
+        assert_eq!(derived.chain, "aptos");
+        assert_eq!(derived.derivation_path, "m/44'/637'/0'/0'/0'");
+        assert_eq!(derived.public_key.len(), 32);
+        assert_eq!(derived.address.len(), 66);
+        assert!(module.validate_address(&derived.address));
+
+        // Same curve and path shape as Sui, different coin type and hash
+        let sui = crate::wallet::chains::SuiModule::new()
+            .derive_address(&test_seed(), 0, 0)
+            .unwrap();
+        assert_ne!(sui.address, derived.address);
+    }
+
+    #[test]
+    fn test_aptos_known_vector() {
+        // Vector from the Aptos TypeScript SDK account derivation tests
+        let mnemonic = parse_mnemonic(
+            "shoot island position soft burden budget tooth cruel issue economy destroy above",
+        )
+        .unwrap();
+        let seed = mnemonic_to_seed(&mnemonic, "").unwrap();
+
+        let derived = AptosModule::new()
+            .derive_address(seed.as_bytes(), 0, 0)
+            .unwrap();
+        assert_eq!(
+            derived.address,
+            "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30"
+        );
+    }
+
+    #[test]
+    fn test_aptos_validate_address() {
+        let module = AptosModule::new();
+
+        assert!(module.validate_address(
+            "0x07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30"
+        ));
+
+        // Short form, missing prefix, EVM address
+        assert!(!module.validate_address("0x1"));
+        assert!(!module
+            .validate_address("07968dab936c1bad187c60ce4082f307d030d780e91e694ae03aef16aba73f30"));
+        assert!(!module.validate_address("0x9858EfFD232B4033E47d90003D41EC34EcaEda94"));
+        assert!(!module.validate_address(""));
+    }
+}
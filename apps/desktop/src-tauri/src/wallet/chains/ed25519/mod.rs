@@ -1,12 +1,18 @@
-//! Ed25519 chain implementations (Solana, NEAR)
+//! Ed25519 chain implementations (Solana, Stellar, Sui, Aptos, Cardano, NEAR)
 //!
 //! All chains in this module use the Ed25519 elliptic curve with EdDSA signatures.
-//! Key derivation follows SLIP-0010 standard (all hardened paths).
+//! Key derivation follows SLIP-0010 standard (all hardened paths), except
+//! Cardano which uses BIP32-Ed25519 (see `cardano`).
 
+pub mod aptos;
+pub mod cardano;
 pub mod solana;
+pub mod stellar;
+pub mod sui;
 
 use ed25519_dalek::SigningKey;
 use sha2::{Digest, Sha512};
+use zeroize::Zeroizing;
 
 use crate::wallet::error::{WalletError, WalletResult};
 
@@ -102,12 +108,102 @@ pub fn slip10_derive_path(seed: &[u8; 64], path: &[u32]) -> WalletResult<[u8; 32
     Ok(key)
 }
 
+/// Intermediate SLIP-0010 node (private key and chain code)
+///
+/// Bulk derivation keeps the account-level node and derives each index from
+/// it with a single hardened step instead of walking the path from the seed.
+/// Both halves are zeroized on drop.
+#[derive(Clone)]
+pub struct Slip10Node {
+    key: Zeroizing<[u8; 32]>,
+    chain_code: Zeroizing<[u8; 32]>,
+}
+
+impl Slip10Node {
+    /// Derive the node at `path` (indices without the hardening bit)
+    pub fn derive(seed: &[u8; 64], path: &[u32]) -> WalletResult<Self> {
+        let (key, chain_code) = slip10_master_key(seed)?;
+        let mut node = Self {
+            key: Zeroizing::new(key),
+            chain_code: Zeroizing::new(chain_code),
+        };
+        for &index in path {
+            node = node.derive_child(index)?;
+        }
+        Ok(node)
+    }
+
+    /// Hardened child at `index`
+    pub fn derive_child(&self, index: u32) -> WalletResult<Self> {
+        let (key, chain_code) = slip10_derive_child(&self.key, &self.chain_code, index)?;
+        Ok(Self {
+            key: Zeroizing::new(key),
+            chain_code: Zeroizing::new(chain_code),
+        })
+    }
+
+    /// The node's 32-byte private key
+    pub fn private_key(&self) -> &[u8; 32] {
+        &self.key
+    }
+}
+
+impl std::fmt::Debug for Slip10Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Slip10Node([REDACTED])")
+    }
+}
+
+/// Parse a SLIP-0010 path string (e.g. `m/44'/501'/0'`) into indices
+///
+/// Every component must be hardened; the hardening bit is stripped so the
+/// result can be passed to `slip10_derive_path`.
+pub fn parse_slip10_path(path: &str) -> WalletResult<Vec<u32>> {
+    let invalid = || WalletError::DerivationError(format!("Invalid derivation path: {}", path));
+
+    let mut components = path.split('/');
+    if components.next() != Some("m") {
+        return Err(invalid());
+    }
+
+    components
+        .map(|component| {
+            let index = component
+                .strip_suffix('\'')
+                .or_else(|| component.strip_suffix('h'))
+                .ok_or_else(|| {
+                    WalletError::DerivationError(format!(
+                        "Ed25519 only supports hardened derivation: {}",
+                        path
+                    ))
+                })?;
+            index
+                .parse::<u32>()
+                .ok()
+                .filter(|i| *i < 0x80000000)
+                .ok_or_else(invalid)
+        })
+        .collect()
+}
+
 /// Create Ed25519 signing key from derived private key
 pub fn create_signing_key(private_key: &[u8; 32]) -> WalletResult<SigningKey> {
     // ed25519-dalek 2.x: from_bytes returns SigningKey directly (infallible for 32 bytes)
     Ok(SigningKey::from_bytes(private_key))
 }
 
+/// Decode a `0x`-prefixed, 32-byte hex account address (Sui, Aptos)
+pub(crate) fn decode_hex_address(address: &str) -> Option<[u8; 32]> {
+    let hex_str = address.strip_prefix("0x")?;
+    if hex_str.len() != 64 {
+        return None;
+    }
+
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(hex_str, &mut bytes).ok()?;
+    Some(bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,6 +260,33 @@ mod tests {
         assert_eq!(public_key.as_bytes().len(), 32);
     }
 
+    #[test]
+    fn test_slip10_node_matches_path_derivation() {
+        let seed = test_seed();
+        let account = Slip10Node::derive(&seed, &[44, 501, 0]).unwrap();
+
+        for index in 0..3 {
+            let child = account.derive_child(index).unwrap();
+            let expected = slip10_derive_path(&seed, &[44, 501, 0, index]).unwrap();
+            assert_eq!(child.private_key(), &expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_slip10_path() {
+        assert_eq!(
+            parse_slip10_path("m/44'/501'/0'/0'").unwrap(),
+            vec![44, 501, 0, 0]
+        );
+        assert_eq!(parse_slip10_path("m/44h/501h").unwrap(), vec![44, 501]);
+        assert_eq!(parse_slip10_path("m").unwrap(), Vec::<u32>::new());
+
+        // Non-hardened components are not derivable on Ed25519
+        assert!(parse_slip10_path("m/44'/501'/0'/0").is_err());
+        assert!(parse_slip10_path("44'/501'").is_err());
+        assert!(parse_slip10_path("m/abc'").is_err());
+    }
+
     #[test]
     fn test_slip10_deterministic() {
         let seed = test_seed();
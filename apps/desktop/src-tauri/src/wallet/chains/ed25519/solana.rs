@@ -4,12 +4,66 @@
 //! Path: m/44'/501'/account'/index' (all hardened)
 //!
 //! Solana addresses are the Base58-encoded Ed25519 public key (32 bytes).
+//!
+//! Alternative schemes: Phantom / Solflare (`m/44'/501'/x'/0'`), Solana CLI
+//! (`m/44'/501'`) and Ledger (`m/44'/501'/x'`).
+//!
+//! Messages are signed as off-chain messages (the `solana sign-offchain-message`
+//! v0 format); transactions by signing the serialized transaction message.
+
+use ed25519_dalek::{Signature, Signer, Verifier, VerifyingKey};
 
-use crate::wallet::chains::{coin_types, ChainModule};
-use crate::wallet::error::WalletResult;
+use crate::wallet::chains::{
+    coin_types, ChainModule, ChainSignature, ChainSigner, DerivationNode, DerivationScheme,
+    DEFAULT_SCHEME,
+};
+use crate::wallet::error::{WalletError, WalletResult};
 use crate::wallet::types::{ChainFamily, DerivedAddress};
 
-use super::{create_signing_key, slip10_derive_path};
+use super::{create_signing_key, parse_slip10_path, slip10_derive_path};
+
+/// Signing domain prefixed to every off-chain message
+const OFFCHAIN_SIGNING_DOMAIN: &[u8] = b"\xffsolana offchain";
+
+/// Longest off-chain message Ledger devices can display (1232 - 20 header bytes)
+const OFFCHAIN_MAX_LEN_LEDGER: usize = 1212;
+
+/// Longest off-chain message (u16 length minus the 20 header bytes)
+const OFFCHAIN_MAX_LEN: usize = 65515;
+
+/// Serialize a v0 off-chain message for signing
+///
+/// Layout: signing domain, header version (0), message format, u16 LE
+/// length, message. The format is restricted ASCII (0), limited UTF-8 (1)
+/// or extended UTF-8 (2), picked from the content and length.
+pub fn offchain_message_bytes(message: &[u8]) -> WalletResult<Vec<u8>> {
+    let is_utf8 = std::str::from_utf8(message).is_ok();
+    let format: u8 = match message.len() {
+        0 => None,
+        len if len <= OFFCHAIN_MAX_LEN_LEDGER => {
+            if message.iter().all(|c| (0x20..=0x7e).contains(c)) {
+                Some(0)
+            } else {
+                is_utf8.then_some(1)
+            }
+        }
+        len if len <= OFFCHAIN_MAX_LEN => is_utf8.then_some(2),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        WalletError::SigningError(
+            "Off-chain messages must be non-empty UTF-8 of at most 65515 bytes".to_string(),
+        )
+    })?;
+
+    let mut bytes = Vec::with_capacity(OFFCHAIN_SIGNING_DOMAIN.len() + 4 + message.len());
+    bytes.extend_from_slice(OFFCHAIN_SIGNING_DOMAIN);
+    bytes.push(0);
+    bytes.push(format);
+    bytes.extend_from_slice(&(message.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(message);
+    Ok(bytes)
+}
 
 /// Solana chain module
 pub struct SolanaModule {
@@ -27,6 +81,40 @@ impl SolanaModule {
         Self { is_devnet: true }
     }
 
+    /// Sign arbitrary bytes with the key at `path`
+    fn sign_bytes(seed: &[u8; 64], path: &str, bytes: &[u8]) -> WalletResult<(String, String)> {
+        let private_key = slip10_derive_path(seed, &parse_slip10_path(path)?)?;
+        let signing_key = create_signing_key(&private_key)?;
+
+        let address = bs58::encode(signing_key.verifying_key().as_bytes()).into_string();
+        let signature = bs58::encode(signing_key.sign(bytes).to_bytes()).into_string();
+        Ok((address, signature))
+    }
+
+    /// Build the address for a derived private key
+    fn address_from_private_key(
+        &self,
+        private_key: &[u8; 32],
+        path: &str,
+    ) -> WalletResult<DerivedAddress> {
+        // Create signing key and get public key
+        let signing_key = create_signing_key(private_key)?;
+        let public_key = signing_key.verifying_key();
+        let public_key_bytes = public_key.as_bytes();
+
+        // Solana address is Base58-encoded public key
+        let address = bs58::encode(public_key_bytes).into_string();
+
+        Ok(DerivedAddress {
+            chain: self.chain_id().to_string(),
+            chain_family: self.chain_family(),
+            address,
+            derivation_path: path.to_string(),
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key_bytes.to_vec(),
+        })
+    }
+
     /// Validate Base58 string
     fn is_valid_base58(s: &str) -> bool {
         // Base58 alphabet (Bitcoin variant, used by Solana)
@@ -75,6 +163,26 @@ impl ChainModule for SolanaModule {
         "SOL"
     }
 
+    fn decimals(&self) -> u8 {
+        9
+    }
+
+    fn rpc_urls(&self) -> Vec<String> {
+        if self.is_devnet {
+            vec!["https://api.devnet.solana.com".to_string()]
+        } else {
+            vec!["https://api.mainnet-beta.solana.com".to_string()]
+        }
+    }
+
+    fn explorer_url(&self) -> Option<&str> {
+        if self.is_devnet {
+            None
+        } else {
+            Some("https://explorer.solana.com")
+        }
+    }
+
     fn derive_address(
         &self,
         seed: &[u8; 64],
@@ -83,27 +191,41 @@ impl ChainModule for SolanaModule {
     ) -> WalletResult<DerivedAddress> {
         // SLIP-0010 path for Solana: m/44'/501'/account'/index'
         // Note: All components are hardened in SLIP-0010 Ed25519
-        let path = [44, coin_types::SOLANA, account, index];
-        let derivation_path = self.derivation_path(account, index);
+        self.derive_address_at_path(seed, &self.derivation_path(account, index))
+    }
 
-        // Derive private key using SLIP-0010
-        let private_key = slip10_derive_path(seed, &path)?;
+    fn derivation_schemes(&self) -> Vec<DerivationScheme> {
+        vec![
+            DerivationScheme::new("phantom", "Phantom / Solflare", "m/44'/501'/{account}'/0'"),
+            DerivationScheme::new("solana_cli", "Solana CLI", "m/44'/501'"),
+            DerivationScheme::new("ledger", "Ledger", "m/44'/501'/{account}'"),
+        ]
+    }
 
-        // Create signing key and get public key
-        let signing_key = create_signing_key(&private_key)?;
-        let public_key = signing_key.verifying_key();
-        let public_key_bytes = public_key.as_bytes();
+    fn derive_address_at_path(&self, seed: &[u8; 64], path: &str) -> WalletResult<DerivedAddress> {
+        // Derive private key using SLIP-0010
+        let private_key = slip10_derive_path(seed, &parse_slip10_path(path)?)?;
+        self.address_from_private_key(&private_key, path)
+    }
 
-        // Solana address is Base58-encoded public key
-        let address = bs58::encode(public_key_bytes).into_string();
+    fn account_node_path(&self, account: u32) -> Option<String> {
+        Some(format!("m/44'/{}'/{account}'", self.coin_type()))
+    }
 
-        Ok(DerivedAddress {
-            chain: self.chain_id().to_string(),
-            chain_family: self.chain_family(),
-            address,
-            derivation_path,
-            public_key: public_key_bytes.to_vec(),
-        })
+    fn derive_address_from_node(
+        &self,
+        node: &DerivationNode,
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        let DerivationNode::Slip10(account_node) = node else {
+            return Err(WalletError::DerivationError(
+                "Solana derives from SLIP-0010 nodes".to_string(),
+            ));
+        };
+
+        let child = account_node.derive_child(index)?;
+        self.address_from_private_key(child.private_key(), &self.derivation_path(account, index))
     }
 
     fn validate_address(&self, address: &str) -> bool {
@@ -135,6 +257,70 @@ impl ChainModule for SolanaModule {
     fn address_prefix(&self) -> Option<&str> {
         None // Solana addresses don't have a prefix
     }
+
+    fn signer(&self) -> Option<&dyn ChainSigner> {
+        Some(self)
+    }
+}
+
+impl ChainSigner for SolanaModule {
+    fn sign_message(
+        &self,
+        seed: &[u8; 64],
+        path: &str,
+        message: &[u8],
+    ) -> WalletResult<ChainSignature> {
+        let (address, signature) = Self::sign_bytes(seed, path, &offchain_message_bytes(message)?)?;
+
+        Ok(ChainSignature {
+            address,
+            scheme: "solana-offchain".to_string(),
+            signature,
+        })
+    }
+
+    /// Sign a serialized transaction message (the part of a transaction
+    /// after the signatures); the result is the base58 signature
+    fn sign_transaction(
+        &self,
+        seed: &[u8; 64],
+        path: &str,
+        payload: &[u8],
+    ) -> WalletResult<ChainSignature> {
+        let (address, signature) = Self::sign_bytes(seed, path, payload)?;
+
+        Ok(ChainSignature {
+            address,
+            scheme: "ed25519".to_string(),
+            signature,
+        })
+    }
+
+    fn verify_message(&self, address: &str, message: &[u8], signature: &str) -> WalletResult<bool> {
+        let invalid_address = || WalletError::InvalidAddress {
+            chain: self.chain_id().to_string(),
+            address: address.to_string(),
+        };
+        let public_key: [u8; 32] = bs58::decode(address)
+            .into_vec()
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(invalid_address)?;
+        let verifying_key = VerifyingKey::from_bytes(&public_key).map_err(|_| invalid_address())?;
+
+        let Some(signature) = bs58::decode(signature)
+            .into_vec()
+            .ok()
+            .and_then(|bytes| Signature::from_slice(&bytes).ok())
+        else {
+            return Ok(false);
+        };
+        let Ok(serialized) = offchain_message_bytes(message) else {
+            return Ok(false);
+        };
+
+        Ok(verifying_key.verify(&serialized, &signature).is_ok())
+    }
 }
 
 #[cfg(test)]
@@ -260,6 +446,80 @@ mod tests {
         assert_eq!(addr1.public_key, addr2.public_key);
     }
 
+    #[test]
+    fn test_solana_derivation_schemes() {
+        let module = SolanaModule::new();
+        let seed = test_seed();
+        let schemes = module.derivation_schemes();
+        let scheme = |id: &str| schemes.iter().find(|s| s.id == id).unwrap();
+
+        // Phantom uses the same path as the default scheme
+        let default = module.derive_address(&seed, 1, 0).unwrap();
+        let phantom = module
+            .derive_address_at_path(&seed, &scheme("phantom").path(1, 0))
+            .unwrap();
+        assert_eq!(phantom.address, default.address);
+
+        // Solana CLI derives a single key at m/44'/501'
+        let cli = module
+            .derive_address_at_path(&seed, &scheme("solana_cli").path(0, 0))
+            .unwrap();
+        assert_eq!(cli.derivation_path, "m/44'/501'");
+        assert_ne!(cli.address, default.address);
+
+        // Non-hardened paths are rejected
+        assert!(module
+            .derive_address_at_path(&seed, "m/44'/501'/0'/0")
+            .is_err());
+    }
+
+    #[test]
+    fn test_offchain_message_bytes() {
+        let bytes = offchain_message_bytes(b"Hello").unwrap();
+        assert_eq!(&bytes[..16], b"\xffsolana offchain");
+        assert_eq!(&bytes[16..20], &[0, 0, 5, 0]);
+        assert_eq!(&bytes[20..], b"Hello");
+
+        // Non-ASCII UTF-8 uses the limited UTF-8 format
+        assert_eq!(offchain_message_bytes("héllo".as_bytes()).unwrap()[17], 1);
+        // Long messages use the extended format
+        assert_eq!(offchain_message_bytes(&[b'a'; 2000]).unwrap()[17], 2);
+
+        assert!(offchain_message_bytes(b"").is_err());
+        assert!(offchain_message_bytes(&[0xff, 0xfe]).is_err());
+    }
+
+    #[test]
+    fn test_solana_sign_and_verify_message() {
+        let module = SolanaModule::new();
+        let seed = test_seed();
+        let path = module.derivation_path(0, 0);
+
+        let signed = module.sign_message(&seed, &path, b"Hello").unwrap();
+        assert_eq!(
+            signed.address,
+            module.derive_address(&seed, 0, 0).unwrap().address
+        );
+        assert_eq!(signed.scheme, "solana-offchain");
+
+        assert!(module
+            .verify_message(&signed.address, b"Hello", &signed.signature)
+            .unwrap());
+        assert!(!module
+            .verify_message(&signed.address, b"Hello!", &signed.signature)
+            .unwrap());
+        assert!(!module
+            .verify_message(&signed.address, b"Hello", "not-a-signature")
+            .unwrap());
+        assert!(module
+            .verify_message("0OIl", b"Hello", &signed.signature)
+            .is_err());
+
+        // Transactions sign the raw message bytes, not the off-chain envelope
+        let tx = module.sign_transaction(&seed, &path, b"Hello").unwrap();
+        assert_ne!(tx.signature, signed.signature);
+    }
+
     #[test]
     fn test_base58_validation() {
         // Valid Base58
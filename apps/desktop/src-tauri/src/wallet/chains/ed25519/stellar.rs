@@ -0,0 +1,325 @@
+//! Stellar chain module (SEP-0005 Ed25519)
+//!
+//! Generates G... addresses using SLIP-0010 derivation.
+//! Path: m/44'/148'/account' (all hardened)
+//!
+//! SEP-0005 derives one keypair per account, so there is no address index.
+//! Addresses are StrKeys: base32 of `version || public key || CRC16`.
+//! Muxed accounts (M...) additionally embed a 64-bit ID after the key.
+
+use crate::wallet::chains::{coin_types, ChainModule, DEFAULT_SCHEME};
+use crate::wallet::error::{WalletError, WalletResult};
+use crate::wallet::types::{AddressValidation, ChainFamily, DerivedAddress};
+
+use super::{create_signing_key, slip10_derive_path};
+
+/// StrKey version byte for Ed25519 public keys ("G...")
+const VERSION_ACCOUNT_ID: u8 = 6 << 3;
+/// StrKey version byte for muxed accounts ("M...")
+const VERSION_MUXED_ACCOUNT: u8 = 12 << 3;
+
+const BASE32: base32::Alphabet = base32::Alphabet::Rfc4648 { padding: false };
+
+/// Decoded muxed account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MuxedAccount {
+    /// Underlying G... account
+    pub account_id: String,
+    /// Multiplexing ID
+    pub id: u64,
+}
+
+/// Stellar chain module
+pub struct StellarModule;
+
+impl StellarModule {
+    /// Create a new Stellar mainnet module
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for StellarModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// CRC16-XModem checksum used by StrKey
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for &byte in data {
+        crc ^= u16::from(byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Encode a StrKey from its version byte and payload
+fn encode_strkey(version: u8, payload: &[u8]) -> String {
+    let mut data = Vec::with_capacity(payload.len() + 3);
+    data.push(version);
+    data.extend_from_slice(payload);
+    let checksum = crc16_xmodem(&data);
+    data.extend_from_slice(&checksum.to_le_bytes());
+    base32::encode(BASE32, &data)
+}
+
+/// Decode a StrKey, checking its version byte and checksum
+fn decode_strkey(version: u8, strkey: &str) -> Option<Vec<u8>> {
+    // StrKeys are canonical upper-case base32
+    if !strkey
+        .bytes()
+        .all(|b| b.is_ascii_uppercase() || (b'2'..=b'7').contains(&b))
+    {
+        return None;
+    }
+
+    let data = base32::decode(BASE32, strkey)?;
+    if data.len() < 3 || data[0] != version {
+        return None;
+    }
+
+    let (body, checksum) = data.split_at(data.len() - 2);
+    if crc16_xmodem(body).to_le_bytes() != checksum {
+        return None;
+    }
+
+    // Reject non-canonical encodings (trailing bits set)
+    if base32::encode(BASE32, &data) != strkey {
+        return None;
+    }
+
+    Some(body[1..].to_vec())
+}
+
+/// Encode a 32-byte Ed25519 public key as a G... account ID
+pub fn encode_account_id(public_key: &[u8; 32]) -> String {
+    encode_strkey(VERSION_ACCOUNT_ID, public_key)
+}
+
+/// Decode a G... account ID into its Ed25519 public key
+pub fn decode_account_id(address: &str) -> Option<[u8; 32]> {
+    decode_strkey(VERSION_ACCOUNT_ID, address)?.try_into().ok()
+}
+
+/// Encode a muxed M... address from a G... account ID and multiplexing ID
+pub fn encode_muxed_account(account_id: &str, id: u64) -> WalletResult<String> {
+    let public_key = decode_account_id(account_id).ok_or_else(|| WalletError::InvalidAddress {
+        chain: "stellar".to_string(),
+        address: account_id.to_string(),
+    })?;
+
+    let mut payload = Vec::with_capacity(40);
+    payload.extend_from_slice(&public_key);
+    payload.extend_from_slice(&id.to_be_bytes());
+    Ok(encode_strkey(VERSION_MUXED_ACCOUNT, &payload))
+}
+
+/// Decode a muxed M... address into its G... account ID and multiplexing ID
+pub fn decode_muxed_account(address: &str) -> Option<MuxedAccount> {
+    let payload = decode_strkey(VERSION_MUXED_ACCOUNT, address)?;
+    if payload.len() != 40 {
+        return None;
+    }
+
+    let public_key: [u8; 32] = payload[..32].try_into().ok()?;
+    let id = u64::from_be_bytes(payload[32..].try_into().ok()?);
+
+    Some(MuxedAccount {
+        account_id: encode_account_id(&public_key),
+        id,
+    })
+}
+
+impl ChainModule for StellarModule {
+    fn chain_id(&self) -> &str {
+        "stellar"
+    }
+
+    fn display_name(&self) -> &str {
+        "Stellar"
+    }
+
+    fn chain_family(&self) -> ChainFamily {
+        ChainFamily::Ed25519
+    }
+
+    fn coin_type(&self) -> u32 {
+        coin_types::STELLAR
+    }
+
+    fn symbol(&self) -> &str {
+        "XLM"
+    }
+
+    fn decimals(&self) -> u8 {
+        7
+    }
+
+    fn rpc_urls(&self) -> Vec<String> {
+        vec!["https://horizon.stellar.org".to_string()]
+    }
+
+    fn explorer_url(&self) -> Option<&str> {
+        Some("https://stellar.expert/explorer/public")
+    }
+
+    fn derive_address(
+        &self,
+        seed: &[u8; 64],
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        // SEP-0005 path: m/44'/148'/account' (one address per account)
+        if index != 0 {
+            return Err(WalletError::DerivationError(
+                "Stellar derives one address per account; use the account index instead"
+                    .to_string(),
+            ));
+        }
+
+        let path = [44, coin_types::STELLAR, account];
+        let private_key = slip10_derive_path(seed, &path)?;
+
+        let signing_key = create_signing_key(&private_key)?;
+        let public_key = signing_key.verifying_key();
+        let public_key_bytes = public_key.as_bytes();
+
+        Ok(DerivedAddress {
+            chain: self.chain_id().to_string(),
+            chain_family: self.chain_family(),
+            address: encode_account_id(public_key_bytes),
+            derivation_path: self.derivation_path(account, index),
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key_bytes.to_vec(),
+        })
+    }
+
+    fn validate_address(&self, address: &str) -> bool {
+        self.validate_address_details(address).is_valid
+    }
+
+    fn validate_address_details(&self, address: &str) -> AddressValidation {
+        let memo_field = Some("memo".to_string());
+
+        if decode_account_id(address).is_some() {
+            return AddressValidation {
+                is_valid: true,
+                address_type: Some("account".to_string()),
+                memo_field,
+                ..Default::default()
+            };
+        }
+
+        match decode_muxed_account(address) {
+            Some(muxed) => AddressValidation {
+                is_valid: true,
+                address_type: Some("muxed".to_string()),
+                memo_field,
+                embedded_memo: Some(muxed.id),
+                base_address: Some(muxed.account_id),
+            },
+            None => AddressValidation::default(),
+        }
+    }
+
+    fn derivation_path(&self, account: u32, _index: u32) -> String {
+        format!("m/44'/{}'/{account}'", self.coin_type())
+    }
+
+    fn address_prefix(&self) -> Option<&str> {
+        Some("G")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test seed from mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    fn test_seed() -> [u8; 64] {
+        let seed_hex = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+        let mut seed = [0u8; 64];
+        hex::decode_to_slice(seed_hex, &mut seed).unwrap();
+        seed
+    }
+
+    const ACCOUNT: &str = "GB3JDWCQJCWMJ3IILWIGDTQJJC5567PGVEVXSCVPEQOTDN64VJBDQBYX";
+
+    #[test]
+    fn test_stellar_module_chain_id() {
+        let module = StellarModule::new();
+        assert_eq!(module.chain_id(), "stellar");
+        assert_eq!(module.chain_family(), ChainFamily::Ed25519);
+        assert_eq!(module.coin_type(), 148);
+        assert_eq!(module.symbol(), "XLM");
+        assert_eq!(module.decimals(), 7);
+        assert_eq!(module.derivation_path(0, 0), "m/44'/148'/0'");
+        assert_eq!(module.derivation_path(3, 0), "m/44'/148'/3'");
+    }
+
+    #[test]
+    fn test_stellar_derive_address() {
+        let module = StellarModule::new();
+        let derived = module.derive_address(&test_seed(), 0, 0).unwrap();
+
+        assert_eq!(derived.chain, "stellar");
+        assert_eq!(derived.derivation_path, "m/44'/148'/0'");
+        assert_eq!(derived.public_key.len(), 32);
+        assert_eq!(derived.address, ACCOUNT);
+        assert!(module.validate_address(&derived.address));
+
+        // Accounts yield distinct addresses; address indexes are not supported
+        let second = module.derive_address(&test_seed(), 1, 0).unwrap();
+        assert_ne!(second.address, derived.address);
+        assert!(module.derive_address(&test_seed(), 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_stellar_validate_address() {
+        let module = StellarModule::new();
+
+        assert!(module.validate_address(ACCOUNT));
+
+        // Bad checksum, lowercase, wrong chain
+        assert!(
+            !module.validate_address("GB3JDWCQJCWMJ3IILWIGDTQJJC5567PGVEVXSCVPEQOTDN64VJBDQBYY")
+        );
+        assert!(!module.validate_address(&ACCOUNT.to_lowercase()));
+        assert!(!module.validate_address("rHsMGQEkVNJmpGWs8XUBoTBiAAbwxZN5v3"));
+        assert!(!module.validate_address(""));
+    }
+
+    #[test]
+    fn test_stellar_muxed_account() {
+        let module = StellarModule::new();
+
+        let muxed = encode_muxed_account(ACCOUNT, 1234).unwrap();
+        assert!(muxed.starts_with('M'));
+        assert_eq!(muxed.len(), 69);
+
+        let decoded = decode_muxed_account(&muxed).unwrap();
+        assert_eq!(decoded.account_id, ACCOUNT);
+        assert_eq!(decoded.id, 1234);
+
+        let details = module.validate_address_details(&muxed);
+        assert!(details.is_valid);
+        assert_eq!(details.address_type.as_deref(), Some("muxed"));
+        assert_eq!(details.memo_field.as_deref(), Some("memo"));
+        assert_eq!(details.embedded_memo, Some(1234));
+        assert_eq!(details.base_address.as_deref(), Some(ACCOUNT));
+
+        let plain = module.validate_address_details(ACCOUNT);
+        assert_eq!(plain.address_type.as_deref(), Some("account"));
+        assert_eq!(plain.embedded_memo, None);
+
+        assert!(encode_muxed_account("invalid", 1).is_err());
+    }
+}
@@ -0,0 +1,218 @@
+//! Sui chain module (SLIP-0010 Ed25519)
+//!
+//! Generates 0x... addresses using SLIP-0010 derivation.
+//! Path: m/44'/784'/account'/0'/index' (all hardened)
+//!
+//! Sui addresses are BLAKE2b-256 of the signature scheme flag (0x00 for
+//! Ed25519) followed by the 32-byte public key, hex-encoded with `0x`.
+
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+
+use crate::wallet::chains::{coin_types, ChainModule, DerivationNode, DEFAULT_SCHEME};
+use crate::wallet::error::{WalletError, WalletResult};
+use crate::wallet::types::{ChainFamily, DerivedAddress};
+
+use super::{create_signing_key, decode_hex_address, slip10_derive_path};
+
+/// Signature scheme flag for Ed25519 keys
+const ED25519_FLAG: u8 = 0x00;
+
+/// Sui chain module
+pub struct SuiModule;
+
+impl SuiModule {
+    /// Create a new Sui mainnet module
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Build the address for the derived private key at `account`/`index`
+    fn address_from_private_key(
+        &self,
+        private_key: &[u8; 32],
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        let signing_key = create_signing_key(private_key)?;
+        let public_key = signing_key.verifying_key();
+        let public_key_bytes = public_key.as_bytes();
+
+        // Address is BLAKE2b-256(flag || public key)
+        let mut hasher = Blake2b::<U32>::new();
+        hasher.update([ED25519_FLAG]);
+        hasher.update(public_key_bytes);
+        let address = format!("0x{}", hex::encode(hasher.finalize()));
+
+        Ok(DerivedAddress {
+            chain: self.chain_id().to_string(),
+            chain_family: self.chain_family(),
+            address,
+            derivation_path: self.derivation_path(account, index),
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key_bytes.to_vec(),
+        })
+    }
+}
+
+impl Default for SuiModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChainModule for SuiModule {
+    fn chain_id(&self) -> &str {
+        "sui"
+    }
+
+    fn display_name(&self) -> &str {
+        "Sui"
+    }
+
+    fn chain_family(&self) -> ChainFamily {
+        ChainFamily::Ed25519
+    }
+
+    fn coin_type(&self) -> u32 {
+        coin_types::SUI
+    }
+
+    fn symbol(&self) -> &str {
+        "SUI"
+    }
+
+    fn decimals(&self) -> u8 {
+        9
+    }
+
+    fn rpc_urls(&self) -> Vec<String> {
+        vec!["https://fullnode.mainnet.sui.io:443".to_string()]
+    }
+
+    fn explorer_url(&self) -> Option<&str> {
+        Some("https://suiscan.xyz/mainnet")
+    }
+
+    fn derive_address(
+        &self,
+        seed: &[u8; 64],
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        // SLIP-0010 path for Sui: m/44'/784'/account'/0'/index'
+        let path = [44, coin_types::SUI, account, 0, index];
+        let private_key = slip10_derive_path(seed, &path)?;
+        self.address_from_private_key(&private_key, account, index)
+    }
+
+    fn account_node_path(&self, account: u32) -> Option<String> {
+        Some(format!("m/44'/{}'/{account}'/0'", self.coin_type()))
+    }
+
+    fn derive_address_from_node(
+        &self,
+        node: &DerivationNode,
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        let DerivationNode::Slip10(account_node) = node else {
+            return Err(WalletError::DerivationError(format!(
+                "{} derives from SLIP-0010 nodes",
+                self.display_name()
+            )));
+        };
+
+        let child = account_node.derive_child(index)?;
+        self.address_from_private_key(child.private_key(), account, index)
+    }
+
+    fn validate_address(&self, address: &str) -> bool {
+        decode_hex_address(address).is_some()
+    }
+
+    fn derivation_path(&self, account: u32, index: u32) -> String {
+        format!("m/44'/{}'/{account}'/0'/{index}'", self.coin_type())
+    }
+
+    fn address_prefix(&self) -> Option<&str> {
+        Some("0x")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::mnemonic::{mnemonic_to_seed, parse_mnemonic};
+
+    // Test seed from mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    fn test_seed() -> [u8; 64] {
+        let seed_hex = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+        let mut seed = [0u8; 64];
+        hex::decode_to_slice(seed_hex, &mut seed).unwrap();
+        seed
+    }
+
+    #[test]
+    fn test_sui_module_chain_id() {
+        let module = SuiModule::new();
+        assert_eq!(module.chain_id(), "sui");
+        assert_eq!(module.chain_family(), ChainFamily::Ed25519);
+        assert_eq!(module.coin_type(), 784);
+        assert_eq!(module.symbol(), "SUI");
+        assert_eq!(module.decimals(), 9);
+        assert_eq!(module.derivation_path(0, 0), "m/44'/784'/0'/0'/0'");
+    }
+
+    #[test]
+    fn test_sui_derive_address() {
+        let module = SuiModule::new();
+        let derived = module.derive_address(&test_seed(), 0, 0).unwrap();
+
+        assert_eq!(derived.chain, "sui");
+        assert_eq!(derived.derivation_path, "m/44'/784'/0'/0'/0'");
+        assert_eq!(derived.public_key.len(), 32);
+        assert_eq!(derived.address.len(), 66);
+        assert!(module.validate_address(&derived.address));
+
+        let second = module.derive_address(&test_seed(), 0, 1).unwrap();
+        assert_ne!(second.address, derived.address);
+    }
+
+    #[test]
+    fn test_sui_known_vector() {
+        // Vector from the Sui TypeScript SDK Ed25519 keypair tests
+        let mnemonic = parse_mnemonic(
+            "film crazy soon outside stand loop subway crumble thrive popular green nuclear \
+             struggle pistol arm wife phrase warfare march wheat nephew ask sunny firm",
+        )
+        .unwrap();
+        let seed = mnemonic_to_seed(&mnemonic, "").unwrap();
+
+        let derived = SuiModule::new()
+            .derive_address(seed.as_bytes(), 0, 0)
+            .unwrap();
+        assert_eq!(
+            derived.address,
+            "0xa2d14fad60c56049ecf75246a481934691214ce413e6a8ae2fe6834c173a6133"
+        );
+    }
+
+    #[test]
+    fn test_sui_validate_address() {
+        let module = SuiModule::new();
+
+        assert!(module.validate_address(
+            "0xa2d14fad60c56049ecf75246a481934691214ce413e6a8ae2fe6834c173a6133"
+        ));
+
+        // Missing prefix, wrong length, non-hex
+        assert!(!module
+            .validate_address("a2d14fad60c56049ecf75246a481934691214ce413e6a8ae2fe6834c173a6133"));
+        assert!(!module.validate_address("0x9858EfFD232B4033E47d90003D41EC34EcaEda94"));
+        assert!(!module.validate_address(
+            "0xz2d14fad60c56049ecf75246a481934691214ce413e6a8ae2fe6834c173a6133"
+        ));
+        assert!(!module.validate_address(""));
+    }
+}
@@ -23,30 +23,63 @@
 //!     ┌──────┼──────┐          ┌────┴────┐           (future)
 //!     ▼      ▼      ▼          ▼         ▼
 //!  Bitcoin  ETH   Cosmos    Solana    NEAR
+//!  LTC/DOGE/BCH             Cardano
 //! ```
+//!
+//! Most chains derive from the 64-byte BIP39 seed. Chains whose master key
+//! comes from the mnemonic entropy instead (Cardano) report
+//! `MasterSecretKind::Entropy` and implement `derive_address_from_entropy`.
+//!
+//! Chains that can sign also implement the companion `ChainSigner` trait
+//! (see `signer`) and expose it through `ChainModule::signer`.
 
-pub mod secp256k1;
 pub mod ed25519;
+pub mod node;
+pub mod scheme;
+pub mod secp256k1;
+pub mod signer;
 
 use async_trait::async_trait;
 
-use crate::wallet::error::WalletResult;
-use crate::wallet::types::{ChainFamily, DerivedAddress};
+use crate::wallet::error::{WalletError, WalletResult};
+use crate::wallet::types::{AddressValidation, ChainFamily, DerivedAddress, MasterSecretKind};
 
 /// SLIP-0044 coin types for BIP44 derivation
 pub mod coin_types {
     /// Bitcoin
     pub const BITCOIN: u32 = 0;
+    /// All testnets (Bitcoin testnet3/testnet4/signet/regtest)
+    pub const TESTNET: u32 = 1;
+    /// Litecoin
+    pub const LITECOIN: u32 = 2;
+    /// Dogecoin
+    pub const DOGECOIN: u32 = 3;
     /// Ethereum (and all EVM chains)
     pub const ETHEREUM: u32 = 60;
     /// Cosmos Hub
     pub const COSMOS: u32 = 118;
+    /// XRP Ledger
+    pub const XRP: u32 = 144;
+    /// Bitcoin Cash
+    pub const BITCOIN_CASH: u32 = 145;
+    /// Stellar
+    pub const STELLAR: u32 = 148;
+    /// Tron
+    pub const TRON: u32 = 195;
     /// NEAR Protocol
     pub const NEAR: u32 = 397;
     /// Polkadot
     pub const POLKADOT: u32 = 354;
     /// Solana
     pub const SOLANA: u32 = 501;
+    /// Aptos
+    pub const APTOS: u32 = 637;
+    /// Sui
+    pub const SUI: u32 = 784;
+    /// Nostr (NIP-06 identity keys)
+    pub const NOSTR: u32 = 1237;
+    /// Cardano
+    pub const CARDANO: u32 = 1815;
     /// Avalanche
     pub const AVALANCHE: u32 = 9000;
 }
@@ -111,6 +144,98 @@ pub trait ChainModule: Send + Sync {
         index: u32,
     ) -> WalletResult<DerivedAddress>;
 
+    /// Which BIP39 secret this chain derives from
+    ///
+    /// Chains returning `MasterSecretKind::Entropy` must implement
+    /// `derive_address_from_entropy`; their `derive_address` fails.
+    fn master_secret_kind(&self) -> MasterSecretKind {
+        MasterSecretKind::Seed
+    }
+
+    /// Derive an address from the raw BIP39 entropy
+    ///
+    /// # Arguments
+    /// * `entropy` - 16-32 bytes of mnemonic entropy
+    /// * `account` - Account index (0, 1, 2...)
+    /// * `index` - Address index within the account
+    fn derive_address_from_entropy(
+        &self,
+        _entropy: &[u8],
+        _account: u32,
+        _index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        Err(WalletError::DerivationError(format!(
+            "{} derives from the BIP39 seed, not entropy",
+            self.chain_id()
+        )))
+    }
+
+    /// Alternative derivation schemes used by other wallets
+    ///
+    /// The chain's own `derivation_path` is the implicit `default` scheme and
+    /// is not listed here. Modules returning schemes must implement
+    /// `derive_address_at_path`.
+    fn derivation_schemes(&self) -> Vec<DerivationScheme> {
+        Vec::new()
+    }
+
+    /// Derive the address at an explicit derivation path
+    ///
+    /// The returned address keeps the `default` scheme ID; callers deriving
+    /// via a named scheme set `derivation_scheme` themselves.
+    fn derive_address_at_path(&self, _seed: &[u8; 64], path: &str) -> WalletResult<DerivedAddress> {
+        Err(WalletError::DerivationError(format!(
+            "{} does not support custom derivation path {}",
+            self.chain_id(),
+            path
+        )))
+    }
+
+    /// Derive a receive address from an account-level extended public key
+    ///
+    /// Watch-only wallets use this instead of `derive_address`: the address
+    /// at `account_key/0/index` needs no secrets. Only BIP32 secp256k1
+    /// chains support it; the returned path assumes the chain's default
+    /// scheme.
+    fn derive_address_from_xpub(
+        &self,
+        _account_key: &AccountXpub,
+        _index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        Err(WalletError::DerivationError(format!(
+            "{} does not support watch-only derivation from an extended public key",
+            self.chain_id()
+        )))
+    }
+
+    /// Path of the account-level node that `derive_address_from_node` expands
+    ///
+    /// Chains returning a path get cached bulk derivation: the node is
+    /// derived once per session and each index below it costs one or two
+    /// derivation steps instead of the full path from the seed.
+    fn account_node_path(&self, _account: u32) -> Option<String> {
+        None
+    }
+
+    /// Derive the default-scheme address at `index` below an account node
+    ///
+    /// `node` is the node at `account_node_path(account)`. BIP32 nodes are
+    /// handled through `derive_address_from_xpub`; SLIP-0010 chains override.
+    fn derive_address_from_node(
+        &self,
+        node: &DerivationNode,
+        _account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        match node {
+            DerivationNode::Bip32(account_key) => self.derive_address_from_xpub(account_key, index),
+            DerivationNode::Slip10(_) => Err(WalletError::DerivationError(format!(
+                "{} does not derive from SLIP-0010 nodes",
+                self.chain_id()
+            ))),
+        }
+    }
+
     /// Validate an address string for this chain
     ///
     /// # Arguments
@@ -120,6 +245,17 @@ pub trait ChainModule: Send + Sync {
     /// `true` if the address is valid for this chain
     fn validate_address(&self, address: &str) -> bool;
 
+    /// Validate an address and report chain-specific details
+    ///
+    /// Chains that distinguish address types (e.g., Bitcoin P2PKH vs P2TR)
+    /// override this to fill in `address_type`.
+    fn validate_address_details(&self, address: &str) -> AddressValidation {
+        AddressValidation {
+            is_valid: self.validate_address(address),
+            ..Default::default()
+        }
+    }
+
     /// Get the derivation path for this chain
     ///
     /// # Arguments
@@ -135,19 +271,59 @@ pub trait ChainModule: Send + Sync {
         None
     }
 
+    /// Address/script type produced by `derive_address` (e.g., "p2wpkh", "p2tr")
+    fn address_type(&self) -> Option<&str> {
+        None
+    }
+
     /// Token symbol (e.g., "ETH", "BTC", "SOL")
     fn symbol(&self) -> &str;
 
+    /// Decimals of the native currency (e.g., 8 for BTC, 18 for ETH)
+    fn decimals(&self) -> u8;
+
+    /// EIP-155 chain ID (EVM networks only)
+    fn evm_chain_id(&self) -> Option<u64> {
+        None
+    }
+
+    /// Default RPC endpoints, in order of preference
+    fn rpc_urls(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Block explorer base URL
+    fn explorer_url(&self) -> Option<&str> {
+        None
+    }
+
     /// Icon name for frontend display
     fn icon_name(&self) -> &str {
         self.chain_id()
     }
+
+    /// Message/transaction signer for this chain, if signing is supported
+    fn signer(&self) -> Option<&dyn ChainSigner> {
+        None
+    }
 }
 
 // Re-export specific chain modules
-pub use secp256k1::bitcoin::BitcoinModule;
-pub use secp256k1::ethereum::EthereumModule;
+pub use ed25519::aptos::AptosModule;
+pub use ed25519::cardano::CardanoModule;
 pub use ed25519::solana::SolanaModule;
+pub use ed25519::stellar::StellarModule;
+pub use ed25519::sui::SuiModule;
+pub use node::DerivationNode;
+pub use scheme::{DerivationScheme, DerivationSchemeInfo, DEFAULT_SCHEME};
+pub use secp256k1::bitcoin::{BitcoinModule, BitcoinScriptType};
+pub use secp256k1::ethereum::{EthereumModule, EvmNetwork};
+pub use secp256k1::nostr::{NostrEvent, NostrModule, UnsignedEvent, NOSTR_CHAIN_ID};
+pub use secp256k1::tron::TronModule;
+pub use secp256k1::utxo::{UtxoChainParams, UtxoModule};
+pub use secp256k1::xrpl::XrplModule;
+pub use secp256k1::AccountXpub;
+pub use signer::{ChainSignature, ChainSigner};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,123 @@
+//! Cached account-level derivation nodes
+//!
+//! Deriving an address from the seed rebuilds the master key and walks every
+//! level of the path, including the expensive hardened prefix. Bulk
+//! derivation (gap-limit scans, discovery) instead derives the account-level
+//! node once, keeps it in the session cache and expands each index below it
+//! with one or two cheap steps.
+//!
+//! ```text
+//! seed ──► m/84'/0'/0' ──┬──► /0/0
+//!          (cached node) ├──► /0/1
+//!                        └──► /0/...
+//! ```
+
+use crate::wallet::error::{WalletError, WalletResult};
+use crate::wallet::types::ChainFamily;
+
+use super::ed25519::{parse_slip10_path, Slip10Node};
+use super::secp256k1::AccountXpub;
+
+/// Account-level node below which a chain derives its addresses
+#[derive(Debug, Clone)]
+pub enum DerivationNode {
+    /// BIP32 account key (secp256k1); indices are derived publicly at `0/index`
+    Bip32(AccountXpub),
+    /// SLIP-0010 node (ed25519); indices are hardened children
+    Slip10(Slip10Node),
+}
+
+impl DerivationNode {
+    /// Derive the node at `path` for a chain family
+    pub fn derive(
+        family: ChainFamily,
+        is_testnet: bool,
+        seed: &[u8; 64],
+        path: &str,
+    ) -> WalletResult<Self> {
+        match family {
+            ChainFamily::Secp256k1 => {
+                Ok(Self::Bip32(AccountXpub::from_seed(seed, path, is_testnet)?))
+            }
+            ChainFamily::Ed25519 => Ok(Self::Slip10(Slip10Node::derive(
+                seed,
+                &parse_slip10_path(path)?,
+            )?)),
+            ChainFamily::Sr25519 => Err(WalletError::DerivationError(
+                "Sr25519 chains have no cached derivation nodes".to_string(),
+            )),
+        }
+    }
+
+    /// Session cache key of a node
+    ///
+    /// Chains of the same family, network kind and path share a node (e.g.
+    /// every EVM mainnet at `m/44'/60'/0'`).
+    pub fn cache_key(family: ChainFamily, is_testnet: bool, path: &str) -> String {
+        let network = if is_testnet { "test" } else { "main" };
+        format!("{:?}:{}:{}", family, network, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test seed from mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    fn test_seed() -> [u8; 64] {
+        let seed_hex = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+        let mut seed = [0u8; 64];
+        hex::decode_to_slice(seed_hex, &mut seed).unwrap();
+        seed
+    }
+
+    #[test]
+    fn test_derive_bip32_node() {
+        let node =
+            DerivationNode::derive(ChainFamily::Secp256k1, false, &test_seed(), "m/84'/0'/0'")
+                .unwrap();
+        let DerivationNode::Bip32(account_key) = node else {
+            panic!("expected a BIP32 node");
+        };
+
+        // BIP84 test vector, account 0
+        let zpub = AccountXpub::parse("zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs").unwrap();
+        assert_eq!(account_key.xpub, zpub.xpub);
+        assert_eq!(account_key.version.prefix, "xpub");
+        assert_eq!(account_key.account(), 0);
+    }
+
+    #[test]
+    fn test_derive_testnet_node() {
+        let node =
+            DerivationNode::derive(ChainFamily::Secp256k1, true, &test_seed(), "m/84'/1'/0'")
+                .unwrap();
+        let DerivationNode::Bip32(account_key) = node else {
+            panic!("expected a BIP32 node");
+        };
+        assert_eq!(account_key.version.prefix, "tpub");
+        assert!(account_key.encoded.starts_with("tpub"));
+    }
+
+    #[test]
+    fn test_derive_slip10_node() {
+        let node =
+            DerivationNode::derive(ChainFamily::Ed25519, false, &test_seed(), "m/44'/501'/0'")
+                .unwrap();
+        assert!(matches!(node, DerivationNode::Slip10(_)));
+
+        // Ed25519 nodes only exist on hardened paths
+        assert!(
+            DerivationNode::derive(ChainFamily::Ed25519, false, &test_seed(), "m/44'/501'/0")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_cache_key() {
+        let mainnet = DerivationNode::cache_key(ChainFamily::Secp256k1, false, "m/44'/60'/0'");
+        let testnet = DerivationNode::cache_key(ChainFamily::Secp256k1, true, "m/44'/60'/0'");
+        assert_ne!(mainnet, testnet);
+        assert_eq!(mainnet, "Secp256k1:main:m/44'/60'/0'");
+    }
+}
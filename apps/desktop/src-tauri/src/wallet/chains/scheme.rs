@@ -0,0 +1,84 @@
+//! Named derivation schemes
+//!
+//! The same seed maps to different addresses depending on which wallet
+//! derived them: Ledger Live puts the account at the account level
+//! (`m/44'/60'/x'/0/0`), MetaMask at the address index (`m/44'/60'/0'/0/x`),
+//! Solana CLI uses a single `m/44'/501'` key, and so on.
+//!
+//! Every chain has an implicit `default` scheme (its `derivation_path`).
+//! Modules list alternative schemes via `ChainModule::derivation_schemes`
+//! and derive them with `ChainModule::derive_address_at_path`.
+
+use serde::Serialize;
+
+/// ID of the scheme implemented by a module's `derivation_path`
+pub const DEFAULT_SCHEME: &str = "default";
+
+/// Alternative derivation scheme used by another wallet
+///
+/// `path_template` is a derivation path with `{account}` and `{index}`
+/// placeholders, e.g. `m/44'/60'/{account}'/0/0`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DerivationScheme {
+    /// Stable identifier stored with each address (e.g., "ledger_live")
+    pub id: String,
+    /// Human-readable name (e.g., "Ledger Live")
+    pub name: String,
+    /// Path template with `{account}` / `{index}` placeholders
+    pub path_template: String,
+}
+
+impl DerivationScheme {
+    pub fn new(id: &str, name: &str, path_template: impl Into<String>) -> Self {
+        Self {
+            id: id.to_string(),
+            name: name.to_string(),
+            path_template: path_template.into(),
+        }
+    }
+
+    /// Concrete derivation path for an account and address index
+    pub fn path(&self, account: u32, index: u32) -> String {
+        self.path_template
+            .replace("{account}", &account.to_string())
+            .replace("{index}", &index.to_string())
+    }
+
+    /// Whether the path varies with the address index
+    ///
+    /// Schemes like Ledger Live or Solana CLI have one address per account.
+    pub fn uses_index(&self) -> bool {
+        self.path_template.contains("{index}")
+    }
+}
+
+/// Scheme summary for the frontend (including the implicit default)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DerivationSchemeInfo {
+    pub id: String,
+    pub name: String,
+    /// Path of the first address (account 0, index 0)
+    pub example_path: String,
+    /// Whether this is the chain's default scheme
+    pub is_default: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scheme_path() {
+        let ledger_live =
+            DerivationScheme::new("ledger_live", "Ledger Live", "m/44'/60'/{account}'/0/0");
+        assert_eq!(ledger_live.path(3, 7), "m/44'/60'/3'/0/0");
+        assert!(!ledger_live.uses_index());
+
+        let metamask = DerivationScheme::new("metamask", "MetaMask", "m/44'/60'/0'/0/{account}");
+        assert_eq!(metamask.path(2, 0), "m/44'/60'/0'/0/2");
+
+        let bip44 = DerivationScheme::new("bip44", "BIP44", "m/44'/0'/{account}'/0/{index}");
+        assert_eq!(bip44.path(1, 5), "m/44'/0'/1'/0/5");
+        assert!(bip44.uses_index());
+    }
+}
@@ -0,0 +1,416 @@
+//! Bitcoin message signing (BIP-322, with BIP-137 for legacy addresses)
+//!
+//! - Native SegWit (P2WPKH) and Taproot (P2TR) use the BIP-322 "simple"
+//!   format: the base64 witness of a virtual `to_sign` transaction spending
+//!   a `to_spend` output locked to the address.
+//! - Legacy (P2PKH) and Nested SegWit (P2SH-P2WPKH) use the classic
+//!   `signmessage` compact signature, with BIP-137 header bytes.
+//!
+//! Verification accepts both formats for any single-key address.
+
+use bitcoin::base64::prelude::{Engine as _, BASE64_STANDARD};
+use bitcoin::blockdata::opcodes::all::{OP_PUSHBYTES_0, OP_RETURN};
+use bitcoin::consensus::encode;
+use bitcoin::ecdsa;
+use bitcoin::hashes::{sha256, sha256d, Hash, HashEngine};
+use bitcoin::key::{Keypair, TapTweak, XOnlyPublicKey};
+use bitcoin::secp256k1::ecdsa::{RecoverableSignature, RecoveryId};
+use bitcoin::secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+use bitcoin::sighash::{EcdsaSighashType, Prevouts, SighashCache, TapSighashType};
+use bitcoin::taproot;
+use bitcoin::transaction::Version;
+use bitcoin::{
+    absolute, Address, AddressType, Amount, CompressedPublicKey, Network, OutPoint, Script,
+    ScriptBuf, Sequence, Transaction, TxIn, TxOut, Txid, Witness,
+};
+
+use super::bitcoin::BitcoinScriptType;
+use crate::wallet::error::{WalletError, WalletResult};
+
+/// Tag for the BIP-322 message hash
+const BIP322_TAG: &[u8] = b"BIP0322-signed-message";
+
+/// Prefix of the classic `signmessage` hash
+const SIGNED_MESSAGE_PREFIX: &[u8] = b"\x18Bitcoin Signed Message:\n";
+
+// =============================================================================
+// Hashes and virtual transactions
+// =============================================================================
+
+/// BIP-340 tagged hash of the message with tag "BIP0322-signed-message"
+pub fn message_hash(message: &[u8]) -> [u8; 32] {
+    let tag = sha256::Hash::hash(BIP322_TAG);
+    let mut engine = sha256::Hash::engine();
+    engine.input(tag.as_ref());
+    engine.input(tag.as_ref());
+    engine.input(message);
+    sha256::Hash::from_engine(engine).to_byte_array()
+}
+
+/// Classic `signmessage` hash: sha256d(prefix || varint(len) || message)
+pub fn signed_message_hash(message: &[u8]) -> [u8; 32] {
+    let mut engine = sha256d::Hash::engine();
+    engine.input(SIGNED_MESSAGE_PREFIX);
+    engine.input(&encode::serialize(&encode::VarInt(message.len() as u64)));
+    engine.input(message);
+    sha256d::Hash::from_engine(engine).to_byte_array()
+}
+
+/// Virtual transaction whose only output is locked to the signing address
+fn to_spend(script_pubkey: &Script, message: &[u8]) -> Transaction {
+    let script_sig = ScriptBuf::builder()
+        .push_opcode(OP_PUSHBYTES_0)
+        .push_slice(message_hash(message))
+        .into_script();
+
+    Transaction {
+        version: Version(0),
+        lock_time: absolute::LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint {
+                txid: Txid::all_zeros(),
+                vout: 0xFFFF_FFFF,
+            },
+            script_sig,
+            sequence: Sequence::ZERO,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: Amount::ZERO,
+            script_pubkey: script_pubkey.to_owned(),
+        }],
+    }
+}
+
+/// Virtual transaction spending `to_spend`; its witness is the signature
+fn to_sign(to_spend: &Transaction) -> Transaction {
+    Transaction {
+        version: Version(0),
+        lock_time: absolute::LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint {
+                txid: to_spend.compute_txid(),
+                vout: 0,
+            },
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::ZERO,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: Amount::ZERO,
+            script_pubkey: ScriptBuf::builder().push_opcode(OP_RETURN).into_script(),
+        }],
+    }
+}
+
+fn signing_error(e: impl std::fmt::Display) -> WalletError {
+    WalletError::SigningError(e.to_string())
+}
+
+// =============================================================================
+// Signing
+// =============================================================================
+
+/// Sign a message for the address of `script_type`, returning base64
+pub fn sign(
+    secret_key: &SecretKey,
+    script_type: BitcoinScriptType,
+    network: Network,
+    message: &[u8],
+) -> WalletResult<String> {
+    let secp = Secp256k1::new();
+    let public_key = PublicKey::from_secret_key(&secp, secret_key);
+
+    match script_type {
+        BitcoinScriptType::P2wpkh | BitcoinScriptType::P2tr => {
+            let script_pubkey = script_type
+                .address(&secp, &public_key, network)
+                .script_pubkey();
+            let witness = sign_simple(secret_key, script_type, &script_pubkey, message)?;
+            Ok(BASE64_STANDARD.encode(encode::serialize(&witness)))
+        }
+        BitcoinScriptType::P2pkh | BitcoinScriptType::P2shP2wpkh => {
+            Ok(BASE64_STANDARD.encode(sign_legacy(secret_key, script_type, message)))
+        }
+    }
+}
+
+/// BIP-322 simple signature (witness of `to_sign`)
+fn sign_simple(
+    secret_key: &SecretKey,
+    script_type: BitcoinScriptType,
+    script_pubkey: &Script,
+    message: &[u8],
+) -> WalletResult<Witness> {
+    let secp = Secp256k1::new();
+    let to_spend = to_spend(script_pubkey, message);
+    let to_sign = to_sign(&to_spend);
+    let mut cache = SighashCache::new(&to_sign);
+
+    match script_type {
+        BitcoinScriptType::P2wpkh => {
+            let sighash = cache
+                .p2wpkh_signature_hash(0, script_pubkey, Amount::ZERO, EcdsaSighashType::All)
+                .map_err(signing_error)?;
+            // Low-R grinding, as in Bitcoin Core (and the BIP-322 test vectors)
+            let signature =
+                secp.sign_ecdsa_low_r(&Message::from_digest(sighash.to_byte_array()), secret_key);
+            let public_key = PublicKey::from_secret_key(&secp, secret_key);
+            Ok(Witness::p2wpkh(
+                &ecdsa::Signature::sighash_all(signature),
+                &public_key,
+            ))
+        }
+        BitcoinScriptType::P2tr => {
+            let prevouts = [to_spend.output[0].clone()];
+            let sighash = cache
+                .taproot_key_spend_signature_hash(
+                    0,
+                    &Prevouts::All(&prevouts),
+                    TapSighashType::Default,
+                )
+                .map_err(signing_error)?;
+            let keypair = Keypair::from_secret_key(&secp, secret_key).tap_tweak(&secp, None);
+            let signature = secp.sign_schnorr_no_aux_rand(
+                &Message::from_digest(sighash.to_byte_array()),
+                &keypair.to_keypair(),
+            );
+            Ok(Witness::p2tr_key_spend(&taproot::Signature {
+                signature,
+                sighash_type: TapSighashType::Default,
+            }))
+        }
+        _ => Err(WalletError::SigningError(format!(
+            "BIP-322 simple signatures are not supported for {}",
+            script_type
+        ))),
+    }
+}
+
+/// Compact recoverable signature with a BIP-137 header byte
+fn sign_legacy(secret_key: &SecretKey, script_type: BitcoinScriptType, message: &[u8]) -> [u8; 65] {
+    let secp = Secp256k1::new();
+    let msg = Message::from_digest(signed_message_hash(message));
+    let (recovery_id, compact) = secp
+        .sign_ecdsa_recoverable(&msg, secret_key)
+        .serialize_compact();
+
+    // BIP-137 headers for compressed keys: 31 (P2PKH), 35 (P2SH-P2WPKH), 39 (P2WPKH)
+    let header_base = match script_type {
+        BitcoinScriptType::P2shP2wpkh => 35,
+        BitcoinScriptType::P2wpkh => 39,
+        _ => 31,
+    };
+
+    let mut bytes = [0u8; 65];
+    bytes[0] = header_base + recovery_id.to_i32() as u8;
+    bytes[1..].copy_from_slice(&compact);
+    bytes
+}
+
+// =============================================================================
+// Verification
+// =============================================================================
+
+/// Verify a base64 BIP-322 simple or BIP-137 signature against an address
+pub fn verify(address: &Address, message: &[u8], signature: &str) -> bool {
+    let Ok(bytes) = BASE64_STANDARD.decode(signature.trim()) else {
+        return false;
+    };
+
+    if bytes.len() == 65 && (27..=42).contains(&bytes[0]) {
+        return verify_legacy(address, message, &bytes);
+    }
+
+    match encode::deserialize::<Witness>(&bytes) {
+        Ok(witness) => verify_simple(address, message, &witness),
+        Err(_) => false,
+    }
+}
+
+fn verify_simple(address: &Address, message: &[u8], witness: &Witness) -> bool {
+    let secp = Secp256k1::verification_only();
+    let script_pubkey = address.script_pubkey();
+    let to_spend = to_spend(&script_pubkey, message);
+    let to_sign = to_sign(&to_spend);
+    let mut cache = SighashCache::new(&to_sign);
+
+    match address.address_type() {
+        Some(AddressType::P2wpkh) => {
+            let (Some(sig), Some(pk), 2) = (witness.nth(0), witness.nth(1), witness.len()) else {
+                return false;
+            };
+            let (Ok(signature), Ok(public_key)) =
+                (ecdsa::Signature::from_slice(sig), PublicKey::from_slice(pk))
+            else {
+                return false;
+            };
+            if ScriptBuf::new_p2wpkh(&CompressedPublicKey(public_key).wpubkey_hash())
+                != script_pubkey
+            {
+                return false;
+            }
+
+            cache
+                .p2wpkh_signature_hash(0, &script_pubkey, Amount::ZERO, signature.sighash_type)
+                .is_ok_and(|sighash| {
+                    let msg = Message::from_digest(sighash.to_byte_array());
+                    secp.verify_ecdsa(&msg, &signature.signature, &public_key)
+                        .is_ok()
+                })
+        }
+        Some(AddressType::P2tr) => {
+            let (Some(sig), 1) = (witness.nth(0), witness.len()) else {
+                return false;
+            };
+            let Ok(signature) = taproot::Signature::from_slice(sig) else {
+                return false;
+            };
+            let Ok(output_key) = XOnlyPublicKey::from_slice(&script_pubkey.as_bytes()[2..]) else {
+                return false;
+            };
+
+            let prevouts = [to_spend.output[0].clone()];
+            cache
+                .taproot_key_spend_signature_hash(
+                    0,
+                    &Prevouts::All(&prevouts),
+                    signature.sighash_type,
+                )
+                .is_ok_and(|sighash| {
+                    let msg = Message::from_digest(sighash.to_byte_array());
+                    secp.verify_schnorr(&signature.signature, &msg, &output_key)
+                        .is_ok()
+                })
+        }
+        _ => false,
+    }
+}
+
+fn verify_legacy(address: &Address, message: &[u8], bytes: &[u8]) -> bool {
+    let secp = Secp256k1::verification_only();
+    let header = bytes[0] - 27;
+
+    let recovered = RecoveryId::from_i32((header & 0x03) as i32)
+        .and_then(|id| RecoverableSignature::from_compact(&bytes[1..], id))
+        .and_then(|sig| {
+            secp.recover_ecdsa(&Message::from_digest(signed_message_hash(message)), &sig)
+        });
+    let Ok(public_key) = recovered else {
+        return false;
+    };
+
+    // Headers 27-30 are uncompressed P2PKH; everything else is a compressed key
+    // which may back any single-key address type.
+    if header < 4 {
+        let key = bitcoin::PublicKey::new_uncompressed(public_key);
+        return address.pubkey_hash() == Some(key.pubkey_hash());
+    }
+
+    let script_pubkey = address.script_pubkey();
+    let key = CompressedPublicKey(public_key);
+    [
+        ScriptBuf::new_p2pkh(&key.pubkey_hash()),
+        ScriptBuf::new_p2sh(&ScriptBuf::new_p2wpkh(&key.wpubkey_hash()).script_hash()),
+        ScriptBuf::new_p2wpkh(&key.wpubkey_hash()),
+    ]
+    .contains(&script_pubkey)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::PrivateKey;
+
+    // BIP-322 test vectors
+    const VECTOR_WIF: &str = "L3VFeEujGtevx9w18HD1fhRbCH67Az2dpCymeRE1SoPK6XQtaN2k";
+    const VECTOR_ADDRESS: &str = "bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l";
+    const VECTOR_SIG_EMPTY: &str = "AkcwRAIgM2gBAQqvZX15ZiysmKmQpDrG83avLIT492QBzLnQIxYCIBaTpOaD20qRlEylyxFSeEA2ba9YOixpX8z46TSDtS40ASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=";
+    const VECTOR_SIG_HELLO: &str = "AkcwRAIgZRfIY3p7/DoVTty6YZbWS71bc5Vct9p9Fia83eRmw2QCICK/ENGfwLtptFluMGs2KsqoNSk89pO7F29zJLUx9a/sASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=";
+
+    fn vector_address() -> Address {
+        VECTOR_ADDRESS
+            .parse::<Address<_>>()
+            .unwrap()
+            .assume_checked()
+    }
+
+    #[test]
+    fn test_bip322_message_hash() {
+        assert_eq!(
+            hex::encode(message_hash(b"")),
+            "c90c269c4f8fcbe6880f72a721ddfbf1914268a794cbb21cfafee13770ae19f1"
+        );
+        assert_eq!(
+            hex::encode(message_hash(b"Hello World")),
+            "f0eb03b1a75ac6d9847f55c624a99169b5dccba2a31f5b23bea77ba270de0a7a"
+        );
+    }
+
+    #[test]
+    fn test_bip322_simple_vectors() {
+        let key = PrivateKey::from_wif(VECTOR_WIF).unwrap();
+        assert_eq!(
+            sign(&key.inner, BitcoinScriptType::P2wpkh, Network::Bitcoin, b"").unwrap(),
+            VECTOR_SIG_EMPTY
+        );
+        assert_eq!(
+            sign(
+                &key.inner,
+                BitcoinScriptType::P2wpkh,
+                Network::Bitcoin,
+                b"Hello World"
+            )
+            .unwrap(),
+            VECTOR_SIG_HELLO
+        );
+
+        assert!(verify(&vector_address(), b"", VECTOR_SIG_EMPTY));
+        assert!(verify(&vector_address(), b"Hello World", VECTOR_SIG_HELLO));
+        assert!(!verify(&vector_address(), b"Hello World", VECTOR_SIG_EMPTY));
+        assert!(!verify(&vector_address(), b"", "not base64!"));
+    }
+
+    #[test]
+    fn test_bip322_taproot_roundtrip() {
+        let key = PrivateKey::from_wif(VECTOR_WIF).unwrap();
+        let secp = Secp256k1::new();
+        let address =
+            BitcoinScriptType::P2tr.address(&secp, &key.public_key(&secp).inner, Network::Bitcoin);
+
+        let signature = sign(
+            &key.inner,
+            BitcoinScriptType::P2tr,
+            Network::Bitcoin,
+            b"Hello World",
+        )
+        .unwrap();
+        assert!(verify(&address, b"Hello World", &signature));
+        assert!(!verify(&address, b"Hello", &signature));
+        assert!(!verify(&vector_address(), b"Hello World", &signature));
+    }
+
+    #[test]
+    fn test_legacy_signatures() {
+        let key = PrivateKey::from_wif(VECTOR_WIF).unwrap();
+        let secp = Secp256k1::new();
+        let public_key = key.public_key(&secp).inner;
+
+        for script_type in [BitcoinScriptType::P2pkh, BitcoinScriptType::P2shP2wpkh] {
+            let address = script_type.address(&secp, &public_key, Network::Bitcoin);
+            let signature =
+                sign(&key.inner, script_type, Network::Bitcoin, b"Hello World").unwrap();
+            assert_eq!(BASE64_STANDARD.decode(&signature).unwrap().len(), 65);
+            assert!(verify(&address, b"Hello World", &signature));
+            assert!(!verify(&address, b"Hello", &signature));
+        }
+
+        // BIP-137 signatures are also accepted for native SegWit addresses
+        let legacy = BASE64_STANDARD.encode(sign_legacy(
+            &key.inner,
+            BitcoinScriptType::P2wpkh,
+            b"Hello World",
+        ));
+        assert!(verify(&vector_address(), b"Hello World", &legacy));
+    }
+}
@@ -1,36 +1,375 @@
-//! Bitcoin chain module (BIP84 Native SegWit)
+//! Bitcoin chain module
 //!
-//! Generates bc1... addresses using BIP84 derivation (Native SegWit / P2WPKH).
-//! Path: m/84'/0'/account'/0/index
+//! Supports the four standard single-key script types:
+//! - BIP44 Legacy (P2PKH, 1...):            m/44'/0'/account'/0/index
+//! - BIP49 Nested SegWit (P2SH-P2WPKH, 3...): m/49'/0'/account'/0/index
+//! - BIP84 Native SegWit (P2WPKH, bc1q...): m/84'/0'/account'/0/index
+//! - BIP86 Taproot (P2TR, bc1p...):         m/86'/0'/account'/0/index
+//!
+//! Test networks (testnet3, testnet4, signet, regtest) use coin type 1 and
+//! their own address prefixes (`tb1`/`bcrt1`, `m`/`n`, `2`).
+//!
+//! Each module also exposes the other three purposes as derivation schemes,
+//! so funds left on e.g. a BIP44 path by an older wallet can be found.
+//!
+//! Messages are signed with BIP-322 (see `bip322`); transactions as PSBTs.
+
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::bip32::Xpriv;
+use bitcoin::key::XOnlyPublicKey;
+use bitcoin::psbt::SigningKeys;
+use bitcoin::secp256k1::{PublicKey, Secp256k1, SecretKey, Verification};
+use bitcoin::{Address, CompressedPublicKey, Network, Psbt};
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::chains::{
+    coin_types, AccountXpub, ChainModule, ChainSignature, ChainSigner, DerivationScheme,
+    DEFAULT_SCHEME,
+};
+use crate::wallet::error::{WalletError, WalletResult};
+use crate::wallet::types::{AddressValidation, ChainFamily, DerivedAddress};
+
+use super::{bip322, derive_key_from_seed, verification_context};
+
+// =============================================================================
+// Script Types
+// =============================================================================
+
+/// Bitcoin single-key script type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BitcoinScriptType {
+    /// BIP44 Legacy (P2PKH)
+    P2pkh,
+    /// BIP49 Nested SegWit (P2SH-P2WPKH)
+    P2shP2wpkh,
+    /// BIP84 Native SegWit (P2WPKH)
+    #[default]
+    P2wpkh,
+    /// BIP86 Taproot (P2TR, key-path only)
+    P2tr,
+}
+
+impl BitcoinScriptType {
+    /// All supported script types
+    pub const ALL: [BitcoinScriptType; 4] = [
+        BitcoinScriptType::P2pkh,
+        BitcoinScriptType::P2shP2wpkh,
+        BitcoinScriptType::P2wpkh,
+        BitcoinScriptType::P2tr,
+    ];
+
+    /// BIP43 purpose for this script type
+    pub fn purpose(&self) -> u32 {
+        match self {
+            BitcoinScriptType::P2pkh => 44,
+            BitcoinScriptType::P2shP2wpkh => 49,
+            BitcoinScriptType::P2wpkh => 84,
+            BitcoinScriptType::P2tr => 86,
+        }
+    }
 
-use bitcoin::secp256k1::{PublicKey, Secp256k1};
-use bitcoin::{Address, CompressedPublicKey, Network};
+    /// Script type for a BIP43 purpose
+    pub fn from_purpose(purpose: u32) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.purpose() == purpose)
+    }
 
-use crate::wallet::chains::{coin_types, ChainModule};
-use crate::wallet::error::{WalletError, WalletResult};
-use crate::wallet::types::{ChainFamily, DerivedAddress};
+    /// Identifier used in chain IDs and over IPC
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BitcoinScriptType::P2pkh => "p2pkh",
+            BitcoinScriptType::P2shP2wpkh => "p2sh-p2wpkh",
+            BitcoinScriptType::P2wpkh => "p2wpkh",
+            BitcoinScriptType::P2tr => "p2tr",
+        }
+    }
+
+    /// Human-readable label
+    pub fn label(&self) -> &'static str {
+        match self {
+            BitcoinScriptType::P2pkh => "Legacy",
+            BitcoinScriptType::P2shP2wpkh => "Nested SegWit",
+            BitcoinScriptType::P2wpkh => "Native SegWit",
+            BitcoinScriptType::P2tr => "Taproot",
+        }
+    }
+
+    /// Suffix appended to the chain ID (Native SegWit keeps the plain ID)
+    fn chain_id_suffix(&self) -> Option<&'static str> {
+        match self {
+            BitcoinScriptType::P2pkh => Some("legacy"),
+            BitcoinScriptType::P2shP2wpkh => Some("nested_segwit"),
+            BitcoinScriptType::P2wpkh => None,
+            BitcoinScriptType::P2tr => Some("taproot"),
+        }
+    }
+
+    /// Wrap a key expression in the output descriptor for this script type
+    ///
+    /// e.g. `wpkh([fingerprint/84'/0'/0']xpub.../0/*)`
+    pub fn descriptor(&self, key: &str) -> String {
+        match self {
+            BitcoinScriptType::P2pkh => format!("pkh({})", key),
+            BitcoinScriptType::P2shP2wpkh => format!("sh(wpkh({}))", key),
+            BitcoinScriptType::P2wpkh => format!("wpkh({})", key),
+            BitcoinScriptType::P2tr => format!("tr({})", key),
+        }
+    }
+
+    /// Build the address for a public key
+    pub fn address<C: Verification>(
+        &self,
+        secp: &Secp256k1<C>,
+        public_key: &PublicKey,
+        network: Network,
+    ) -> Address {
+        let compressed_pk = CompressedPublicKey(*public_key);
+        match self {
+            BitcoinScriptType::P2pkh => Address::p2pkh(compressed_pk.pubkey_hash(), network),
+            BitcoinScriptType::P2shP2wpkh => Address::p2shwpkh(&compressed_pk, network),
+            BitcoinScriptType::P2wpkh => Address::p2wpkh(&compressed_pk, network),
+            BitcoinScriptType::P2tr => {
+                Address::p2tr(secp, XOnlyPublicKey::from(*public_key), None, network)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for BitcoinScriptType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for BitcoinScriptType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "p2pkh" | "legacy" => Ok(BitcoinScriptType::P2pkh),
+            "p2sh-p2wpkh" | "p2sh_p2wpkh" | "nested_segwit" => Ok(BitcoinScriptType::P2shP2wpkh),
+            "p2wpkh" | "native_segwit" => Ok(BitcoinScriptType::P2wpkh),
+            "p2tr" | "taproot" => Ok(BitcoinScriptType::P2tr),
+            _ => Err(format!("Unknown script type: {}", s)),
+        }
+    }
+}
 
-use super::derive_key_from_seed;
+// =============================================================================
+// Chain Module
+// =============================================================================
 
-/// Bitcoin chain module for mainnet
+/// Bitcoin chain module for a given network and script type
 pub struct BitcoinModule {
     network: Network,
+    script_type: BitcoinScriptType,
+    chain_id: String,
+    display_name: String,
+    /// All script types on a network share its icon
+    icon_name: &'static str,
 }
 
 impl BitcoinModule {
-    /// Create a new Bitcoin mainnet module
+    /// Networks registered with the chain registry
+    pub const NETWORKS: [Network; 5] = [
+        Network::Bitcoin,
+        Network::Testnet,
+        Network::Testnet4,
+        Network::Signet,
+        Network::Regtest,
+    ];
+
+    /// Create a new Bitcoin mainnet module (Native SegWit)
     pub fn new() -> Self {
-        Self {
-            network: Network::Bitcoin,
-        }
+        Self::for_network(Network::Bitcoin)
     }
 
-    /// Create a Bitcoin testnet module
+    /// Create a Bitcoin testnet3 module (Native SegWit)
     pub fn testnet() -> Self {
+        Self::for_network(Network::Testnet)
+    }
+
+    /// Create a Bitcoin testnet4 module (Native SegWit)
+    pub fn testnet4() -> Self {
+        Self::for_network(Network::Testnet4)
+    }
+
+    /// Create a Bitcoin signet module (Native SegWit)
+    pub fn signet() -> Self {
+        Self::for_network(Network::Signet)
+    }
+
+    /// Create a Bitcoin regtest module (Native SegWit)
+    pub fn regtest() -> Self {
+        Self::for_network(Network::Regtest)
+    }
+
+    /// Create a Native SegWit module for any network
+    pub fn for_network(network: Network) -> Self {
+        Self::build(network, BitcoinScriptType::P2wpkh)
+    }
+
+    /// Use a different script type on the same network
+    pub fn with_script_type(self, script_type: BitcoinScriptType) -> Self {
+        Self::build(self.network, script_type)
+    }
+
+    fn build(network: Network, script_type: BitcoinScriptType) -> Self {
+        let (base_id, base_name) = match network {
+            Network::Bitcoin => ("bitcoin", "Bitcoin"),
+            Network::Testnet => ("bitcoin_testnet", "Bitcoin Testnet"),
+            Network::Testnet4 => ("bitcoin_testnet4", "Bitcoin Testnet4"),
+            Network::Signet => ("bitcoin_signet", "Bitcoin Signet"),
+            Network::Regtest => ("bitcoin_regtest", "Bitcoin Regtest"),
+        };
+
+        let (chain_id, display_name) = match script_type.chain_id_suffix() {
+            Some(suffix) => (
+                format!("{}_{}", base_id, suffix),
+                format!("{} ({})", base_name, script_type.label()),
+            ),
+            None => (base_id.to_string(), base_name.to_string()),
+        };
+
         Self {
-            network: Network::Testnet,
+            network,
+            script_type,
+            chain_id,
+            display_name,
+            icon_name: base_id,
         }
     }
+
+    /// Script type used for derivation
+    pub fn script_type(&self) -> BitcoinScriptType {
+        self.script_type
+    }
+
+    /// Bitcoin network
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    /// Account-level derivation path: m/purpose'/coin_type'/account'
+    ///
+    /// Shared with the BDK adapter so descriptors and derived addresses agree.
+    pub fn account_path(&self, account: u32) -> String {
+        format!(
+            "m/{}'/{}'/{account}'",
+            self.script_type.purpose(),
+            self.coin_type()
+        )
+    }
+
+    /// Parse an address and check it belongs to this module's network
+    fn parse_address(&self, address: &str) -> Option<Address> {
+        address
+            .parse::<Address<NetworkUnchecked>>()
+            .ok()?
+            .require_network(self.network)
+            .ok()
+    }
+
+    /// Script type implied by a path's purpose (m/44'/... is P2PKH, etc.)
+    fn script_type_for_path(path: &str) -> WalletResult<BitcoinScriptType> {
+        path.strip_prefix("m/")
+            .and_then(|rest| rest.split('/').next())
+            .and_then(|purpose| purpose.strip_suffix('\''))
+            .and_then(|purpose| purpose.parse().ok())
+            .and_then(BitcoinScriptType::from_purpose)
+            .ok_or_else(|| {
+                WalletError::DerivationError(format!(
+                    "Unsupported Bitcoin derivation path: {}",
+                    path
+                ))
+            })
+    }
+
+    /// Private key at a derivation path
+    fn secret_key_at_path(seed: &[u8; 64], path: &str) -> WalletResult<SecretKey> {
+        let derived_key = derive_key_from_seed(seed, path)?;
+        SecretKey::from_slice(&derived_key.private_key().to_bytes())
+            .map_err(|e| WalletError::DerivationError(format!("Invalid private key: {}", e)))
+    }
+}
+
+// =============================================================================
+// Signing
+// =============================================================================
+
+impl ChainSigner for BitcoinModule {
+    fn sign_message(
+        &self,
+        seed: &[u8; 64],
+        path: &str,
+        message: &[u8],
+    ) -> WalletResult<ChainSignature> {
+        let script_type = Self::script_type_for_path(path)?;
+        let secret_key = Self::secret_key_at_path(seed, path)?;
+        let scheme = match script_type {
+            BitcoinScriptType::P2wpkh | BitcoinScriptType::P2tr => "bip322-simple",
+            BitcoinScriptType::P2pkh | BitcoinScriptType::P2shP2wpkh => "bip137",
+        };
+
+        Ok(ChainSignature {
+            address: self.derive_address_at_path(seed, path)?.address,
+            scheme: scheme.to_string(),
+            signature: bip322::sign(&secret_key, script_type, self.network, message)?,
+        })
+    }
+
+    /// Sign a PSBT (BIP-174 binary serialization)
+    ///
+    /// Every input whose BIP32 key origin belongs to this seed is signed,
+    /// regardless of `path`; the result is the signed PSBT in base64.
+    fn sign_transaction(
+        &self,
+        seed: &[u8; 64],
+        path: &str,
+        payload: &[u8],
+    ) -> WalletResult<ChainSignature> {
+        let mut psbt = Psbt::deserialize(payload)
+            .map_err(|e| WalletError::SigningError(format!("Invalid PSBT: {}", e)))?;
+        let master = Xpriv::new_master(self.network, seed)
+            .map_err(|e| WalletError::SigningError(e.to_string()))?;
+
+        let secp = Secp256k1::new();
+        let signed = match psbt.sign(&master, &secp) {
+            Ok(signed) => signed,
+            Err((_, errors)) => {
+                return Err(WalletError::SigningError(format!(
+                    "PSBT signing failed: {:?}",
+                    errors
+                )))
+            }
+        };
+        let signed_any = signed.values().any(|keys| match keys {
+            SigningKeys::Ecdsa(keys) => !keys.is_empty(),
+            SigningKeys::Schnorr(keys) => !keys.is_empty(),
+        });
+        if !signed_any {
+            return Err(WalletError::SigningError(
+                "No PSBT inputs belong to this wallet".to_string(),
+            ));
+        }
+
+        Ok(ChainSignature {
+            address: self.derive_address_at_path(seed, path)?.address,
+            scheme: "psbt".to_string(),
+            signature: psbt.to_string(),
+        })
+    }
+
+    fn verify_message(&self, address: &str, message: &[u8], signature: &str) -> WalletResult<bool> {
+        let parsed = self
+            .parse_address(address)
+            .ok_or_else(|| WalletError::InvalidAddress {
+                chain: self.chain_id().to_string(),
+                address: address.to_string(),
+            })?;
+
+        Ok(bip322::verify(&parsed, message, signature))
+    }
 }
 
 impl Default for BitcoinModule {
@@ -41,21 +380,11 @@ impl Default for BitcoinModule {
 
 impl ChainModule for BitcoinModule {
     fn chain_id(&self) -> &str {
-        match self.network {
-            Network::Bitcoin => "bitcoin",
-            Network::Testnet | Network::Signet => "bitcoin_testnet",
-            Network::Regtest => "bitcoin_regtest",
-            _ => "bitcoin",
-        }
+        &self.chain_id
     }
 
     fn display_name(&self) -> &str {
-        match self.network {
-            Network::Bitcoin => "Bitcoin",
-            Network::Testnet | Network::Signet => "Bitcoin Testnet",
-            Network::Regtest => "Bitcoin Regtest",
-            _ => "Bitcoin",
-        }
+        &self.display_name
     }
 
     fn chain_family(&self) -> ChainFamily {
@@ -63,89 +392,158 @@ impl ChainModule for BitcoinModule {
     }
 
     fn coin_type(&self) -> u32 {
-        coin_types::BITCOIN
+        match self.network {
+            Network::Bitcoin => coin_types::BITCOIN,
+            _ => coin_types::TESTNET,
+        }
     }
 
     fn is_testnet(&self) -> bool {
-        matches!(self.network, Network::Testnet | Network::Signet | Network::Regtest)
+        self.network != Network::Bitcoin
     }
 
     fn symbol(&self) -> &str {
         "BTC"
     }
 
+    fn decimals(&self) -> u8 {
+        8
+    }
+
+    fn explorer_url(&self) -> Option<&str> {
+        match self.network {
+            Network::Bitcoin => Some("https://mempool.space"),
+            Network::Testnet => Some("https://mempool.space/testnet"),
+            Network::Testnet4 => Some("https://mempool.space/testnet4"),
+            Network::Signet => Some("https://mempool.space/signet"),
+            _ => None,
+        }
+    }
+
+    fn address_type(&self) -> Option<&str> {
+        Some(self.script_type.as_str())
+    }
+
+    fn icon_name(&self) -> &str {
+        self.icon_name
+    }
+
     fn derive_address(
         &self,
         seed: &[u8; 64],
         account: u32,
         index: u32,
     ) -> WalletResult<DerivedAddress> {
-        // BIP84 path for Native SegWit: m/84'/0'/account'/0/index
-        let path = self.derivation_path(account, index);
-        let derived_key = derive_key_from_seed(seed, &path)?;
+        // m/purpose'/coin_type'/account'/0/index
+        self.derive_address_at_path(seed, &self.derivation_path(account, index))
+    }
 
-        // Get the private key bytes
-        let private_key_bytes = derived_key.private_key().to_bytes();
+    fn derivation_schemes(&self) -> Vec<DerivationScheme> {
+        BitcoinScriptType::ALL
+            .into_iter()
+            .filter(|t| *t != self.script_type)
+            .map(|t| {
+                DerivationScheme::new(
+                    &format!("bip{}", t.purpose()),
+                    &format!("{} (BIP{})", t.label(), t.purpose()),
+                    format!(
+                        "m/{}'/{}'/{{account}}'/0/{{index}}",
+                        t.purpose(),
+                        self.coin_type()
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn derive_address_at_path(&self, seed: &[u8; 64], path: &str) -> WalletResult<DerivedAddress> {
+        let script_type = Self::script_type_for_path(path)?;
+        let secret_key = Self::secret_key_at_path(seed, path)?;
 
         // Create secp256k1 context and derive public key
         let secp = Secp256k1::new();
-        let secret_key = bitcoin::secp256k1::SecretKey::from_slice(&private_key_bytes)
-            .map_err(|e| WalletError::DerivationError(format!("Invalid private key: {}", e)))?;
         let public_key = PublicKey::from_secret_key(&secp, &secret_key);
 
-        // Create compressed public key for address generation
-        let compressed_pk = CompressedPublicKey(public_key);
+        // Script type taken from the path's purpose, not the module's
+        let address = script_type.address(&secp, &public_key, self.network);
 
-        // Generate P2WPKH (Native SegWit) address
-        let address = Address::p2wpkh(&compressed_pk, self.network);
+        Ok(DerivedAddress {
+            chain: self.chain_id().to_string(),
+            chain_family: self.chain_family(),
+            address: address.to_string(),
+            derivation_path: path.to_string(),
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key.serialize().to_vec(),
+        })
+    }
+
+    fn derive_address_from_xpub(
+        &self,
+        account_key: &AccountXpub,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        account_key.check_compatible(
+            self.chain_id(),
+            self.is_testnet(),
+            self.script_type.as_str(),
+        )?;
+        let public_key = account_key.public_key_at(0, index)?;
+        let address = self
+            .script_type
+            .address(verification_context(), &public_key, self.network);
 
         Ok(DerivedAddress {
             chain: self.chain_id().to_string(),
             chain_family: self.chain_family(),
             address: address.to_string(),
-            derivation_path: path,
+            derivation_path: self.derivation_path(account_key.account(), index),
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
             public_key: public_key.serialize().to_vec(),
         })
     }
 
     fn validate_address(&self, address: &str) -> bool {
-        // Try to parse as a Bitcoin address
-        match address.parse::<Address<_>>() {
-            Ok(_) => {
-                // Check network matches by prefix
-                match self.network {
-                    Network::Bitcoin => {
-                        // Mainnet addresses start with 1, 3, or bc1
-                        address.starts_with('1')
-                            || address.starts_with('3')
-                            || address.starts_with("bc1")
-                    }
-                    Network::Testnet | Network::Signet => {
-                        // Testnet addresses start with m, n, 2, or tb1
-                        address.starts_with('m')
-                            || address.starts_with('n')
-                            || address.starts_with('2')
-                            || address.starts_with("tb1")
-                    }
-                    _ => true, // Allow if parsed successfully
-                }
-            }
-            Err(_) => false,
+        self.parse_address(address).is_some()
+    }
+
+    fn validate_address_details(&self, address: &str) -> AddressValidation {
+        match self.parse_address(address) {
+            Some(parsed) => AddressValidation {
+                is_valid: true,
+                address_type: parsed.address_type().map(|t| t.to_string()),
+                ..Default::default()
+            },
+            None => AddressValidation::default(),
         }
     }
 
     fn derivation_path(&self, account: u32, index: u32) -> String {
-        // BIP84 for Native SegWit
         // Note: All levels except the last two are hardened (')
-        format!("m/84'/{}'/{account}'/0/{index}", self.coin_type())
+        format!("{}/0/{index}", self.account_path(account))
+    }
+
+    fn account_node_path(&self, account: u32) -> Option<String> {
+        Some(self.account_path(account))
+    }
+
+    fn signer(&self) -> Option<&dyn ChainSigner> {
+        Some(self)
     }
 
     fn address_prefix(&self) -> Option<&str> {
-        match self.network {
-            Network::Bitcoin => Some("bc1"),
-            Network::Testnet | Network::Signet => Some("tb1"),
-            _ => None,
-        }
+        let prefix = match (self.network, self.script_type) {
+            (Network::Bitcoin, BitcoinScriptType::P2pkh) => "1",
+            (Network::Bitcoin, BitcoinScriptType::P2shP2wpkh) => "3",
+            (Network::Bitcoin, BitcoinScriptType::P2wpkh) => "bc1q",
+            (Network::Bitcoin, BitcoinScriptType::P2tr) => "bc1p",
+            (Network::Regtest, BitcoinScriptType::P2wpkh) => "bcrt1q",
+            (Network::Regtest, BitcoinScriptType::P2tr) => "bcrt1p",
+            (_, BitcoinScriptType::P2pkh) => "m",
+            (_, BitcoinScriptType::P2shP2wpkh) => "2",
+            (_, BitcoinScriptType::P2wpkh) => "tb1q",
+            (_, BitcoinScriptType::P2tr) => "tb1p",
+        };
+        Some(prefix)
     }
 }
 
@@ -174,9 +572,83 @@ mod tests {
     fn test_bitcoin_testnet_module() {
         let module = BitcoinModule::testnet();
         assert_eq!(module.chain_id(), "bitcoin_testnet");
+        assert_eq!(module.coin_type(), 1);
         assert!(module.is_testnet());
     }
 
+    #[test]
+    fn test_bitcoin_test_networks() {
+        let ids: Vec<String> = BitcoinModule::NETWORKS
+            .iter()
+            .map(|network| BitcoinModule::for_network(*network).chain_id().to_string())
+            .collect();
+        assert_eq!(
+            ids,
+            [
+                "bitcoin",
+                "bitcoin_testnet",
+                "bitcoin_testnet4",
+                "bitcoin_signet",
+                "bitcoin_regtest"
+            ]
+        );
+
+        for module in [
+            BitcoinModule::testnet4(),
+            BitcoinModule::signet(),
+            BitcoinModule::regtest(),
+        ] {
+            assert!(module.is_testnet());
+            assert_eq!(module.derivation_path(0, 0), "m/84'/1'/0'/0/0");
+        }
+    }
+
+    #[test]
+    fn test_bitcoin_testnet_derive_address() {
+        let seed = test_seed();
+
+        // m/84'/1'/0'/0/0 for the "abandon ... about" mnemonic
+        let testnet = BitcoinModule::testnet()
+            .derive_address(&seed, 0, 0)
+            .unwrap();
+        assert_eq!(testnet.derivation_path, "m/84'/1'/0'/0/0");
+        assert_eq!(
+            testnet.address,
+            "tb1q6rz28mcfaxtmd6v789l9rrlrusdprr9pqcpvkl"
+        );
+
+        // Same key on every test network, only the HRP differs
+        let testnet4 = BitcoinModule::testnet4()
+            .derive_address(&seed, 0, 0)
+            .unwrap();
+        let signet = BitcoinModule::signet().derive_address(&seed, 0, 0).unwrap();
+        let regtest = BitcoinModule::regtest()
+            .derive_address(&seed, 0, 0)
+            .unwrap();
+        assert_eq!(testnet4.address, testnet.address);
+        assert_eq!(signet.address, testnet.address);
+        assert_eq!(
+            regtest.address,
+            "bcrt1q6rz28mcfaxtmd6v789l9rrlrusdprr9pz3cppk"
+        );
+        assert_eq!(regtest.public_key, testnet.public_key);
+    }
+
+    #[test]
+    fn test_bitcoin_test_network_validation() {
+        let testnet = BitcoinModule::testnet();
+        let regtest = BitcoinModule::regtest();
+
+        assert!(testnet.validate_address("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"));
+        assert!(!testnet.validate_address("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"));
+        assert!(!testnet.validate_address("bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080"));
+
+        assert!(regtest.validate_address("bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080"));
+        assert!(!regtest.validate_address("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx"));
+        // Base58 test addresses are shared across test networks
+        assert!(regtest.validate_address("mipcBbFg9gMiCh81Kj8tqqdgoZub1ZJRfn"));
+    }
+
     #[test]
     fn test_bitcoin_derivation_path() {
         let module = BitcoinModule::new();
@@ -263,4 +735,226 @@ mod tests {
         assert_eq!(addr1.address, addr2.address);
         assert_eq!(addr1.public_key, addr2.public_key);
     }
+
+    #[test]
+    fn test_script_type_modules() {
+        let legacy = BitcoinModule::new().with_script_type(BitcoinScriptType::P2pkh);
+        assert_eq!(legacy.chain_id(), "bitcoin_legacy");
+        assert_eq!(legacy.display_name(), "Bitcoin (Legacy)");
+        assert_eq!(legacy.icon_name(), "bitcoin");
+        assert_eq!(legacy.address_type(), Some("p2pkh"));
+
+        let taproot = BitcoinModule::testnet().with_script_type(BitcoinScriptType::P2tr);
+        assert_eq!(taproot.chain_id(), "bitcoin_testnet_taproot");
+        assert_eq!(taproot.icon_name(), "bitcoin_testnet");
+        assert!(taproot.is_testnet());
+
+        assert_eq!(BitcoinModule::new().icon_name(), "bitcoin");
+    }
+
+    #[test]
+    fn test_script_type_derivation_paths() {
+        let path = |script_type| {
+            BitcoinModule::new()
+                .with_script_type(script_type)
+                .derivation_path(0, 0)
+        };
+        assert_eq!(path(BitcoinScriptType::P2pkh), "m/44'/0'/0'/0/0");
+        assert_eq!(path(BitcoinScriptType::P2shP2wpkh), "m/49'/0'/0'/0/0");
+        assert_eq!(path(BitcoinScriptType::P2wpkh), "m/84'/0'/0'/0/0");
+        assert_eq!(path(BitcoinScriptType::P2tr), "m/86'/0'/0'/0/0");
+    }
+
+    #[test]
+    fn test_script_type_addresses() {
+        let seed = test_seed();
+        let address = |script_type| {
+            BitcoinModule::new()
+                .with_script_type(script_type)
+                .derive_address(&seed, 0, 0)
+                .unwrap()
+                .address
+        };
+
+        // Test vectors from BIP44/49/84/86 for the "abandon ... about" mnemonic
+        assert_eq!(
+            address(BitcoinScriptType::P2pkh),
+            "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"
+        );
+        assert_eq!(
+            address(BitcoinScriptType::P2shP2wpkh),
+            "37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf"
+        );
+        assert_eq!(
+            address(BitcoinScriptType::P2wpkh),
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        );
+        assert_eq!(
+            address(BitcoinScriptType::P2tr),
+            "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr"
+        );
+    }
+
+    #[test]
+    fn test_bitcoin_derivation_schemes() {
+        let module = BitcoinModule::new();
+        let seed = test_seed();
+
+        // Native SegWit module offers the other three purposes
+        let schemes = module.derivation_schemes();
+        let ids: Vec<&str> = schemes.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["bip44", "bip49", "bip86"]);
+
+        // BIP44 scheme yields the legacy address of the P2PKH module
+        let legacy = module
+            .derive_address_at_path(&seed, &schemes[0].path(0, 0))
+            .unwrap();
+        let p2pkh = BitcoinModule::new().with_script_type(BitcoinScriptType::P2pkh);
+        assert_eq!(
+            legacy.address,
+            p2pkh.derive_address(&seed, 0, 0).unwrap().address
+        );
+        assert!(legacy.address.starts_with('1'));
+
+        // Testnet schemes use coin type 1
+        let testnet = BitcoinModule::testnet().derivation_schemes();
+        assert_eq!(testnet[0].path(0, 0), "m/44'/1'/0'/0/0");
+
+        assert!(module
+            .derive_address_at_path(&seed, "m/0'/0'/0'/0/0")
+            .is_err());
+    }
+
+    #[test]
+    fn test_bitcoin_sign_and_verify_message() {
+        let module = BitcoinModule::new();
+        let seed = test_seed();
+
+        for script_type in BitcoinScriptType::ALL {
+            let path = format!("m/{}'/0'/0'/0/0", script_type.purpose());
+            let signed = module.sign_message(&seed, &path, b"Hello World").unwrap();
+            assert!(module
+                .verify_message(&signed.address, b"Hello World", &signed.signature)
+                .unwrap());
+            assert!(!module
+                .verify_message(&signed.address, b"Hello", &signed.signature)
+                .unwrap());
+        }
+
+        let signed = module
+            .sign_message(&seed, "m/84'/0'/0'/0/0", b"Hello World")
+            .unwrap();
+        assert_eq!(signed.address, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        assert_eq!(signed.scheme, "bip322-simple");
+
+        // Signature for another address of the same wallet does not verify
+        let other = module.derive_address(&seed, 0, 1).unwrap();
+        assert!(!module
+            .verify_message(&other.address, b"Hello World", &signed.signature)
+            .unwrap());
+        assert!(module
+            .verify_message("tb1qinvalid", b"Hello World", &signed.signature)
+            .is_err());
+    }
+
+    #[test]
+    fn test_bitcoin_sign_psbt() {
+        use bitcoin::bip32::{DerivationPath, Xpub};
+        use bitcoin::hashes::Hash;
+        use bitcoin::{absolute, transaction, Amount, OutPoint, Transaction, TxIn, TxOut, Txid};
+
+        let module = BitcoinModule::new();
+        let seed = test_seed();
+        let secp = Secp256k1::new();
+
+        let master = Xpriv::new_master(Network::Bitcoin, &seed).unwrap();
+        let path: DerivationPath = "m/84'/0'/0'/0/0".parse().unwrap();
+        let key = Xpub::from_priv(&secp, &master.derive_priv(&secp, &path).unwrap()).public_key;
+        let script_pubkey = BitcoinScriptType::P2wpkh
+            .address(&secp, &key, Network::Bitcoin)
+            .script_pubkey();
+
+        let tx = Transaction {
+            version: transaction::Version::TWO,
+            lock_time: absolute::LockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: OutPoint::new(Txid::all_zeros(), 0),
+                ..Default::default()
+            }],
+            output: vec![TxOut {
+                value: Amount::from_sat(9_000),
+                script_pubkey: script_pubkey.clone(),
+            }],
+        };
+        let mut psbt = Psbt::from_unsigned_tx(tx).unwrap();
+        psbt.inputs[0].witness_utxo = Some(TxOut {
+            value: Amount::from_sat(10_000),
+            script_pubkey,
+        });
+        psbt.inputs[0]
+            .bip32_derivation
+            .insert(key, (master.fingerprint(&secp), path));
+
+        let signed = module
+            .sign_transaction(&seed, "m/84'/0'/0'/0/0", &psbt.serialize())
+            .unwrap();
+        assert_eq!(signed.scheme, "psbt");
+        let signed_psbt: Psbt = signed.signature.parse().unwrap();
+        assert_eq!(signed_psbt.inputs[0].partial_sigs.len(), 1);
+
+        // A PSBT without inputs from this wallet is rejected
+        let other_seed = [7u8; 64];
+        assert!(module
+            .sign_transaction(&other_seed, "m/84'/0'/0'/0/0", &psbt.serialize())
+            .is_err());
+    }
+
+    #[test]
+    fn test_script_type_descriptor() {
+        assert_eq!(BitcoinScriptType::P2pkh.descriptor("K"), "pkh(K)");
+        assert_eq!(BitcoinScriptType::P2shP2wpkh.descriptor("K"), "sh(wpkh(K))");
+        assert_eq!(BitcoinScriptType::P2wpkh.descriptor("K"), "wpkh(K)");
+        assert_eq!(BitcoinScriptType::P2tr.descriptor("K"), "tr(K)");
+    }
+
+    #[test]
+    fn test_script_type_from_str() {
+        assert_eq!(
+            "p2tr".parse::<BitcoinScriptType>(),
+            Ok(BitcoinScriptType::P2tr)
+        );
+        assert_eq!(
+            "legacy".parse::<BitcoinScriptType>(),
+            Ok(BitcoinScriptType::P2pkh)
+        );
+        assert_eq!(
+            "p2sh-p2wpkh".parse::<BitcoinScriptType>(),
+            Ok(BitcoinScriptType::P2shP2wpkh)
+        );
+        assert!("p2wsh".parse::<BitcoinScriptType>().is_err());
+    }
+
+    #[test]
+    fn test_bitcoin_validate_address_details() {
+        let module = BitcoinModule::new();
+
+        let details = module.validate_address_details("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2");
+        assert!(details.is_valid);
+        assert_eq!(details.address_type.as_deref(), Some("p2pkh"));
+
+        let details = module.validate_address_details("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy");
+        assert_eq!(details.address_type.as_deref(), Some("p2sh"));
+
+        let details = module.validate_address_details("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        assert_eq!(details.address_type.as_deref(), Some("p2wpkh"));
+
+        let details = module.validate_address_details(
+            "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr",
+        );
+        assert_eq!(details.address_type.as_deref(), Some("p2tr"));
+
+        let details = module.validate_address_details("tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx");
+        assert!(!details.is_valid);
+        assert_eq!(details.address_type, None);
+    }
 }
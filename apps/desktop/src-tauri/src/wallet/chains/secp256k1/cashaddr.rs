@@ -0,0 +1,220 @@
+//! CashAddr encoding (Bitcoin Cash)
+//!
+//! Implements the CashAddr format from the Bitcoin Cash address spec:
+//! `prefix:payload` where the payload is base32 of `version || hash`
+//! followed by a 40-bit BCH checksum over the prefix and payload.
+
+use crate::wallet::error::{WalletError, WalletResult};
+
+/// CashAddr base32 alphabet
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Address type: pay to public key hash
+pub const TYPE_P2PKH: u8 = 0;
+/// Address type: pay to script hash
+pub const TYPE_P2SH: u8 = 1;
+
+/// Decoded CashAddr payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CashAddr {
+    /// Address type (`TYPE_P2PKH` or `TYPE_P2SH`)
+    pub address_type: u8,
+    /// Public key or script hash
+    pub hash: Vec<u8>,
+}
+
+/// BCH checksum polymod from the CashAddr spec
+fn polymod(values: impl Iterator<Item = u8>) -> u64 {
+    let mut c: u64 = 1;
+    for d in values {
+        let c0 = (c >> 35) as u8;
+        c = ((c & 0x07_ffff_ffff) << 5) ^ u64::from(d);
+        if c0 & 0x01 != 0 {
+            c ^= 0x98_f2bc_8e61;
+        }
+        if c0 & 0x02 != 0 {
+            c ^= 0x79_b76d_99e2;
+        }
+        if c0 & 0x04 != 0 {
+            c ^= 0xf3_3e5f_b3c4;
+        }
+        if c0 & 0x08 != 0 {
+            c ^= 0xae_2eab_e2a8;
+        }
+        if c0 & 0x10 != 0 {
+            c ^= 0x1e_4f43_e470;
+        }
+    }
+    c ^ 1
+}
+
+/// Prefix contribution to the checksum (lower 5 bits of each char, then a 0 separator)
+fn prefix_values(prefix: &str) -> impl Iterator<Item = u8> + '_ {
+    prefix.bytes().map(|b| b & 0x1f).chain(std::iter::once(0))
+}
+
+/// Regroup bits between 8-bit bytes and 5-bit words
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Option<Vec<u8>> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let max = (1u32 << to) - 1;
+    let mut out = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+
+    for &value in data {
+        if u32::from(value) >> from != 0 {
+            return None;
+        }
+        acc = (acc << from) | u32::from(value);
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            out.push(((acc >> bits) & max) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            out.push(((acc << (to - bits)) & max) as u8);
+        }
+    } else if bits >= from || (acc << (to - bits)) & max != 0 {
+        return None;
+    }
+
+    Some(out)
+}
+
+/// Size code for the version byte (bits 0-2)
+fn size_code(hash_len: usize) -> Option<u8> {
+    match hash_len {
+        20 => Some(0),
+        24 => Some(1),
+        28 => Some(2),
+        32 => Some(3),
+        40 => Some(4),
+        48 => Some(5),
+        56 => Some(6),
+        64 => Some(7),
+        _ => None,
+    }
+}
+
+/// Encode a hash as a CashAddr with the given prefix (e.g., "bitcoincash")
+pub fn encode(prefix: &str, address_type: u8, hash: &[u8]) -> WalletResult<String> {
+    let size = size_code(hash.len()).ok_or_else(|| {
+        WalletError::DerivationError(format!("Invalid CashAddr hash length: {}", hash.len()))
+    })?;
+
+    let mut payload = Vec::with_capacity(hash.len() + 1);
+    payload.push((address_type << 3) | size);
+    payload.extend_from_slice(hash);
+
+    let data = convert_bits(&payload, 8, 5, true)
+        .ok_or_else(|| WalletError::DerivationError("Invalid CashAddr payload".to_string()))?;
+
+    let checksum = polymod(
+        prefix_values(prefix)
+            .chain(data.iter().copied())
+            .chain([0u8; 8]),
+    );
+
+    let mut address = String::with_capacity(prefix.len() + 1 + data.len() + 8);
+    address.push_str(prefix);
+    address.push(':');
+    address.extend(data.iter().map(|&d| CHARSET[d as usize] as char));
+    address.extend((0..8).map(|i| CHARSET[((checksum >> (5 * (7 - i))) & 0x1f) as usize] as char));
+
+    Ok(address)
+}
+
+/// Decode a CashAddr, with or without its prefix
+///
+/// An explicit prefix must match `expected_prefix`.
+pub fn decode(address: &str, expected_prefix: &str) -> Option<CashAddr> {
+    let has_lower = address.bytes().any(|b| b.is_ascii_lowercase());
+    let has_upper = address.bytes().any(|b| b.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return None;
+    }
+
+    let address = address.to_ascii_lowercase();
+    let payload = match address.split_once(':') {
+        Some((prefix, payload)) if prefix == expected_prefix => payload,
+        Some(_) => return None,
+        None => address.as_str(),
+    };
+
+    let data = payload
+        .bytes()
+        .map(|b| CHARSET.iter().position(|&c| c == b).map(|p| p as u8))
+        .collect::<Option<Vec<u8>>>()?;
+    if data.len() < 8 + 1 {
+        return None;
+    }
+
+    if polymod(prefix_values(expected_prefix).chain(data.iter().copied())) != 0 {
+        return None;
+    }
+
+    let bytes = convert_bits(&data[..data.len() - 8], 5, 8, false)?;
+    let (&version, hash) = bytes.split_first()?;
+
+    // High bit is reserved
+    if version & 0x80 != 0 || size_code(hash.len()) != Some(version & 0x07) {
+        return None;
+    }
+
+    Some(CashAddr {
+        address_type: (version >> 3) & 0x0f,
+        hash: hash.to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Spec vector: 1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu
+    const HASH: &str = "76a04053bda0a88bda5177b86a15c3b29f559873";
+    const ADDRESS: &str = "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a";
+
+    #[test]
+    fn test_encode_spec_vector() {
+        let hash = hex::decode(HASH).unwrap();
+        assert_eq!(encode("bitcoincash", TYPE_P2PKH, &hash).unwrap(), ADDRESS);
+    }
+
+    #[test]
+    fn test_decode_spec_vector() {
+        let decoded = decode(ADDRESS, "bitcoincash").unwrap();
+        assert_eq!(decoded.address_type, TYPE_P2PKH);
+        assert_eq!(hex::encode(decoded.hash), HASH);
+
+        // Prefix is optional and case-insensitive (but not mixed case)
+        assert!(decode("qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a", "bitcoincash").is_some());
+        assert!(decode(&ADDRESS.to_uppercase(), "bitcoincash").is_some());
+        assert!(decode(
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvY22gdx6a",
+            "bitcoincash"
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_decode_rejects_bad_input() {
+        // Wrong prefix
+        assert!(decode(ADDRESS, "bchtest").is_none());
+        // Bad checksum
+        assert!(decode(
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6b",
+            "bitcoincash"
+        )
+        .is_none());
+        // Invalid character
+        assert!(decode(
+            "bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6i",
+            "bitcoincash"
+        )
+        .is_none());
+        assert!(decode("", "bitcoincash").is_none());
+    }
+}
@@ -6,16 +6,165 @@
 //! This module is used for Ethereum mainnet and all EVM-compatible chains
 //! (Arbitrum, Optimism, Base, Polygon, etc.) since they all share the same
 //! address format and derivation.
+//!
+//! Alternative schemes: Ledger Live (`m/44'/60'/x'/0/0`), MetaMask
+//! (`m/44'/60'/0'/0/x`) and Ledger legacy / MEW (`m/44'/60'/0'/x`).
+//!
+//! Signing: EIP-191 `personal_sign` for messages; transactions are signed
+//! over keccak256 of the unsigned serialization (legacy RLP or EIP-2718).
+
+use std::sync::OnceLock;
 
-use k256::ecdsa::SigningKey;
+use bip32::XPrv;
+use k256::ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey};
+use serde::{Deserialize, Serialize};
 use sha3::{Digest, Keccak256};
 
-use crate::wallet::chains::{coin_types, ChainModule};
+use crate::wallet::chains::{
+    coin_types, AccountXpub, ChainModule, ChainSignature, ChainSigner, DerivationScheme,
+    DEFAULT_SCHEME,
+};
 use crate::wallet::error::{WalletError, WalletResult};
 use crate::wallet::types::{ChainFamily, DerivedAddress};
 
 use super::derive_key_from_seed;
 
+/// Bundled EVM network definitions (see `evm_networks.json`)
+const BUNDLED_NETWORKS_JSON: &str = include_str!("evm_networks.json");
+
+/// Parsed bundled networks, loaded once on first use
+static BUNDLED_NETWORKS: OnceLock<Vec<EvmNetwork>> = OnceLock::new();
+
+/// Definition of an EVM-compatible network
+///
+/// Bundled networks ship in `evm_networks.json`; user-defined networks are
+/// stored in SQLite and registered with the `ChainRegistry` at runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvmNetwork {
+    /// Registry identifier (e.g., "ethereum", "arbitrum")
+    pub id: String,
+    /// Human-readable display name
+    pub name: String,
+    /// Native currency symbol
+    pub symbol: String,
+    /// EIP-155 chain ID (e.g., 1 for Ethereum mainnet)
+    pub chain_id: u64,
+    /// Native currency decimals
+    #[serde(default = "default_evm_decimals")]
+    pub decimals: u8,
+    /// JSON-RPC endpoints, in order of preference
+    #[serde(default)]
+    pub rpc_urls: Vec<String>,
+    /// Block explorer base URL
+    #[serde(default)]
+    pub explorer_url: Option<String>,
+    /// Whether this is a testnet
+    #[serde(default)]
+    pub is_testnet: bool,
+    /// Whether this network was added by the user
+    #[serde(default)]
+    pub is_custom: bool,
+}
+
+fn default_evm_decimals() -> u8 {
+    18
+}
+
+impl EvmNetwork {
+    /// Validate a network definition before registering it
+    pub fn validate(&self) -> WalletResult<()> {
+        let valid_id = !self.id.is_empty()
+            && self
+                .id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-');
+        if !valid_id {
+            return Err(WalletError::InvalidNetwork(format!(
+                "network id '{}' must be lowercase alphanumeric (with '_' or '-')",
+                self.id
+            )));
+        }
+
+        if self.name.trim().is_empty() || self.symbol.trim().is_empty() {
+            return Err(WalletError::InvalidNetwork(format!(
+                "network '{}' needs a name and a symbol",
+                self.id
+            )));
+        }
+
+        if self.chain_id == 0 {
+            return Err(WalletError::InvalidNetwork(format!(
+                "network '{}' has no EIP-155 chain id",
+                self.id
+            )));
+        }
+
+        let valid_url = |url: &str| url.starts_with("https://") || url.starts_with("http://");
+        if let Some(url) = self.rpc_urls.iter().find(|url| !valid_url(url)) {
+            return Err(WalletError::InvalidNetwork(format!(
+                "invalid RPC URL: {}",
+                url
+            )));
+        }
+        if let Some(url) = self.explorer_url.as_deref().filter(|url| !valid_url(url)) {
+            return Err(WalletError::InvalidNetwork(format!(
+                "invalid explorer URL: {}",
+                url
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Compute the 20-byte keccak address for a derived secp256k1 key
+///
+/// Address = last 20 bytes of Keccak256(uncompressed public key without 0x04).
+/// Shared by Ethereum/EVM and Tron, which only differ in address encoding.
+///
+/// # Returns
+/// The address bytes and the uncompressed (65-byte) public key
+pub(crate) fn keccak_address(derived_key: &XPrv) -> WalletResult<([u8; 20], Vec<u8>)> {
+    // Get the private key bytes
+    let private_key_bytes = derived_key.private_key().to_bytes();
+
+    // Create signing key
+    let signing_key = SigningKey::from_bytes(&private_key_bytes)
+        .map_err(|e| WalletError::DerivationError(format!("Invalid private key: {}", e)))?;
+
+    // Get the public key (uncompressed)
+    let public_key_point = signing_key.verifying_key().to_encoded_point(false);
+    let public_key_bytes = public_key_point.as_bytes();
+
+    Ok((
+        keccak_public_key_address(public_key_bytes),
+        public_key_bytes.to_vec(),
+    ))
+}
+
+/// Address bytes of an uncompressed (0x04-prefixed) public key
+pub(crate) fn keccak_public_key_address(public_key: &[u8]) -> [u8; 20] {
+    // Hash the public key without the 0x04 prefix
+    let mut hasher = Keccak256::new();
+    hasher.update(&public_key[1..]);
+    let hash = hasher.finalize();
+
+    // Take last 20 bytes
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// EIP-191 `personal_sign` hash
+///
+/// keccak256("\x19Ethereum Signed Message:\n" + len(message) + message)
+pub fn eip191_hash(message: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(format!("\x19Ethereum Signed Message:\n{}", message.len()).as_bytes());
+    hasher.update(message);
+    hasher.finalize().into()
+}
+
 /// Ethereum/EVM chain module
 ///
 /// Works for Ethereum mainnet and all EVM-compatible L2s:
@@ -25,41 +174,66 @@ use super::derive_key_from_seed;
 /// - Polygon
 /// - etc.
 pub struct EthereumModule {
-    chain_id: String,
-    display_name: String,
-    symbol: String,
-    is_testnet: bool,
+    network: EvmNetwork,
 }
 
 impl EthereumModule {
-    /// Create a new Ethereum mainnet module
+    /// Create a module for a bundled network by its registry ID
+    ///
+    /// Unknown IDs fall back to an ETH-denominated network without an
+    /// EIP-155 chain ID.
     pub fn new(chain_id: &str) -> Self {
-        let (display_name, symbol) = match chain_id {
-            "ethereum" => ("Ethereum", "ETH"),
-            "arbitrum" => ("Arbitrum One", "ETH"),
-            "optimism" => ("Optimism", "ETH"),
-            "base" => ("Base", "ETH"),
-            "polygon" => ("Polygon", "MATIC"),
-            "avalanche" => ("Avalanche C-Chain", "AVAX"),
-            _ => (chain_id, "ETH"),
-        };
+        let network = Self::bundled_networks()
+            .into_iter()
+            .find(|n| n.id == chain_id)
+            .unwrap_or_else(|| EvmNetwork {
+                id: chain_id.to_string(),
+                name: chain_id.to_string(),
+                symbol: "ETH".to_string(),
+                chain_id: 0,
+                decimals: default_evm_decimals(),
+                rpc_urls: Vec::new(),
+                explorer_url: None,
+                is_testnet: false,
+                is_custom: false,
+            });
 
-        Self {
-            chain_id: chain_id.to_string(),
-            display_name: display_name.to_string(),
-            symbol: symbol.to_string(),
-            is_testnet: false,
-        }
+        Self::from_network(network)
+    }
+
+    /// Create a module from a network definition
+    pub fn from_network(network: EvmNetwork) -> Self {
+        Self { network }
     }
 
     /// Create a testnet module
     pub fn testnet(chain_id: &str, display_name: &str) -> Self {
-        Self {
-            chain_id: chain_id.to_string(),
-            display_name: display_name.to_string(),
+        Self::from_network(EvmNetwork {
+            id: chain_id.to_string(),
+            name: display_name.to_string(),
             symbol: "ETH".to_string(),
+            chain_id: 0,
+            decimals: default_evm_decimals(),
+            rpc_urls: Vec::new(),
+            explorer_url: None,
             is_testnet: true,
-        }
+            is_custom: false,
+        })
+    }
+
+    /// All network definitions bundled with the app
+    pub fn bundled_networks() -> Vec<EvmNetwork> {
+        BUNDLED_NETWORKS
+            .get_or_init(|| {
+                serde_json::from_str(BUNDLED_NETWORKS_JSON)
+                    .expect("bundled evm_networks.json must be valid")
+            })
+            .clone()
+    }
+
+    /// The network definition backing this module
+    pub fn network(&self) -> &EvmNetwork {
+        &self.network
     }
 
     /// Ethereum mainnet
@@ -92,8 +266,13 @@ impl EthereumModule {
         Self::new("avalanche")
     }
 
+    /// Sepolia testnet
+    pub fn sepolia() -> Self {
+        Self::new("sepolia")
+    }
+
     /// Convert address to EIP-55 checksum format
-    fn to_checksum_address(address: &str) -> String {
+    pub fn to_checksum_address(address: &str) -> String {
         let address_lower = address.trim_start_matches("0x").to_lowercase();
 
         // Hash the lowercase address
@@ -143,6 +322,105 @@ impl EthereumModule {
     }
 }
 
+// =============================================================================
+// Signing
+// =============================================================================
+
+impl EthereumModule {
+    /// Sign a 32-byte hash with the key at `path`
+    ///
+    /// Returns `r || s || recovery_id` with a recovery ID of 0 or 1. Message
+    /// signatures (EIP-191, EIP-712) add 27 to get the usual `v`.
+    pub fn sign_prehash(seed: &[u8; 64], path: &str, hash: &[u8; 32]) -> WalletResult<[u8; 65]> {
+        let derived_key = derive_key_from_seed(seed, path)?;
+        let signing_key = SigningKey::from_bytes(&derived_key.private_key().to_bytes())
+            .map_err(|e| WalletError::SigningError(format!("Invalid private key: {}", e)))?;
+
+        let (signature, recovery_id) = signing_key
+            .sign_prehash_recoverable(hash)
+            .map_err(|e| WalletError::SigningError(e.to_string()))?;
+
+        let mut bytes = [0u8; 65];
+        bytes[..64].copy_from_slice(&signature.to_bytes());
+        bytes[64] = recovery_id.to_byte();
+        Ok(bytes)
+    }
+
+    /// Recover the checksummed signer address of a 65-byte signature
+    ///
+    /// Accepts `v` as either 0/1 or 27/28.
+    pub fn recover_address(hash: &[u8; 32], signature: &[u8]) -> Option<String> {
+        let (rs, v) = signature.split_at_checked(64)?;
+        let recovery_id = match v {
+            [v @ (27 | 28)] => RecoveryId::from_byte(v - 27)?,
+            [v @ (0 | 1)] => RecoveryId::from_byte(*v)?,
+            _ => return None,
+        };
+
+        let signature = Signature::from_slice(rs).ok()?;
+        let key = VerifyingKey::recover_from_prehash(hash, &signature, recovery_id).ok()?;
+
+        let public_key = key.to_encoded_point(false);
+        let hash = Keccak256::digest(&public_key.as_bytes()[1..]);
+        Some(Self::to_checksum_address(&format!(
+            "0x{}",
+            hex::encode(&hash[12..])
+        )))
+    }
+}
+
+impl ChainSigner for EthereumModule {
+    fn sign_message(
+        &self,
+        seed: &[u8; 64],
+        path: &str,
+        message: &[u8],
+    ) -> WalletResult<ChainSignature> {
+        let mut signature = Self::sign_prehash(seed, path, &eip191_hash(message))?;
+        signature[64] += 27;
+
+        Ok(ChainSignature {
+            address: self.derive_address_at_path(seed, path)?.address,
+            scheme: "eip191".to_string(),
+            signature: format!("0x{}", hex::encode(signature)),
+        })
+    }
+
+    fn sign_transaction(
+        &self,
+        seed: &[u8; 64],
+        path: &str,
+        payload: &[u8],
+    ) -> WalletResult<ChainSignature> {
+        // The payload is the unsigned serialization; its keccak256 is the
+        // signing hash. The signature's last byte is the y-parity (0/1).
+        let hash: [u8; 32] = Keccak256::digest(payload).into();
+        let signature = Self::sign_prehash(seed, path, &hash)?;
+
+        Ok(ChainSignature {
+            address: self.derive_address_at_path(seed, path)?.address,
+            scheme: "keccak256".to_string(),
+            signature: format!("0x{}", hex::encode(signature)),
+        })
+    }
+
+    fn verify_message(&self, address: &str, message: &[u8], signature: &str) -> WalletResult<bool> {
+        if !self.validate_address(address) {
+            return Err(WalletError::InvalidAddress {
+                chain: self.chain_id().to_string(),
+                address: address.to_string(),
+            });
+        }
+
+        let Ok(signature) = hex::decode(signature.trim_start_matches("0x")) else {
+            return Ok(false);
+        };
+
+        Ok(Self::recover_address(&eip191_hash(message), &signature)
+            .is_some_and(|signer| signer.eq_ignore_ascii_case(address)))
+    }
+}
+
 impl Default for EthereumModule {
     fn default() -> Self {
         Self::ethereum()
@@ -151,11 +429,11 @@ impl Default for EthereumModule {
 
 impl ChainModule for EthereumModule {
     fn chain_id(&self) -> &str {
-        &self.chain_id
+        &self.network.id
     }
 
     fn display_name(&self) -> &str {
-        &self.display_name
+        &self.network.name
     }
 
     fn chain_family(&self) -> ChainFamily {
@@ -167,11 +445,28 @@ impl ChainModule for EthereumModule {
     }
 
     fn is_testnet(&self) -> bool {
-        self.is_testnet
+        self.network.is_testnet
     }
 
     fn symbol(&self) -> &str {
-        &self.symbol
+        &self.network.symbol
+    }
+
+    fn decimals(&self) -> u8 {
+        self.network.decimals
+    }
+
+    fn evm_chain_id(&self) -> Option<u64> {
+        // 0 is not a valid EIP-155 chain ID; it marks ad-hoc modules
+        Some(self.network.chain_id).filter(|id| *id != 0)
+    }
+
+    fn rpc_urls(&self) -> Vec<String> {
+        self.network.rpc_urls.clone()
+    }
+
+    fn explorer_url(&self) -> Option<&str> {
+        self.network.explorer_url.as_deref()
     }
 
     fn derive_address(
@@ -181,42 +476,58 @@ impl ChainModule for EthereumModule {
         index: u32,
     ) -> WalletResult<DerivedAddress> {
         // BIP44 path for Ethereum: m/44'/60'/account'/0/index
-        let path = self.derivation_path(account, index);
-        let derived_key = derive_key_from_seed(seed, &path)?;
-
-        // Get the private key bytes
-        let private_key_bytes = derived_key.private_key().to_bytes();
-
-        // Create signing key
-        let signing_key = SigningKey::from_bytes((&private_key_bytes).into())
-            .map_err(|e| WalletError::DerivationError(format!("Invalid private key: {}", e)))?;
-
-        // Get the public key (uncompressed)
-        let verifying_key = signing_key.verifying_key();
-        let public_key_point = verifying_key.to_encoded_point(false);
-        let public_key_bytes = public_key_point.as_bytes();
+        self.derive_address_at_path(seed, &self.derivation_path(account, index))
+    }
 
-        // Ethereum uses uncompressed public key without the 0x04 prefix
-        // Address = last 20 bytes of Keccak256(public_key[1..])
-        let public_key_no_prefix = &public_key_bytes[1..]; // Remove 0x04 prefix
+    fn derivation_schemes(&self) -> Vec<DerivationScheme> {
+        vec![
+            DerivationScheme::new("ledger_live", "Ledger Live", "m/44'/60'/{account}'/0/0"),
+            DerivationScheme::new("metamask", "MetaMask", "m/44'/60'/0'/0/{account}"),
+            DerivationScheme::new(
+                "ledger_legacy",
+                "Ledger Legacy (MEW, MyCrypto)",
+                "m/44'/60'/0'/{account}",
+            ),
+        ]
+    }
 
-        let mut hasher = Keccak256::new();
-        hasher.update(public_key_no_prefix);
-        let hash = hasher.finalize();
+    fn derive_address_at_path(&self, seed: &[u8; 64], path: &str) -> WalletResult<DerivedAddress> {
+        let derived_key = derive_key_from_seed(seed, path)?;
+        let (address_bytes, public_key_bytes) = keccak_address(&derived_key)?;
 
-        // Take last 20 bytes
-        let address_bytes = &hash[12..];
         let address = format!("0x{}", hex::encode(address_bytes));
 
         // Apply EIP-55 checksum
         let checksum_address = Self::to_checksum_address(&address);
 
         Ok(DerivedAddress {
-            chain: self.chain_id.clone(),
+            chain: self.network.id.clone(),
             chain_family: self.chain_family(),
             address: checksum_address,
-            derivation_path: path,
-            public_key: public_key_bytes.to_vec(),
+            derivation_path: path.to_string(),
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key_bytes,
+        })
+    }
+
+    fn derive_address_from_xpub(
+        &self,
+        account_key: &AccountXpub,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        account_key.check_mainnet(self.chain_id(), self.is_testnet())?;
+        let public_key = account_key
+            .public_key_at(0, index)?
+            .serialize_uncompressed();
+        let address = format!("0x{}", hex::encode(keccak_public_key_address(&public_key)));
+
+        Ok(DerivedAddress {
+            chain: self.network.id.clone(),
+            chain_family: self.chain_family(),
+            address: Self::to_checksum_address(&address),
+            derivation_path: self.derivation_path(account_key.account(), index),
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key.to_vec(),
         })
     }
 
@@ -241,6 +552,14 @@ impl ChainModule for EthereumModule {
         format!("m/44'/{}'/{account}'/0/{index}", self.coin_type())
     }
 
+    fn account_node_path(&self, account: u32) -> Option<String> {
+        Some(format!("m/44'/{}'/{account}'", self.coin_type()))
+    }
+
+    fn signer(&self) -> Option<&dyn ChainSigner> {
+        Some(self)
+    }
+
     fn address_prefix(&self) -> Option<&str> {
         Some("0x")
     }
@@ -315,6 +634,95 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ethereum_derivation_schemes() {
+        let module = EthereumModule::ethereum();
+        let seed = test_seed();
+        let schemes = module.derivation_schemes();
+        let scheme = |id: &str| schemes.iter().find(|s| s.id == id).unwrap();
+
+        // MetaMask account N is the default scheme's address index N
+        let metamask = module
+            .derive_address_at_path(&seed, &scheme("metamask").path(1, 0))
+            .unwrap();
+        assert_eq!(
+            metamask.address,
+            module.derive_address(&seed, 0, 1).unwrap().address
+        );
+
+        // Ledger Live account 0 matches the default, later accounts diverge
+        let ledger_live = scheme("ledger_live");
+        let account0 = module
+            .derive_address_at_path(&seed, &ledger_live.path(0, 0))
+            .unwrap();
+        assert_eq!(
+            account0.address,
+            module.derive_address(&seed, 0, 0).unwrap().address
+        );
+        let account1 = module
+            .derive_address_at_path(&seed, &ledger_live.path(1, 0))
+            .unwrap();
+        assert_eq!(account1.derivation_path, "m/44'/60'/1'/0/0");
+        assert_ne!(account1.address, metamask.address);
+
+        let legacy = module
+            .derive_address_at_path(&seed, &scheme("ledger_legacy").path(0, 0))
+            .unwrap();
+        assert_eq!(legacy.derivation_path, "m/44'/60'/0'/0");
+    }
+
+    #[test]
+    fn test_ethereum_sign_and_verify_message() {
+        let module = EthereumModule::ethereum();
+        let seed = test_seed();
+
+        let signed = module
+            .sign_message(&seed, "m/44'/60'/0'/0/0", b"hello")
+            .unwrap();
+        assert_eq!(signed.address, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(signed.scheme, "eip191");
+        assert_eq!(signed.signature.len(), 2 + 130);
+        assert!(signed.signature.ends_with("1b") || signed.signature.ends_with("1c"));
+
+        assert!(module
+            .verify_message(&signed.address, b"hello", &signed.signature)
+            .unwrap());
+        assert!(module
+            .verify_message(&signed.address.to_lowercase(), b"hello", &signed.signature)
+            .unwrap());
+        assert!(!module
+            .verify_message(&signed.address, b"hello!", &signed.signature)
+            .unwrap());
+        assert!(!module
+            .verify_message(&signed.address, b"hello", "0x1234")
+            .unwrap());
+        assert!(module
+            .verify_message("0x1234", b"hello", &signed.signature)
+            .is_err());
+    }
+
+    #[test]
+    fn test_ethereum_sign_transaction() {
+        let module = EthereumModule::ethereum();
+        let seed = test_seed();
+        let payload = hex::decode(
+            "02e5018084773594008504a817c80082520894000000000000000000000000000000000000dead8080c0",
+        )
+        .unwrap();
+
+        let signed = module
+            .sign_transaction(&seed, "m/44'/60'/0'/0/0", &payload)
+            .unwrap();
+        let signature = hex::decode(&signed.signature[2..]).unwrap();
+        assert!(signature[64] <= 1);
+
+        let hash: [u8; 32] = Keccak256::digest(&payload).into();
+        assert_eq!(
+            EthereumModule::recover_address(&hash, &signature).unwrap(),
+            signed.address
+        );
+    }
+
     #[test]
     fn test_ethereum_derive_multiple_addresses() {
         let module = EthereumModule::ethereum();
@@ -416,4 +824,75 @@ mod tests {
         assert_eq!(arb.chain, "arbitrum");
         assert_eq!(opt.chain, "optimism");
     }
+
+    #[test]
+    fn test_bundled_networks() {
+        let networks = EthereumModule::bundled_networks();
+        assert!(networks.iter().all(|n| n.validate().is_ok()));
+        assert!(networks.iter().all(|n| !n.is_custom));
+
+        let ethereum = EthereumModule::ethereum();
+        assert_eq!(ethereum.evm_chain_id(), Some(1));
+        assert_eq!(ethereum.decimals(), 18);
+        assert_eq!(ethereum.explorer_url(), Some("https://etherscan.io"));
+        assert!(!ethereum.rpc_urls().is_empty());
+
+        let avalanche = EthereumModule::avalanche();
+        assert_eq!(avalanche.display_name(), "Avalanche C-Chain");
+        assert_eq!(avalanche.symbol(), "AVAX");
+        assert_eq!(avalanche.evm_chain_id(), Some(43114));
+
+        let sepolia = EthereumModule::sepolia();
+        assert!(sepolia.is_testnet());
+        assert_eq!(sepolia.evm_chain_id(), Some(11155111));
+    }
+
+    #[test]
+    fn test_custom_network_module() {
+        let network = EvmNetwork {
+            id: "zksync".to_string(),
+            name: "zkSync Era".to_string(),
+            symbol: "ETH".to_string(),
+            chain_id: 324,
+            decimals: 18,
+            rpc_urls: vec!["https://mainnet.era.zksync.io".to_string()],
+            explorer_url: Some("https://explorer.zksync.io".to_string()),
+            is_testnet: false,
+            is_custom: true,
+        };
+        assert!(network.validate().is_ok());
+
+        let module = EthereumModule::from_network(network);
+        assert_eq!(module.chain_id(), "zksync");
+        assert_eq!(module.evm_chain_id(), Some(324));
+
+        // Custom networks derive the same address as every other EVM chain
+        let seed = test_seed();
+        let derived = module.derive_address(&seed, 0, 0).unwrap();
+        assert_eq!(derived.chain, "zksync");
+        assert_eq!(
+            derived.address.to_lowercase(),
+            "0x9858effd232b4033e47d90003d41ec34ecaeda94"
+        );
+    }
+
+    #[test]
+    fn test_network_validation() {
+        let valid = EthereumModule::ethereum().network().clone();
+
+        let mut bad_id = valid.clone();
+        bad_id.id = "My Chain".to_string();
+        assert!(bad_id.validate().is_err());
+
+        let mut no_chain_id = valid.clone();
+        no_chain_id.chain_id = 0;
+        assert!(no_chain_id.validate().is_err());
+
+        let mut bad_rpc = valid.clone();
+        bad_rpc.rpc_urls = vec!["ws://localhost:8545".to_string()];
+        assert!(bad_rpc.validate().is_err());
+
+        // Unknown IDs have no EIP-155 chain ID
+        assert_eq!(EthereumModule::new("unknown").evm_chain_id(), None);
+    }
 }
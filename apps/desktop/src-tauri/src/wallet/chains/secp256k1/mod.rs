@@ -1,17 +1,31 @@
-//! Secp256k1 chain implementations (Bitcoin, Ethereum, Cosmos, Avalanche)
+//! Secp256k1 chain implementations (Bitcoin and its forks, Ethereum, Tron, XRP Ledger, Nostr, Cosmos, Avalanche)
 //!
 //! All chains in this module use the secp256k1 elliptic curve with ECDSA signatures.
 //! Key derivation follows BIP32/BIP44 standards.
 
+pub mod bip322;
 pub mod bitcoin;
+pub mod cashaddr;
 pub mod ethereum;
+pub mod nostr;
+pub mod slip132;
+pub mod tron;
+pub mod utxo;
+pub mod xrpl;
 
 // Common utilities for secp256k1 chains
 
+use std::sync::OnceLock;
+
+use ::bitcoin::bip32::{self as btc_bip32, Xpub};
+use ::bitcoin::secp256k1::{PublicKey, Secp256k1, VerifyOnly};
+use ::bitcoin::NetworkKind;
 use bip32::{ChildNumber, DerivationPath, XPrv};
 
 use crate::wallet::error::{WalletError, WalletResult};
 
+use self::slip132::{ExtendedKey, Slip132Version};
+
 /// Derive a child extended private key from seed using BIP32
 ///
 /// # Arguments
@@ -21,15 +35,23 @@ use crate::wallet::error::{WalletError, WalletResult};
 /// # Returns
 /// The derived extended private key
 pub fn derive_key_from_seed(seed: &[u8; 64], path: &str) -> WalletResult<XPrv> {
+    let master = XPrv::new(seed)
+        .map_err(|e| WalletError::DerivationError(format!("Failed to create master key: {}", e)))?;
+
+    derive_key_from_master(&master, path)
+}
+
+/// Derive a child extended private key from a master key using BIP32
+///
+/// Same as `derive_key_from_seed`, for callers that already hold the master
+/// key (e.g., one imported as an xprv).
+pub fn derive_key_from_master(master: &XPrv, path: &str) -> WalletResult<XPrv> {
     let path: DerivationPath = path
         .parse()
         .map_err(|e| WalletError::DerivationError(format!("Invalid path '{}': {}", path, e)))?;
 
-    let master = XPrv::new(seed)
-        .map_err(|e| WalletError::DerivationError(format!("Failed to create master key: {}", e)))?;
-
     // Derive through each path component
-    let mut derived = master;
+    let mut derived = master.clone();
     for child_number in path.as_ref() {
         derived = derived
             .derive_child(*child_number)
@@ -39,6 +61,166 @@ pub fn derive_key_from_seed(seed: &[u8; 64], path: &str) -> WalletResult<XPrv> {
     Ok(derived)
 }
 
+/// Shared secp256k1 verification context for public derivation
+///
+/// Creating a context allocates, which dominates the cost of deriving an
+/// address from a cached account key; every caller reuses this one.
+pub(crate) fn verification_context() -> &'static Secp256k1<VerifyOnly> {
+    static CONTEXT: OnceLock<Secp256k1<VerifyOnly>> = OnceLock::new();
+    CONTEXT.get_or_init(Secp256k1::verification_only)
+}
+
+// =============================================================================
+// Watch-only Derivation
+// =============================================================================
+
+/// Account-level extended public key (`m/purpose'/coin_type'/account'`)
+///
+/// Watch-only wallets derive receive addresses at `account/0/index` from it
+/// without any secrets. SLIP-132 versions (ypub, zpub, ...) are accepted and
+/// kept as a script type hint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountXpub {
+    /// Key as given by the user, SLIP-132 prefix preserved
+    pub encoded: String,
+    pub xpub: Xpub,
+    pub version: &'static Slip132Version,
+}
+
+impl AccountXpub {
+    /// Parse an account-level extended public key
+    pub fn parse(input: &str) -> WalletResult<Self> {
+        let input = input.trim();
+        let (version, key) = slip132::decode(input).ok_or_else(|| {
+            WalletError::InvalidExtendedKey("not a valid extended public key".to_string())
+        })?;
+
+        let ExtendedKey::Public(xpub) = key else {
+            return Err(WalletError::InvalidExtendedKey(format!(
+                "{} is a private key; provide the matching public key",
+                version.prefix
+            )));
+        };
+
+        if xpub.depth != 3 || xpub.child_number.is_normal() {
+            return Err(WalletError::InvalidExtendedKey(format!(
+                "expected an account-level key (depth 3, hardened), got depth {}",
+                xpub.depth
+            )));
+        }
+
+        Ok(Self {
+            encoded: input.to_string(),
+            xpub,
+            version,
+        })
+    }
+
+    /// Account key derived from a seed at `path` (e.g., `m/84'/0'/0'`)
+    ///
+    /// Gets the plain xpub/tpub version, so it matches any script type of
+    /// its network. Used as the cached account node for bulk derivation.
+    pub fn from_seed(seed: &[u8; 64], path: &str, is_testnet: bool) -> WalletResult<Self> {
+        let account = derive_key_from_seed(seed, path)?;
+        let attrs = account.attrs();
+        let public_key = PublicKey::from_slice(&account.public_key().to_bytes())
+            .map_err(|e| WalletError::DerivationError(format!("Invalid public key: {}", e)))?;
+
+        let xpub = Xpub {
+            network: if is_testnet {
+                NetworkKind::Test
+            } else {
+                NetworkKind::Main
+            },
+            depth: attrs.depth,
+            parent_fingerprint: attrs.parent_fingerprint.into(),
+            child_number: attrs.child_number.0.into(),
+            public_key,
+            chain_code: attrs.chain_code.into(),
+        };
+        let version =
+            Slip132Version::from_bytes(&xpub.encode()[..4]).expect("standard xpub/tpub version");
+
+        Ok(Self {
+            encoded: xpub.to_string(),
+            xpub,
+            version,
+        })
+    }
+
+    /// Account number from the key's hardened child number
+    pub fn account(&self) -> u32 {
+        match self.xpub.child_number {
+            btc_bip32::ChildNumber::Hardened { index }
+            | btc_bip32::ChildNumber::Normal { index } => index,
+        }
+    }
+
+    /// Check the key's SLIP-132 hints against a chain's network and script type
+    ///
+    /// Plain xpub/tpub keys carry no script type and match any.
+    pub fn check_compatible(
+        &self,
+        chain_id: &str,
+        is_testnet: bool,
+        script_type: &str,
+    ) -> WalletResult<()> {
+        if self.version.is_testnet != is_testnet {
+            return Err(WalletError::InvalidExtendedKey(format!(
+                "{} is a {} key, {} is a {}",
+                self.version.prefix,
+                if self.version.is_testnet {
+                    "testnet"
+                } else {
+                    "mainnet"
+                },
+                chain_id,
+                if is_testnet { "testnet" } else { "mainnet" },
+            )));
+        }
+
+        match self.version.script_type {
+            Some(hint) if hint != script_type => Err(WalletError::InvalidExtendedKey(format!(
+                "{} keys are for {} addresses, {} uses {}",
+                self.version.prefix, hint, chain_id, script_type
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Refuse testnet keys (tpub, vpub, ...) for a mainnet chain
+    ///
+    /// For account-based chains, whose test networks derive like mainnet
+    /// and so accept either kind of key.
+    pub fn check_mainnet(&self, chain_id: &str, is_testnet: bool) -> WalletResult<()> {
+        if self.version.is_testnet && !is_testnet {
+            return Err(WalletError::InvalidExtendedKey(format!(
+                "{} is a testnet key, {} is a mainnet",
+                self.version.prefix, chain_id
+            )));
+        }
+        Ok(())
+    }
+
+    /// Public key at `account/change/index` (both levels non-hardened)
+    pub fn public_key_at(&self, change: u32, index: u32) -> WalletResult<PublicKey> {
+        let path = [
+            btc_bip32::ChildNumber::from_normal_idx(change),
+            btc_bip32::ChildNumber::from_normal_idx(index),
+        ]
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| {
+            WalletError::DerivationError(format!("Invalid public derivation index: {}", e))
+        })?;
+
+        self.xpub
+            .derive_pub(verification_context(), &path)
+            .map(|child| child.public_key)
+            .map_err(|e| WalletError::DerivationError(format!("Public derivation failed: {}", e)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,4 +247,41 @@ mod tests {
         let result = derive_key_from_seed(&seed, "invalid/path");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_account_xpub_public_derivation() {
+        // BIP84 test vector, account 0
+        let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+        let account_key = AccountXpub::parse(zpub).unwrap();
+        assert_eq!(account_key.account(), 0);
+        assert_eq!(account_key.version.script_type, Some("p2wpkh"));
+
+        let seed = test_seed();
+        for index in [0, 1, 7] {
+            let private = derive_key_from_seed(&seed, &format!("m/84'/0'/0'/0/{index}")).unwrap();
+            let public = account_key.public_key_at(0, index).unwrap();
+            assert_eq!(
+                public.serialize().to_vec(),
+                private.public_key().to_bytes().to_vec()
+            );
+        }
+
+        assert!(account_key.public_key_at(0, 0x8000_0000).is_err());
+    }
+
+    #[test]
+    fn test_account_xpub_rejects_unsuitable_keys() {
+        // Master private key (BIP32 test vector 1)
+        let xprv = "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi";
+        assert!(matches!(
+            AccountXpub::parse(xprv),
+            Err(WalletError::InvalidExtendedKey(_))
+        ));
+
+        // Master public key: depth 0
+        let xpub = "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8";
+        assert!(AccountXpub::parse(xpub).is_err());
+
+        assert!(AccountXpub::parse("not a key").is_err());
+    }
 }
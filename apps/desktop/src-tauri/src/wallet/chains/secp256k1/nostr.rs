@@ -0,0 +1,539 @@
+//! Nostr identity keys (NIP-06)
+//!
+//! Derives Nostr keys from the wallet seed, so an identity can be recovered
+//! from the same backup as the funds.
+//! Path: m/44'/1237'/account'/0/index (NIP-06 identities use index 0)
+//!
+//! Nostr public keys are BIP-340 x-only keys, shown as bech32 `npub` (NIP-19);
+//! the private key exports as `nsec`. Events are signed with BIP-340 Schnorr
+//! over the NIP-01 event ID.
+//!
+//! Signing: messages are signed over their SHA-256 digest; transactions are
+//! unsigned events as JSON, returned as the signed event JSON.
+
+use bitcoin::bech32::{self, Bech32, Hrp};
+use bitcoin::secp256k1::schnorr::Signature;
+use bitcoin::secp256k1::{Keypair, Message, Secp256k1, SecretKey, XOnlyPublicKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::wallet::chains::{
+    coin_types, AccountXpub, ChainModule, ChainSignature, ChainSigner, DEFAULT_SCHEME,
+};
+use crate::wallet::error::{WalletError, WalletResult};
+use crate::wallet::types::{ChainFamily, DerivedAddress};
+
+use super::{derive_key_from_seed, verification_context};
+
+/// Registry ID of the Nostr module
+pub const NOSTR_CHAIN_ID: &str = "nostr";
+
+/// NIP-19 prefix of public keys
+const NPUB_HRP: &str = "npub";
+
+/// NIP-19 prefix of private keys
+const NSEC_HRP: &str = "nsec";
+
+// =============================================================================
+// NIP-19 Encoding
+// =============================================================================
+
+fn encode_bech32(hrp: &str, data: &[u8; 32]) -> String {
+    let hrp = Hrp::parse(hrp).expect("valid NIP-19 prefix");
+    bech32::encode::<Bech32>(hrp, data).expect("32-byte payload fits in bech32")
+}
+
+fn decode_bech32(hrp: &str, encoded: &str) -> Option<[u8; 32]> {
+    let (decoded_hrp, data) = bech32::decode(encoded).ok()?;
+    if decoded_hrp.as_str() != hrp {
+        return None;
+    }
+    data.try_into().ok()
+}
+
+/// Encode an x-only public key as `npub1...`
+pub fn encode_npub(public_key: &[u8; 32]) -> String {
+    encode_bech32(NPUB_HRP, public_key)
+}
+
+/// Decode an `npub1...` into its x-only public key
+pub fn decode_npub(npub: &str) -> Option<[u8; 32]> {
+    decode_bech32(NPUB_HRP, npub)
+}
+
+/// Encode a private key as `nsec1...`
+pub fn encode_nsec(secret_key: &[u8; 32]) -> Zeroizing<String> {
+    Zeroizing::new(encode_bech32(NSEC_HRP, secret_key))
+}
+
+/// Decode an `nsec1...` into its private key
+pub fn decode_nsec(nsec: &str) -> Option<Zeroizing<[u8; 32]>> {
+    decode_bech32(NSEC_HRP, nsec).map(Zeroizing::new)
+}
+
+// =============================================================================
+// Events (NIP-01)
+// =============================================================================
+
+/// Event to be signed; the signer fills in `pubkey`, `id` and `sig`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnsignedEvent {
+    /// Unix timestamp in seconds
+    pub created_at: u64,
+    /// Event kind (e.g., 1 for a text note)
+    pub kind: u32,
+    /// Tags, each a list of strings
+    #[serde(default)]
+    pub tags: Vec<Vec<String>>,
+    /// Arbitrary content
+    #[serde(default)]
+    pub content: String,
+}
+
+/// Signed event, ready to publish to relays
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NostrEvent {
+    /// Hex SHA-256 of the serialized event
+    pub id: String,
+    /// Hex x-only public key of the author
+    pub pubkey: String,
+    pub created_at: u64,
+    pub kind: u32,
+    pub tags: Vec<Vec<String>>,
+    pub content: String,
+    /// Hex BIP-340 signature over `id`
+    pub sig: String,
+}
+
+impl UnsignedEvent {
+    /// NIP-01 event ID: SHA-256 of `[0, pubkey, created_at, kind, tags, content]`
+    pub fn id(&self, pubkey: &str) -> [u8; 32] {
+        let serialized = serde_json::json!([
+            0,
+            pubkey,
+            self.created_at,
+            self.kind,
+            self.tags,
+            self.content
+        ])
+        .to_string();
+        Sha256::digest(serialized.as_bytes()).into()
+    }
+}
+
+impl NostrEvent {
+    /// Check the event ID and the author's signature over it
+    pub fn verify(&self) -> bool {
+        let unsigned = UnsignedEvent {
+            created_at: self.created_at,
+            kind: self.kind,
+            tags: self.tags.clone(),
+            content: self.content.clone(),
+        };
+        let id = unsigned.id(&self.pubkey);
+        if hex::encode(id) != self.id {
+            return false;
+        }
+
+        let mut public_key = [0u8; 32];
+        if hex::decode_to_slice(&self.pubkey, &mut public_key).is_err() {
+            return false;
+        }
+        verify_schnorr(&public_key, &id, &self.sig)
+    }
+}
+
+/// Verify a hex BIP-340 signature over a 32-byte digest
+fn verify_schnorr(public_key: &[u8; 32], digest: &[u8; 32], signature: &str) -> bool {
+    let Ok(public_key) = XOnlyPublicKey::from_slice(public_key) else {
+        return false;
+    };
+    let Some(signature) = hex::decode(signature)
+        .ok()
+        .and_then(|bytes| Signature::from_slice(&bytes).ok())
+    else {
+        return false;
+    };
+
+    verification_context()
+        .verify_schnorr(&signature, &Message::from_digest(*digest), &public_key)
+        .is_ok()
+}
+
+// =============================================================================
+// Module
+// =============================================================================
+
+/// Nostr identity module
+pub struct NostrModule;
+
+impl NostrModule {
+    /// Create a new Nostr module
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Private key at a derivation path
+    pub fn secret_key_at_path(seed: &[u8; 64], path: &str) -> WalletResult<SecretKey> {
+        let derived_key = derive_key_from_seed(seed, path)?;
+        SecretKey::from_slice(&derived_key.private_key().to_bytes())
+            .map_err(|e| WalletError::DerivationError(format!("Invalid private key: {}", e)))
+    }
+
+    /// `nsec` of the key at a derivation path
+    pub fn nsec_at_path(seed: &[u8; 64], path: &str) -> WalletResult<Zeroizing<String>> {
+        let secret_key = Self::secret_key_at_path(seed, path)?;
+        Ok(encode_nsec(&secret_key.secret_bytes()))
+    }
+
+    /// Sign an event with the key at `path`
+    pub fn sign_event(
+        seed: &[u8; 64],
+        path: &str,
+        event: &UnsignedEvent,
+    ) -> WalletResult<NostrEvent> {
+        let secp = Secp256k1::signing_only();
+        let keypair = Keypair::from_secret_key(&secp, &Self::secret_key_at_path(seed, path)?);
+        let pubkey = hex::encode(keypair.x_only_public_key().0.serialize());
+
+        let id = event.id(&pubkey);
+        let signature = secp.sign_schnorr_with_aux_rand(
+            &Message::from_digest(id),
+            &keypair,
+            &rand::random::<[u8; 32]>(),
+        );
+
+        Ok(NostrEvent {
+            id: hex::encode(id),
+            pubkey,
+            created_at: event.created_at,
+            kind: event.kind,
+            tags: event.tags.clone(),
+            content: event.content.clone(),
+            sig: hex::encode(signature.serialize()),
+        })
+    }
+
+    fn address_from_public_key(&self, public_key: [u8; 32], path: String) -> DerivedAddress {
+        DerivedAddress {
+            chain: self.chain_id().to_string(),
+            chain_family: self.chain_family(),
+            address: encode_npub(&public_key),
+            derivation_path: path,
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key.to_vec(),
+        }
+    }
+}
+
+impl Default for NostrModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChainSigner for NostrModule {
+    fn sign_message(
+        &self,
+        seed: &[u8; 64],
+        path: &str,
+        message: &[u8],
+    ) -> WalletResult<ChainSignature> {
+        let secp = Secp256k1::signing_only();
+        let keypair = Keypair::from_secret_key(&secp, &Self::secret_key_at_path(seed, path)?);
+        let digest: [u8; 32] = Sha256::digest(message).into();
+        let signature = secp.sign_schnorr_with_aux_rand(
+            &Message::from_digest(digest),
+            &keypair,
+            &rand::random::<[u8; 32]>(),
+        );
+
+        Ok(ChainSignature {
+            address: encode_npub(&keypair.x_only_public_key().0.serialize()),
+            scheme: "bip340".to_string(),
+            signature: hex::encode(signature.serialize()),
+        })
+    }
+
+    /// Sign an event; the payload is the `UnsignedEvent` JSON
+    fn sign_transaction(
+        &self,
+        seed: &[u8; 64],
+        path: &str,
+        payload: &[u8],
+    ) -> WalletResult<ChainSignature> {
+        let event: UnsignedEvent = serde_json::from_slice(payload)
+            .map_err(|e| WalletError::SigningError(format!("Invalid Nostr event: {}", e)))?;
+        let signed = Self::sign_event(seed, path, &event)?;
+
+        let mut public_key = [0u8; 32];
+        hex::decode_to_slice(&signed.pubkey, &mut public_key)
+            .map_err(|e| WalletError::SigningError(e.to_string()))?;
+
+        Ok(ChainSignature {
+            address: encode_npub(&public_key),
+            scheme: "nip01".to_string(),
+            signature: serde_json::to_string(&signed)
+                .map_err(|e| WalletError::SigningError(e.to_string()))?,
+        })
+    }
+
+    fn verify_message(&self, address: &str, message: &[u8], signature: &str) -> WalletResult<bool> {
+        let public_key = decode_npub(address).ok_or_else(|| WalletError::InvalidAddress {
+            chain: self.chain_id().to_string(),
+            address: address.to_string(),
+        })?;
+        let digest: [u8; 32] = Sha256::digest(message).into();
+
+        Ok(verify_schnorr(&public_key, &digest, signature))
+    }
+}
+
+impl ChainModule for NostrModule {
+    fn chain_id(&self) -> &str {
+        NOSTR_CHAIN_ID
+    }
+
+    fn display_name(&self) -> &str {
+        "Nostr"
+    }
+
+    fn chain_family(&self) -> ChainFamily {
+        ChainFamily::Secp256k1
+    }
+
+    fn coin_type(&self) -> u32 {
+        coin_types::NOSTR
+    }
+
+    fn symbol(&self) -> &str {
+        "NOSTR"
+    }
+
+    fn decimals(&self) -> u8 {
+        0
+    }
+
+    fn derive_address(
+        &self,
+        seed: &[u8; 64],
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        self.derive_address_at_path(seed, &self.derivation_path(account, index))
+    }
+
+    fn derive_address_at_path(&self, seed: &[u8; 64], path: &str) -> WalletResult<DerivedAddress> {
+        let derived_key = derive_key_from_seed(seed, path)?;
+        let compressed = derived_key.public_key().to_bytes();
+
+        let mut public_key = [0u8; 32];
+        public_key.copy_from_slice(&compressed[1..]);
+        Ok(self.address_from_public_key(public_key, path.to_string()))
+    }
+
+    fn derive_address_from_xpub(
+        &self,
+        account_key: &AccountXpub,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        let public_key = account_key.public_key_at(0, index)?.x_only_public_key().0;
+
+        Ok(self.address_from_public_key(
+            public_key.serialize(),
+            self.derivation_path(account_key.account(), index),
+        ))
+    }
+
+    fn validate_address(&self, address: &str) -> bool {
+        decode_npub(address).is_some_and(|key| XOnlyPublicKey::from_slice(&key).is_ok())
+    }
+
+    fn derivation_path(&self, account: u32, index: u32) -> String {
+        format!("m/44'/{}'/{account}'/0/{index}", self.coin_type())
+    }
+
+    fn account_node_path(&self, account: u32) -> Option<String> {
+        Some(format!("m/44'/{}'/{account}'", self.coin_type()))
+    }
+
+    fn address_prefix(&self) -> Option<&str> {
+        Some(NPUB_HRP)
+    }
+
+    fn signer(&self) -> Option<&dyn ChainSigner> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::mnemonic::{mnemonic_to_seed, parse_mnemonic};
+
+    /// NIP-06 test vector mnemonic
+    const NIP06_MNEMONIC: &str =
+        "leader monkey parrot ring guide accident before fence cannon height naive bean";
+
+    fn nip06_seed() -> [u8; 64] {
+        let mnemonic = parse_mnemonic(NIP06_MNEMONIC).unwrap();
+        *mnemonic_to_seed(&mnemonic, "").unwrap().as_bytes()
+    }
+
+    fn text_note() -> UnsignedEvent {
+        UnsignedEvent {
+            created_at: 1_700_000_000,
+            kind: 1,
+            tags: vec![vec!["t".to_string(), "coinbox".to_string()]],
+            content: "hello \"nostr\"\n".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_nostr_module_chain_id() {
+        let module = NostrModule::new();
+        assert_eq!(module.chain_id(), "nostr");
+        assert_eq!(module.chain_family(), ChainFamily::Secp256k1);
+        assert_eq!(module.coin_type(), 1237);
+        assert_eq!(module.derivation_path(0, 0), "m/44'/1237'/0'/0/0");
+        assert_eq!(module.derivation_path(2, 0), "m/44'/1237'/2'/0/0");
+    }
+
+    #[test]
+    fn test_nip06_vector() {
+        let seed = nip06_seed();
+        let module = NostrModule::new();
+        let derived = module.derive_address(&seed, 0, 0).unwrap();
+
+        assert_eq!(
+            hex::encode(&derived.public_key),
+            "17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917"
+        );
+        assert_eq!(
+            derived.address,
+            "npub1zutzeysacnf9rru6zqwmxd54mud0k44tst6l70ja5mhv8jjumytsd2x7nu"
+        );
+
+        let secret_key = NostrModule::secret_key_at_path(&seed, &derived.derivation_path).unwrap();
+        assert_eq!(
+            hex::encode(secret_key.secret_bytes()),
+            "7f7ff03d123792d6ac594bfa67bf6d0c0ab55b6b1fdb6249303fe861f1ccba9a"
+        );
+        assert_eq!(
+            NostrModule::nsec_at_path(&seed, &derived.derivation_path)
+                .unwrap()
+                .as_str(),
+            "nsec10allq0gjx7fddtzef0ax00mdps9t2kmtrldkyjfs8l5xruwvh2dq0lhhkp"
+        );
+    }
+
+    #[test]
+    fn test_nip19_roundtrip_and_validation() {
+        let module = NostrModule::new();
+        let npub = "npub1zutzeysacnf9rru6zqwmxd54mud0k44tst6l70ja5mhv8jjumytsd2x7nu";
+        let public_key = decode_npub(npub).unwrap();
+        assert_eq!(encode_npub(&public_key), npub);
+        assert!(module.validate_address(npub));
+
+        // An nsec is not an address; bad checksum and foreign formats fail
+        let nsec = "nsec10allq0gjx7fddtzef0ax00mdps9t2kmtrldkyjfs8l5xruwvh2dq0lhhkp";
+        assert!(decode_nsec(nsec).is_some());
+        assert!(!module.validate_address(nsec));
+        assert!(!module.validate_address(&npub.replace("x7nu", "x7nv")));
+        assert!(!module.validate_address("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"));
+        assert!(!module.validate_address(""));
+    }
+
+    #[test]
+    fn test_sign_and_verify_event() {
+        let seed = nip06_seed();
+        let path = NostrModule::new().derivation_path(0, 0);
+        let event = NostrModule::sign_event(&seed, &path, &text_note()).unwrap();
+
+        assert_eq!(
+            event.pubkey,
+            "17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917"
+        );
+        assert_eq!(event.id, hex::encode(text_note().id(&event.pubkey)));
+        assert_eq!(event.sig.len(), 128);
+        assert!(event.verify());
+
+        // Any change to the content invalidates the ID
+        let mut tampered = event.clone();
+        tampered.content.push('!');
+        assert!(!tampered.verify());
+
+        // A valid ID for different fields fails against the original signature
+        let mut tampered = event.clone();
+        tampered.kind = 7;
+        tampered.id = hex::encode(
+            UnsignedEvent {
+                kind: 7,
+                ..text_note()
+            }
+            .id(&tampered.pubkey),
+        );
+        assert!(!tampered.verify());
+    }
+
+    #[test]
+    fn test_event_id_serialization() {
+        // NIP-01 serialization: compact JSON, strings escaped as JSON
+        let event = text_note();
+        let pubkey = "17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917";
+        let expected = format!(
+            r#"[0,"{}",1700000000,1,[["t","coinbox"]],"hello \"nostr\"\n"]"#,
+            pubkey
+        );
+        let digest: [u8; 32] = Sha256::digest(expected.as_bytes()).into();
+        assert_eq!(event.id(pubkey), digest);
+    }
+
+    #[test]
+    fn test_chain_signer() {
+        let seed = nip06_seed();
+        let module = NostrModule::new();
+        let path = module.derivation_path(0, 0);
+        let npub = "npub1zutzeysacnf9rru6zqwmxd54mud0k44tst6l70ja5mhv8jjumytsd2x7nu";
+
+        let signed = module.sign_message(&seed, &path, b"coinbox").unwrap();
+        assert_eq!(signed.address, npub);
+        assert_eq!(signed.scheme, "bip340");
+        assert!(module
+            .verify_message(npub, b"coinbox", &signed.signature)
+            .unwrap());
+        assert!(!module
+            .verify_message(npub, b"other", &signed.signature)
+            .unwrap());
+        assert!(!module.verify_message(npub, b"coinbox", "zz").unwrap());
+        assert!(module
+            .verify_message("npub1invalid", b"coinbox", &signed.signature)
+            .is_err());
+
+        let payload = serde_json::to_vec(&text_note()).unwrap();
+        let signed = module.sign_transaction(&seed, &path, &payload).unwrap();
+        assert_eq!(signed.scheme, "nip01");
+        let event: NostrEvent = serde_json::from_str(&signed.signature).unwrap();
+        assert!(event.verify());
+        assert!(module.sign_transaction(&seed, &path, b"not json").is_err());
+    }
+
+    #[test]
+    fn test_xpub_derivation_matches_seed() {
+        let seed = nip06_seed();
+        let module = NostrModule::new();
+        let account_path = module.account_node_path(0).unwrap();
+        let account_key = AccountXpub::from_seed(&seed, &account_path, false).unwrap();
+
+        for index in 0..3 {
+            let from_xpub = module
+                .derive_address_from_xpub(&account_key, index)
+                .unwrap();
+            let from_seed = module.derive_address(&seed, 0, index).unwrap();
+            assert_eq!(from_xpub.address, from_seed.address);
+            assert_eq!(from_xpub.derivation_path, from_seed.derivation_path);
+            assert_eq!(from_xpub.public_key, from_seed.public_key);
+        }
+    }
+}
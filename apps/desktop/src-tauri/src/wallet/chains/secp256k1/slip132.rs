@@ -0,0 +1,230 @@
+//! SLIP-132 extended key versions
+//!
+//! Wallets encode the intended script type in the version bytes of an
+//! extended key: `ypub` for nested SegWit, `zpub` for native SegWit, and
+//! `Ypub`/`Zpub` for their multisig counterparts. BIP32 libraries only accept
+//! the plain `xpub`/`tpub` versions, so keys are normalized before decoding
+//! and the SLIP-132 version is kept alongside as a script type hint.
+
+use bitcoin::base58;
+use bitcoin::bip32::{Xpriv, Xpub};
+use serde::Serialize;
+
+// =============================================================================
+// Versions
+// =============================================================================
+
+/// A registered extended key version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slip132Version {
+    /// Human-readable prefix of the encoded key (e.g., "zpub")
+    pub prefix: &'static str,
+    /// Serialized version bytes
+    pub bytes: [u8; 4],
+    /// Whether the key is private (xprv, zprv, ...)
+    pub is_private: bool,
+    /// Whether the key belongs to a test network
+    pub is_testnet: bool,
+    /// Script type implied by the version, `None` for plain xpub/tpub
+    pub script_type: Option<&'static str>,
+}
+
+const fn version(
+    prefix: &'static str,
+    bytes: u32,
+    is_private: bool,
+    is_testnet: bool,
+    script_type: Option<&'static str>,
+) -> Slip132Version {
+    Slip132Version {
+        prefix,
+        bytes: bytes.to_be_bytes(),
+        is_private,
+        is_testnet,
+        script_type,
+    }
+}
+
+/// Bitcoin extended key versions from SLIP-132
+pub const VERSIONS: [Slip132Version; 20] = [
+    version("xpub", 0x0488b21e, false, false, None),
+    version("ypub", 0x049d7cb2, false, false, Some("p2sh-p2wpkh")),
+    version("zpub", 0x04b24746, false, false, Some("p2wpkh")),
+    version("Ypub", 0x0295b43f, false, false, Some("p2sh-p2wsh")),
+    version("Zpub", 0x02aa7ed3, false, false, Some("p2wsh")),
+    version("tpub", 0x043587cf, false, true, None),
+    version("upub", 0x044a5262, false, true, Some("p2sh-p2wpkh")),
+    version("vpub", 0x045f1cf6, false, true, Some("p2wpkh")),
+    version("Upub", 0x024289ef, false, true, Some("p2sh-p2wsh")),
+    version("Vpub", 0x02575483, false, true, Some("p2wsh")),
+    version("xprv", 0x0488ade4, true, false, None),
+    version("yprv", 0x049d7878, true, false, Some("p2sh-p2wpkh")),
+    version("zprv", 0x04b2430c, true, false, Some("p2wpkh")),
+    version("Yprv", 0x0295b005, true, false, Some("p2sh-p2wsh")),
+    version("Zprv", 0x02aa7a99, true, false, Some("p2wsh")),
+    version("tprv", 0x04358394, true, true, None),
+    version("uprv", 0x044a4e28, true, true, Some("p2sh-p2wpkh")),
+    version("vprv", 0x045f18bc, true, true, Some("p2wpkh")),
+    version("Uprv", 0x024285b5, true, true, Some("p2sh-p2wsh")),
+    version("Vprv", 0x02575048, true, true, Some("p2wsh")),
+];
+
+impl Slip132Version {
+    /// Look up a version by its serialized bytes
+    pub fn from_bytes(bytes: &[u8]) -> Option<&'static Slip132Version> {
+        VERSIONS.iter().find(|v| v.bytes == bytes)
+    }
+
+    /// The plain BIP32 version (xpub/tpub/xprv/tprv) for the same key kind
+    pub fn standard(&self) -> &'static Slip132Version {
+        VERSIONS
+            .iter()
+            .find(|v| {
+                v.script_type.is_none()
+                    && v.is_private == self.is_private
+                    && v.is_testnet == self.is_testnet
+            })
+            .expect("standard version for every key kind")
+    }
+}
+
+// =============================================================================
+// Decoding
+// =============================================================================
+
+/// A decoded extended key of either kind
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtendedKey {
+    Public(Xpub),
+    Private(Xpriv),
+}
+
+/// Summary of an extended key, safe to show to the user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExtendedKeyInfo {
+    /// SLIP-132 prefix (e.g., "xpub", "zpub", "tprv")
+    pub prefix: String,
+    pub is_private: bool,
+    pub is_testnet: bool,
+    /// Script type implied by the prefix, if any
+    pub script_type: Option<String>,
+    /// Depth in the BIP32 tree (3 for a BIP44/49/84/86 account key)
+    pub depth: u8,
+    /// Fingerprint of the parent key (hex)
+    pub parent_fingerprint: String,
+}
+
+/// Decode a base58 extended key with any SLIP-132 version
+///
+/// Returns `None` if the checksum, version or key data is invalid.
+pub fn decode(input: &str) -> Option<(&'static Slip132Version, ExtendedKey)> {
+    let mut data = base58::decode_check(input).ok()?;
+    if data.len() != 78 {
+        return None;
+    }
+
+    let version = Slip132Version::from_bytes(&data[..4])?;
+    data[..4].copy_from_slice(&version.standard().bytes);
+
+    let key = if version.is_private {
+        ExtendedKey::Private(Xpriv::decode(&data).ok()?)
+    } else {
+        ExtendedKey::Public(Xpub::decode(&data).ok()?)
+    };
+
+    Some((version, key))
+}
+
+/// Decode an extended key and describe it
+pub fn inspect(input: &str) -> Option<ExtendedKeyInfo> {
+    let (version, key) = decode(input)?;
+    let (depth, parent_fingerprint) = match &key {
+        ExtendedKey::Public(xpub) => (xpub.depth, xpub.parent_fingerprint),
+        ExtendedKey::Private(xpriv) => (xpriv.depth, xpriv.parent_fingerprint),
+    };
+
+    Some(ExtendedKeyInfo {
+        prefix: version.prefix.to_string(),
+        is_private: version.is_private,
+        is_testnet: version.is_testnet,
+        script_type: version.script_type.map(str::to_string),
+        depth,
+        parent_fingerprint: parent_fingerprint.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::bip32::DerivationPath;
+    use bitcoin::secp256k1::Secp256k1;
+    use bitcoin::NetworkKind;
+    use std::str::FromStr;
+
+    // Test seed from mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    fn test_seed() -> [u8; 64] {
+        let seed_hex = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+        let mut seed = [0u8; 64];
+        hex::decode_to_slice(seed_hex, &mut seed).unwrap();
+        seed
+    }
+
+    fn account_xpub(path: &str) -> Xpub {
+        let secp = Secp256k1::new();
+        let master = Xpriv::new_master(NetworkKind::Main, &test_seed()).unwrap();
+        let account = master
+            .derive_priv(&secp, &DerivationPath::from_str(path).unwrap())
+            .unwrap();
+        Xpub::from_priv(&secp, &account)
+    }
+
+    #[test]
+    fn test_decode_zpub() {
+        // BIP84 test vector, account 0
+        let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+        let (version, key) = decode(zpub).unwrap();
+
+        assert_eq!(version.prefix, "zpub");
+        assert_eq!(version.script_type, Some("p2wpkh"));
+        assert_eq!(key, ExtendedKey::Public(account_xpub("m/84'/0'/0'")));
+    }
+
+    #[test]
+    fn test_decode_ypub() {
+        // BIP49 test vector, account 0
+        let ypub = "ypub6Ww3ibxVfGzLrAH1PNcjyAWenMTbbAosGNB6VvmSEgytSER9azLDWCxoJwW7Ke7icmizBMXrzBx9979FfaHxHcrArf3zbeJJJUZPf663zsP";
+        let (version, key) = decode(ypub).unwrap();
+
+        assert_eq!(version.script_type, Some("p2sh-p2wpkh"));
+        assert_eq!(key, ExtendedKey::Public(account_xpub("m/49'/0'/0'")));
+    }
+
+    #[test]
+    fn test_inspect_xpub() {
+        let xpub = account_xpub("m/44'/0'/0'").to_string();
+        let info = inspect(&xpub).unwrap();
+
+        assert_eq!(info.prefix, "xpub");
+        assert!(!info.is_private);
+        assert!(!info.is_testnet);
+        assert_eq!(info.script_type, None);
+        assert_eq!(info.depth, 3);
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert!(decode("zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYt").is_none());
+        assert!(decode("1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2").is_none());
+        assert!(decode("not a key").is_none());
+    }
+
+    #[test]
+    fn test_standard_versions() {
+        for version in VERSIONS {
+            let standard = version.standard();
+            assert_eq!(standard.is_private, version.is_private);
+            assert_eq!(standard.is_testnet, version.is_testnet);
+            assert!(standard.script_type.is_none());
+        }
+    }
+}
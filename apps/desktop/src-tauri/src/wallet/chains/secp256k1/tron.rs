@@ -0,0 +1,272 @@
+//! Tron chain module (BIP44)
+//!
+//! Generates T... addresses using BIP44 derivation.
+//! Path: m/44'/195'/account'/0/index
+//!
+//! Tron addresses use the same keccak-derived 20 bytes as Ethereum, prefixed
+//! with 0x41 and encoded as Base58Check. Tron APIs also use the hex form
+//! (`41` + 40 hex chars), so conversion helpers are provided.
+
+use bitcoin::base58;
+
+use crate::wallet::chains::{coin_types, AccountXpub, ChainModule, DEFAULT_SCHEME};
+use crate::wallet::error::{WalletError, WalletResult};
+use crate::wallet::types::{ChainFamily, DerivedAddress};
+
+use super::derive_key_from_seed;
+use super::ethereum::{keccak_address, keccak_public_key_address};
+
+/// Address prefix byte for Tron mainnet
+pub const TRON_ADDRESS_PREFIX: u8 = 0x41;
+
+/// Tron chain module
+pub struct TronModule;
+
+impl TronModule {
+    /// Create a new Tron mainnet module
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for TronModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Encode 20 address bytes as a Base58Check Tron address
+fn encode_address(address_bytes: &[u8; 20]) -> String {
+    let mut payload = [0u8; 21];
+    payload[0] = TRON_ADDRESS_PREFIX;
+    payload[1..].copy_from_slice(address_bytes);
+    base58::encode_check(&payload)
+}
+
+/// Decode a Base58Check Tron address into its 21-byte payload (0x41 || address)
+fn decode_address(address: &str) -> Option<[u8; 21]> {
+    let payload = base58::decode_check(address).ok()?;
+    if payload.len() != 21 || payload[0] != TRON_ADDRESS_PREFIX {
+        return None;
+    }
+    let mut bytes = [0u8; 21];
+    bytes.copy_from_slice(&payload);
+    Some(bytes)
+}
+
+/// Convert a hex Tron address to Base58Check
+///
+/// Accepts the Tron hex form (`41` + 40 hex chars) or an EVM-style
+/// 20-byte address (`0x` + 40 hex chars), which gets the 0x41 prefix.
+pub fn hex_to_base58(hex_address: &str) -> WalletResult<String> {
+    let invalid = || WalletError::InvalidAddress {
+        chain: "tron".to_string(),
+        address: hex_address.to_string(),
+    };
+
+    let hex_str = hex_address.trim_start_matches("0x");
+    let bytes = hex::decode(hex_str).map_err(|_| invalid())?;
+
+    let address_bytes: [u8; 20] = match bytes.as_slice() {
+        [TRON_ADDRESS_PREFIX, rest @ ..] if rest.len() == 20 => rest.try_into(),
+        rest if rest.len() == 20 => rest.try_into(),
+        _ => return Err(invalid()),
+    }
+    .map_err(|_| invalid())?;
+
+    Ok(encode_address(&address_bytes))
+}
+
+/// Convert a Base58Check Tron address to its hex form (`41` + 40 hex chars)
+pub fn base58_to_hex(address: &str) -> WalletResult<String> {
+    decode_address(address)
+        .map(hex::encode)
+        .ok_or_else(|| WalletError::InvalidAddress {
+            chain: "tron".to_string(),
+            address: address.to_string(),
+        })
+}
+
+impl ChainModule for TronModule {
+    fn chain_id(&self) -> &str {
+        "tron"
+    }
+
+    fn display_name(&self) -> &str {
+        "Tron"
+    }
+
+    fn chain_family(&self) -> ChainFamily {
+        ChainFamily::Secp256k1
+    }
+
+    fn coin_type(&self) -> u32 {
+        coin_types::TRON
+    }
+
+    fn symbol(&self) -> &str {
+        "TRX"
+    }
+
+    fn decimals(&self) -> u8 {
+        6
+    }
+
+    fn rpc_urls(&self) -> Vec<String> {
+        vec!["https://api.trongrid.io".to_string()]
+    }
+
+    fn explorer_url(&self) -> Option<&str> {
+        Some("https://tronscan.org")
+    }
+
+    fn derive_address(
+        &self,
+        seed: &[u8; 64],
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        // BIP44 path for Tron: m/44'/195'/account'/0/index
+        let path = self.derivation_path(account, index);
+        let derived_key = derive_key_from_seed(seed, &path)?;
+
+        // Same keccak address bytes as Ethereum, different encoding
+        let (address_bytes, public_key_bytes) = keccak_address(&derived_key)?;
+
+        Ok(DerivedAddress {
+            chain: self.chain_id().to_string(),
+            chain_family: self.chain_family(),
+            address: encode_address(&address_bytes),
+            derivation_path: path,
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key_bytes,
+        })
+    }
+
+    fn derive_address_from_xpub(
+        &self,
+        account_key: &AccountXpub,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        account_key.check_mainnet(self.chain_id(), self.is_testnet())?;
+        let public_key = account_key
+            .public_key_at(0, index)?
+            .serialize_uncompressed();
+
+        Ok(DerivedAddress {
+            chain: self.chain_id().to_string(),
+            chain_family: self.chain_family(),
+            address: encode_address(&keccak_public_key_address(&public_key)),
+            derivation_path: self.derivation_path(account_key.account(), index),
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key.to_vec(),
+        })
+    }
+
+    fn validate_address(&self, address: &str) -> bool {
+        address.len() == 34 && address.starts_with('T') && decode_address(address).is_some()
+    }
+
+    fn derivation_path(&self, account: u32, index: u32) -> String {
+        format!("m/44'/{}'/{account}'/0/{index}", self.coin_type())
+    }
+
+    fn account_node_path(&self, account: u32) -> Option<String> {
+        Some(format!("m/44'/{}'/{account}'", self.coin_type()))
+    }
+
+    fn address_prefix(&self) -> Option<&str> {
+        Some("T")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test seed from mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    fn test_seed() -> [u8; 64] {
+        let seed_hex = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+        let mut seed = [0u8; 64];
+        hex::decode_to_slice(seed_hex, &mut seed).unwrap();
+        seed
+    }
+
+    #[test]
+    fn test_tron_module_chain_id() {
+        let module = TronModule::new();
+        assert_eq!(module.chain_id(), "tron");
+        assert_eq!(module.chain_family(), ChainFamily::Secp256k1);
+        assert_eq!(module.coin_type(), 195);
+        assert_eq!(module.symbol(), "TRX");
+        assert_eq!(module.decimals(), 6);
+    }
+
+    #[test]
+    fn test_tron_derivation_path() {
+        let module = TronModule::new();
+        assert_eq!(module.derivation_path(0, 0), "m/44'/195'/0'/0/0");
+        assert_eq!(module.derivation_path(1, 3), "m/44'/195'/1'/0/3");
+    }
+
+    #[test]
+    fn test_tron_derive_address() {
+        let module = TronModule::new();
+        let derived = module.derive_address(&test_seed(), 0, 0).unwrap();
+
+        assert_eq!(derived.chain, "tron");
+        assert_eq!(derived.derivation_path, "m/44'/195'/0'/0/0");
+        assert_eq!(derived.public_key.len(), 65);
+        assert_eq!(derived.address, "TUEZSdKsoDHQMeZwihtdoBiN46zxhGWYdH");
+        assert!(module.validate_address(&derived.address));
+    }
+
+    #[test]
+    fn test_tron_validate_address() {
+        let module = TronModule::new();
+
+        assert!(module.validate_address("TUEZSdKsoDHQMeZwihtdoBiN46zxhGWYdH"));
+
+        // Bad checksum, wrong chain, wrong format
+        assert!(!module.validate_address("TUEZSdKsoDHQMeZwihtdoBiN46zxhGWYdI"));
+        assert!(!module.validate_address("1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"));
+        assert!(!module.validate_address("0x9858EfFD232B4033E47d90003D41EC34EcaEda94"));
+        assert!(!module.validate_address(""));
+    }
+
+    #[test]
+    fn test_tron_hex_base58_roundtrip() {
+        let base58 = "TUEZSdKsoDHQMeZwihtdoBiN46zxhGWYdH";
+        let hex = base58_to_hex(base58).unwrap();
+        assert_eq!(hex.len(), 42);
+        assert!(hex.starts_with("41"));
+        assert_eq!(hex_to_base58(&hex).unwrap(), base58);
+
+        // EVM-style 20-byte form maps to the same address
+        let evm_style = format!("0x{}", &hex[2..]);
+        assert_eq!(hex_to_base58(&evm_style).unwrap(), base58);
+
+        assert!(hex_to_base58("41zz").is_err());
+        assert!(hex_to_base58("42ffffffffffffffffffffffffffffffffffffffff").is_err());
+        assert!(base58_to_hex("1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA").is_err());
+    }
+
+    #[test]
+    fn test_tron_shares_key_with_ethereum_derivation() {
+        use crate::wallet::chains::EthereumModule;
+
+        // Same keccak logic: the hex form of an address derived on the
+        // Ethereum path matches Ethereum's address bytes
+        let seed = test_seed();
+        let eth = EthereumModule::ethereum()
+            .derive_address(&seed, 0, 0)
+            .unwrap();
+        let key = derive_key_from_seed(&seed, "m/44'/60'/0'/0/0").unwrap();
+        let (address_bytes, _) = keccak_address(&key).unwrap();
+
+        assert_eq!(
+            hex_to_base58(&eth.address).unwrap(),
+            encode_address(&address_bytes)
+        );
+    }
+}
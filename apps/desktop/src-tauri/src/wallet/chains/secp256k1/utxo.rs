@@ -0,0 +1,469 @@
+//! Bitcoin-like UTXO chain modules (Litecoin, Dogecoin, Bitcoin Cash)
+//!
+//! Forks of Bitcoin share BIP32 key derivation and HASH160-based addresses;
+//! they differ only in network constants (coin type, Base58Check version
+//! bytes, bech32 HRP, CashAddr prefix). `UtxoChainParams` captures those
+//! constants so adding another fork is a single params definition.
+//!
+//! - Litecoin (BIP84, ltc1...):        m/84'/2'/account'/0/index
+//! - Dogecoin (BIP44, D...):           m/44'/3'/account'/0/index
+//! - Bitcoin Cash (BIP44, CashAddr):   m/44'/145'/account'/0/index
+
+use bitcoin::base58;
+use bitcoin::bech32::{segwit, Fe32, Hrp};
+use bitcoin::hashes::{hash160, Hash};
+use bitcoin::secp256k1::{PublicKey, Secp256k1, SecretKey};
+
+use crate::wallet::chains::{
+    coin_types, AccountXpub, BitcoinScriptType, ChainModule, DEFAULT_SCHEME,
+};
+use crate::wallet::error::{WalletError, WalletResult};
+use crate::wallet::types::{AddressValidation, ChainFamily, DerivedAddress};
+
+use super::cashaddr;
+use super::derive_key_from_seed;
+
+/// Network constants for a Bitcoin-like chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UtxoChainParams {
+    /// Registry identifier (e.g., "litecoin")
+    pub id: &'static str,
+    /// Human-readable display name
+    pub name: &'static str,
+    /// Native currency symbol
+    pub symbol: &'static str,
+    /// SLIP-44 coin type
+    pub coin_type: u32,
+    /// Native currency decimals
+    pub decimals: u8,
+    /// Script type used for derived addresses
+    pub script_type: BitcoinScriptType,
+    /// Base58Check version byte for P2PKH addresses
+    pub p2pkh_version: u8,
+    /// Base58Check version bytes for P2SH addresses (first is used for encoding)
+    pub p2sh_versions: &'static [u8],
+    /// Bech32 HRP for SegWit addresses, if the chain supports SegWit
+    pub bech32_hrp: Option<&'static str>,
+    /// CashAddr prefix, if the chain uses CashAddr
+    pub cashaddr_prefix: Option<&'static str>,
+    /// Address prefix hint for display
+    pub address_prefix: &'static str,
+    /// Block explorer base URL
+    pub explorer_url: Option<&'static str>,
+}
+
+impl UtxoChainParams {
+    /// Litecoin mainnet
+    pub const LITECOIN: UtxoChainParams = UtxoChainParams {
+        id: "litecoin",
+        name: "Litecoin",
+        symbol: "LTC",
+        coin_type: coin_types::LITECOIN,
+        decimals: 8,
+        script_type: BitcoinScriptType::P2wpkh,
+        p2pkh_version: 0x30,
+        // 'M' addresses, plus legacy '3' addresses
+        p2sh_versions: &[0x32, 0x05],
+        bech32_hrp: Some("ltc"),
+        cashaddr_prefix: None,
+        address_prefix: "ltc1",
+        explorer_url: Some("https://litecoinspace.org"),
+    };
+
+    /// Dogecoin mainnet
+    pub const DOGECOIN: UtxoChainParams = UtxoChainParams {
+        id: "dogecoin",
+        name: "Dogecoin",
+        symbol: "DOGE",
+        coin_type: coin_types::DOGECOIN,
+        decimals: 8,
+        script_type: BitcoinScriptType::P2pkh,
+        p2pkh_version: 0x1e,
+        p2sh_versions: &[0x16],
+        bech32_hrp: None,
+        cashaddr_prefix: None,
+        address_prefix: "D",
+        explorer_url: Some("https://dogechain.info"),
+    };
+
+    /// Bitcoin Cash mainnet
+    pub const BITCOIN_CASH: UtxoChainParams = UtxoChainParams {
+        id: "bitcoin_cash",
+        name: "Bitcoin Cash",
+        symbol: "BCH",
+        coin_type: coin_types::BITCOIN_CASH,
+        decimals: 8,
+        script_type: BitcoinScriptType::P2pkh,
+        // Legacy Base58 addresses are still accepted
+        p2pkh_version: 0x00,
+        p2sh_versions: &[0x05],
+        bech32_hrp: None,
+        cashaddr_prefix: Some("bitcoincash"),
+        address_prefix: "bitcoincash:",
+        explorer_url: Some("https://blockchair.com/bitcoin-cash"),
+    };
+}
+
+/// Chain module for Bitcoin-like UTXO chains
+pub struct UtxoModule {
+    params: UtxoChainParams,
+}
+
+impl UtxoModule {
+    /// Create a module from network constants
+    pub fn new(params: UtxoChainParams) -> Self {
+        Self { params }
+    }
+
+    /// Litecoin
+    pub fn litecoin() -> Self {
+        Self::new(UtxoChainParams::LITECOIN)
+    }
+
+    /// Dogecoin
+    pub fn dogecoin() -> Self {
+        Self::new(UtxoChainParams::DOGECOIN)
+    }
+
+    /// Bitcoin Cash
+    pub fn bitcoin_cash() -> Self {
+        Self::new(UtxoChainParams::BITCOIN_CASH)
+    }
+
+    /// Network constants for this chain
+    pub fn params(&self) -> &UtxoChainParams {
+        &self.params
+    }
+
+    /// Encode a P2PKH address (CashAddr if the chain uses it)
+    fn encode_p2pkh(&self, hash: &[u8; 20]) -> WalletResult<String> {
+        match self.params.cashaddr_prefix {
+            Some(prefix) => cashaddr::encode(prefix, cashaddr::TYPE_P2PKH, hash),
+            None => Ok(encode_base58(self.params.p2pkh_version, hash)),
+        }
+    }
+
+    /// Encode a P2SH address (CashAddr if the chain uses it)
+    fn encode_p2sh(&self, hash: &[u8; 20]) -> WalletResult<String> {
+        match self.params.cashaddr_prefix {
+            Some(prefix) => cashaddr::encode(prefix, cashaddr::TYPE_P2SH, hash),
+            None => Ok(encode_base58(self.params.p2sh_versions[0], hash)),
+        }
+    }
+
+    /// Encode a SegWit v0 address with the chain's HRP
+    fn encode_segwit_v0(&self, program: &[u8]) -> WalletResult<String> {
+        let hrp = self.params.bech32_hrp.ok_or_else(|| {
+            WalletError::DerivationError(format!("{} does not support SegWit", self.params.name))
+        })?;
+        let hrp = Hrp::parse(hrp)
+            .map_err(|e| WalletError::DerivationError(format!("Invalid HRP: {}", e)))?;
+        segwit::encode_v0(hrp, program)
+            .map_err(|e| WalletError::DerivationError(format!("Bech32 encoding failed: {}", e)))
+    }
+
+    /// Address of a compressed public key for this module's script type
+    fn address_for_public_key(&self, public_key: &[u8; 33]) -> WalletResult<String> {
+        let pubkey_hash = hash160::Hash::hash(public_key).to_byte_array();
+
+        match self.params.script_type {
+            BitcoinScriptType::P2pkh => self.encode_p2pkh(&pubkey_hash),
+            BitcoinScriptType::P2shP2wpkh => {
+                // Redeem script: OP_0 <20-byte pubkey hash>
+                let mut redeem_script = vec![0x00, 0x14];
+                redeem_script.extend_from_slice(&pubkey_hash);
+                self.encode_p2sh(&hash160::Hash::hash(&redeem_script).to_byte_array())
+            }
+            BitcoinScriptType::P2wpkh => self.encode_segwit_v0(&pubkey_hash),
+            BitcoinScriptType::P2tr => Err(WalletError::DerivationError(format!(
+                "{} does not support Taproot derivation",
+                self.params.name
+            ))),
+        }
+    }
+
+    /// Determine the address type, or `None` if the address is invalid
+    fn parse_address(&self, address: &str) -> Option<&'static str> {
+        if let Some(prefix) = self.params.cashaddr_prefix {
+            if let Some(decoded) = cashaddr::decode(address, prefix) {
+                return match (decoded.address_type, decoded.hash.len()) {
+                    (cashaddr::TYPE_P2PKH, 20) => Some("p2pkh"),
+                    (cashaddr::TYPE_P2SH, 20 | 32) => Some("p2sh"),
+                    _ => None,
+                };
+            }
+        }
+
+        if let Some(hrp) = self.params.bech32_hrp {
+            let is_segwit = address
+                .to_ascii_lowercase()
+                .strip_prefix(hrp)
+                .is_some_and(|rest| rest.starts_with('1'));
+            if is_segwit {
+                let (decoded_hrp, version, program) = segwit::decode(address).ok()?;
+                if decoded_hrp.to_lowercase() != hrp {
+                    return None;
+                }
+                return match (version, program.len()) {
+                    (Fe32::Q, 20) => Some("p2wpkh"),
+                    (Fe32::Q, 32) => Some("p2wsh"),
+                    (Fe32::P, 32) => Some("p2tr"),
+                    _ => None,
+                };
+            }
+        }
+
+        let payload = base58::decode_check(address).ok()?;
+        let (&version, hash) = payload.split_first()?;
+        if hash.len() != 20 {
+            return None;
+        }
+        if version == self.params.p2pkh_version {
+            Some("p2pkh")
+        } else if self.params.p2sh_versions.contains(&version) {
+            Some("p2sh")
+        } else {
+            None
+        }
+    }
+}
+
+/// Base58Check encode `version || hash`
+fn encode_base58(version: u8, hash: &[u8; 20]) -> String {
+    let mut payload = [0u8; 21];
+    payload[0] = version;
+    payload[1..].copy_from_slice(hash);
+    base58::encode_check(&payload)
+}
+
+impl ChainModule for UtxoModule {
+    fn chain_id(&self) -> &str {
+        self.params.id
+    }
+
+    fn display_name(&self) -> &str {
+        self.params.name
+    }
+
+    fn chain_family(&self) -> ChainFamily {
+        ChainFamily::Secp256k1
+    }
+
+    fn coin_type(&self) -> u32 {
+        self.params.coin_type
+    }
+
+    fn symbol(&self) -> &str {
+        self.params.symbol
+    }
+
+    fn decimals(&self) -> u8 {
+        self.params.decimals
+    }
+
+    fn explorer_url(&self) -> Option<&str> {
+        self.params.explorer_url
+    }
+
+    fn address_type(&self) -> Option<&str> {
+        Some(self.params.script_type.as_str())
+    }
+
+    fn derive_address(
+        &self,
+        seed: &[u8; 64],
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        let path = self.derivation_path(account, index);
+        let derived_key = derive_key_from_seed(seed, &path)?;
+
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&derived_key.private_key().to_bytes())
+            .map_err(|e| WalletError::DerivationError(format!("Invalid private key: {}", e)))?;
+        let public_key = PublicKey::from_secret_key(&secp, &secret_key).serialize();
+
+        Ok(DerivedAddress {
+            chain: self.chain_id().to_string(),
+            chain_family: self.chain_family(),
+            address: self.address_for_public_key(&public_key)?,
+            derivation_path: path,
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key.to_vec(),
+        })
+    }
+
+    fn derive_address_from_xpub(
+        &self,
+        account_key: &AccountXpub,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        account_key.check_compatible(self.chain_id(), false, self.params.script_type.as_str())?;
+        let public_key = account_key.public_key_at(0, index)?.serialize();
+
+        Ok(DerivedAddress {
+            chain: self.chain_id().to_string(),
+            chain_family: self.chain_family(),
+            address: self.address_for_public_key(&public_key)?,
+            derivation_path: self.derivation_path(account_key.account(), index),
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key.to_vec(),
+        })
+    }
+
+    fn validate_address(&self, address: &str) -> bool {
+        self.parse_address(address).is_some()
+    }
+
+    fn validate_address_details(&self, address: &str) -> AddressValidation {
+        let address_type = self.parse_address(address);
+        AddressValidation {
+            is_valid: address_type.is_some(),
+            address_type: address_type.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    fn derivation_path(&self, account: u32, index: u32) -> String {
+        format!(
+            "m/{}'/{}'/{account}'/0/{index}",
+            self.params.script_type.purpose(),
+            self.params.coin_type
+        )
+    }
+
+    fn account_node_path(&self, account: u32) -> Option<String> {
+        Some(format!(
+            "m/{}'/{}'/{account}'",
+            self.params.script_type.purpose(),
+            self.params.coin_type
+        ))
+    }
+
+    fn address_prefix(&self) -> Option<&str> {
+        Some(self.params.address_prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test seed from mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    fn test_seed() -> [u8; 64] {
+        let seed_hex = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+        let mut seed = [0u8; 64];
+        hex::decode_to_slice(seed_hex, &mut seed).unwrap();
+        seed
+    }
+
+    #[test]
+    fn test_utxo_module_metadata() {
+        let ltc = UtxoModule::litecoin();
+        assert_eq!(ltc.chain_id(), "litecoin");
+        assert_eq!(ltc.symbol(), "LTC");
+        assert_eq!(ltc.coin_type(), 2);
+        assert_eq!(ltc.derivation_path(0, 0), "m/84'/2'/0'/0/0");
+
+        let doge = UtxoModule::dogecoin();
+        assert_eq!(doge.chain_id(), "dogecoin");
+        assert_eq!(doge.coin_type(), 3);
+        assert_eq!(doge.derivation_path(0, 0), "m/44'/3'/0'/0/0");
+
+        let bch = UtxoModule::bitcoin_cash();
+        assert_eq!(bch.chain_id(), "bitcoin_cash");
+        assert_eq!(bch.coin_type(), 145);
+        assert_eq!(bch.derivation_path(1, 2), "m/44'/145'/1'/0/2");
+    }
+
+    #[test]
+    fn test_litecoin_derive_address() {
+        let derived = UtxoModule::litecoin()
+            .derive_address(&test_seed(), 0, 0)
+            .unwrap();
+        assert_eq!(
+            derived.address,
+            "ltc1qjmxnz78nmc8nq77wuxh25n2es7rzm5c2rkk4wh"
+        );
+        assert_eq!(derived.public_key.len(), 33);
+    }
+
+    #[test]
+    fn test_dogecoin_derive_address() {
+        let derived = UtxoModule::dogecoin()
+            .derive_address(&test_seed(), 0, 0)
+            .unwrap();
+        assert_eq!(derived.address, "DBus3bamQjgJULBJtYXpEzDWQRwF5iwxgC");
+    }
+
+    #[test]
+    fn test_bitcoin_cash_derive_address() {
+        let derived = UtxoModule::bitcoin_cash()
+            .derive_address(&test_seed(), 0, 0)
+            .unwrap();
+        assert_eq!(
+            derived.address,
+            "bitcoincash:qqyx49mu0kkn9ftfj6hje6g2wfer34yfnq5tahq3q6"
+        );
+    }
+
+    #[test]
+    fn test_litecoin_validate_address() {
+        let ltc = UtxoModule::litecoin();
+
+        let details = ltc.validate_address_details("ltc1qjmxnz78nmc8nq77wuxh25n2es7rzm5c2rkk4wh");
+        assert!(details.is_valid);
+        assert_eq!(details.address_type.as_deref(), Some("p2wpkh"));
+
+        // Legacy L... and nested SegWit M... addresses
+        for (script_type, prefix, address_type) in [
+            (BitcoinScriptType::P2pkh, "L", "p2pkh"),
+            (BitcoinScriptType::P2shP2wpkh, "M", "p2sh"),
+        ] {
+            let module = UtxoModule::new(UtxoChainParams {
+                script_type,
+                ..UtxoChainParams::LITECOIN
+            });
+            let derived = module.derive_address(&test_seed(), 0, 0).unwrap();
+            assert!(derived.address.starts_with(prefix));
+
+            let details = ltc.validate_address_details(&derived.address);
+            assert_eq!(details.address_type.as_deref(), Some(address_type));
+        }
+
+        // Bitcoin addresses are not Litecoin addresses
+        assert!(!ltc.validate_address("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"));
+        assert!(!ltc.validate_address("1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"));
+        assert!(!ltc.validate_address(""));
+    }
+
+    #[test]
+    fn test_dogecoin_validate_address() {
+        let doge = UtxoModule::dogecoin();
+
+        let details = doge.validate_address_details("DBus3bamQjgJULBJtYXpEzDWQRwF5iwxgC");
+        assert!(details.is_valid);
+        assert_eq!(details.address_type.as_deref(), Some("p2pkh"));
+
+        assert!(!doge.validate_address("DBus3bamQjgJULBJtYXpEzDWQRwF5iwxgD"));
+        assert!(!doge.validate_address("1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"));
+        assert!(!doge.validate_address("ltc1qjmxnz78nmc8nq77wuxh25n2es7rzm5c2rkk4wh"));
+    }
+
+    #[test]
+    fn test_bitcoin_cash_validate_address() {
+        let bch = UtxoModule::bitcoin_cash();
+
+        // CashAddr with and without prefix
+        assert!(bch.validate_address("bitcoincash:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"));
+        assert!(bch.validate_address("qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"));
+
+        // Legacy format is still valid
+        let details = bch.validate_address_details("1BpEi6DfDAUFd7GtittLSdBeYJvcoaVggu");
+        assert!(details.is_valid);
+        assert_eq!(details.address_type.as_deref(), Some("p2pkh"));
+
+        assert!(!bch.validate_address("bchtest:qpm2qsznhks23z7629mms6s4cwef74vcwvy22gdx6a"));
+        assert!(!bch.validate_address("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"));
+    }
+}
@@ -0,0 +1,375 @@
+//! XRP Ledger chain module (BIP44)
+//!
+//! Generates r... addresses using BIP44 derivation.
+//! Path: m/44'/144'/account'/0/index
+//!
+//! The account ID is HASH160 of the compressed public key, encoded as
+//! Base58Check with version 0x00 using the Ripple alphabet. X-addresses
+//! (XLS-5d) pack the account ID and an optional destination tag into a
+//! single string; helpers are provided to convert between both forms.
+
+use bitcoin::hashes::{hash160, Hash};
+use bitcoin::secp256k1::{PublicKey, Secp256k1, SecretKey};
+
+use crate::wallet::chains::{coin_types, AccountXpub, ChainModule, DEFAULT_SCHEME};
+use crate::wallet::error::{WalletError, WalletResult};
+use crate::wallet::types::{AddressValidation, ChainFamily, DerivedAddress};
+
+use super::derive_key_from_seed;
+
+/// Version byte for classic account addresses
+const ACCOUNT_ID_VERSION: u8 = 0x00;
+/// X-address prefix bytes for mainnet ("X...")
+const X_ADDRESS_PREFIX_MAINNET: [u8; 2] = [0x05, 0x44];
+/// X-address prefix bytes for testnet ("T...")
+const X_ADDRESS_PREFIX_TESTNET: [u8; 2] = [0x04, 0x93];
+
+/// Decoded X-address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XAddress {
+    /// Classic r... address of the account
+    pub classic_address: String,
+    /// Destination tag, if the X-address carries one
+    pub tag: Option<u32>,
+    /// Whether the X-address is for a test network
+    pub is_testnet: bool,
+}
+
+/// XRP Ledger chain module
+pub struct XrplModule;
+
+impl XrplModule {
+    /// Create a new XRP Ledger mainnet module
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for XrplModule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Base58Check encode with the Ripple alphabet
+fn encode_check(payload: &[u8]) -> String {
+    bs58::encode(payload)
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .with_check()
+        .into_string()
+}
+
+/// Base58Check decode with the Ripple alphabet
+fn decode_check(address: &str) -> Option<Vec<u8>> {
+    bs58::decode(address)
+        .with_alphabet(bs58::Alphabet::RIPPLE)
+        .with_check(None)
+        .into_vec()
+        .ok()
+}
+
+/// Encode a 20-byte account ID as a classic r... address
+fn encode_account_id(account_id: &[u8; 20]) -> String {
+    let mut payload = [0u8; 21];
+    payload[0] = ACCOUNT_ID_VERSION;
+    payload[1..].copy_from_slice(account_id);
+    encode_check(&payload)
+}
+
+/// Decode a classic r... address into its 20-byte account ID
+fn decode_account_id(address: &str) -> Option<[u8; 20]> {
+    let payload = decode_check(address)?;
+    match payload.as_slice() {
+        [ACCOUNT_ID_VERSION, rest @ ..] => rest.try_into().ok(),
+        _ => None,
+    }
+}
+
+/// Convert a classic address and optional destination tag to an X-address
+pub fn encode_x_address(
+    classic_address: &str,
+    tag: Option<u32>,
+    is_testnet: bool,
+) -> WalletResult<String> {
+    let account_id =
+        decode_account_id(classic_address).ok_or_else(|| WalletError::InvalidAddress {
+            chain: "xrpl".to_string(),
+            address: classic_address.to_string(),
+        })?;
+
+    let prefix = if is_testnet {
+        X_ADDRESS_PREFIX_TESTNET
+    } else {
+        X_ADDRESS_PREFIX_MAINNET
+    };
+
+    // prefix(2) || account ID(20) || flag(1) || tag u32 LE(4) || reserved(4)
+    let mut payload = Vec::with_capacity(31);
+    payload.extend_from_slice(&prefix);
+    payload.extend_from_slice(&account_id);
+    payload.push(u8::from(tag.is_some()));
+    payload.extend_from_slice(&tag.unwrap_or(0).to_le_bytes());
+    payload.extend_from_slice(&[0u8; 4]);
+
+    Ok(encode_check(&payload))
+}
+
+/// Decode an X-address into its classic address and destination tag
+pub fn decode_x_address(address: &str) -> Option<XAddress> {
+    let payload = decode_check(address)?;
+    if payload.len() != 31 {
+        return None;
+    }
+
+    let is_testnet = match [payload[0], payload[1]] {
+        X_ADDRESS_PREFIX_MAINNET => false,
+        X_ADDRESS_PREFIX_TESTNET => true,
+        _ => return None,
+    };
+
+    let account_id: [u8; 20] = payload[2..22].try_into().ok()?;
+    let tag = u32::from_le_bytes(payload[23..27].try_into().ok()?);
+
+    // 64-bit tags are reserved; a missing tag must be encoded as zero
+    if payload[27..] != [0u8; 4] {
+        return None;
+    }
+    let tag = match payload[22] {
+        0 if tag == 0 => None,
+        1 => Some(tag),
+        _ => return None,
+    };
+
+    Some(XAddress {
+        classic_address: encode_account_id(&account_id),
+        tag,
+        is_testnet,
+    })
+}
+
+impl ChainModule for XrplModule {
+    fn chain_id(&self) -> &str {
+        "xrpl"
+    }
+
+    fn display_name(&self) -> &str {
+        "XRP Ledger"
+    }
+
+    fn chain_family(&self) -> ChainFamily {
+        ChainFamily::Secp256k1
+    }
+
+    fn coin_type(&self) -> u32 {
+        coin_types::XRP
+    }
+
+    fn symbol(&self) -> &str {
+        "XRP"
+    }
+
+    fn decimals(&self) -> u8 {
+        6
+    }
+
+    fn icon_name(&self) -> &str {
+        "xrp"
+    }
+
+    fn rpc_urls(&self) -> Vec<String> {
+        vec!["https://xrplcluster.com".to_string()]
+    }
+
+    fn explorer_url(&self) -> Option<&str> {
+        Some("https://livenet.xrpl.org")
+    }
+
+    fn derive_address(
+        &self,
+        seed: &[u8; 64],
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        // BIP44 path for XRP: m/44'/144'/account'/0/index
+        let path = self.derivation_path(account, index);
+        let derived_key = derive_key_from_seed(seed, &path)?;
+
+        let secp = Secp256k1::new();
+        let secret_key = SecretKey::from_slice(&derived_key.private_key().to_bytes())
+            .map_err(|e| WalletError::DerivationError(format!("Invalid private key: {}", e)))?;
+        let public_key = PublicKey::from_secret_key(&secp, &secret_key).serialize();
+
+        // Account ID is HASH160 of the compressed public key
+        let account_id = hash160::Hash::hash(&public_key).to_byte_array();
+
+        Ok(DerivedAddress {
+            chain: self.chain_id().to_string(),
+            chain_family: self.chain_family(),
+            address: encode_account_id(&account_id),
+            derivation_path: path,
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key.to_vec(),
+        })
+    }
+
+    fn derive_address_from_xpub(
+        &self,
+        account_key: &AccountXpub,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        account_key.check_mainnet(self.chain_id(), self.is_testnet())?;
+        let public_key = account_key.public_key_at(0, index)?.serialize();
+        let account_id = hash160::Hash::hash(&public_key).to_byte_array();
+
+        Ok(DerivedAddress {
+            chain: self.chain_id().to_string(),
+            chain_family: self.chain_family(),
+            address: encode_account_id(&account_id),
+            derivation_path: self.derivation_path(account_key.account(), index),
+            derivation_scheme: DEFAULT_SCHEME.to_string(),
+            public_key: public_key.to_vec(),
+        })
+    }
+
+    fn validate_address(&self, address: &str) -> bool {
+        self.validate_address_details(address).is_valid
+    }
+
+    fn validate_address_details(&self, address: &str) -> AddressValidation {
+        let memo_field = Some("destination_tag".to_string());
+
+        if address.starts_with('r') && decode_account_id(address).is_some() {
+            return AddressValidation {
+                is_valid: true,
+                address_type: Some("classic".to_string()),
+                memo_field,
+                ..Default::default()
+            };
+        }
+
+        match decode_x_address(address) {
+            Some(x_address) if !x_address.is_testnet => AddressValidation {
+                is_valid: true,
+                address_type: Some("x-address".to_string()),
+                memo_field,
+                embedded_memo: x_address.tag.map(u64::from),
+                base_address: Some(x_address.classic_address),
+            },
+            _ => AddressValidation::default(),
+        }
+    }
+
+    fn derivation_path(&self, account: u32, index: u32) -> String {
+        format!("m/44'/{}'/{account}'/0/{index}", self.coin_type())
+    }
+
+    fn account_node_path(&self, account: u32) -> Option<String> {
+        Some(format!("m/44'/{}'/{account}'", self.coin_type()))
+    }
+
+    fn address_prefix(&self) -> Option<&str> {
+        Some("r")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test seed from mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+    fn test_seed() -> [u8; 64] {
+        let seed_hex = "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4";
+        let mut seed = [0u8; 64];
+        hex::decode_to_slice(seed_hex, &mut seed).unwrap();
+        seed
+    }
+
+    // XLS-5d reference vectors
+    const CLASSIC: &str = "r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59";
+    const X_NO_TAG: &str = "X7AcgcsBL6XDcUb289X4mJ8djcdyKaB5hJDWMArnXr61cqZ";
+    const X_TAG_1: &str = "X7AcgcsBL6XDcUb289X4mJ8djcdyKaGZMhc9YTE92ehJ2Fu";
+
+    #[test]
+    fn test_xrpl_module_chain_id() {
+        let module = XrplModule::new();
+        assert_eq!(module.chain_id(), "xrpl");
+        assert_eq!(module.chain_family(), ChainFamily::Secp256k1);
+        assert_eq!(module.coin_type(), 144);
+        assert_eq!(module.symbol(), "XRP");
+        assert_eq!(module.decimals(), 6);
+        assert_eq!(module.derivation_path(0, 0), "m/44'/144'/0'/0/0");
+    }
+
+    #[test]
+    fn test_xrpl_derive_address() {
+        let module = XrplModule::new();
+        let derived = module.derive_address(&test_seed(), 0, 0).unwrap();
+
+        assert_eq!(derived.chain, "xrpl");
+        assert_eq!(derived.derivation_path, "m/44'/144'/0'/0/0");
+        assert_eq!(derived.public_key.len(), 33);
+        assert_eq!(derived.address, "rHsMGQEkVNJmpGWs8XUBoTBiAAbwxZN5v3");
+        assert!(module.validate_address(&derived.address));
+    }
+
+    #[test]
+    fn test_xrpl_validate_address() {
+        let module = XrplModule::new();
+
+        assert!(module.validate_address(CLASSIC));
+        assert!(module.validate_address(X_NO_TAG));
+        assert!(module.validate_address(X_TAG_1));
+
+        // Bad checksum, Bitcoin alphabet, wrong chain
+        assert!(!module.validate_address("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpg"));
+        assert!(!module.validate_address("1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"));
+        assert!(!module.validate_address("TUEZSdKsoDHQMeZwihtdoBiN46zxhGWYdH"));
+        assert!(!module.validate_address(""));
+    }
+
+    #[test]
+    fn test_xrpl_x_address_roundtrip() {
+        assert_eq!(encode_x_address(CLASSIC, None, false).unwrap(), X_NO_TAG);
+        assert_eq!(encode_x_address(CLASSIC, Some(1), false).unwrap(), X_TAG_1);
+
+        let decoded = decode_x_address(X_TAG_1).unwrap();
+        assert_eq!(decoded.classic_address, CLASSIC);
+        assert_eq!(decoded.tag, Some(1));
+        assert!(!decoded.is_testnet);
+
+        let testnet = encode_x_address(CLASSIC, Some(42), true).unwrap();
+        assert!(testnet.starts_with('T'));
+        let decoded = decode_x_address(&testnet).unwrap();
+        assert_eq!(decoded.tag, Some(42));
+        assert!(decoded.is_testnet);
+
+        // Testnet X-addresses are not valid on mainnet
+        assert!(!XrplModule::new().validate_address(&testnet));
+        assert!(decode_x_address(CLASSIC).is_none());
+    }
+
+    #[test]
+    fn test_xrpl_validation_details() {
+        let module = XrplModule::new();
+
+        let classic = module.validate_address_details(CLASSIC);
+        assert!(classic.is_valid);
+        assert_eq!(classic.address_type.as_deref(), Some("classic"));
+        assert_eq!(classic.memo_field.as_deref(), Some("destination_tag"));
+        assert_eq!(classic.embedded_memo, None);
+
+        let tagged = module.validate_address_details(X_TAG_1);
+        assert_eq!(tagged.address_type.as_deref(), Some("x-address"));
+        assert_eq!(tagged.embedded_memo, Some(1));
+        assert_eq!(tagged.base_address.as_deref(), Some(CLASSIC));
+
+        let untagged = module.validate_address_details(X_NO_TAG);
+        assert_eq!(untagged.embedded_memo, None);
+        assert_eq!(untagged.base_address.as_deref(), Some(CLASSIC));
+
+        assert_eq!(
+            module.validate_address_details("invalid"),
+            AddressValidation::default()
+        );
+    }
+}
@@ -0,0 +1,60 @@
+//! Chain-generic signing
+//!
+//! `ChainSigner` is a companion to `ChainModule`: modules that can sign
+//! return themselves from `ChainModule::signer`, and `ChainRegistry`
+//! dispatches to them. Each chain uses its native scheme:
+//!
+//! | Chain     | Messages                         | Transactions                    |
+//! |-----------|----------------------------------|---------------------------------|
+//! | EVM       | EIP-191 personal_sign            | keccak256 of the unsigned tx    |
+//! | Bitcoin   | BIP-322 simple (legacy: BIP-137) | PSBT (inputs with key origins)  |
+//! | Solana    | Off-chain message (v0 header)    | ed25519 over the message bytes  |
+//! | Nostr     | BIP-340 over SHA-256 of message  | NIP-01 event JSON               |
+//!
+//! Signatures are returned in the chain's customary text encoding (0x-hex,
+//! base64 or base58) so they can be shown or pasted as-is.
+
+use serde::Serialize;
+
+use crate::wallet::error::WalletResult;
+
+/// Signature produced by a `ChainSigner`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChainSignature {
+    /// Address of the signing key
+    pub address: String,
+    /// Signing scheme (e.g., "eip191", "bip322-simple", "psbt")
+    pub scheme: String,
+    /// Encoded signature, or the signed payload for PSBTs
+    pub signature: String,
+}
+
+/// Message and transaction signing for a chain
+///
+/// Keys are derived from the BIP39 seed at an explicit derivation path, so
+/// addresses from any derivation scheme can sign.
+pub trait ChainSigner: Send + Sync {
+    /// Sign an arbitrary message with the chain's message-signing scheme
+    fn sign_message(
+        &self,
+        seed: &[u8; 64],
+        path: &str,
+        message: &[u8],
+    ) -> WalletResult<ChainSignature>;
+
+    /// Sign a serialized transaction
+    ///
+    /// The payload encoding is chain-specific (see the module docs).
+    fn sign_transaction(
+        &self,
+        seed: &[u8; 64],
+        path: &str,
+        payload: &[u8],
+    ) -> WalletResult<ChainSignature>;
+
+    /// Verify a message signature produced by `sign_message`
+    ///
+    /// Returns `Ok(false)` for signatures that are malformed or do not match,
+    /// and an error only if the address itself is invalid.
+    fn verify_message(&self, address: &str, message: &[u8], signature: &str) -> WalletResult<bool>;
+}
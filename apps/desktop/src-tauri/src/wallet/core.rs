@@ -4,6 +4,8 @@
 //! - Creating HD wallets from mnemonic
 //! - Importing existing wallets
 //! - Deriving addresses for multiple chains
+//! - Deriving addresses with alternative derivation schemes
+//! - Signing messages and transactions
 //! - Lock/unlock wallet sessions
 //!
 //! # Architecture
@@ -13,14 +15,29 @@
 //! - SecureStorage for encrypted secret storage
 //! - Database for wallet metadata
 
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 use std::sync::Arc;
 
+use parking_lot::RwLock;
+use zeroize::Zeroizing;
+
+use crate::wallet::bip85::{self, Bip85Application, Bip85Child, Bip85Language};
+use crate::wallet::chains::{
+    AccountXpub, ChainSignature, DerivationNode, DerivationSchemeInfo, NostrEvent, NostrModule,
+    UnsignedEvent, DEFAULT_SCHEME, NOSTR_CHAIN_ID,
+};
+use crate::wallet::discovery::SchemeCandidate;
 use crate::wallet::error::{WalletError, WalletResult};
-use crate::wallet::mnemonic::{generate_mnemonic, mnemonic_to_seed, parse_mnemonic, MnemonicLength};
+use crate::wallet::inspect::AddressInspection;
+use crate::wallet::mnemonic::{
+    generate_mnemonic, mnemonic_to_master_secret, parse_mnemonic, MnemonicLength,
+};
 use crate::wallet::registry::{ChainInfo, ChainRegistry};
 use crate::wallet::storage::SecureStorage;
 use crate::wallet::types::{
-    CreateHDWalletRequest, CreateHDWalletResponse, DerivedAddress, SecretMnemonic, WalletType,
+    AddressValidation, CreateHDWalletRequest, CreateHDWalletResponse, DerivedAddress, MasterSecret,
+    SecretMnemonic, WalletType,
 };
 
 /// Main wallet manager instance
@@ -31,6 +48,8 @@ pub struct WalletManager {
     registry: ChainRegistry,
     /// Secure storage for secrets
     storage: Arc<SecureStorage>,
+    /// Account xpubs of watch-only wallets: wallet ID -> chain ID -> key
+    watch_keys: RwLock<HashMap<String, HashMap<String, AccountXpub>>>,
 }
 
 impl Default for WalletManager {
@@ -45,6 +64,7 @@ impl WalletManager {
         Self {
             registry: ChainRegistry::new(),
             storage: Arc::new(SecureStorage::new()),
+            watch_keys: RwLock::new(HashMap::new()),
         }
     }
 
@@ -77,6 +97,20 @@ impl WalletManager {
         self.registry.validate_address(chain_id, address)
     }
 
+    /// Validate an address for a specific chain, reporting its address type
+    pub fn validate_address_details(
+        &self,
+        chain_id: &str,
+        address: &str,
+    ) -> WalletResult<AddressValidation> {
+        self.registry.validate_address_details(chain_id, address)
+    }
+
+    /// Detect which chains, networks and address types an input belongs to
+    pub fn inspect_address(&self, input: &str) -> AddressInspection {
+        self.registry.inspect_address(input)
+    }
+
     // =========================================================================
     // Mnemonic Operations
     // =========================================================================
@@ -122,19 +156,19 @@ impl WalletManager {
         // 1. Generate mnemonic
         let mnemonic = self.generate_mnemonic(request.word_count)?;
 
-        // 2. Derive seed from mnemonic
-        let seed = mnemonic_to_seed(&mnemonic, "")?;
+        // 2. Derive seed (and entropy, for entropy-based chains) from mnemonic
+        let secret = mnemonic_to_master_secret(&mnemonic, "")?;
 
         // 3. Derive addresses for each requested chain
         let addresses = self
             .registry
-            .derive_addresses(&request.chains, seed.as_bytes(), 0)?;
+            .derive_addresses(&request.chains, &secret, 0)?;
 
         // 4. Generate wallet ID
         let wallet_id = uuid::Uuid::new_v4().to_string();
 
         // 5. Cache seed in session (for further derivation)
-        self.storage.cache_seed(&wallet_id, *seed.as_bytes());
+        self.storage.cache_master_secret(&wallet_id, &secret);
 
         // 6. Return response (mnemonic is shown once for backup)
         Ok(CreateHDWalletResponse {
@@ -161,25 +195,71 @@ impl WalletManager {
         // 1. Parse and validate mnemonic
         let mnemonic = parse_mnemonic(mnemonic_phrase)?;
 
-        // 2. Derive seed
-        let seed = mnemonic_to_seed(&mnemonic, "")?;
+        // 2. Derive seed and entropy
+        let secret = mnemonic_to_master_secret(&mnemonic, "")?;
 
+        self.register_hd_wallet(&mnemonic, &secret, chains)
+    }
+
+    /// Derive first addresses and cache the secret under a new wallet ID
+    fn register_hd_wallet(
+        &self,
+        mnemonic: &SecretMnemonic,
+        secret: &MasterSecret,
+        chains: &[String],
+    ) -> WalletResult<CreateHDWalletResponse> {
         // 3. Derive addresses
-        let addresses = self.registry.derive_addresses(chains, seed.as_bytes(), 0)?;
+        let addresses = self.registry.derive_addresses(chains, secret, 0)?;
 
         // 4. Generate wallet ID
         let wallet_id = uuid::Uuid::new_v4().to_string();
 
         // 5. Cache seed
-        self.storage.cache_seed(&wallet_id, *seed.as_bytes());
+        self.storage.cache_master_secret(&wallet_id, secret);
 
         Ok(CreateHDWalletResponse {
             wallet_id,
-            mnemonic: mnemonic_phrase.to_string(),
+            mnemonic: mnemonic.as_str().to_string(),
             addresses,
         })
     }
 
+    // =========================================================================
+    // BIP-85 Child Wallets
+    // =========================================================================
+
+    /// Derive a BIP-85 child secret (mnemonic, WIF, xprv, hex, password)
+    ///
+    /// The wallet must be unlocked.
+    pub fn derive_bip85_child(
+        &self,
+        wallet_id: &str,
+        application: Bip85Application,
+        index: u32,
+    ) -> WalletResult<Bip85Child> {
+        let seed = Zeroizing::new(self.storage.get_seed(wallet_id)?);
+        bip85::derive_child(&bip85::master_key(&seed)?, application, index)
+    }
+
+    /// Import a BIP-85 child mnemonic of an unlocked wallet as a new HD wallet
+    ///
+    /// The child is derived in place, so non-English mnemonics import too.
+    /// The response carries the child mnemonic for backup.
+    pub fn import_bip85_wallet(
+        &self,
+        parent_wallet_id: &str,
+        language: Bip85Language,
+        words: u32,
+        index: u32,
+        chains: &[String],
+    ) -> WalletResult<CreateHDWalletResponse> {
+        let seed = Zeroizing::new(self.storage.get_seed(parent_wallet_id)?);
+        let (mnemonic, secret) =
+            bip85::derive_mnemonic_secret(&bip85::master_key(&seed)?, language, words, index)?;
+
+        self.register_hd_wallet(&mnemonic, &secret, chains)
+    }
+
     // =========================================================================
     // Address Derivation
     // =========================================================================
@@ -198,10 +278,325 @@ impl WalletManager {
         account: u32,
         index: u32,
     ) -> WalletResult<DerivedAddress> {
-        // Get cached seed (wallet must be unlocked)
-        let seed = self.storage.get_seed(wallet_id)?;
+        let mut addresses =
+            self.derive_range(wallet_id, chain_id, account, index..index.saturating_add(1))?;
+        addresses
+            .pop()
+            .ok_or_else(|| WalletError::DerivationError(format!("Invalid address index {}", index)))
+    }
+
+    /// Derive the addresses at `indices` of an account in one call
+    ///
+    /// The account-level node is derived once and cached in the session, so
+    /// each further address costs one or two derivation steps instead of
+    /// walking the full path from the seed. Chains without cached nodes
+    /// (Cardano, Stellar) fall back to per-index derivation.
+    pub fn derive_range(
+        &self,
+        wallet_id: &str,
+        chain_id: &str,
+        account: u32,
+        indices: Range<u32>,
+    ) -> WalletResult<Vec<DerivedAddress>> {
+        if let Some(account_key) = self.watch_key(wallet_id, chain_id) {
+            return indices
+                .map(|index| self.derive_watch_address(chain_id, &account_key, account, index))
+                .collect();
+        }
+
+        let module = self
+            .registry
+            .get(chain_id)
+            .ok_or_else(|| WalletError::UnsupportedChain(chain_id.to_string()))?;
+
+        let Some(path) = module.account_node_path(account) else {
+            // Get cached secrets (wallet must be unlocked)
+            let secret = self.storage.get_master_secret(wallet_id)?;
+            return indices
+                .map(|index| {
+                    self.registry
+                        .derive_address_with_secret(chain_id, &secret, account, index)
+                })
+                .collect();
+        };
+
+        let family = module.chain_family();
+        let is_testnet = module.is_testnet();
+        let node = self.storage.account_node(
+            wallet_id,
+            &DerivationNode::cache_key(family, is_testnet, &path),
+            |seed| DerivationNode::derive(family, is_testnet, seed, &path),
+        )?;
+
+        indices
+            .map(|index| module.derive_address_from_node(&node, account, index))
+            .collect()
+    }
+
+    // =========================================================================
+    // Watch-only Wallets
+    // =========================================================================
+
+    /// Attach an account-level xpub to a watch-only wallet
+    ///
+    /// Afterwards `derive_address` and `scheme_candidates` work for the
+    /// wallet and chain without any secrets. Returns the first receive address.
+    /// Wallets with a master secret in the session are refused, as the xpub
+    /// would take over their derivation.
+    pub fn add_watch_xpub(
+        &self,
+        wallet_id: &str,
+        chain_id: &str,
+        xpub: &str,
+    ) -> WalletResult<DerivedAddress> {
+        let (account_key, first) = self.parse_watch_xpub(wallet_id, chain_id, xpub)?;
+
+        self.watch_keys
+            .write()
+            .entry(wallet_id.to_string())
+            .or_default()
+            .insert(chain_id.to_string(), account_key);
+
+        Ok(first)
+    }
+
+    /// Check that `add_watch_xpub` would accept an xpub, without attaching it
+    ///
+    /// Lets callers persist the key before registering it.
+    pub fn check_watch_xpub(
+        &self,
+        wallet_id: &str,
+        chain_id: &str,
+        xpub: &str,
+    ) -> WalletResult<DerivedAddress> {
+        self.parse_watch_xpub(wallet_id, chain_id, xpub)
+            .map(|(_, first)| first)
+    }
+
+    fn parse_watch_xpub(
+        &self,
+        wallet_id: &str,
+        chain_id: &str,
+        xpub: &str,
+    ) -> WalletResult<(AccountXpub, DerivedAddress)> {
+        if self.storage.session().has_seed(wallet_id) {
+            return Err(WalletError::InvalidExtendedKey(format!(
+                "wallet {} has its own keys; xpubs are for watch-only wallets",
+                wallet_id
+            )));
+        }
+        let account_key = AccountXpub::parse(xpub)?;
+        let first = self
+            .registry
+            .derive_address_from_xpub(chain_id, &account_key, 0)?;
+        Ok((account_key, first))
+    }
+
+    /// Forget all xpubs of a watch-only wallet
+    pub fn remove_watch_wallet(&self, wallet_id: &str) {
+        self.watch_keys.write().remove(wallet_id);
+    }
+
+    /// Account xpub of a watch-only wallet on a chain
+    pub fn watch_key(&self, wallet_id: &str, chain_id: &str) -> Option<AccountXpub> {
+        self.watch_keys
+            .read()
+            .get(wallet_id)
+            .and_then(|keys| keys.get(chain_id))
+            .cloned()
+    }
+
+    fn derive_watch_address(
+        &self,
+        chain_id: &str,
+        account_key: &AccountXpub,
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        if account != account_key.account() {
+            return Err(WalletError::DerivationError(format!(
+                "Watch-only key covers account {} only",
+                account_key.account()
+            )));
+        }
+
+        self.registry
+            .derive_address_from_xpub(chain_id, account_key, index)
+    }
+
+    // =========================================================================
+    // Derivation Schemes
+    // =========================================================================
+
+    /// List the derivation schemes available for a chain
+    pub fn get_derivation_schemes(
+        &self,
+        chain_id: &str,
+    ) -> WalletResult<Vec<DerivationSchemeInfo>> {
+        self.registry.derivation_schemes(chain_id)
+    }
+
+    /// Derive an address for an existing wallet using a named scheme
+    pub fn derive_address_with_scheme(
+        &self,
+        wallet_id: &str,
+        chain_id: &str,
+        scheme_id: &str,
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        if let Some(account_key) = self.watch_key(wallet_id, chain_id) {
+            if scheme_id != DEFAULT_SCHEME {
+                return Err(WalletError::DerivationError(
+                    "Watch-only wallets only support the default scheme".to_string(),
+                ));
+            }
+            return self.derive_watch_address(chain_id, &account_key, account, index);
+        }
+
+        let secret = self.storage.get_master_secret(wallet_id)?;
 
-        self.registry.derive_address(chain_id, &seed, account, index)
+        self.registry
+            .derive_address_with_scheme(chain_id, &secret, scheme_id, account, index)
+    }
+
+    /// Derive the first address of accounts `0..accounts` for every scheme
+    ///
+    /// Used by the import-time scan. Paths shared by several schemes (e.g.
+    /// MetaMask account 0 and the default) are only listed once, under the
+    /// first scheme.
+    ///
+    /// An xpub pins both the scheme and the account, so watch-only wallets
+    /// get a single candidate, the first receive address, whatever
+    /// `accounts` is.
+    pub fn scheme_candidates(
+        &self,
+        wallet_id: &str,
+        chain_id: &str,
+        accounts: u32,
+    ) -> WalletResult<Vec<SchemeCandidate>> {
+        if let Some(account_key) = self.watch_key(wallet_id, chain_id) {
+            return Ok(vec![SchemeCandidate {
+                scheme_name: "Watch-only".to_string(),
+                account: account_key.account(),
+                address: self
+                    .registry
+                    .derive_address_from_xpub(chain_id, &account_key, 0)?,
+            }]);
+        }
+
+        let secret = self.storage.get_master_secret(wallet_id)?;
+        let mut seen_paths = HashSet::new();
+        let mut candidates = Vec::new();
+
+        for scheme in self.registry.derivation_schemes(chain_id)? {
+            for account in 0..accounts {
+                let address = self
+                    .registry
+                    .derive_address_with_scheme(chain_id, &secret, &scheme.id, account, 0)?;
+                if seen_paths.insert(address.derivation_path.clone()) {
+                    candidates.push(SchemeCandidate {
+                        scheme_name: scheme.name.clone(),
+                        account,
+                        address,
+                    });
+                }
+            }
+        }
+
+        Ok(candidates)
+    }
+
+    // =========================================================================
+    // Signing
+    // =========================================================================
+
+    /// Sign a message with a wallet address
+    ///
+    /// The address is identified by chain, derivation scheme, account and
+    /// index, as returned by derivation.
+    pub fn sign_message(
+        &self,
+        wallet_id: &str,
+        chain_id: &str,
+        scheme_id: &str,
+        account: u32,
+        index: u32,
+        message: &[u8],
+    ) -> WalletResult<ChainSignature> {
+        let secret = self.storage.get_master_secret(wallet_id)?;
+        let path = self
+            .registry
+            .scheme_path(chain_id, scheme_id, account, index)?;
+
+        self.registry
+            .sign_message(chain_id, &secret, &path, message)
+    }
+
+    /// Sign a serialized transaction with a wallet address
+    pub fn sign_transaction(
+        &self,
+        wallet_id: &str,
+        chain_id: &str,
+        scheme_id: &str,
+        account: u32,
+        index: u32,
+        payload: &[u8],
+    ) -> WalletResult<ChainSignature> {
+        let secret = self.storage.get_master_secret(wallet_id)?;
+        let path = self
+            .registry
+            .scheme_path(chain_id, scheme_id, account, index)?;
+
+        self.registry
+            .sign_transaction(chain_id, &secret, &path, payload)
+    }
+
+    /// Verify a message signature for an address
+    pub fn verify_message(
+        &self,
+        chain_id: &str,
+        address: &str,
+        message: &[u8],
+        signature: &str,
+    ) -> WalletResult<bool> {
+        self.registry
+            .verify_message(chain_id, address, message, signature)
+    }
+
+    // =========================================================================
+    // Nostr Identity
+    // =========================================================================
+
+    /// Sign a Nostr event with the wallet's NIP-06 identity for `account`
+    pub fn sign_nostr_event(
+        &self,
+        wallet_id: &str,
+        account: u32,
+        event: &UnsignedEvent,
+    ) -> WalletResult<NostrEvent> {
+        let seed = Zeroizing::new(self.storage.get_seed(wallet_id)?);
+        let path = self
+            .registry
+            .scheme_path(NOSTR_CHAIN_ID, DEFAULT_SCHEME, account, 0)?;
+
+        NostrModule::sign_event(&seed, &path, event)
+    }
+
+    /// Export the NIP-06 identity key for `account` as `nsec`
+    ///
+    /// The wallet must be unlocked.
+    pub fn export_nostr_nsec(
+        &self,
+        wallet_id: &str,
+        account: u32,
+    ) -> WalletResult<Zeroizing<String>> {
+        let seed = Zeroizing::new(self.storage.get_seed(wallet_id)?);
+        let path = self
+            .registry
+            .scheme_path(NOSTR_CHAIN_ID, DEFAULT_SCHEME, account, 0)?;
+
+        NostrModule::nsec_at_path(&seed, &path)
     }
 
     // =========================================================================
@@ -264,9 +659,7 @@ mod tests {
             .unwrap());
 
         // Invalid for chain
-        assert!(!manager
-            .validate_address("bitcoin", "0x1234")
-            .unwrap());
+        assert!(!manager.validate_address("bitcoin", "0x1234").unwrap());
     }
 
     #[test]
@@ -377,6 +770,359 @@ mod tests {
         assert_ne!(addr.address, response.addresses[0].address);
     }
 
+    #[test]
+    fn test_wallet_manager_derive_cardano_after_import() {
+        let manager = WalletManager::new();
+
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let response = manager
+            .import_hd_wallet("Test", mnemonic, &["cardano".to_string()], "password")
+            .unwrap();
+        assert!(response.addresses[0].address.starts_with("addr1"));
+
+        // Entropy is cached alongside the seed for later derivation
+        let addr = manager
+            .derive_address(&response.wallet_id, "cardano", 0, 1)
+            .unwrap();
+        assert_eq!(addr.derivation_path, "m/1852'/1815'/0'/0/1");
+        assert_ne!(addr.address, response.addresses[0].address);
+    }
+
+    #[test]
+    fn test_wallet_manager_scheme_candidates() {
+        let manager = WalletManager::new();
+
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let response = manager
+            .import_hd_wallet("Test", mnemonic, &["solana".to_string()], "password")
+            .unwrap();
+
+        let candidates = manager
+            .scheme_candidates(&response.wallet_id, "solana", 2)
+            .unwrap();
+        let paths: Vec<&str> = candidates
+            .iter()
+            .map(|c| c.address.derivation_path.as_str())
+            .collect();
+
+        // Phantom duplicates the default paths; Solana CLI has a single key
+        assert_eq!(
+            paths,
+            vec![
+                "m/44'/501'/0'/0'",
+                "m/44'/501'/1'/0'",
+                "m/44'/501'",
+                "m/44'/501'/0'",
+                "m/44'/501'/1'"
+            ]
+        );
+        assert_eq!(candidates[2].address.derivation_scheme, "solana_cli");
+        assert_eq!(candidates[2].address.address, {
+            manager
+                .derive_address_with_scheme(&response.wallet_id, "solana", "solana_cli", 0, 0)
+                .unwrap()
+                .address
+        });
+    }
+
+    #[test]
+    fn test_wallet_manager_sign_message() {
+        let manager = WalletManager::new();
+
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let response = manager
+            .import_hd_wallet("Test", mnemonic, &["ethereum".to_string()], "password")
+            .unwrap();
+
+        let signed = manager
+            .sign_message(&response.wallet_id, "ethereum", "default", 0, 0, b"hello")
+            .unwrap();
+        assert_eq!(signed.address, response.addresses[0].address);
+        assert!(manager
+            .verify_message("ethereum", &signed.address, b"hello", &signed.signature)
+            .unwrap());
+
+        // Signing needs the unlocked session
+        manager.lock();
+        assert!(manager
+            .sign_message(&response.wallet_id, "ethereum", "default", 0, 0, b"hello")
+            .is_err());
+    }
+
+    #[test]
+    fn test_derive_range_matches_seed_derivation() {
+        let manager = WalletManager::new();
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let chains = manager.registry().chain_ids();
+        let wallet = manager
+            .import_hd_wallet("Test", mnemonic, &chains, "password")
+            .unwrap();
+        let secret = manager
+            .storage
+            .get_master_secret(&wallet.wallet_id)
+            .unwrap();
+
+        for chain_id in &chains {
+            let module = manager.registry().get(chain_id).unwrap();
+            let count = if module.account_node_path(1).is_some() {
+                4
+            } else {
+                1
+            };
+
+            let range = manager
+                .derive_range(&wallet.wallet_id, chain_id, 1, 0..count)
+                .unwrap();
+            assert_eq!(range.len() as u32, count);
+
+            for (index, address) in (0..count).zip(&range) {
+                let expected = manager
+                    .registry()
+                    .derive_address_with_secret(chain_id, &secret, 1, index)
+                    .unwrap();
+                assert_eq!(
+                    address.address, expected.address,
+                    "{} index {}",
+                    chain_id, index
+                );
+                assert_eq!(address.derivation_path, expected.derivation_path);
+                assert_eq!(
+                    address.public_key, expected.public_key,
+                    "{} public key",
+                    chain_id
+                );
+            }
+        }
+
+        // Single derivations reuse the cached nodes
+        let cached = manager.storage.session().node_count(&wallet.wallet_id);
+        assert!(cached > 0);
+        manager
+            .derive_address(&wallet.wallet_id, "ethereum", 1, 9)
+            .unwrap();
+        manager
+            .derive_address(&wallet.wallet_id, "arbitrum", 1, 10)
+            .unwrap();
+        assert_eq!(
+            manager.storage.session().node_count(&wallet.wallet_id),
+            cached
+        );
+
+        // Locking clears the nodes
+        manager.lock();
+        assert!(manager
+            .derive_range(&wallet.wallet_id, "ethereum", 1, 0..2)
+            .is_err());
+        assert_eq!(manager.storage.session().node_count(&wallet.wallet_id), 0);
+    }
+
+    #[test]
+    fn test_import_bip85_wallet() {
+        let manager = WalletManager::new();
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let chains = vec!["ethereum".to_string(), "solana".to_string()];
+        let parent = manager
+            .import_hd_wallet("Root", mnemonic, &chains, "password")
+            .unwrap();
+
+        let child = manager
+            .import_bip85_wallet(&parent.wallet_id, Bip85Language::English, 12, 0, &chains)
+            .unwrap();
+        assert_eq!(child.mnemonic.split_whitespace().count(), 12);
+        assert_ne!(child.addresses[0].address, parent.addresses[0].address);
+
+        // The child phrase restores the same wallet
+        let restored = manager
+            .import_hd_wallet("Restored", &child.mnemonic, &chains, "password")
+            .unwrap();
+        assert_eq!(restored.addresses[0].address, child.addresses[0].address);
+
+        // Same as the BIP-85 child derived for display
+        let shown = manager
+            .derive_bip85_child(
+                &parent.wallet_id,
+                Bip85Application::Mnemonic {
+                    language: Bip85Language::English,
+                    words: 12,
+                },
+                0,
+            )
+            .unwrap();
+        assert_eq!(shown.value, child.mnemonic);
+
+        // Other wordlists import without going through English parsing
+        let japanese = manager
+            .import_bip85_wallet(&parent.wallet_id, Bip85Language::Japanese, 24, 0, &chains)
+            .unwrap();
+        assert_eq!(japanese.addresses.len(), 2);
+
+        manager.lock();
+        assert!(manager
+            .derive_bip85_child(&parent.wallet_id, Bip85Application::Wif, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_nostr_identity() {
+        let manager = WalletManager::new();
+        let mnemonic =
+            "leader monkey parrot ring guide accident before fence cannon height naive bean";
+        let response = manager
+            .import_hd_wallet("Nostr", mnemonic, &["nostr".to_string()], "password")
+            .unwrap();
+
+        // NIP-06 test vector
+        let npub = "npub1zutzeysacnf9rru6zqwmxd54mud0k44tst6l70ja5mhv8jjumytsd2x7nu";
+        assert_eq!(response.addresses[0].address, npub);
+        assert_eq!(
+            manager
+                .export_nostr_nsec(&response.wallet_id, 0)
+                .unwrap()
+                .as_str(),
+            "nsec10allq0gjx7fddtzef0ax00mdps9t2kmtrldkyjfs8l5xruwvh2dq0lhhkp"
+        );
+
+        let note = UnsignedEvent {
+            created_at: 1_700_000_000,
+            kind: 1,
+            tags: Vec::new(),
+            content: "gm".to_string(),
+        };
+        let event = manager
+            .sign_nostr_event(&response.wallet_id, 0, &note)
+            .unwrap();
+        assert!(event.verify());
+
+        // Generic signing goes through the registry
+        let signed = manager
+            .sign_message(&response.wallet_id, "nostr", DEFAULT_SCHEME, 0, 0, b"gm")
+            .unwrap();
+        assert_eq!(signed.address, npub);
+        assert!(manager
+            .verify_message("nostr", npub, b"gm", &signed.signature)
+            .unwrap());
+
+        manager.lock();
+        assert!(manager
+            .sign_nostr_event(&response.wallet_id, 0, &note)
+            .is_err());
+    }
+
+    /// Account xpub of the test mnemonic at `m/purpose'/coin_type'/0'`
+    fn test_account_xpub(path: &str) -> String {
+        use bitcoin::bip32::{DerivationPath, Xpriv, Xpub};
+        use bitcoin::secp256k1::Secp256k1;
+        use std::str::FromStr;
+
+        use crate::wallet::mnemonic::mnemonic_to_seed;
+
+        let mnemonic = parse_mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about").unwrap();
+        let seed = mnemonic_to_seed(&mnemonic, "").unwrap();
+        let secp = Secp256k1::new();
+        let master = Xpriv::new_master(bitcoin::NetworkKind::Main, seed.as_bytes()).unwrap();
+        let account = master
+            .derive_priv(&secp, &DerivationPath::from_str(path).unwrap())
+            .unwrap();
+        Xpub::from_priv(&secp, &account).to_string()
+    }
+
+    #[test]
+    fn test_watch_only_xpub_derivation() {
+        let manager = WalletManager::new();
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let chains = ["ethereum", "tron", "bitcoin_legacy", "xrpl"];
+        let hd = manager
+            .import_hd_wallet("HD", mnemonic, &chains.map(str::to_string), "password")
+            .unwrap();
+
+        let watch_id = "watch_test";
+        for (chain, path) in [
+            ("ethereum", "m/44'/60'/0'"),
+            ("tron", "m/44'/195'/0'"),
+            ("bitcoin_legacy", "m/44'/0'/0'"),
+            ("xrpl", "m/44'/144'/0'"),
+        ] {
+            let first = manager
+                .add_watch_xpub(watch_id, chain, &test_account_xpub(path))
+                .unwrap();
+            assert_eq!(
+                first.address,
+                manager
+                    .derive_address(&hd.wallet_id, chain, 0, 0)
+                    .unwrap()
+                    .address
+            );
+
+            let watched = manager.derive_address(watch_id, chain, 0, 5).unwrap();
+            let derived = manager.derive_address(&hd.wallet_id, chain, 0, 5).unwrap();
+            assert_eq!(watched.address, derived.address);
+            assert_eq!(watched.derivation_path, derived.derivation_path);
+        }
+
+        // Discovery only lists the watched account
+        let candidates = manager.scheme_candidates(watch_id, "ethereum", 3).unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].account, 0);
+        assert_eq!(candidates[0].address.derivation_path, "m/44'/60'/0'/0/0");
+
+        // Only the xpub's own account and the default scheme are available
+        assert!(manager.derive_address(watch_id, "ethereum", 1, 0).is_err());
+        assert!(manager
+            .derive_address_with_scheme(watch_id, "ethereum", "ledger_live", 0, 0)
+            .is_err());
+
+        // Watch-only derivation needs no unlocked session
+        manager.lock();
+        assert!(manager.derive_address(watch_id, "tron", 0, 1).is_ok());
+
+        manager.remove_watch_wallet(watch_id);
+        assert!(manager.derive_address(watch_id, "tron", 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_watch_only_xpub_rejects_mismatches() {
+        let manager = WalletManager::new();
+        // BIP84 zpub: native SegWit only
+        let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+
+        assert_eq!(
+            manager
+                .add_watch_xpub("w", "bitcoin", zpub)
+                .unwrap()
+                .address,
+            "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+        );
+        assert!(manager.add_watch_xpub("w", "bitcoin_legacy", zpub).is_err());
+        assert!(manager
+            .add_watch_xpub("w", "bitcoin_testnet", zpub)
+            .is_err());
+        assert!(manager.add_watch_xpub("w", "solana", zpub).is_err());
+
+        // Testnet keys are refused on mainnet account-based chains
+        let mut tpub: bitcoin::bip32::Xpub = test_account_xpub("m/44'/60'/0'").parse().unwrap();
+        tpub.network = bitcoin::NetworkKind::Test;
+        let tpub = tpub.to_string();
+        for chain in ["ethereum", "tron", "xrpl"] {
+            assert!(manager.add_watch_xpub("w", chain, &tpub).is_err());
+        }
+        assert!(manager.add_watch_xpub("w", "sepolia", &tpub).is_ok());
+
+        // An HD wallet keeps deriving from its seed
+        let hd = manager
+            .import_hd_wallet(
+                "HD",
+                "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+                &["ethereum".to_string()],
+                "password",
+            )
+            .unwrap();
+        let other = test_account_xpub("m/44'/60'/1'");
+        assert!(manager
+            .add_watch_xpub(&hd.wallet_id, "ethereum", &other)
+            .is_err());
+        assert!(manager.watch_key(&hd.wallet_id, "ethereum").is_none());
+    }
+
     #[test]
     fn test_wallet_manager_lock_unlock() {
         let manager = WalletManager::new();
@@ -0,0 +1,402 @@
+//! Derivation scheme discovery
+//!
+//! A mnemonic imported from another wallet may hold funds on a path other
+//! than our default (Ledger Live accounts, a Solana CLI key, an old BIP44
+//! Bitcoin wallet, ...). The import-time scan derives the first accounts of
+//! every known scheme and probes each address for on-chain activity:
+//!
+//! - EVM chains: `eth_getBalance` + `eth_getTransactionCount` on the chain RPC
+//! - Solana: `getBalance` + `getSignaturesForAddress`
+//! - Bitcoin mainnet: Esplora `/address/{address}` stats (mempool.space)
+//!
+//! Other chains are listed with an error so the frontend can show them as
+//! "not scanned" instead of "empty".
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use tokio::task::JoinSet;
+use tracing::warn;
+
+use crate::wallet::chains::{coin_types, ChainModule};
+use crate::wallet::error::{WalletError, WalletResult};
+use crate::wallet::types::DerivedAddress;
+
+/// Default number of accounts scanned per scheme
+pub const DEFAULT_SCAN_ACCOUNTS: u32 = 3;
+
+/// Per-request timeout for activity probes
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Esplora API used for Bitcoin mainnet probes
+const BITCOIN_ESPLORA_URL: &str = "https://mempool.space/api";
+
+// =============================================================================
+// Types
+// =============================================================================
+
+/// Address derived with a given scheme, waiting to be probed
+#[derive(Debug, Clone)]
+pub struct SchemeCandidate {
+    /// Human-readable scheme name
+    pub scheme_name: String,
+    /// Account number passed to the scheme template
+    pub account: u32,
+    /// Derived address (carries chain, scheme ID and path)
+    pub address: DerivedAddress,
+}
+
+/// Result of probing one scheme candidate
+#[derive(Debug, Clone, Serialize)]
+pub struct SchemeScanResult {
+    pub chain: String,
+    pub scheme: String,
+    pub scheme_name: String,
+    pub account: u32,
+    pub address: String,
+    pub derivation_path: String,
+    /// Balance in the chain's smallest unit (wei, lamports, satoshis)
+    pub balance: Option<String>,
+    /// Transactions seen for the address (a lower bound on some chains)
+    pub tx_count: Option<u64>,
+    /// Whether the address has a balance or any history
+    pub has_activity: bool,
+    /// Probe error, or why the chain cannot be scanned
+    pub error: Option<String>,
+}
+
+/// On-chain activity of a single address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressActivity {
+    pub balance: u128,
+    pub tx_count: u64,
+}
+
+impl AddressActivity {
+    pub fn has_activity(&self) -> bool {
+        self.balance > 0 || self.tx_count > 0
+    }
+}
+
+// =============================================================================
+// Activity Probes
+// =============================================================================
+
+/// How to look up activity for a chain's addresses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActivityProbe {
+    /// Ethereum JSON-RPC endpoint
+    Evm { rpc_url: String },
+    /// Solana JSON-RPC endpoint
+    Solana { rpc_url: String },
+    /// Esplora REST API base URL
+    Esplora { base_url: String },
+}
+
+impl ActivityProbe {
+    /// Pick a probe for a chain module, if the chain can be scanned
+    pub fn for_module(module: &dyn ChainModule) -> Option<Self> {
+        let rpc_url = module.rpc_urls().into_iter().next();
+
+        match module.coin_type() {
+            coin_types::ETHEREUM => rpc_url.map(|rpc_url| ActivityProbe::Evm { rpc_url }),
+            coin_types::SOLANA => rpc_url.map(|rpc_url| ActivityProbe::Solana { rpc_url }),
+            coin_types::BITCOIN if module.chain_id().starts_with("bitcoin") => {
+                Some(ActivityProbe::Esplora {
+                    base_url: BITCOIN_ESPLORA_URL.to_string(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Fetch balance and transaction count for an address
+    pub async fn probe(
+        &self,
+        client: &reqwest::Client,
+        address: &str,
+    ) -> WalletResult<AddressActivity> {
+        match self {
+            ActivityProbe::Evm { rpc_url } => {
+                let balance = json_rpc(
+                    client,
+                    rpc_url,
+                    "eth_getBalance",
+                    json!([address, "latest"]),
+                )
+                .await?;
+                let nonce = json_rpc(
+                    client,
+                    rpc_url,
+                    "eth_getTransactionCount",
+                    json!([address, "latest"]),
+                )
+                .await?;
+
+                Ok(AddressActivity {
+                    balance: parse_hex_quantity(&balance)?,
+                    tx_count: parse_hex_quantity(&nonce)? as u64,
+                })
+            }
+            ActivityProbe::Solana { rpc_url } => {
+                let balance = json_rpc(client, rpc_url, "getBalance", json!([address])).await?;
+                let signatures = json_rpc(
+                    client,
+                    rpc_url,
+                    "getSignaturesForAddress",
+                    json!([address, { "limit": 1 }]),
+                )
+                .await?;
+
+                Ok(AddressActivity {
+                    balance: balance["value"].as_u64().unwrap_or(0) as u128,
+                    tx_count: signatures.as_array().map_or(0, |s| s.len() as u64),
+                })
+            }
+            ActivityProbe::Esplora { base_url } => {
+                let stats: Value = client
+                    .get(format!("{}/address/{}", base_url, address))
+                    .send()
+                    .await
+                    .and_then(|r| r.error_for_status())
+                    .map_err(|e| WalletError::NetworkError(e.to_string()))?
+                    .json()
+                    .await
+                    .map_err(|e| WalletError::NetworkError(e.to_string()))?;
+
+                Ok(parse_esplora_stats(&stats))
+            }
+        }
+    }
+}
+
+/// Send a JSON-RPC 2.0 request and return its `result`
+async fn json_rpc(
+    client: &reqwest::Client,
+    url: &str,
+    method: &str,
+    params: Value,
+) -> WalletResult<Value> {
+    let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+
+    let response: Value = client
+        .post(url)
+        .json(&body)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| WalletError::NetworkError(format!("{}: {}", method, e)))?
+        .json()
+        .await
+        .map_err(|e| WalletError::NetworkError(format!("{}: {}", method, e)))?;
+
+    if let Some(error) = response.get("error") {
+        return Err(WalletError::NetworkError(format!("{}: {}", method, error)));
+    }
+
+    response
+        .get("result")
+        .cloned()
+        .ok_or_else(|| WalletError::NetworkError(format!("{}: missing result", method)))
+}
+
+/// Parse an Ethereum hex quantity (e.g. "0x1bc16d674ec80000")
+fn parse_hex_quantity(value: &Value) -> WalletResult<u128> {
+    value
+        .as_str()
+        .and_then(|s| s.strip_prefix("0x"))
+        .and_then(|hex| u128::from_str_radix(if hex.is_empty() { "0" } else { hex }, 16).ok())
+        .ok_or_else(|| WalletError::NetworkError(format!("Invalid hex quantity: {}", value)))
+}
+
+/// Sum confirmed and mempool stats from an Esplora address response
+fn parse_esplora_stats(stats: &Value) -> AddressActivity {
+    let mut activity = AddressActivity {
+        balance: 0,
+        tx_count: 0,
+    };
+    let mut funded = 0u64;
+    let mut spent = 0u64;
+
+    for key in ["chain_stats", "mempool_stats"] {
+        let section = &stats[key];
+        funded += section["funded_txo_sum"].as_u64().unwrap_or(0);
+        spent += section["spent_txo_sum"].as_u64().unwrap_or(0);
+        activity.tx_count += section["tx_count"].as_u64().unwrap_or(0);
+    }
+
+    activity.balance = funded.saturating_sub(spent) as u128;
+    activity
+}
+
+// =============================================================================
+// Scan
+// =============================================================================
+
+/// Probe every candidate of one chain concurrently
+///
+/// Results keep the candidates' order. Chains without a probe get one result
+/// per candidate with `error` set.
+pub async fn scan_candidates(
+    client: &reqwest::Client,
+    probe: Option<ActivityProbe>,
+    candidates: Vec<SchemeCandidate>,
+) -> Vec<SchemeScanResult> {
+    let Some(probe) = probe else {
+        return candidates
+            .into_iter()
+            .map(|c| {
+                scan_result(
+                    c,
+                    Err("Activity scan is not supported for this chain".to_string()),
+                )
+            })
+            .collect();
+    };
+
+    let mut tasks = JoinSet::new();
+    let mut spawned = HashMap::new();
+    for (position, candidate) in candidates.into_iter().enumerate() {
+        let client = client.clone();
+        let probe = probe.clone();
+        let task_candidate = candidate.clone();
+        let handle = tasks.spawn(async move {
+            let activity = probe
+                .probe(&client, &task_candidate.address.address)
+                .await
+                .map_err(|e| e.to_string());
+            (position, scan_result(task_candidate, activity))
+        });
+        spawned.insert(handle.id(), (position, candidate));
+    }
+
+    // A probe that panicked is reported, so its scheme isn't silently missed
+    let mut results = Vec::with_capacity(tasks.len());
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok(result) => results.push(result),
+            Err(e) => {
+                let Some((position, candidate)) = spawned.remove(&e.id()) else {
+                    continue;
+                };
+                warn!(
+                    "Activity probe for {} ({}) failed: {}",
+                    candidate.address.address, candidate.scheme_name, e
+                );
+                let error = format!("Activity probe failed: {}", e);
+                results.push((position, scan_result(candidate, Err(error))));
+            }
+        }
+    }
+    results.sort_by_key(|(position, _)| *position);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// HTTP client used for scans
+pub fn scan_client() -> WalletResult<reqwest::Client> {
+    reqwest::Client::builder()
+        .timeout(PROBE_TIMEOUT)
+        .build()
+        .map_err(|e| WalletError::NetworkError(e.to_string()))
+}
+
+fn scan_result(
+    candidate: SchemeCandidate,
+    activity: Result<AddressActivity, String>,
+) -> SchemeScanResult {
+    let SchemeCandidate {
+        scheme_name,
+        account,
+        address,
+    } = candidate;
+
+    let (balance, tx_count, has_activity, error) = match activity {
+        Ok(activity) => (
+            Some(activity.balance.to_string()),
+            Some(activity.tx_count),
+            activity.has_activity(),
+            None,
+        ),
+        Err(error) => (None, None, false, Some(error)),
+    };
+
+    SchemeScanResult {
+        chain: address.chain,
+        scheme: address.derivation_scheme,
+        scheme_name,
+        account,
+        address: address.address,
+        derivation_path: address.derivation_path,
+        balance,
+        tx_count,
+        has_activity,
+        error,
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::chains::{BitcoinModule, EthereumModule, SolanaModule, TronModule};
+
+    #[test]
+    fn test_probe_for_module() {
+        let eth = ActivityProbe::for_module(&EthereumModule::ethereum()).unwrap();
+        assert!(matches!(eth, ActivityProbe::Evm { .. }));
+
+        let sol = ActivityProbe::for_module(&SolanaModule::new()).unwrap();
+        assert_eq!(
+            sol,
+            ActivityProbe::Solana {
+                rpc_url: "https://api.mainnet-beta.solana.com".to_string()
+            }
+        );
+
+        assert!(matches!(
+            ActivityProbe::for_module(&BitcoinModule::new()),
+            Some(ActivityProbe::Esplora { .. })
+        ));
+
+        // Test networks share coin type 1 and are not scanned
+        assert!(ActivityProbe::for_module(&BitcoinModule::testnet()).is_none());
+        assert!(ActivityProbe::for_module(&TronModule::new()).is_none());
+    }
+
+    #[test]
+    fn test_parse_hex_quantity() {
+        assert_eq!(parse_hex_quantity(&json!("0x0")).unwrap(), 0);
+        assert_eq!(
+            parse_hex_quantity(&json!("0x1bc16d674ec80000")).unwrap(),
+            2_000_000_000_000_000_000
+        );
+        assert!(parse_hex_quantity(&json!("12")).is_err());
+        assert!(parse_hex_quantity(&json!(12)).is_err());
+    }
+
+    #[test]
+    fn test_parse_esplora_stats() {
+        let stats = json!({
+            "address": "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+            "chain_stats": { "funded_txo_sum": 150000, "spent_txo_sum": 50000, "tx_count": 3 },
+            "mempool_stats": { "funded_txo_sum": 0, "spent_txo_sum": 10000, "tx_count": 1 }
+        });
+        let activity = parse_esplora_stats(&stats);
+        assert_eq!(
+            activity,
+            AddressActivity {
+                balance: 90000,
+                tx_count: 4
+            }
+        );
+        assert!(activity.has_activity());
+
+        let empty = parse_esplora_stats(&json!({ "chain_stats": {}, "mempool_stats": {} }));
+        assert!(!empty.has_activity());
+    }
+}
@@ -41,10 +41,26 @@ pub enum WalletError {
     #[error("Invalid address for {chain}: {address}")]
     InvalidAddress { chain: String, address: String },
 
+    /// Invalid network definition
+    #[error("Invalid network: {0}")]
+    InvalidNetwork(String),
+
     /// Key derivation failed
     #[error("Key derivation failed: {0}")]
     DerivationError(String),
 
+    /// Malformed or unsuitable extended key (xpub)
+    #[error("Invalid extended key: {0}")]
+    InvalidExtendedKey(String),
+
+    /// Signing failed or is not supported
+    #[error("Signing failed: {0}")]
+    SigningError(String),
+
+    /// Network request failed
+    #[error("Network error: {0}")]
+    NetworkError(String),
+
     /// Storage error
     #[error("Storage error: {0}")]
     StorageError(String),
@@ -0,0 +1,565 @@
+//! Address detection
+//!
+//! `inspect_address` takes whatever the user pasted and reports everything
+//! it could be: the registered chains (and networks) whose address format
+//! accepts it, the address type on each, and whether its checksum holds.
+//! Inputs that are not addresses are recognized too:
+//!
+//! - Extended keys (xpub/ypub/zpub/tpub..., and private keys so the UI can warn)
+//! - Output descriptors (`wpkh([...]xpub.../0/*)#checksum`)
+//! - ENS names (`vitalik.eth`), resolvable on any EVM chain
+
+use std::sync::Arc;
+
+use bdk_wallet::miniscript::descriptor::checksum::desc_checksum;
+use bdk_wallet::miniscript::descriptor::{DescriptorPublicKey, DescriptorType};
+use bdk_wallet::miniscript::{Descriptor, ForEachKey};
+use bitcoin::bech32::primitives::decode::UncheckedHrpstring;
+use bitcoin::bech32::{Bech32, Bech32m};
+use bitcoin::secp256k1::Secp256k1;
+use bitcoin::{base58, NetworkKind};
+use serde::Serialize;
+
+use crate::wallet::chains::secp256k1::slip132::{self, ExtendedKeyInfo};
+use crate::wallet::chains::{coin_types, ChainModule, EthereumModule};
+use crate::wallet::types::AddressValidation;
+
+// =============================================================================
+// Types
+// =============================================================================
+
+/// What kind of input was inspected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputKind {
+    /// A single address on one or more chains
+    Address,
+    /// BIP32 extended public key (any SLIP-132 version)
+    ExtendedPublicKey,
+    /// BIP32 extended private key; should never be pasted into a watch-only flow
+    ExtendedPrivateKey,
+    /// Bitcoin output descriptor
+    Descriptor,
+    /// ENS name to be resolved on Ethereum
+    EnsName,
+    /// Nothing recognized
+    Unknown,
+}
+
+/// A chain that accepts the inspected input
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AddressMatch {
+    pub chain_id: String,
+    pub chain_name: String,
+    pub is_testnet: bool,
+    /// Address or script type on this chain (e.g., "p2wpkh", "x-address")
+    pub address_type: Option<String>,
+    /// Name of the memo field transfers to this chain can carry
+    pub memo_field: Option<String>,
+    /// Memo embedded in the address itself
+    pub embedded_memo: Option<u64>,
+    /// Underlying account when the address embeds a memo
+    pub base_address: Option<String>,
+}
+
+/// Details of a parsed output descriptor
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DescriptorInfo {
+    /// Script type (e.g., "p2wpkh", "p2tr", "p2wsh")
+    pub script_type: String,
+    /// Network of the descriptor's extended keys, if it has any
+    pub is_testnet: Option<bool>,
+    /// Whether the descriptor contains private keys
+    pub has_private_keys: bool,
+    /// Whether the descriptor derives addresses (`/*`)
+    pub has_wildcard: bool,
+    /// Whether receive and change are combined (`/<0;1>/*`)
+    pub is_multipath: bool,
+}
+
+/// Result of inspecting a pasted string
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AddressInspection {
+    /// Input with surrounding whitespace removed
+    pub input: String,
+    pub kind: InputKind,
+    /// Whether the input is usable as-is (a valid address, key, descriptor or name)
+    pub is_valid: bool,
+    /// Every chain that accepts the input, mainnets first
+    pub matches: Vec<AddressMatch>,
+    /// `Some(false)` if the input looks like an encoded address or key whose
+    /// checksum does not match, `None` if its format has no checksum
+    pub checksum_valid: Option<bool>,
+    /// Set for extended keys
+    pub extended_key: Option<ExtendedKeyInfo>,
+    /// Set for valid descriptors
+    pub descriptor: Option<DescriptorInfo>,
+    /// Why a descriptor could not be parsed
+    pub error: Option<String>,
+}
+
+impl AddressInspection {
+    fn new(input: &str, kind: InputKind) -> Self {
+        Self {
+            input: input.to_string(),
+            kind,
+            is_valid: false,
+            matches: Vec::new(),
+            checksum_valid: None,
+            extended_key: None,
+            descriptor: None,
+            error: None,
+        }
+    }
+}
+
+// =============================================================================
+// Inspection
+// =============================================================================
+
+/// Inspect an input against every given chain module
+pub fn inspect_address(modules: &[Arc<dyn ChainModule>], input: &str) -> AddressInspection {
+    let input = input.trim();
+
+    if let Some(info) = slip132::inspect(input) {
+        return inspect_extended_key(modules, input, info);
+    }
+
+    if looks_like_descriptor(input) {
+        return inspect_descriptor(modules, input);
+    }
+
+    if is_ens_name(input) {
+        let mut inspection = AddressInspection::new(input, InputKind::EnsName);
+        inspection.is_valid = true;
+        inspection.matches = modules
+            .iter()
+            .filter(|m| m.coin_type() == coin_types::ETHEREUM && !m.is_testnet())
+            .map(|m| module_match(m.as_ref(), Some("ens".to_string())))
+            .collect();
+        sort_matches(&mut inspection.matches);
+        return inspection;
+    }
+
+    let matches = address_matches(modules, input);
+    if matches.is_empty() {
+        let mut inspection = AddressInspection::new(input, InputKind::Unknown);
+        inspection.checksum_valid = detect_checksum(input);
+        return inspection;
+    }
+
+    let has_checksum = matches
+        .iter()
+        .any(|(module, _)| has_checksum(module.as_ref(), input));
+    let mut inspection = AddressInspection::new(input, InputKind::Address);
+    inspection.is_valid = true;
+    inspection.checksum_valid = has_checksum.then_some(true);
+    inspection.matches = matches.into_iter().map(|(_, m)| m).collect();
+    sort_matches(&mut inspection.matches);
+    inspection
+}
+
+/// Modules that accept the input as an address
+///
+/// Registries hold one module per script type (e.g. Bitcoin P2PKH and
+/// P2WPKH share a network), all of which accept any address of the network.
+/// When a module's own type equals the detected type, modules of other
+/// types are dropped; otherwise (e.g. P2WSH) all are kept.
+fn address_matches(
+    modules: &[Arc<dyn ChainModule>],
+    input: &str,
+) -> Vec<(Arc<dyn ChainModule>, AddressMatch)> {
+    let accepted: Vec<_> = modules
+        .iter()
+        .filter_map(|module| {
+            let validation = module.validate_address_details(input);
+            validation.is_valid.then(|| {
+                (
+                    module.clone(),
+                    validation_match(module.as_ref(), validation),
+                )
+            })
+        })
+        .collect();
+
+    let is_exact = |module: &Arc<dyn ChainModule>, m: &AddressMatch| {
+        module.address_type().is_some() && module.address_type() == m.address_type.as_deref()
+    };
+    let is_other_type = |module: &Arc<dyn ChainModule>, m: &AddressMatch| {
+        module.address_type().is_some() && m.address_type.is_some() && !is_exact(module, m)
+    };
+
+    if accepted.iter().any(|(module, m)| is_exact(module, m)) {
+        accepted
+            .into_iter()
+            .filter(|(module, m)| !is_other_type(module, m))
+            .collect()
+    } else {
+        accepted
+    }
+}
+
+/// Bitcoin modules an extended key can watch
+fn inspect_extended_key(
+    modules: &[Arc<dyn ChainModule>],
+    input: &str,
+    info: ExtendedKeyInfo,
+) -> AddressInspection {
+    let kind = if info.is_private {
+        InputKind::ExtendedPrivateKey
+    } else {
+        InputKind::ExtendedPublicKey
+    };
+
+    let mut inspection = AddressInspection::new(input, kind);
+    inspection.is_valid = true;
+    inspection.checksum_valid = Some(true);
+    inspection.matches =
+        bitcoin_matches(modules, Some(info.is_testnet), info.script_type.as_deref());
+    inspection.extended_key = Some(info);
+    inspection
+}
+
+fn inspect_descriptor(modules: &[Arc<dyn ChainModule>], input: &str) -> AddressInspection {
+    let mut inspection = AddressInspection::new(input, InputKind::Descriptor);
+
+    let (body, checksum) = match input.split_once('#') {
+        Some((body, checksum)) => (body, Some(checksum)),
+        None => (input, None),
+    };
+    inspection.checksum_valid =
+        checksum.map(|checksum| desc_checksum(body).is_ok_and(|expected| expected == checksum));
+
+    let secp = Secp256k1::new();
+    let (descriptor, key_map) = match Descriptor::parse_descriptor(&secp, body) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            inspection.error = Some(e.to_string());
+            return inspection;
+        }
+    };
+
+    let info = descriptor_info(&descriptor, !key_map.is_empty());
+    inspection.is_valid = inspection.checksum_valid != Some(false);
+    inspection.matches = bitcoin_matches(modules, info.is_testnet, Some(&info.script_type));
+    inspection.descriptor = Some(info);
+    inspection
+}
+
+fn descriptor_info(
+    descriptor: &Descriptor<DescriptorPublicKey>,
+    has_private_keys: bool,
+) -> DescriptorInfo {
+    let script_type = match descriptor.desc_type() {
+        DescriptorType::Bare => "bare",
+        DescriptorType::Sh | DescriptorType::ShSortedMulti => "p2sh",
+        DescriptorType::Pkh => "p2pkh",
+        DescriptorType::Wpkh => "p2wpkh",
+        DescriptorType::ShWpkh => "p2sh-p2wpkh",
+        DescriptorType::Wsh | DescriptorType::WshSortedMulti => "p2wsh",
+        DescriptorType::ShWsh | DescriptorType::ShWshSortedMulti => "p2sh-p2wsh",
+        DescriptorType::Tr => "p2tr",
+    };
+
+    let mut networks = Vec::new();
+    descriptor.for_each_key(|key| {
+        match key {
+            DescriptorPublicKey::XPub(xkey) => networks.push(xkey.xkey.network),
+            DescriptorPublicKey::MultiXPub(xkey) => networks.push(xkey.xkey.network),
+            DescriptorPublicKey::Single(_) => {}
+        }
+        true
+    });
+    let is_testnet = match networks.split_first() {
+        Some((first, rest)) if rest.iter().all(|n| n == first) => Some(*first == NetworkKind::Test),
+        _ => None,
+    };
+
+    DescriptorInfo {
+        script_type: script_type.to_string(),
+        is_testnet,
+        has_private_keys,
+        has_wildcard: descriptor.has_wildcard(),
+        is_multipath: descriptor.is_multipath(),
+    }
+}
+
+/// Bitcoin modules on the given network kind with the given script type
+///
+/// `None` matches any network or script type.
+fn bitcoin_matches(
+    modules: &[Arc<dyn ChainModule>],
+    is_testnet: Option<bool>,
+    script_type: Option<&str>,
+) -> Vec<AddressMatch> {
+    let mut matches: Vec<_> = modules
+        .iter()
+        .filter(|m| {
+            matches!(m.coin_type(), coin_types::BITCOIN | coin_types::TESTNET)
+                && m.chain_id().starts_with("bitcoin")
+        })
+        .filter(|m| is_testnet.is_none_or(|testnet| m.is_testnet() == testnet))
+        .filter(|m| script_type.is_none() || m.address_type() == script_type)
+        .map(|m| module_match(m.as_ref(), m.address_type().map(str::to_string)))
+        .collect();
+    sort_matches(&mut matches);
+    matches
+}
+
+fn module_match(module: &dyn ChainModule, address_type: Option<String>) -> AddressMatch {
+    AddressMatch {
+        chain_id: module.chain_id().to_string(),
+        chain_name: module.display_name().to_string(),
+        is_testnet: module.is_testnet(),
+        address_type,
+        memo_field: None,
+        embedded_memo: None,
+        base_address: None,
+    }
+}
+
+fn validation_match(module: &dyn ChainModule, validation: AddressValidation) -> AddressMatch {
+    AddressMatch {
+        memo_field: validation.memo_field,
+        embedded_memo: validation.embedded_memo,
+        base_address: validation.base_address,
+        ..module_match(module, validation.address_type)
+    }
+}
+
+/// Mainnets first, then by chain ID
+fn sort_matches(matches: &mut [AddressMatch]) {
+    matches.sort_by(|a, b| {
+        a.is_testnet
+            .cmp(&b.is_testnet)
+            .then_with(|| a.chain_id.cmp(&b.chain_id))
+    });
+}
+
+// =============================================================================
+// Format Detection
+// =============================================================================
+
+fn looks_like_descriptor(input: &str) -> bool {
+    let body = input.split_once('#').map_or(input, |(body, _)| body);
+    body.ends_with(')') && body.find('(').is_some_and(|open| open > 0)
+}
+
+/// `name.eth`, `sub.name.eth`
+fn is_ens_name(input: &str) -> bool {
+    let Some(name) = input
+        .to_lowercase()
+        .strip_suffix(".eth")
+        .map(str::to_string)
+    else {
+        return false;
+    };
+
+    !name.is_empty()
+        && name.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|c| c == '-' || c == '_' || c.is_alphanumeric() || !c.is_ascii())
+        })
+}
+
+/// Whether a matched address format carries a checksum
+fn has_checksum(module: &dyn ChainModule, input: &str) -> bool {
+    match module.coin_type() {
+        // Plain hex; EIP-55 only applies to mixed-case addresses
+        coin_types::ETHEREUM => is_mixed_case(input),
+        // Raw base58 public keys and hex account addresses
+        coin_types::SOLANA | coin_types::SUI | coin_types::APTOS => false,
+        _ => true,
+    }
+}
+
+fn is_mixed_case(input: &str) -> bool {
+    input.chars().any(|c| c.is_ascii_lowercase()) && input.chars().any(|c| c.is_ascii_uppercase())
+}
+
+/// Checksum verdict for input no chain accepted
+///
+/// Recognizes EIP-55 hex, bech32/bech32m and base58check payloads, so a
+/// mistyped character can be reported as such instead of "unknown format".
+fn detect_checksum(input: &str) -> Option<bool> {
+    if let Some(hex) = input.strip_prefix("0x") {
+        if hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return is_mixed_case(hex).then(|| EthereumModule::to_checksum_address(input) == input);
+        }
+        return None;
+    }
+
+    if input.contains('1') {
+        if let Ok(unchecked) = UncheckedHrpstring::new(input) {
+            return Some(
+                unchecked.has_valid_checksum::<Bech32>()
+                    || unchecked.has_valid_checksum::<Bech32m>(),
+            );
+        }
+    }
+
+    match base58::decode(input) {
+        Ok(payload) if (25..=82).contains(&payload.len()) => {
+            Some(base58::decode_check(input).is_ok())
+        }
+        _ => None,
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::chains::BitcoinScriptType;
+    use crate::wallet::registry::ChainRegistry;
+
+    fn inspect(input: &str) -> AddressInspection {
+        ChainRegistry::new().inspect_address(input)
+    }
+
+    fn chain_ids(inspection: &AddressInspection) -> Vec<&str> {
+        inspection
+            .matches
+            .iter()
+            .map(|m| m.chain_id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_inspect_bitcoin_addresses() {
+        let segwit = inspect(" bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu\n");
+        assert_eq!(segwit.kind, InputKind::Address);
+        assert_eq!(segwit.input, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu");
+        assert_eq!(chain_ids(&segwit), vec!["bitcoin"]);
+        assert_eq!(segwit.matches[0].address_type.as_deref(), Some("p2wpkh"));
+        assert_eq!(segwit.checksum_valid, Some(true));
+
+        let legacy = inspect("1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA");
+        // Legacy addresses are also valid Bitcoin Cash addresses
+        assert_eq!(chain_ids(&legacy), vec!["bitcoin_cash", "bitcoin_legacy"]);
+
+        // Testnet addresses are valid on every test network
+        let testnet = inspect("tb1q6rz28mcfaxtmd6v789l9rrlrusdprr9pqcpvkl");
+        assert!(testnet.matches.iter().all(|m| m.is_testnet));
+        assert!(chain_ids(&testnet).contains(&"bitcoin_testnet"));
+        assert!(chain_ids(&testnet).contains(&"bitcoin_signet"));
+    }
+
+    #[test]
+    fn test_inspect_bad_checksum() {
+        // Last character changed
+        let bech32 = inspect("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyv");
+        assert_eq!(bech32.kind, InputKind::Unknown);
+        assert!(!bech32.is_valid);
+        assert_eq!(bech32.checksum_valid, Some(false));
+
+        let base58 = inspect("1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabB");
+        assert_eq!(base58.checksum_valid, Some(false));
+
+        // Case of the first letter flipped
+        let eip55 = inspect("0x9858efFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(eip55.checksum_valid, Some(false));
+
+        assert_eq!(inspect("hello").checksum_valid, None);
+    }
+
+    #[test]
+    fn test_inspect_evm_address() {
+        let checksummed = inspect("0x9858EfFD232B4033E47d90003D41EC34EcaEda94");
+        assert_eq!(checksummed.kind, InputKind::Address);
+        assert_eq!(checksummed.checksum_valid, Some(true));
+        assert!(chain_ids(&checksummed).contains(&"ethereum"));
+        assert!(!checksummed.matches[0].is_testnet);
+        assert!(checksummed.matches.iter().any(|m| m.is_testnet));
+
+        let lowercase = inspect("0x9858effd232b4033e47d90003d41ec34ecaeda94");
+        assert_eq!(lowercase.checksum_valid, None);
+        assert_eq!(lowercase.matches.len(), checksummed.matches.len());
+    }
+
+    #[test]
+    fn test_inspect_other_chains() {
+        let solana = inspect("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+        assert_eq!(chain_ids(&solana), vec!["solana", "solana_devnet"]);
+        assert_eq!(solana.checksum_valid, None);
+
+        let tron = inspect("TUEZSdKsoDHQMeZwihtdoBiN46zxhGWYdH");
+        assert_eq!(chain_ids(&tron), vec!["tron"]);
+
+        let xrpl = inspect("rHsMGQEkVNJmpGWs8XUBoTBiAAbwxZN5v3");
+        assert_eq!(chain_ids(&xrpl), vec!["xrpl"]);
+        assert_eq!(
+            xrpl.matches[0].memo_field.as_deref(),
+            Some("destination_tag")
+        );
+    }
+
+    #[test]
+    fn test_inspect_extended_keys() {
+        let zpub = inspect("zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs");
+        assert_eq!(zpub.kind, InputKind::ExtendedPublicKey);
+        assert_eq!(chain_ids(&zpub), vec!["bitcoin"]);
+        assert_eq!(zpub.extended_key.unwrap().depth, 3);
+
+        let xprv = inspect("xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi");
+        assert_eq!(xprv.kind, InputKind::ExtendedPrivateKey);
+        assert!(xprv.matches.iter().all(|m| !m.is_testnet));
+        assert_eq!(xprv.matches.len(), BitcoinScriptType::ALL.len());
+    }
+
+    #[test]
+    fn test_inspect_descriptor() {
+        let zpub = "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs";
+        let Some((_, slip132::ExtendedKey::Public(xpub))) = slip132::decode(zpub) else {
+            panic!("zpub should decode");
+        };
+        let descriptor = &format!("wpkh([73c5da0a/84'/0'/0']{}/0/*)", xpub);
+        let checksum = desc_checksum(descriptor).unwrap();
+
+        let inspection = inspect(&format!("{}#{}", descriptor, checksum));
+        assert_eq!(inspection.kind, InputKind::Descriptor);
+        assert!(inspection.is_valid);
+        assert_eq!(inspection.checksum_valid, Some(true));
+        assert_eq!(chain_ids(&inspection), vec!["bitcoin"]);
+
+        let info = inspection.descriptor.unwrap();
+        assert_eq!(info.script_type, "p2wpkh");
+        assert_eq!(info.is_testnet, Some(false));
+        assert!(info.has_wildcard);
+        assert!(!info.has_private_keys);
+
+        let bad_checksum = inspect(&format!("{}#aaaaaaaa", descriptor));
+        assert_eq!(bad_checksum.checksum_valid, Some(false));
+        assert!(!bad_checksum.is_valid);
+
+        let unchecked = inspect(descriptor);
+        assert_eq!(unchecked.checksum_valid, None);
+        assert!(unchecked.is_valid);
+
+        let invalid = inspect("wpkh(notakey)");
+        assert_eq!(invalid.kind, InputKind::Descriptor);
+        assert!(!invalid.is_valid);
+        assert!(invalid.error.is_some());
+    }
+
+    #[test]
+    fn test_inspect_ens_name() {
+        let ens = inspect("vitalik.eth");
+        assert_eq!(ens.kind, InputKind::EnsName);
+        assert!(ens.is_valid);
+        assert!(chain_ids(&ens).contains(&"ethereum"));
+        assert!(ens.matches.iter().all(|m| !m.is_testnet));
+
+        assert!(is_ens_name("pay.alice.eth"));
+        assert!(!is_ens_name(".eth"));
+        assert!(!is_ens_name("alice..eth"));
+        assert!(!is_ens_name("alice.com"));
+    }
+}
@@ -14,7 +14,9 @@ use bip39::{Language, Mnemonic};
 use rand::RngCore;
 
 use crate::wallet::error::{WalletError, WalletResult};
-use crate::wallet::types::{SecretMnemonic, SecretSeed, ValidateMnemonicResponse};
+use crate::wallet::types::{
+    MasterSecret, SecretEntropy, SecretMnemonic, SecretSeed, ValidateMnemonicResponse,
+};
 
 /// Number of words in a standard mnemonic
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -162,6 +164,28 @@ pub fn mnemonic_to_seed(mnemonic: &SecretMnemonic, passphrase: &str) -> WalletRe
     Ok(SecretSeed::new(seed))
 }
 
+/// Recover the raw BIP39 entropy encoded by a mnemonic
+///
+/// Some chains (Cardano) derive their master key from the entropy rather
+/// than from the PBKDF2 seed.
+pub fn mnemonic_to_entropy(mnemonic: &SecretMnemonic) -> WalletResult<SecretEntropy> {
+    let parsed = Mnemonic::parse_in(Language::English, mnemonic.as_str())
+        .map_err(|e| WalletError::InvalidMnemonic(e.to_string()))?;
+
+    Ok(SecretEntropy::new(parsed.to_entropy()))
+}
+
+/// Derive both the seed and the entropy of a mnemonic
+pub fn mnemonic_to_master_secret(
+    mnemonic: &SecretMnemonic,
+    passphrase: &str,
+) -> WalletResult<MasterSecret> {
+    Ok(MasterSecret::new(
+        mnemonic_to_seed(mnemonic, passphrase)?,
+        Some(mnemonic_to_entropy(mnemonic)?),
+    ))
+}
+
 /// Get all BIP39 words for autocomplete
 ///
 /// # Returns
@@ -317,6 +341,20 @@ mod tests {
         assert_eq!(actual_seed_hex, expected_seed_hex);
     }
 
+    #[test]
+    fn test_mnemonic_to_entropy() {
+        let mnemonic = SecretMnemonic::new(TEST_MNEMONIC_12.to_string());
+        let entropy = mnemonic_to_entropy(&mnemonic).unwrap();
+        assert_eq!(entropy.as_bytes(), &[0u8; 16]);
+
+        let secret = mnemonic_to_master_secret(&mnemonic, "").unwrap();
+        assert_eq!(secret.entropy(), Some(&[0u8; 16][..]));
+        assert_eq!(
+            hex::encode(secret.seed()),
+            "5eb00bbddcf069084889a8ab9155568165f5c453ccb85e70811aaed6f6da5fc19a5ac40b389cd370d086206dec8aa6c43daea6690f20ad3d8d48b2d2ce9e38e4"
+        );
+    }
+
     #[test]
     fn test_get_wordlist() {
         let wordlist = get_wordlist();
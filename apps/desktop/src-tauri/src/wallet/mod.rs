@@ -33,25 +33,38 @@
 //! └─────────────────────────────────────────────────────────────────┘
 //! ```
 
+pub mod bip85;
 pub mod bitcoin;
 pub mod chains;
 pub mod core;
+pub mod discovery;
 pub mod error;
+pub mod inspect;
 pub mod mnemonic;
 pub mod registry;
 pub mod storage;
 pub mod types;
 
 // Re-export commonly used items
+pub use bip85::{Bip85Application, Bip85Child, Bip85Language};
 pub use bitcoin::{BitcoinAdapter, BitcoinBalance, BitcoinConfig, BitcoinNetwork, BitcoinTransaction};
-pub use chains::{BitcoinModule, ChainModule, EthereumModule, SolanaModule};
+pub use chains::{
+    AptosModule, BitcoinModule, BitcoinScriptType, CardanoModule, ChainModule, ChainSignature,
+    ChainSigner, DerivationScheme, DerivationSchemeInfo, EthereumModule, EvmNetwork, SolanaModule,
+    StellarModule, SuiModule, TronModule, UtxoChainParams, UtxoModule, XrplModule,
+};
 pub use core::WalletManager;
+pub use discovery::{SchemeCandidate, SchemeScanResult};
 pub use error::{WalletError, WalletResult};
-pub use mnemonic::{generate_mnemonic, mnemonic_to_seed, parse_mnemonic, validate_mnemonic, MnemonicLength};
+pub use inspect::{AddressInspection, AddressMatch, InputKind};
+pub use mnemonic::{
+    generate_mnemonic, mnemonic_to_entropy, mnemonic_to_master_secret, mnemonic_to_seed,
+    parse_mnemonic, validate_mnemonic, MnemonicLength,
+};
 pub use types::{
-    ChainFamily, CreateHDWalletRequest, CreateHDWalletResponse, DerivedAddress, SecretMnemonic,
-    SecretPrivateKey, SecretSeed, ValidateMnemonicResponse, WalletAddress, WalletInfo, WalletType,
-    WalletWithAddresses,
+    AddressValidation, ChainFamily, CreateHDWalletRequest, CreateHDWalletResponse, DerivedAddress,
+    MasterSecret, MasterSecretKind, SecretEntropy, SecretMnemonic, SecretPrivateKey, SecretSeed,
+    ValidateMnemonicResponse, WalletAddress, WalletInfo, WalletType, WalletWithAddresses,
 };
 
 #[cfg(test)]
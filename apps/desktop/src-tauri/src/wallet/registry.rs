@@ -4,6 +4,7 @@
 //! It enables dynamic dispatch to chain-specific implementations for:
 //! - Address derivation
 //! - Address validation
+//! - Message and transaction signing
 //! - Chain metadata queries
 //!
 //! # Extensibility
@@ -14,12 +15,23 @@
 //!
 //! No changes needed to WalletManager, commands, or frontend!
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use crate::wallet::chains::{BitcoinModule, ChainModule, EthereumModule, SolanaModule};
+use parking_lot::RwLock;
+use tracing::warn;
+
+use crate::wallet::chains::{
+    AccountXpub, AptosModule, BitcoinModule, BitcoinScriptType, CardanoModule, ChainModule,
+    ChainSignature, ChainSigner, DerivationScheme, DerivationSchemeInfo, EthereumModule,
+    EvmNetwork, NostrModule, SolanaModule, StellarModule, SuiModule, TronModule, UtxoModule,
+    XrplModule, DEFAULT_SCHEME,
+};
 use crate::wallet::error::{WalletError, WalletResult};
-use crate::wallet::types::{ChainFamily, DerivedAddress};
+use crate::wallet::inspect::{self, AddressInspection};
+use crate::wallet::types::{
+    AddressValidation, ChainFamily, DerivedAddress, MasterSecret, MasterSecretKind,
+};
 
 /// Information about a supported chain
 #[derive(Debug, Clone, serde::Serialize)]
@@ -38,14 +50,49 @@ pub struct ChainInfo {
     pub is_testnet: bool,
     /// Icon name for frontend
     pub icon_name: String,
+    /// EIP-155 chain ID (EVM networks only)
+    pub evm_chain_id: Option<u64>,
+    /// Default RPC endpoints
+    pub rpc_urls: Vec<String>,
+    /// Block explorer base URL
+    pub explorer_url: Option<String>,
+    /// Address/script type derived for this chain (e.g., "p2wpkh", "p2tr")
+    pub address_type: Option<String>,
+    /// Native currency decimals
+    pub decimals: u8,
+    /// Whether this chain was added by the user at runtime
+    pub is_custom: bool,
+}
+
+impl ChainInfo {
+    fn from_module(module: &dyn ChainModule, is_custom: bool) -> Self {
+        Self {
+            id: module.chain_id().to_string(),
+            name: module.display_name().to_string(),
+            symbol: module.symbol().to_string(),
+            family: module.chain_family(),
+            coin_type: module.coin_type(),
+            is_testnet: module.is_testnet(),
+            icon_name: module.icon_name().to_string(),
+            evm_chain_id: module.evm_chain_id(),
+            rpc_urls: module.rpc_urls(),
+            explorer_url: module.explorer_url().map(str::to_string),
+            address_type: module.address_type().map(str::to_string),
+            decimals: module.decimals(),
+            is_custom,
+        }
+    }
 }
 
 /// Registry of all supported blockchain chains
 ///
 /// Provides dynamic dispatch to chain-specific implementations.
+/// User-defined EVM networks can be registered and removed at runtime.
 pub struct ChainRegistry {
     /// Registered chain modules by chain_id
-    modules: HashMap<String, Arc<dyn ChainModule>>,
+    modules: RwLock<HashMap<String, Arc<dyn ChainModule>>>,
+    /// IDs of networks registered at runtime by the user
+    custom_ids: RwLock<HashSet<String>>,
 }
 
 impl Default for ChainRegistry {
@@ -57,30 +104,62 @@ impl Default for ChainRegistry {
 impl ChainRegistry {
     /// Create a new registry with all supported chains
     pub fn new() -> Self {
-        let mut registry = Self {
-            modules: HashMap::new(),
+        let registry = Self {
+            modules: RwLock::new(HashMap::new()),
+            custom_ids: RwLock::new(HashSet::new()),
         };
 
         // =========================================================================
-        // Secp256k1 Family (Bitcoin, Ethereum, EVMs)
+        // Secp256k1 Family (Bitcoin and forks, Ethereum, EVMs, Tron, XRP Ledger, Nostr)
         // =========================================================================
 
-        // Bitcoin
-        registry.register(Arc::new(BitcoinModule::new()));
+        // Bitcoin mainnet and test networks, one entry per script type
+        // (legacy, nested segwit, native segwit, taproot)
+        for network in BitcoinModule::NETWORKS {
+            for script_type in BitcoinScriptType::ALL {
+                registry.register(Arc::new(
+                    BitcoinModule::for_network(network).with_script_type(script_type),
+                ));
+            }
+        }
 
-        // Ethereum and EVM-compatible chains
-        registry.register(Arc::new(EthereumModule::ethereum()));
-        registry.register(Arc::new(EthereumModule::arbitrum()));
-        registry.register(Arc::new(EthereumModule::optimism()));
-        registry.register(Arc::new(EthereumModule::base()));
-        registry.register(Arc::new(EthereumModule::polygon()));
+        // Bitcoin forks (Litecoin, Dogecoin, Bitcoin Cash)
+        registry.register(Arc::new(UtxoModule::litecoin()));
+        registry.register(Arc::new(UtxoModule::dogecoin()));
+        registry.register(Arc::new(UtxoModule::bitcoin_cash()));
+
+        // Ethereum and EVM-compatible chains, mainnets and testnets
+        // (bundled evm_networks.json)
+        for network in EthereumModule::bundled_networks() {
+            registry.register(Arc::new(EthereumModule::from_network(network)));
+        }
+
+        // Tron
+        registry.register(Arc::new(TronModule::new()));
+
+        // XRP Ledger
+        registry.register(Arc::new(XrplModule::new()));
+
+        // Nostr identity keys (NIP-06)
+        registry.register(Arc::new(NostrModule::new()));
 
         // =========================================================================
-        // Ed25519 Family (Solana, NEAR)
+        // Ed25519 Family (Solana, Stellar, Sui, Aptos, Cardano, NEAR)
         // =========================================================================
 
         // Solana
         registry.register(Arc::new(SolanaModule::new()));
+        registry.register(Arc::new(SolanaModule::devnet()));
+
+        // Stellar
+        registry.register(Arc::new(StellarModule::new()));
+
+        // Sui and Aptos (IDs match the unified data model's `Chain` enum)
+        registry.register(Arc::new(SuiModule::new()));
+        registry.register(Arc::new(AptosModule::new()));
+
+        // Cardano (BIP32-Ed25519 from mnemonic entropy)
+        registry.register(Arc::new(CardanoModule::new()));
 
         // TODO: Add more chains as needed
         // registry.register(Arc::new(CosmosModule::new()));
@@ -90,33 +169,29 @@ impl ChainRegistry {
     }
 
     /// Register a chain module
-    fn register(&mut self, module: Arc<dyn ChainModule>) {
-        self.modules.insert(module.chain_id().to_string(), module);
+    fn register(&self, module: Arc<dyn ChainModule>) {
+        self.modules
+            .write()
+            .insert(module.chain_id().to_string(), module);
     }
 
     /// Get a chain module by ID
-    pub fn get(&self, chain_id: &str) -> Option<&Arc<dyn ChainModule>> {
-        self.modules.get(chain_id)
+    pub fn get(&self, chain_id: &str) -> Option<Arc<dyn ChainModule>> {
+        self.modules.read().get(chain_id).cloned()
     }
 
     /// Get all registered chain IDs
     pub fn chain_ids(&self) -> Vec<String> {
-        self.modules.keys().cloned().collect()
+        self.modules.read().keys().cloned().collect()
     }
 
     /// Get info about all supported chains
     pub fn all_chains(&self) -> Vec<ChainInfo> {
+        let custom_ids = self.custom_ids.read();
         self.modules
+            .read()
             .values()
-            .map(|m| ChainInfo {
-                id: m.chain_id().to_string(),
-                name: m.display_name().to_string(),
-                symbol: m.symbol().to_string(),
-                family: m.chain_family(),
-                coin_type: m.coin_type(),
-                is_testnet: m.is_testnet(),
-                icon_name: m.icon_name().to_string(),
-            })
+            .map(|m| ChainInfo::from_module(m.as_ref(), custom_ids.contains(m.chain_id())))
             .collect()
     }
 
@@ -138,17 +213,171 @@ impl ChainRegistry {
 
     /// Check if a chain is supported
     pub fn is_supported(&self, chain_id: &str) -> bool {
-        self.modules.contains_key(chain_id)
+        self.modules.read().contains_key(chain_id)
+    }
+
+    /// Get a chain module by ID or fail with `UnsupportedChain`
+    fn module(&self, chain_id: &str) -> WalletResult<Arc<dyn ChainModule>> {
+        self.get(chain_id)
+            .ok_or_else(|| WalletError::UnsupportedChain(chain_id.to_string()))
+    }
+
+    // =========================================================================
+    // User-defined EVM Networks
+    // =========================================================================
+
+    /// Register (or update) a user-defined EVM network
+    ///
+    /// Built-in chains cannot be replaced, and EIP-155 chain IDs must be
+    /// unique across all registered EVM networks.
+    pub fn register_evm_network(&self, mut network: EvmNetwork) -> WalletResult<ChainInfo> {
+        network.validate()?;
+        network.is_custom = true;
+
+        let mut custom_ids = self.custom_ids.write();
+        let mut modules = self.modules.write();
+        Self::check_evm_network(&modules, &custom_ids, &network.id, network.chain_id)?;
+
+        let module = EthereumModule::from_network(network);
+        let info = ChainInfo::from_module(&module, true);
+
+        custom_ids.insert(info.id.clone());
+        modules.insert(info.id.clone(), Arc::new(module));
+
+        Ok(info)
+    }
+
+    /// Check a user-defined network without registering it
+    ///
+    /// Runs the same checks as `register_evm_network`, so callers can
+    /// persist the definition before it becomes visible.
+    pub fn check_custom_evm_network(&self, network: &EvmNetwork) -> WalletResult<()> {
+        network.validate()?;
+
+        let custom_ids = self.custom_ids.read();
+        let modules = self.modules.read();
+        Self::check_evm_network(&modules, &custom_ids, &network.id, network.chain_id)
+    }
+
+    /// Check that a user-defined network neither replaces a built-in chain
+    /// nor reuses another network's EIP-155 chain ID
+    fn check_evm_network(
+        modules: &HashMap<String, Arc<dyn ChainModule>>,
+        custom_ids: &HashSet<String>,
+        id: &str,
+        chain_id: u64,
+    ) -> WalletResult<()> {
+        if modules.contains_key(id) && !custom_ids.contains(id) {
+            return Err(WalletError::InvalidNetwork(format!(
+                "'{}' is a built-in chain",
+                id
+            )));
+        }
+
+        if let Some(existing) = modules
+            .values()
+            .find(|m| m.evm_chain_id() == Some(chain_id) && m.chain_id() != id)
+        {
+            return Err(WalletError::InvalidNetwork(format!(
+                "chain id {} is already used by '{}'",
+                chain_id,
+                existing.chain_id()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Remove a user-defined EVM network
+    pub fn remove_evm_network(&self, chain_id: &str) -> WalletResult<()> {
+        let mut custom_ids = self.custom_ids.write();
+
+        if !custom_ids.remove(chain_id) {
+            return Err(WalletError::InvalidNetwork(format!(
+                "'{}' is not a user-defined network",
+                chain_id
+            )));
+        }
+
+        self.modules.write().remove(chain_id);
+        Ok(())
     }
 
+    /// Replace all user-defined EVM networks (e.g., after loading from SQLite)
+    ///
+    /// The new set is swapped in under one lock, so lookups never see a
+    /// custom network missing. Invalid or conflicting entries are skipped
+    /// and logged, keeping the previous definition of that network if any.
+    /// Returns the number of networks registered.
+    pub fn reload_evm_networks(&self, networks: Vec<EvmNetwork>) -> usize {
+        let mut custom_ids = self.custom_ids.write();
+        let mut modules = self.modules.write();
+
+        let mut previous: HashMap<String, Arc<dyn ChainModule>> = custom_ids
+            .drain()
+            .filter_map(|id| modules.remove(&id).map(|module| (id, module)))
+            .collect();
+
+        let mut registered = 0;
+        let mut skipped = Vec::new();
+        for mut network in networks {
+            network.is_custom = true;
+            let checked = network.validate().and_then(|()| {
+                Self::check_evm_network(&modules, &custom_ids, &network.id, network.chain_id)
+            });
+            if let Err(e) = checked {
+                warn!("Skipping custom network '{}': {}", network.id, e);
+                skipped.push(network.id);
+                continue;
+            }
+
+            custom_ids.insert(network.id.clone());
+            modules.insert(
+                network.id.clone(),
+                Arc::new(EthereumModule::from_network(network)),
+            );
+            registered += 1;
+        }
+
+        for id in skipped {
+            let Some(module) = previous.remove(&id) else {
+                continue;
+            };
+            let chain_id = module.evm_chain_id().unwrap_or_default();
+            if Self::check_evm_network(&modules, &custom_ids, &id, chain_id).is_ok() {
+                warn!("Keeping the previous definition of custom network '{}'", id);
+                custom_ids.insert(id.clone());
+                modules.insert(id, module);
+            }
+        }
+
+        registered
+    }
+
+    // =========================================================================
+    // Validation & Derivation
+    // =========================================================================
+
     /// Validate an address for a specific chain
     pub fn validate_address(&self, chain_id: &str, address: &str) -> WalletResult<bool> {
-        let module = self
-            .modules
-            .get(chain_id)
-            .ok_or_else(|| WalletError::UnsupportedChain(chain_id.to_string()))?;
+        Ok(self.module(chain_id)?.validate_address(address))
+    }
+
+    /// Validate an address for a specific chain, reporting its address type
+    pub fn validate_address_details(
+        &self,
+        chain_id: &str,
+        address: &str,
+    ) -> WalletResult<AddressValidation> {
+        Ok(self.module(chain_id)?.validate_address_details(address))
+    }
 
-        Ok(module.validate_address(address))
+    /// Detect every chain, network and address type an input could be
+    ///
+    /// Also recognizes extended keys, output descriptors and ENS names.
+    pub fn inspect_address(&self, input: &str) -> AddressInspection {
+        let modules: Vec<_> = self.modules.read().values().cloned().collect();
+        inspect::inspect_address(&modules, input)
     }
 
     /// Derive an address for a specific chain
@@ -159,26 +388,203 @@ impl ChainRegistry {
         account: u32,
         index: u32,
     ) -> WalletResult<DerivedAddress> {
-        let module = self
-            .modules
-            .get(chain_id)
-            .ok_or_else(|| WalletError::UnsupportedChain(chain_id.to_string()))?;
+        self.module(chain_id)?.derive_address(seed, account, index)
+    }
 
-        module.derive_address(seed, account, index)
+    /// Derive an address from whichever master secret the chain requires
+    ///
+    /// Entropy-based chains (Cardano) fail if the secret has no entropy,
+    /// e.g. for a wallet restored from a bare seed.
+    pub fn derive_address_with_secret(
+        &self,
+        chain_id: &str,
+        secret: &MasterSecret,
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        let module = self.module(chain_id)?;
+        match module.master_secret_kind() {
+            MasterSecretKind::Seed => module.derive_address(secret.seed(), account, index),
+            MasterSecretKind::Entropy => {
+                let entropy = secret.entropy().ok_or_else(|| {
+                    WalletError::DerivationError(format!(
+                        "{} requires the mnemonic entropy, which is not available for this wallet",
+                        chain_id
+                    ))
+                })?;
+                module.derive_address_from_entropy(entropy, account, index)
+            }
+        }
+    }
+
+    /// Derive a receive address from an account-level extended public key
+    pub fn derive_address_from_xpub(
+        &self,
+        chain_id: &str,
+        account_key: &AccountXpub,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        self.module(chain_id)?
+            .derive_address_from_xpub(account_key, index)
     }
 
     /// Derive addresses for multiple chains at once
     pub fn derive_addresses(
         &self,
         chain_ids: &[String],
-        seed: &[u8; 64],
+        secret: &MasterSecret,
         account: u32,
     ) -> WalletResult<Vec<DerivedAddress>> {
         chain_ids
             .iter()
-            .map(|chain_id| self.derive_address(chain_id, seed, account, 0))
+            .map(|chain_id| self.derive_address_with_secret(chain_id, secret, account, 0))
             .collect()
     }
+
+    // =========================================================================
+    // Derivation Schemes
+    // =========================================================================
+
+    /// List the derivation schemes for a chain, default scheme first
+    pub fn derivation_schemes(&self, chain_id: &str) -> WalletResult<Vec<DerivationSchemeInfo>> {
+        let module = self.module(chain_id)?;
+
+        let default = DerivationSchemeInfo {
+            id: DEFAULT_SCHEME.to_string(),
+            name: "Default".to_string(),
+            example_path: module.derivation_path(0, 0),
+            is_default: true,
+        };
+        let alternatives =
+            module
+                .derivation_schemes()
+                .into_iter()
+                .map(|scheme| DerivationSchemeInfo {
+                    example_path: scheme.path(0, 0),
+                    id: scheme.id,
+                    name: scheme.name,
+                    is_default: false,
+                });
+
+        Ok(std::iter::once(default).chain(alternatives).collect())
+    }
+
+    /// Derive an address using a named derivation scheme
+    ///
+    /// The `default` scheme is the chain's own `derivation_path`; other
+    /// schemes are derived from the BIP39 seed at the scheme's path.
+    pub fn derive_address_with_scheme(
+        &self,
+        chain_id: &str,
+        secret: &MasterSecret,
+        scheme_id: &str,
+        account: u32,
+        index: u32,
+    ) -> WalletResult<DerivedAddress> {
+        if scheme_id == DEFAULT_SCHEME {
+            return self.derive_address_with_secret(chain_id, secret, account, index);
+        }
+
+        let module = self.module(chain_id)?;
+        let scheme = Self::find_scheme(module.as_ref(), scheme_id)?;
+
+        let mut derived =
+            module.derive_address_at_path(secret.seed(), &scheme.path(account, index))?;
+        derived.derivation_scheme = scheme.id;
+        Ok(derived)
+    }
+
+    /// Derivation path of an address under a named scheme
+    pub fn scheme_path(
+        &self,
+        chain_id: &str,
+        scheme_id: &str,
+        account: u32,
+        index: u32,
+    ) -> WalletResult<String> {
+        let module = self.module(chain_id)?;
+        if scheme_id == DEFAULT_SCHEME {
+            return Ok(module.derivation_path(account, index));
+        }
+
+        Ok(Self::find_scheme(module.as_ref(), scheme_id)?.path(account, index))
+    }
+
+    fn find_scheme(module: &dyn ChainModule, scheme_id: &str) -> WalletResult<DerivationScheme> {
+        module
+            .derivation_schemes()
+            .into_iter()
+            .find(|scheme| scheme.id == scheme_id)
+            .ok_or_else(|| {
+                WalletError::DerivationError(format!(
+                    "Unknown derivation scheme for {}: {}",
+                    module.chain_id(),
+                    scheme_id
+                ))
+            })
+    }
+
+    // =========================================================================
+    // Signing
+    // =========================================================================
+
+    /// Run `f` with the chain's signer, failing if the chain cannot sign
+    fn with_signer<T>(
+        &self,
+        chain_id: &str,
+        f: impl FnOnce(&dyn ChainSigner) -> WalletResult<T>,
+    ) -> WalletResult<T> {
+        let module = self.module(chain_id)?;
+        let signer = module.signer().ok_or_else(|| {
+            WalletError::SigningError(format!("{} does not support signing", chain_id))
+        })?;
+        f(signer)
+    }
+
+    /// Whether a chain supports message/transaction signing
+    pub fn supports_signing(&self, chain_id: &str) -> bool {
+        self.get(chain_id)
+            .is_some_and(|module| module.signer().is_some())
+    }
+
+    /// Sign a message with the key at `path`
+    pub fn sign_message(
+        &self,
+        chain_id: &str,
+        secret: &MasterSecret,
+        path: &str,
+        message: &[u8],
+    ) -> WalletResult<ChainSignature> {
+        self.with_signer(chain_id, |signer| {
+            signer.sign_message(secret.seed(), path, message)
+        })
+    }
+
+    /// Sign a serialized transaction with the key at `path`
+    pub fn sign_transaction(
+        &self,
+        chain_id: &str,
+        secret: &MasterSecret,
+        path: &str,
+        payload: &[u8],
+    ) -> WalletResult<ChainSignature> {
+        self.with_signer(chain_id, |signer| {
+            signer.sign_transaction(secret.seed(), path, payload)
+        })
+    }
+
+    /// Verify a message signature for an address
+    pub fn verify_message(
+        &self,
+        chain_id: &str,
+        address: &str,
+        message: &[u8],
+        signature: &str,
+    ) -> WalletResult<bool> {
+        self.with_signer(chain_id, |signer| {
+            signer.verify_message(address, message, signature)
+        })
+    }
 }
 
 // =============================================================================
@@ -202,17 +608,114 @@ mod tests {
 
         // Should have common chains registered
         assert!(registry.is_supported("bitcoin"));
+        assert!(registry.is_supported("bitcoin_legacy"));
+        assert!(registry.is_supported("bitcoin_nested_segwit"));
+        assert!(registry.is_supported("bitcoin_taproot"));
         assert!(registry.is_supported("ethereum"));
         assert!(registry.is_supported("solana"));
         assert!(registry.is_supported("arbitrum"));
         assert!(registry.is_supported("optimism"));
         assert!(registry.is_supported("base"));
         assert!(registry.is_supported("polygon"));
+        assert!(registry.is_supported("avalanche"));
+        assert!(registry.is_supported("litecoin"));
+        assert!(registry.is_supported("dogecoin"));
+        assert!(registry.is_supported("bitcoin_cash"));
+        assert!(registry.is_supported("tron"));
+        assert!(registry.is_supported("xrpl"));
+        assert!(registry.is_supported("stellar"));
+        assert!(registry.is_supported("sui"));
+        assert!(registry.is_supported("aptos"));
+        assert!(registry.is_supported("cardano"));
 
         // Unknown chain should not be supported
         assert!(!registry.is_supported("unknown"));
     }
 
+    #[test]
+    fn test_registry_derivation_schemes() {
+        let registry = ChainRegistry::new();
+        let secret = MasterSecret::from_seed(test_seed());
+
+        let schemes = registry.derivation_schemes("arbitrum").unwrap();
+        assert_eq!(schemes[0].id, DEFAULT_SCHEME);
+        assert!(schemes[0].is_default);
+        assert_eq!(schemes[0].example_path, "m/44'/60'/0'/0/0");
+        assert!(schemes.iter().any(|s| s.id == "ledger_live"));
+
+        // Chains without alternatives still report the default scheme
+        assert_eq!(registry.derivation_schemes("tron").unwrap().len(), 1);
+
+        let ledger_live = registry
+            .derive_address_with_scheme("ethereum", &secret, "ledger_live", 1, 0)
+            .unwrap();
+        assert_eq!(ledger_live.derivation_scheme, "ledger_live");
+        assert_eq!(ledger_live.derivation_path, "m/44'/60'/1'/0/0");
+
+        let default = registry
+            .derive_address_with_scheme("ethereum", &secret, DEFAULT_SCHEME, 1, 0)
+            .unwrap();
+        assert_eq!(default.derivation_scheme, DEFAULT_SCHEME);
+        assert_eq!(default.address, ledger_live.address);
+
+        assert!(registry
+            .derive_address_with_scheme("ethereum", &secret, "phantom", 0, 0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_registry_signing() {
+        let registry = ChainRegistry::new();
+        let secret = MasterSecret::from_seed(test_seed());
+
+        for chain_id in [
+            "ethereum",
+            "arbitrum",
+            "bitcoin",
+            "bitcoin_taproot",
+            "solana",
+        ] {
+            assert!(registry.supports_signing(chain_id), "{chain_id}");
+
+            let path = registry
+                .scheme_path(chain_id, DEFAULT_SCHEME, 0, 0)
+                .unwrap();
+            let signed = registry
+                .sign_message(chain_id, &secret, &path, b"coinbox")
+                .unwrap();
+            assert_eq!(
+                signed.address,
+                registry
+                    .derive_address(chain_id, secret.seed(), 0, 0)
+                    .unwrap()
+                    .address
+            );
+            assert!(registry
+                .verify_message(chain_id, &signed.address, b"coinbox", &signed.signature)
+                .unwrap());
+        }
+
+        // Addresses from alternative schemes sign with their own key
+        let path = registry.scheme_path("solana", "solana_cli", 0, 0).unwrap();
+        assert_eq!(path, "m/44'/501'");
+        let signed = registry
+            .sign_message("solana", &secret, &path, b"coinbox")
+            .unwrap();
+        assert_eq!(
+            signed.address,
+            registry
+                .derive_address_with_scheme("solana", &secret, "solana_cli", 0, 0)
+                .unwrap()
+                .address
+        );
+
+        assert!(!registry.supports_signing("cardano"));
+        assert!(registry
+            .sign_message("cardano", &secret, "m/1852'", b"x")
+            .is_err());
+        assert!(registry.scheme_path("ethereum", "phantom", 0, 0).is_err());
+    }
+
     #[test]
     fn test_registry_get() {
         let registry = ChainRegistry::new();
@@ -278,6 +781,43 @@ mod tests {
         for chain in &mainnets {
             assert!(!chain.is_testnet, "{} should be mainnet", chain.id);
         }
+        assert!(mainnets.iter().any(|c| c.id == "bitcoin"));
+    }
+
+    #[test]
+    fn test_registry_testnets() {
+        let registry = ChainRegistry::new();
+
+        for id in [
+            "bitcoin_testnet",
+            "bitcoin_testnet4",
+            "bitcoin_signet",
+            "bitcoin_regtest_taproot",
+            "solana_devnet",
+            "sepolia",
+            "base_sepolia",
+        ] {
+            let module = registry
+                .get(id)
+                .unwrap_or_else(|| panic!("{} not registered", id));
+            assert!(module.is_testnet(), "{} should be a testnet", id);
+        }
+
+        let mainnet_ids: Vec<String> = registry
+            .mainnet_chains()
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+        assert!(!mainnet_ids.contains(&"bitcoin_signet".to_string()));
+        assert!(!mainnet_ids.contains(&"solana_devnet".to_string()));
+        assert!(!mainnet_ids.contains(&"sepolia".to_string()));
+
+        // Test networks derive with coin type 1
+        let signet = registry
+            .derive_address("bitcoin_signet", &test_seed(), 0, 0)
+            .unwrap();
+        assert_eq!(signet.derivation_path, "m/84'/1'/0'/0/0");
+        assert!(signet.address.starts_with("tb1q"));
     }
 
     #[test]
@@ -303,6 +843,41 @@ mod tests {
         assert!(registry.validate_address("unknown", "whatever").is_err());
     }
 
+    #[test]
+    fn test_registry_validate_address_memo_details() {
+        let registry = ChainRegistry::new();
+
+        // XRPL X-address embeds the destination tag
+        let xrpl = registry
+            .validate_address_details("xrpl", "X7AcgcsBL6XDcUb289X4mJ8djcdyKaGZMhc9YTE92ehJ2Fu")
+            .unwrap();
+        assert!(xrpl.is_valid);
+        assert_eq!(xrpl.memo_field.as_deref(), Some("destination_tag"));
+        assert_eq!(xrpl.embedded_memo, Some(1));
+        assert_eq!(
+            xrpl.base_address.as_deref(),
+            Some("r9cZA1mLK5R5Am25ArfXFmqgNwjZgnfk59")
+        );
+
+        // Plain Stellar account: memo supported but not embedded
+        let stellar = registry
+            .validate_address_details(
+                "stellar",
+                "GB3JDWCQJCWMJ3IILWIGDTQJJC5567PGVEVXSCVPEQOTDN64VJBDQBYX",
+            )
+            .unwrap();
+        assert!(stellar.is_valid);
+        assert_eq!(stellar.memo_field.as_deref(), Some("memo"));
+        assert_eq!(stellar.embedded_memo, None);
+
+        // Chains without memos leave the fields empty
+        let eth = registry
+            .validate_address_details("ethereum", "0x9858EfFD232B4033E47d90003D41EC34EcaEda94")
+            .unwrap();
+        assert!(eth.is_valid);
+        assert_eq!(eth.memo_field, None);
+    }
+
     #[test]
     fn test_registry_derive_address() {
         let registry = ChainRegistry::new();
@@ -327,7 +902,7 @@ mod tests {
     #[test]
     fn test_registry_derive_addresses() {
         let registry = ChainRegistry::new();
-        let seed = test_seed();
+        let secret = MasterSecret::from_seed(test_seed());
 
         let chains = vec![
             "bitcoin".to_string(),
@@ -335,7 +910,7 @@ mod tests {
             "solana".to_string(),
         ];
 
-        let addresses = registry.derive_addresses(&chains, &seed, 0).unwrap();
+        let addresses = registry.derive_addresses(&chains, &secret, 0).unwrap();
 
         assert_eq!(addresses.len(), 3);
         assert_eq!(addresses[0].chain, "bitcoin");
@@ -343,6 +918,34 @@ mod tests {
         assert_eq!(addresses[2].chain, "solana");
     }
 
+    #[test]
+    fn test_registry_derive_entropy_chain() {
+        use crate::wallet::mnemonic::{mnemonic_to_master_secret, parse_mnemonic};
+
+        let registry = ChainRegistry::new();
+        let chains = vec!["ethereum".to_string(), "cardano".to_string()];
+
+        // Mnemonic-backed secret derives both seed- and entropy-based chains
+        let mnemonic = parse_mnemonic(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        )
+        .unwrap();
+        let secret = mnemonic_to_master_secret(&mnemonic, "").unwrap();
+        let addresses = registry.derive_addresses(&chains, &secret, 0).unwrap();
+        assert_eq!(
+            addresses[0].address,
+            "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+        );
+        assert!(addresses[1].address.starts_with("addr1"));
+
+        // A bare seed cannot derive Cardano
+        let seed_only = MasterSecret::from_seed(test_seed());
+        assert!(registry.derive_addresses(&chains, &seed_only, 0).is_err());
+        assert!(registry
+            .derive_address("cardano", &test_seed(), 0, 0)
+            .is_err());
+    }
+
     #[test]
     fn test_registry_derive_unsupported_chain() {
         let registry = ChainRegistry::new();
@@ -369,4 +972,128 @@ mod tests {
         assert_eq!(eth.address, base.address);
         assert_eq!(eth.address, poly.address);
     }
+
+    fn custom_network(id: &str, chain_id: u64) -> EvmNetwork {
+        EvmNetwork {
+            id: id.to_string(),
+            name: "Custom L2".to_string(),
+            symbol: "ETH".to_string(),
+            chain_id,
+            decimals: 18,
+            rpc_urls: vec!["https://rpc.example.org".to_string()],
+            explorer_url: None,
+            is_testnet: false,
+            is_custom: false,
+        }
+    }
+
+    #[test]
+    fn test_registry_chain_info_fields() {
+        let registry = ChainRegistry::new();
+        let chains = registry.all_chains();
+
+        let eth = chains.iter().find(|c| c.id == "ethereum").unwrap();
+        assert_eq!(eth.evm_chain_id, Some(1));
+        assert_eq!(eth.decimals, 18);
+        assert!(!eth.rpc_urls.is_empty());
+        assert!(eth.explorer_url.is_some());
+        assert!(!eth.is_custom);
+
+        let btc = chains.iter().find(|c| c.id == "bitcoin").unwrap();
+        assert_eq!(btc.evm_chain_id, None);
+        assert_eq!(btc.decimals, 8);
+
+        let sol = chains.iter().find(|c| c.id == "solana").unwrap();
+        assert_eq!(sol.decimals, 9);
+    }
+
+    #[test]
+    fn test_register_custom_evm_network() {
+        let registry = ChainRegistry::new();
+        let seed = test_seed();
+
+        let info = registry
+            .register_evm_network(custom_network("linea", 59144))
+            .unwrap();
+        assert_eq!(info.id, "linea");
+        assert_eq!(info.evm_chain_id, Some(59144));
+        assert!(info.is_custom);
+
+        assert!(registry.is_supported("linea"));
+        let linea = registry.derive_address("linea", &seed, 0, 0).unwrap();
+        let eth = registry.derive_address("ethereum", &seed, 0, 0).unwrap();
+        assert_eq!(linea.address, eth.address);
+
+        let listed = registry.all_chains();
+        assert!(listed.iter().any(|c| c.id == "linea" && c.is_custom));
+
+        // Re-registering a custom network updates it in place
+        let mut renamed = custom_network("linea", 59144);
+        renamed.name = "Linea".to_string();
+        assert_eq!(
+            registry.register_evm_network(renamed).unwrap().name,
+            "Linea"
+        );
+
+        registry.remove_evm_network("linea").unwrap();
+        assert!(!registry.is_supported("linea"));
+    }
+
+    #[test]
+    fn test_register_custom_evm_network_conflicts() {
+        let registry = ChainRegistry::new();
+
+        // Built-in chains cannot be replaced or removed
+        assert!(matches!(
+            registry.register_evm_network(custom_network("ethereum", 999_999)),
+            Err(WalletError::InvalidNetwork(_))
+        ));
+        assert!(registry.remove_evm_network("ethereum").is_err());
+        assert!(registry.is_supported("ethereum"));
+
+        // EIP-155 chain IDs must be unique
+        assert!(registry
+            .register_evm_network(custom_network("mainnet-copy", 1))
+            .is_err());
+
+        // Invalid definitions are rejected
+        assert!(registry
+            .register_evm_network(custom_network("Bad Id", 12345))
+            .is_err());
+
+        // Checking runs the same rules without registering anything
+        assert!(registry
+            .check_custom_evm_network(&custom_network("ethereum", 999_999))
+            .is_err());
+        assert!(registry
+            .check_custom_evm_network(&custom_network("mainnet-copy", 1))
+            .is_err());
+        assert!(registry
+            .check_custom_evm_network(&custom_network("linea", 59144))
+            .is_ok());
+        assert!(!registry.is_supported("linea"));
+    }
+
+    #[test]
+    fn test_reload_evm_networks() {
+        let registry = ChainRegistry::new();
+        registry
+            .register_evm_network(custom_network("old", 11111))
+            .unwrap();
+
+        let loaded = registry.reload_evm_networks(vec![
+            custom_network("scroll", 534352),
+            custom_network("ethereum", 22222), // conflicts with built-in, skipped
+        ]);
+
+        assert_eq!(loaded, 1);
+        assert!(registry.is_supported("scroll"));
+        assert!(!registry.is_supported("old"));
+        assert_eq!(registry.get("ethereum").unwrap().evm_chain_id(), Some(1));
+
+        // A network whose new definition is invalid keeps the old one
+        let broken = custom_network("scroll", 0);
+        assert_eq!(registry.reload_evm_networks(vec![broken]), 0);
+        assert_eq!(registry.get("scroll").unwrap().evm_chain_id(), Some(534352));
+    }
 }
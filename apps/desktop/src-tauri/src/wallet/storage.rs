@@ -24,8 +24,9 @@ use std::sync::Arc;
 use parking_lot::RwLock;
 use zeroize::Zeroizing;
 
+use crate::wallet::chains::DerivationNode;
 use crate::wallet::error::{WalletError, WalletResult};
-use crate::wallet::types::{SecretMnemonic, SecretSeed};
+use crate::wallet::types::{MasterSecret, SecretEntropy, SecretMnemonic, SecretSeed};
 
 /// Record keys for different secret types
 pub mod record_keys {
@@ -44,6 +45,12 @@ pub mod record_keys {
 pub struct SessionCache {
     /// Cached seeds by wallet ID
     seeds: RwLock<HashMap<String, Zeroizing<[u8; 64]>>>,
+    /// Cached mnemonic entropy by wallet ID (entropy-based chains like Cardano)
+    entropies: RwLock<HashMap<String, Zeroizing<Vec<u8>>>>,
+    /// Cached account-level derivation nodes by wallet ID, then node key
+    ///
+    /// SLIP-0010 nodes hold private keys and zeroize on drop.
+    nodes: RwLock<HashMap<String, HashMap<String, Arc<DerivationNode>>>>,
     /// Whether a session is active
     is_unlocked: RwLock<bool>,
 }
@@ -59,6 +66,8 @@ impl SessionCache {
     pub fn new() -> Self {
         Self {
             seeds: RwLock::new(HashMap::new()),
+            entropies: RwLock::new(HashMap::new()),
+            nodes: RwLock::new(HashMap::new()),
             is_unlocked: RwLock::new(false),
         }
     }
@@ -88,14 +97,52 @@ impl SessionCache {
         self.seeds.read().contains_key(wallet_id)
     }
 
-    /// Remove a cached seed
+    /// Remove a cached seed (and its entropy and derivation nodes)
     pub fn remove_seed(&self, wallet_id: &str) {
         self.seeds.write().remove(wallet_id);
+        self.entropies.write().remove(wallet_id);
+        self.nodes.write().remove(wallet_id);
+    }
+
+    /// Cache the mnemonic entropy for a wallet
+    pub fn cache_entropy(&self, wallet_id: &str, entropy: &[u8]) {
+        self.entropies
+            .write()
+            .insert(wallet_id.to_string(), Zeroizing::new(entropy.to_vec()));
+    }
+
+    /// Get cached mnemonic entropy
+    pub fn get_entropy(&self, wallet_id: &str) -> Option<SecretEntropy> {
+        self.entropies
+            .read()
+            .get(wallet_id)
+            .map(|e| SecretEntropy::new(e.to_vec()))
+    }
+
+    /// Cache an account-level derivation node for a wallet
+    pub fn cache_node(&self, wallet_id: &str, key: &str, node: Arc<DerivationNode>) {
+        self.nodes
+            .write()
+            .entry(wallet_id.to_string())
+            .or_default()
+            .insert(key.to_string(), node);
+    }
+
+    /// Get a cached derivation node
+    pub fn get_node(&self, wallet_id: &str, key: &str) -> Option<Arc<DerivationNode>> {
+        self.nodes.read().get(wallet_id)?.get(key).cloned()
+    }
+
+    /// Number of derivation nodes cached for a wallet
+    pub fn node_count(&self, wallet_id: &str) -> usize {
+        self.nodes.read().get(wallet_id).map_or(0, HashMap::len)
     }
 
     /// Clear all cached secrets (on lock)
     pub fn clear(&self) {
         self.seeds.write().clear();
+        self.entropies.write().clear();
+        self.nodes.write().clear();
         *self.is_unlocked.write() = false;
     }
 }
@@ -168,6 +215,47 @@ impl SecureStorage {
         self.session_cache.cache_seed(wallet_id, seed);
     }
 
+    /// Get the cached master secret (seed, plus entropy if known)
+    pub fn get_master_secret(&self, wallet_id: &str) -> WalletResult<MasterSecret> {
+        let seed = self.get_seed(wallet_id)?;
+        Ok(MasterSecret::new(
+            SecretSeed::new(seed),
+            self.session_cache.get_entropy(wallet_id),
+        ))
+    }
+
+    /// Cache a master secret in the session
+    pub fn cache_master_secret(&self, wallet_id: &str, secret: &MasterSecret) {
+        self.cache_seed(wallet_id, *secret.seed());
+        if let Some(entropy) = secret.entropy() {
+            self.session_cache.cache_entropy(wallet_id, entropy);
+        }
+    }
+
+    /// Get the account node at `key`, deriving and caching it on first use
+    ///
+    /// `derive` receives the cached seed; fails if the wallet is locked.
+    pub fn account_node(
+        &self,
+        wallet_id: &str,
+        key: &str,
+        derive: impl FnOnce(&[u8; 64]) -> WalletResult<DerivationNode>,
+    ) -> WalletResult<Arc<DerivationNode>> {
+        if !self.is_unlocked() {
+            return Err(WalletError::WalletLocked);
+        }
+
+        if let Some(node) = self.session_cache.get_node(wallet_id, key) {
+            return Ok(node);
+        }
+
+        let seed = Zeroizing::new(self.get_seed(wallet_id)?);
+        let node = Arc::new(derive(&seed)?);
+        self.session_cache
+            .cache_node(wallet_id, key, Arc::clone(&node));
+        Ok(node)
+    }
+
     /// Lock the wallet (clear all cached secrets)
     pub fn lock(&self) {
         self.session_cache.clear();
@@ -298,6 +386,32 @@ mod tests {
         assert!(!cache.has_seed("wallet2"));
     }
 
+    #[test]
+    fn test_account_node_cache() {
+        use crate::wallet::types::ChainFamily;
+
+        let storage = SecureStorage::new();
+        let derive = |seed: &[u8; 64]| {
+            DerivationNode::derive(ChainFamily::Ed25519, false, seed, "m/44'/501'/0'")
+        };
+
+        // Locked: nothing to derive from
+        assert!(storage.account_node("wallet1", "solana", derive).is_err());
+
+        storage.cache_seed("wallet1", [7u8; 64]);
+        let first = storage.account_node("wallet1", "solana", derive).unwrap();
+        let second = storage
+            .account_node("wallet1", "solana", |_| panic!("node should be cached"))
+            .unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(storage.session().node_count("wallet1"), 1);
+
+        // Locking drops the nodes with the seeds
+        storage.lock();
+        assert_eq!(storage.session().node_count("wallet1"), 0);
+        assert!(storage.account_node("wallet1", "solana", derive).is_err());
+    }
+
     #[test]
     fn test_secure_storage_new() {
         let storage = SecureStorage::new();
@@ -340,6 +454,32 @@ mod tests {
         assert!(matches!(result, Err(WalletError::WalletLocked)));
     }
 
+    #[test]
+    fn test_secure_storage_master_secret() {
+        let storage = SecureStorage::new();
+        let secret = MasterSecret::new(
+            SecretSeed::new([7u8; 64]),
+            Some(SecretEntropy::new(vec![1u8; 16])),
+        );
+
+        storage.cache_master_secret("wallet1", &secret);
+        let retrieved = storage.get_master_secret("wallet1").unwrap();
+        assert_eq!(retrieved.seed(), &[7u8; 64]);
+        assert_eq!(retrieved.entropy(), Some(&[1u8; 16][..]));
+
+        // Seed-only wallets have no entropy
+        storage.cache_seed("wallet2", [8u8; 64]);
+        assert!(storage
+            .get_master_secret("wallet2")
+            .unwrap()
+            .entropy()
+            .is_none());
+
+        // Lock clears entropy along with seeds
+        storage.lock();
+        assert!(storage.session().get_entropy("wallet1").is_none());
+    }
+
     #[test]
     fn test_vault_path() {
         assert_eq!(vault_path("abc123"), "wallet:abc123");
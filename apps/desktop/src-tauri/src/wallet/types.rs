@@ -133,6 +133,66 @@ impl std::fmt::Debug for SecretSeed {
     }
 }
 
+/// BIP39 entropy recovered from a mnemonic (securely zeroized on drop)
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct SecretEntropy(pub Vec<u8>);
+
+impl SecretEntropy {
+    pub fn new(entropy: Vec<u8>) -> Self {
+        Self(entropy)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for SecretEntropy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretEntropy([REDACTED {} bytes])", self.0.len())
+    }
+}
+
+/// Which BIP39 secret a chain derives its keys from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MasterSecretKind {
+    /// The 64-byte PBKDF2 seed (BIP32, SLIP-0010)
+    #[default]
+    Seed,
+    /// The raw mnemonic entropy (Cardano Icarus)
+    Entropy,
+}
+
+/// Master secrets of an HD wallet
+///
+/// The seed is always available. The entropy is only known when the wallet
+/// was created or imported from a mnemonic (not from a bare seed).
+#[derive(Debug)]
+pub struct MasterSecret {
+    seed: SecretSeed,
+    entropy: Option<SecretEntropy>,
+}
+
+impl MasterSecret {
+    pub fn new(seed: SecretSeed, entropy: Option<SecretEntropy>) -> Self {
+        Self { seed, entropy }
+    }
+
+    /// Master secret without entropy (entropy-based chains cannot derive)
+    pub fn from_seed(seed: [u8; 64]) -> Self {
+        Self::new(SecretSeed::new(seed), None)
+    }
+
+    pub fn seed(&self) -> &[u8; 64] {
+        self.seed.as_bytes()
+    }
+
+    pub fn entropy(&self) -> Option<&[u8]> {
+        self.entropy.as_ref().map(SecretEntropy::as_bytes)
+    }
+}
+
 /// 256-bit private key (securely zeroized on drop)
 #[derive(Zeroize, ZeroizeOnDrop)]
 pub struct SecretPrivateKey(pub [u8; 32]);
@@ -168,11 +228,18 @@ pub struct DerivedAddress {
     pub address: String,
     /// BIP44/SLIP10 derivation path
     pub derivation_path: String,
+    /// Derivation scheme ID ("default", "ledger_live", "phantom", ...)
+    #[serde(default = "default_derivation_scheme")]
+    pub derivation_scheme: String,
     /// Public key bytes (for verification)
     #[serde(with = "hex_bytes")]
     pub public_key: Vec<u8>,
 }
 
+fn default_derivation_scheme() -> String {
+    crate::wallet::chains::DEFAULT_SCHEME.to_string()
+}
+
 /// Hex serialization for byte arrays
 mod hex_bytes {
     use serde::{Deserialize, Deserializer, Serializer};
@@ -193,6 +260,22 @@ mod hex_bytes {
     }
 }
 
+/// Result of validating an address for a chain
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressValidation {
+    /// Whether the address is valid for the chain
+    pub is_valid: bool,
+    /// Chain-specific address type, if known (e.g., "p2pkh", "p2tr")
+    pub address_type: Option<String>,
+    /// Name of the memo field transfers to this chain can carry
+    /// (e.g., "destination_tag" on XRPL, "memo" on Stellar)
+    pub memo_field: Option<String>,
+    /// Memo embedded in the address itself (XRPL X-address tag, Stellar muxed ID)
+    pub embedded_memo: Option<u64>,
+    /// Underlying account when the address embeds a memo (classic r... / G... address)
+    pub base_address: Option<String>,
+}
+
 // =============================================================================
 // Wallet Info (Public metadata)
 // =============================================================================
@@ -218,6 +301,9 @@ pub struct WalletAddress {
     pub chain_family: ChainFamily,
     pub address: String,
     pub derivation_path: Option<String>,
+    /// Derivation scheme the address was derived with (see `DerivedAddress`)
+    #[serde(default = "default_derivation_scheme")]
+    pub derivation_scheme: String,
     pub account_index: u32,
     pub address_index: u32,
     pub is_primary: bool,
@@ -371,6 +457,7 @@ mod tests {
             chain_family: ChainFamily::Secp256k1,
             address: "0x1234567890abcdef".to_string(),
             derivation_path: "m/44'/60'/0'/0/0".to_string(),
+            derivation_scheme: "default".to_string(),
             public_key: vec![0x04, 0x01, 0x02, 0x03],
         };
 
@@ -38,7 +38,7 @@ export function BitcoinBalanceCard({ walletId, address, onSync }: BitcoinBalance
   const syncChainStore = useChainStore((s) => s.syncWallet);
 
   const walletState = getWalletState(walletId);
-  const { balance, isSyncing, lastSynced, error } = walletState;
+  const { balance, isSyncing, lastSynced, watching, error } = walletState;
 
   // Fetch price on mount
   useEffect(() => {
@@ -47,10 +47,11 @@ export function BitcoinBalanceCard({ walletId, address, onSync }: BitcoinBalance
     }
   }, [price.btcUsd, fetchPrice]);
 
-  // Auto-sync on mount if not recently synced (within 5 minutes)
+  // Auto-sync on mount if not recently synced (within 5 minutes); watched
+  // wallets are kept current by push updates
   useEffect(() => {
-    const shouldAutoSync = !lastSynced ||
-      (Date.now() - new Date(lastSynced).getTime() > 5 * 60 * 1000);
+    const shouldAutoSync = !watching && (!lastSynced ||
+      (Date.now() - new Date(lastSynced).getTime() > 5 * 60 * 1000));
 
     if (shouldAutoSync && !isSyncing) {
       // Sync both stores - bitcoinStore for local component, chainStore for global
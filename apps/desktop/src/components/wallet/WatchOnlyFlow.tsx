@@ -29,7 +29,7 @@ import { Button } from "@/components/ui/button";
 import { Input } from "@/components/ui/input";
 import { cn } from "@/lib/utils";
 import { useWalletStore } from "@/stores/walletStore";
-import { validateChainAddress } from "@/lib/tauri/wallet";
+import { inspectAddress, validateChainAddress } from "@/lib/tauri/wallet";
 
 // =============================================================================
 // Types
@@ -121,13 +121,29 @@ export function WatchOnlyFlow({ open, onOpenChange }: WatchOnlyFlowProps) {
     }
   }, [chainsLoaded, loadChains]);
 
+  const selectedChainInfo = useMemo(
+    () => mainnetChains.find((c) => c.id === state.selectedChain),
+    [mainnetChains, state.selectedChain]
+  );
+
   // Validate address when it changes
   useEffect(() => {
     if (state.address.length > 10 && state.selectedChain) {
       const timer = setTimeout(async () => {
         setIsValidating(true);
         try {
-          const valid = await validateChainAddress(state.selectedChain!, state.address);
+          let valid = await validateChainAddress(state.selectedChain!, state.address);
+          // secp256k1 chains also accept an account xpub to derive addresses from,
+          // and Bitcoin output descriptors
+          if (!valid && selectedChainInfo?.family === "secp256k1") {
+            const inspection = await inspectAddress(state.address);
+            const isDescriptor =
+              state.selectedChain === "bitcoin" &&
+              inspection.kind === "descriptor" &&
+              !inspection.descriptor?.has_private_keys;
+            valid =
+              (inspection.kind === "extended_public_key" || isDescriptor) && inspection.is_valid;
+          }
           setAddressValid(valid);
         } catch {
           setAddressValid(false);
@@ -138,12 +154,7 @@ export function WatchOnlyFlow({ open, onOpenChange }: WatchOnlyFlowProps) {
     } else {
       setAddressValid(null);
     }
-  }, [state.address, state.selectedChain]);
-
-  const selectedChainInfo = useMemo(
-    () => mainnetChains.find((c) => c.id === state.selectedChain),
-    [mainnetChains, state.selectedChain]
-  );
+  }, [state.address, state.selectedChain, selectedChainInfo]);
 
   const canProceed = useMemo(() => {
     switch (state.step) {
@@ -324,6 +335,7 @@ export function WatchOnlyFlow({ open, onOpenChange }: WatchOnlyFlowProps) {
                     <div className="flex items-center justify-between text-xs">
                       <span className="text-muted-foreground">
                         {selectedChainInfo?.name} address
+                        {selectedChainInfo?.family === "secp256k1" && " or account xpub"}
                       </span>
                       {isValidating && (
                         <span className="text-muted-foreground">Validating...</span>
@@ -6,16 +6,26 @@
  */
 
 import { invoke } from "@tauri-apps/api/core";
+import { listen, UnlistenFn } from "@tauri-apps/api/event";
 
 // =============================================================================
 // Types matching Rust backend
 // =============================================================================
 
-export type BitcoinNetwork = "mainnet" | "testnet" | "signet" | "regtest";
+export type BitcoinNetwork = "mainnet" | "testnet" | "testnet4" | "signet" | "regtest";
+
+/** Single-key script type (BIP44 / BIP49 / BIP84 / BIP86) */
+export type BitcoinScriptType = "p2pkh" | "p2sh-p2wpkh" | "p2wpkh" | "p2tr";
 
 export type TransactionDirection = "received" | "sent" | "internal";
 
-export type SyncStage = "connecting" | "scanning" | "updating" | "complete" | "failed";
+export type SyncStage =
+  | "connecting"
+  | "scanning"
+  | "updating"
+  | "complete"
+  | "failed"
+  | "cancelled";
 
 export interface BitcoinBalance {
   /** Confirmed balance in satoshis */
@@ -48,14 +58,48 @@ export interface BitcoinTransaction {
   status: ConfirmationStatus;
   /** Transaction timestamp (block time if confirmed, first seen if unconfirmed) */
   timestamp: number | null;
-  /** Addresses involved */
+  /** When the transaction was first seen unconfirmed, if it was */
+  first_seen: number | null;
+  /** Addresses involved (recipients for sent, our addresses for received) */
   addresses: string[];
+  /** Our addresses among the inputs and outputs */
+  own_addresses: string[];
+  /** Other parties' addresses among the inputs and outputs */
+  counterparty_addresses: string[];
+  /** Inputs, in transaction order */
+  inputs: TransactionInput[];
+  /** Outputs, in transaction order */
+  outputs: TransactionOutput[];
   /** Raw transaction size in bytes */
   size: number | null;
   /** Virtual size in vbytes (for fee calculation) */
   vsize: number | null;
 }
 
+export interface TransactionInput {
+  /** Spent output (`txid:vout`) */
+  previous_output: string;
+  /** Address of the spent output, if known */
+  address: string | null;
+  /** Value of the spent output in satoshis, if known */
+  value_sats: number | null;
+  /** Whether the spent output was ours */
+  is_mine: boolean;
+}
+
+export interface TransactionOutput {
+  /** Output index */
+  vout: number;
+  /** Address, if the script has one (not OP_RETURN) */
+  address: string | null;
+  /** Value in satoshis */
+  value_sats: number;
+  /** Whether the output is ours */
+  is_mine: boolean;
+  /** Whether the output is ours and on the change keychain */
+  is_change: boolean;
+}
+
 export interface UtxoInfo {
   /** Transaction ID containing this UTXO */
   txid: string;
@@ -78,8 +122,42 @@ export interface SyncProgress {
   progress: number;
   /** Optional message describing current operation */
   message: string | null;
+  /** External (receive) scripts checked so far */
+  external_scripts: number;
+  /** Internal (change) scripts checked so far */
+  internal_scripts: number;
+}
+
+/** Progress update emitted while a wallet syncs */
+export interface SyncProgressEvent extends SyncProgress {
+  wallet_id: string;
 }
 
+/** Push update for watched wallets */
+export type ChainEvent =
+  | { type: "new_block"; height: number }
+  | { type: "reorg"; height: number }
+  | {
+      type: "transaction_received";
+      wallet_id: string;
+      txid: string;
+      amount_sats: number;
+      confirmed: boolean;
+    }
+  | {
+      type: "transaction_confirmed";
+      wallet_id: string;
+      txid: string;
+      block_height: number;
+    }
+  | {
+      type: "transaction_reorged";
+      wallet_id: string;
+      txid: string;
+      previous_height: number;
+    }
+  | { type: "wallet_updated"; wallet_id: string };
+
 export interface FeeEstimate {
   /** Fee rate in satoshis per vbyte */
   sat_per_vbyte: number;
@@ -100,6 +178,33 @@ export interface SendTransactionResult {
   broadcast: boolean;
 }
 
+export type BackendKind = "electrum" | "esplora" | "rpc";
+
+export type BackendHealth = "unknown" | "healthy" | "degraded" | "down";
+
+export interface BackendStatus {
+  /** Backend protocol */
+  kind: BackendKind;
+  /** Server URL */
+  url: string;
+  /** Current health */
+  health: BackendHealth;
+  /** Whether this server handled the most recent successful request */
+  active: boolean;
+  /** Smoothed round-trip time of health checks in milliseconds */
+  latency_ms: number | null;
+  /** Chain tip height reported by the last health check */
+  tip_height: number | null;
+  /** Failures since the last success */
+  consecutive_failures: number;
+  /** Most recent error message */
+  last_error: string | null;
+  /** Time of the last success (Unix timestamp) */
+  last_success: number | null;
+  /** Time of the last failure (Unix timestamp) */
+  last_failure: number | null;
+}
+
 // =============================================================================
 // Utility Functions
 // =============================================================================
@@ -134,25 +239,29 @@ export function formatBtc(sats: number, decimals: number = 8): string {
  *
  * @param walletId - Unique identifier for the wallet
  * @param seed - 64-byte seed (from mnemonic)
- * @param account - BIP84 account index (default: 0)
+ * @param account - Account index (default: 0)
+ * @param scriptType - Script type to derive (default: p2wpkh)
  */
 export async function createBitcoinWallet(
   walletId: string,
   seed: number[],
-  account: number = 0
+  account: number = 0,
+  scriptType: BitcoinScriptType = "p2wpkh"
 ): Promise<string> {
   return invoke<string>("bitcoin_create_wallet", {
     walletId,
     seed,
     account,
+    scriptType,
   });
 }
 
 /**
- * Create a watch-only Bitcoin wallet from xpub
+ * Create a watch-only Bitcoin wallet
  *
  * @param walletId - Unique identifier for the wallet
- * @param xpub - Extended public key (xpub, zpub, etc.)
+ * @param xpub - Address, extended public key (xpub, ypub, zpub, etc.) or
+ *   output descriptors, including Sparrow, Specter and Bitcoin Core exports
  */
 export async function createBitcoinWatchWallet(
   walletId: string,
@@ -164,6 +273,22 @@ export async function createBitcoinWatchWallet(
   });
 }
 
+/**
+ * Receive and change descriptors of a wallet, with checksums
+ */
+export interface WalletDescriptors {
+  external: string;
+  /** Absent for wallets without a change keychain */
+  internal: string | null;
+}
+
+/**
+ * Export a Bitcoin wallet's public descriptors, e.g. to watch it elsewhere
+ */
+export async function exportBitcoinDescriptors(walletId: string): Promise<WalletDescriptors> {
+  return invoke<WalletDescriptors>("bitcoin_export_descriptors", { walletId });
+}
+
 /**
  * Check if a Bitcoin wallet exists
  */
@@ -178,15 +303,18 @@ export async function bitcoinWalletExists(walletId: string): Promise<boolean> {
  * that includes Bitcoin, to initialize the BDK wallet for syncing.
  *
  * @param walletId - Wallet ID from createHDWallet/importHDWallet
- * @param account - BIP84 account index (default: 0)
+ * @param account - Account index (default: 0)
+ * @param scriptType - Script type to derive (default: p2wpkh)
  */
 export async function initBitcoinFromCachedSeed(
   walletId: string,
-  account: number = 0
+  account: number = 0,
+  scriptType: BitcoinScriptType = "p2wpkh"
 ): Promise<string> {
   return invoke<string>("bitcoin_init_from_cached_seed", {
     walletId,
     account,
+    scriptType,
   });
 }
 
@@ -198,10 +326,84 @@ export async function initBitcoinFromCachedSeed(
  * Sync a Bitcoin wallet with the blockchain
  *
  * This will connect to Electrum servers and fetch the latest
- * transaction data for the wallet.
+ * transaction data for the wallet. The first sync scans the full gap
+ * limit; pass `rescan` to force that again.
+ */
+export async function syncBitcoinWallet(
+  walletId: string,
+  rescan?: boolean
+): Promise<SyncProgress> {
+  return invoke<SyncProgress>("bitcoin_sync_wallet", { walletId, rescan });
+}
+
+/**
+ * Cancel a running sync
+ *
+ * Returns whether the wallet was syncing; the pending
+ * `syncBitcoinWallet` call then rejects.
+ */
+export async function cancelBitcoinSync(walletId: string): Promise<boolean> {
+  return invoke<boolean>("bitcoin_cancel_sync", { walletId });
+}
+
+/**
+ * Listen for sync progress
+ *
+ * Pass a wallet ID to only receive that wallet's updates.
+ */
+export async function onBitcoinSyncProgress(
+  callback: (event: SyncProgressEvent) => void,
+  walletId?: string
+): Promise<UnlistenFn> {
+  return listen<SyncProgressEvent>("bitcoin-sync-progress", (event) => {
+    if (!walletId || event.payload.wallet_id === walletId) {
+      callback(event.payload);
+    }
+  });
+}
+
+/**
+ * Start push updates for a wallet
+ *
+ * Subscribes to the wallet's addresses on the Electrum server; changes
+ * are synced automatically and reported through `onBitcoinChainEvent`,
+ * replacing periodic `syncBitcoinWallet` calls. Sync the wallet once
+ * first.
+ *
+ * @param address - For single-address watch wallets, the address
+ */
+export async function watchBitcoinWallet(
+  walletId: string,
+  address?: string
+): Promise<void> {
+  return invoke<void>("bitcoin_watch_wallet", { walletId, address });
+}
+
+/**
+ * Stop push updates for a wallet
+ *
+ * Returns whether the wallet was watched.
+ */
+export async function unwatchBitcoinWallet(walletId: string): Promise<boolean> {
+  return invoke<boolean>("bitcoin_unwatch_wallet", { walletId });
+}
+
+/**
+ * Listen for push updates of watched wallets
+ *
+ * Pass a wallet ID to only receive that wallet's events; block and
+ * reorg events are always delivered.
  */
-export async function syncBitcoinWallet(walletId: string): Promise<SyncProgress> {
-  return invoke<SyncProgress>("bitcoin_sync_wallet", { walletId });
+export async function onBitcoinChainEvent(
+  callback: (event: ChainEvent) => void,
+  walletId?: string
+): Promise<UnlistenFn> {
+  return listen<ChainEvent>("bitcoin-chain-event", (event) => {
+    const payload = event.payload;
+    if (!walletId || !("wallet_id" in payload) || payload.wallet_id === walletId) {
+      callback(payload);
+    }
+  });
 }
 
 // =============================================================================
@@ -268,6 +470,17 @@ export async function getBitcoinNetwork(): Promise<BitcoinNetwork> {
   return invoke<BitcoinNetwork>("bitcoin_get_network");
 }
 
+/**
+ * Get the status of the configured backend servers, preferred first
+ *
+ * @param refresh - Health-check every server before reporting
+ */
+export async function getBitcoinBackendStatus(
+  refresh?: boolean
+): Promise<BackendStatus[]> {
+  return invoke<BackendStatus[]>("bitcoin_get_backend_status", { refresh });
+}
+
 // =============================================================================
 // Single Address Queries (for watch-only addresses)
 // =============================================================================
@@ -303,18 +516,32 @@ export async function getBitcoinAddressTransactions(
 }
 
 /**
- * Check if a string is a Bitcoin address (vs an xpub)
+ * Get the combined transaction history of several watched addresses
+ *
+ * Transactions shared by the addresses appear once with their net
+ * amount; transfers between the addresses are "internal".
+ *
+ * @param addresses - Bitcoin addresses (bc1..., 1..., 3...)
+ */
+export async function getBitcoinAddressesTransactions(
+  addresses: string[]
+): Promise<BitcoinTransaction[]> {
+  return invoke<BitcoinTransaction[]>("bitcoin_get_addresses_transactions", {
+    addresses,
+  });
+}
+
+const BECH32_ADDRESS = /^(bc|tb|bcrt)1[02-9ac-hj-np-z]{8,87}$/;
+const BASE58_ADDRESS = /^[123mn][1-9A-HJ-NP-Za-km-z]{25,34}$/;
+
+/**
+ * Check if a string is shaped like a Bitcoin address (vs an xpub)
+ *
+ * This is a synchronous format check only; use `inspectAddress` for
+ * checksum and network validation.
  */
 export function isBitcoinAddress(input: string): boolean {
-  return (
-    input.startsWith("bc1") ||
-    input.startsWith("tb1") ||
-    input.startsWith("1") ||
-    input.startsWith("3") ||
-    input.startsWith("m") ||
-    input.startsWith("n") ||
-    input.startsWith("2")
-  );
+  return BECH32_ADDRESS.test(input.toLowerCase()) || BASE58_ADDRESS.test(input);
 }
 
 // =============================================================================
@@ -21,6 +21,85 @@ export interface ChainInfo {
   coin_type: number;
   is_testnet: boolean;
   icon_name: string;
+  evm_chain_id: number | null;
+  rpc_urls: string[];
+  explorer_url: string | null;
+  address_type: string | null;
+  decimals: number;
+  is_custom: boolean;
+}
+
+export interface AddressValidation {
+  is_valid: boolean;
+  address_type: string | null;
+  /** Memo field transfers can carry ("destination_tag" on XRPL, "memo" on Stellar) */
+  memo_field: string | null;
+  /** Memo embedded in the address (XRPL X-address tag, Stellar muxed ID) */
+  embedded_memo: number | null;
+  /** Underlying classic account when the address embeds a memo */
+  base_address: string | null;
+}
+
+export type InputKind =
+  | "address"
+  | "extended_public_key"
+  | "extended_private_key"
+  | "descriptor"
+  | "ens_name"
+  | "unknown";
+
+/** A chain that accepts an inspected input */
+export interface AddressMatch {
+  chain_id: string;
+  chain_name: string;
+  is_testnet: boolean;
+  address_type: string | null;
+  memo_field: string | null;
+  embedded_memo: number | null;
+  base_address: string | null;
+}
+
+export interface ExtendedKeyInfo {
+  /** SLIP-132 prefix ("xpub", "zpub", "tprv", ...) */
+  prefix: string;
+  is_private: boolean;
+  is_testnet: boolean;
+  script_type: string | null;
+  depth: number;
+  parent_fingerprint: string;
+}
+
+export interface DescriptorInfo {
+  script_type: string;
+  is_testnet: boolean | null;
+  has_private_keys: boolean;
+  has_wildcard: boolean;
+  is_multipath: boolean;
+}
+
+export interface AddressInspection {
+  input: string;
+  kind: InputKind;
+  is_valid: boolean;
+  /** Every chain that accepts the input, mainnets first */
+  matches: AddressMatch[];
+  /** false when the input looks encoded but its checksum is wrong, null when it has none */
+  checksum_valid: boolean | null;
+  extended_key: ExtendedKeyInfo | null;
+  descriptor: DescriptorInfo | null;
+  error: string | null;
+}
+
+export interface EvmNetwork {
+  id: string;
+  name: string;
+  symbol: string;
+  chain_id: number;
+  decimals: number;
+  rpc_urls: string[];
+  explorer_url: string | null;
+  is_testnet: boolean;
+  is_custom: boolean;
 }
 
 export interface DerivedAddress {
@@ -28,9 +107,30 @@ export interface DerivedAddress {
   chain_family: ChainFamily;
   address: string;
   derivation_path: string;
+  derivation_scheme: string; // "default", "ledger_live", "phantom", ...
   public_key: string; // hex encoded
 }
 
+export interface DerivationSchemeInfo {
+  id: string;
+  name: string;
+  example_path: string;
+  is_default: boolean;
+}
+
+export interface SchemeScanResult {
+  chain: string;
+  scheme: string;
+  scheme_name: string;
+  account: number;
+  address: string;
+  derivation_path: string;
+  balance: string | null; // smallest unit (wei, lamports, satoshis)
+  tx_count: number | null;
+  has_activity: boolean;
+  error: string | null;
+}
+
 export interface CreateHDWalletResponse {
   wallet_id: string;
   mnemonic: string;
@@ -74,6 +174,55 @@ export async function validateChainAddress(
   });
 }
 
+/**
+ * Validate an address for a specific chain and report its address type
+ */
+export async function validateChainAddressDetails(
+  chainId: string,
+  address: string
+): Promise<AddressValidation> {
+  return invoke<AddressValidation>("validate_chain_address_details", {
+    chainId,
+    address,
+  });
+}
+
+/**
+ * Detect the chains, networks and address types of a pasted string
+ *
+ * Also recognizes extended keys, output descriptors and ENS names.
+ */
+export async function inspectAddress(input: string): Promise<AddressInspection> {
+  return invoke<AddressInspection>("inspect_address", { input });
+}
+
+// =============================================================================
+// Custom EVM Networks
+// =============================================================================
+
+/**
+ * Get user-defined EVM networks
+ */
+export async function getCustomEvmNetworks(): Promise<EvmNetwork[]> {
+  return invoke<EvmNetwork[]>("get_custom_evm_networks");
+}
+
+/**
+ * Add or update a user-defined EVM network
+ */
+export async function addCustomEvmNetwork(
+  network: EvmNetwork
+): Promise<ChainInfo> {
+  return invoke<ChainInfo>("add_custom_evm_network", { network });
+}
+
+/**
+ * Remove a user-defined EVM network
+ */
+export async function removeCustomEvmNetwork(chainId: string): Promise<void> {
+  return invoke<void>("remove_custom_evm_network", { chainId });
+}
+
 // =============================================================================
 // Mnemonic Operations
 // =============================================================================
@@ -163,6 +312,322 @@ export async function deriveWalletAddress(
   });
 }
 
+/**
+ * Derive the addresses at indices `start..end` (exclusive) of a wallet account
+ *
+ * Much faster than repeated `deriveWalletAddress` calls for large ranges.
+ */
+export async function deriveWalletAddressRange(
+  walletId: string,
+  chainId: string,
+  start: number,
+  end: number,
+  account: number = 0
+): Promise<DerivedAddress[]> {
+  return invoke<DerivedAddress[]>("derive_wallet_address_range", {
+    walletId,
+    chainId,
+    account,
+    start,
+    end,
+  });
+}
+
+// =============================================================================
+// Watch-only Wallets
+// =============================================================================
+
+/** A wallet row in the backend database */
+export interface StoredWallet {
+  id: string;
+  name: string;
+  chain: string;
+  address: string;
+  is_watch_only: boolean;
+  created_at: string;
+}
+
+/**
+ * Register a watch-only wallet in the backend database
+ *
+ * Account xpubs can only be attached to wallets registered this way.
+ */
+export async function addWatchOnlyWallet(
+  name: string,
+  chain: string,
+  address: string
+): Promise<StoredWallet> {
+  return invoke<StoredWallet>("add_wallet", {
+    request: { name, chain, address, is_watch_only: true },
+  });
+}
+
+/**
+ * Delete a wallet row and its watch-only keys from the backend database
+ */
+export async function removeStoredWallet(walletId: string): Promise<void> {
+  return invoke<void>("remove_wallet", { walletId });
+}
+
+/**
+ * Attach an account-level xpub (xpub/ypub/zpub/tpub...) to a watch-only wallet
+ *
+ * The wallet can then derive addresses and run discovery without secrets.
+ * @returns The first receive address of the account
+ */
+export async function addWatchOnlyXpub(
+  walletId: string,
+  chainId: string,
+  xpub: string
+): Promise<DerivedAddress> {
+  return invoke<DerivedAddress>("add_watch_only_xpub", {
+    walletId,
+    chainId,
+    xpub,
+  });
+}
+
+/**
+ * Forget the account xpubs of a watch-only wallet
+ */
+export async function removeWatchOnlyKeys(walletId: string): Promise<void> {
+  return invoke<void>("remove_watch_only_keys", { walletId });
+}
+
+// =============================================================================
+// Derivation Schemes
+// =============================================================================
+
+/**
+ * List the derivation schemes for a chain (default scheme first)
+ */
+export async function getDerivationSchemes(
+  chainId: string
+): Promise<DerivationSchemeInfo[]> {
+  return invoke<DerivationSchemeInfo[]>("get_derivation_schemes", { chainId });
+}
+
+/**
+ * Derive an address for an existing wallet using a named derivation scheme
+ */
+export async function deriveWalletAddressWithScheme(
+  walletId: string,
+  chainId: string,
+  scheme: string,
+  account: number = 0,
+  index: number = 0
+): Promise<DerivedAddress> {
+  return invoke<DerivedAddress>("derive_wallet_address_with_scheme", {
+    walletId,
+    chainId,
+    scheme,
+    account,
+    index,
+  });
+}
+
+/**
+ * Probe every derivation scheme of the given chains for funds/history
+ *
+ * Run after importing a mnemonic to find where an older wallet kept funds.
+ */
+export async function scanDerivationSchemes(
+  walletId: string,
+  chainIds: string[],
+  accounts?: number
+): Promise<SchemeScanResult[]> {
+  return invoke<SchemeScanResult[]>("scan_derivation_schemes", {
+    walletId,
+    chainIds,
+    accounts,
+  });
+}
+
+// =============================================================================
+// Signing
+// =============================================================================
+
+/**
+ * Signature produced by a chain's native signing scheme
+ */
+export interface ChainSignature {
+  address: string;
+  /** "eip191", "keccak256", "bip322-simple", "bip137", "psbt", "solana-offchain" or "ed25519" */
+  scheme: string;
+  /** Encoded signature (0x-hex, base64 or base58), or the signed PSBT */
+  signature: string;
+}
+
+/**
+ * Sign a message with a wallet address (EIP-191, BIP-322, Solana off-chain)
+ */
+export async function signChainMessage(
+  walletId: string,
+  chainId: string,
+  message: string,
+  account?: number,
+  index?: number,
+  scheme?: string
+): Promise<ChainSignature> {
+  return invoke<ChainSignature>("sign_chain_message", {
+    walletId,
+    chainId,
+    message,
+    account,
+    index,
+    scheme,
+  });
+}
+
+/**
+ * Sign a hex-encoded transaction payload (unsigned EVM tx, PSBT, Solana message)
+ */
+export async function signChainTransaction(
+  walletId: string,
+  chainId: string,
+  payload: string,
+  account?: number,
+  index?: number,
+  scheme?: string
+): Promise<ChainSignature> {
+  return invoke<ChainSignature>("sign_chain_transaction", {
+    walletId,
+    chainId,
+    payload,
+    account,
+    index,
+    scheme,
+  });
+}
+
+/**
+ * Verify a message signature for an address
+ */
+export async function verifyChainMessage(
+  chainId: string,
+  address: string,
+  message: string,
+  signature: string
+): Promise<boolean> {
+  return invoke<boolean>("verify_chain_message", {
+    chainId,
+    address,
+    message,
+    signature,
+  });
+}
+
+// =============================================================================
+// Nostr Identity (NIP-06)
+// =============================================================================
+
+/** Nostr event to sign; `pubkey`, `id` and `sig` are filled in by the wallet */
+export interface UnsignedNostrEvent {
+  created_at: number;
+  kind: number;
+  tags: string[][];
+  content: string;
+}
+
+/** Signed Nostr event (NIP-01), ready to publish */
+export interface NostrEvent extends UnsignedNostrEvent {
+  id: string;
+  pubkey: string;
+  sig: string;
+}
+
+/**
+ * Sign a Nostr event with the wallet's NIP-06 identity
+ *
+ * The identity's npub is the wallet address on the "nostr" chain.
+ */
+export async function signNostrEvent(
+  walletId: string,
+  event: UnsignedNostrEvent,
+  account: number = 0
+): Promise<NostrEvent> {
+  return invoke<NostrEvent>("sign_nostr_event", { walletId, event, account });
+}
+
+/**
+ * Export the wallet's NIP-06 identity key as nsec (secret; display once)
+ */
+export async function exportNostrNsec(
+  walletId: string,
+  account: number = 0
+): Promise<string> {
+  return invoke<string>("export_nostr_nsec", { walletId, account });
+}
+
+// =============================================================================
+// BIP-85 Child Wallets
+// =============================================================================
+
+export type Bip85Language =
+  | "english"
+  | "japanese"
+  | "korean"
+  | "spanish"
+  | "chinese_simplified"
+  | "chinese_traditional"
+  | "french"
+  | "italian"
+  | "czech"
+  | "portuguese";
+
+/** What to derive from BIP-85 child entropy */
+export type Bip85Application =
+  | { type: "mnemonic"; language: Bip85Language; words: 12 | 18 | 24 }
+  | { type: "wif" }
+  | { type: "xprv" }
+  | { type: "hex"; num_bytes: number } // 16-64
+  | { type: "password_base64"; length: number } // 20-86
+  | { type: "password_base85"; length: number }; // 10-80
+
+export interface Bip85Child {
+  /** "BIP39", "HD-Seed WIF", "XPRV", "HEX", "PWD BASE64" or "PWD BASE85" */
+  application: string;
+  path: string;
+  /** The child secret; display once, never store */
+  value: string;
+}
+
+/**
+ * Derive a BIP-85 child secret (mnemonic, WIF, xprv, hex, password)
+ */
+export async function deriveBip85Child(
+  walletId: string,
+  application: Bip85Application,
+  index: number = 0
+): Promise<Bip85Child> {
+  return invoke<Bip85Child>("derive_bip85_child", {
+    walletId,
+    application,
+    index,
+  });
+}
+
+/**
+ * Create a new HD wallet from a BIP-85 child mnemonic of an unlocked wallet
+ *
+ * @returns The new wallet; `mnemonic` is the child phrase for backup
+ */
+export async function importBip85Wallet(
+  parentWalletId: string,
+  index: number,
+  chains: string[],
+  language: Bip85Language = "english",
+  words: 12 | 18 | 24 = 12
+): Promise<CreateHDWalletResponse> {
+  return invoke<CreateHDWalletResponse>("import_bip85_wallet", {
+    parentWalletId,
+    language,
+    words,
+    index,
+    chains,
+  });
+}
+
 // =============================================================================
 // Session Management
 // =============================================================================
@@ -5,6 +5,7 @@
  */
 
 import { create } from "zustand";
+import { UnlistenFn } from "@tauri-apps/api/event";
 import { devtools, persist } from "zustand/middleware";
 import {
   BitcoinBalance,
@@ -13,6 +14,9 @@ import {
   getBitcoinBalance,
   getBitcoinTransactions,
   syncBitcoinWallet,
+  onBitcoinSyncProgress,
+  watchBitcoinWallet,
+  onBitcoinChainEvent,
   satsToBtc,
   getBitcoinAddressBalance,
   getBitcoinAddressTransactions,
@@ -29,6 +33,8 @@ export interface WalletBitcoinState {
   lastSynced: string | null;
   isSyncing: boolean;
   syncProgress: SyncProgress | null;
+  /** Whether push updates are active, so periodic syncs are unneeded */
+  watching: boolean;
   error: string | null;
 }
 
@@ -54,6 +60,9 @@ interface BitcoinStoreState {
   fetchTransactions: (walletId: string, address?: string) => Promise<void>;
   fetchPrice: () => Promise<void>;
   refreshWallet: (walletId: string, address?: string) => Promise<void>;
+  watchWallet: (walletId: string, address?: string) => Promise<void>;
+  // Forget push updates after the backend dropped them (e.g. on lock)
+  resetWatching: () => void;
 
   // Selectors
   getWalletState: (walletId: string) => WalletBitcoinState;
@@ -71,6 +80,7 @@ const initialWalletState: WalletBitcoinState = {
   lastSynced: null,
   isSyncing: false,
   syncProgress: null,
+  watching: false,
   error: null,
 };
 
@@ -81,6 +91,11 @@ const initialPriceState: PriceState = {
   error: null,
 };
 
+// Addresses of watched wallets (undefined for HD wallets), for refetching
+// their data when a push update arrives
+const watchedWallets = new Map<string, string | undefined>();
+let chainEventListener: Promise<UnlistenFn> | null = null;
+
 // =============================================================================
 // Store
 // =============================================================================
@@ -124,15 +139,38 @@ export const useBitcoinStore = create<BitcoinStoreState>()(
                 [walletId]: {
                   ...state.walletStates[walletId] || initialWalletState,
                   isSyncing: false,
-                  syncProgress: { stage: "complete", progress: 100, message: "Complete" },
+                  syncProgress: {
+                    stage: "complete",
+                    progress: 100,
+                    message: "Complete",
+                    external_scripts: 0,
+                    internal_scripts: 0,
+                  },
                   lastSynced: new Date().toISOString(),
                   error: null,
                 },
               },
             }));
           } else {
-            // HD wallet - use BDK sync
-            const progress = await syncBitcoinWallet(walletId);
+            // HD wallet - use BDK sync, tracking progress as it streams in
+            const unlisten = await onBitcoinSyncProgress((progress) => {
+              set((state) => ({
+                walletStates: {
+                  ...state.walletStates,
+                  [walletId]: {
+                    ...state.walletStates[walletId] || initialWalletState,
+                    syncProgress: progress,
+                  },
+                },
+              }));
+            }, walletId);
+
+            let progress: SyncProgress;
+            try {
+              progress = await syncBitcoinWallet(walletId);
+            } finally {
+              unlisten();
+            }
 
             set((state) => ({
               walletStates: {
@@ -151,6 +189,15 @@ export const useBitcoinStore = create<BitcoinStoreState>()(
           // After sync, fetch updated balance and transactions
           await get().fetchBalance(walletId, address);
           await get().fetchTransactions(walletId, address);
+
+          // Keep the wallet current from here on without polling
+          if (!get().getWalletState(walletId).watching) {
+            get()
+              .watchWallet(walletId, address)
+              .catch((error) =>
+                console.warn(`[BitcoinStore] Push updates unavailable for ${walletId}:`, error)
+              );
+          }
         } catch (error) {
           let errorMessage = error instanceof Error ? error.message : "Sync failed";
 
@@ -273,6 +320,63 @@ export const useBitcoinStore = create<BitcoinStoreState>()(
         await get().fetchPrice();
       },
 
+      // Subscribe to push updates for a synced wallet
+      // For single-address watch-only wallets, pass the address
+      watchWallet: async (walletId: string, address?: string) => {
+        const isSingleAddress = address && isBitcoinAddress(address);
+        await watchBitcoinWallet(walletId, isSingleAddress ? address : undefined);
+        watchedWallets.set(walletId, address);
+
+        if (!chainEventListener) {
+          chainEventListener = onBitcoinChainEvent((event) => {
+            if (event.type === "wallet_updated") {
+              if (!watchedWallets.has(event.wallet_id)) return;
+              const walletAddress = watchedWallets.get(event.wallet_id);
+              get().fetchBalance(event.wallet_id, walletAddress);
+              get().fetchTransactions(event.wallet_id, walletAddress);
+              set((state) => ({
+                walletStates: {
+                  ...state.walletStates,
+                  [event.wallet_id]: {
+                    ...state.walletStates[event.wallet_id] || initialWalletState,
+                    lastSynced: new Date().toISOString(),
+                  },
+                },
+              }));
+            } else if (event.type === "new_block" || event.type === "reorg") {
+              // Confirmation counts moved for every wallet
+              for (const [walletId, walletAddress] of watchedWallets) {
+                get().fetchTransactions(walletId, walletAddress);
+              }
+            }
+          });
+        }
+
+        set((state) => ({
+          walletStates: {
+            ...state.walletStates,
+            [walletId]: {
+              ...state.walletStates[walletId] || initialWalletState,
+              watching: true,
+            },
+          },
+        }));
+      },
+
+      // Forget push updates after the backend dropped them (e.g. on lock),
+      // so the next sync watches the wallet again
+      resetWatching: () => {
+        watchedWallets.clear();
+        set((state) => ({
+          walletStates: Object.fromEntries(
+            Object.entries(state.walletStates).map(([id, ws]) => [
+              id,
+              { ...ws, watching: false },
+            ])
+          ),
+        }));
+      },
+
       // Calculate total BTC balance across wallets
       getTotalBalanceBtc: (walletIds: string[]) => {
         const { walletStates } = get();
@@ -309,6 +413,7 @@ export const useBitcoinStore = create<BitcoinStoreState>()(
                 lastSynced: ws.lastSynced,
                 isSyncing: false,
                 syncProgress: null,
+                watching: false,
                 error: null,
               },
             ])
@@ -14,15 +14,24 @@ import {
   getMainnetChains,
   createHDWallet,
   importHDWallet,
+  inspectAddress,
+  addWatchOnlyWallet,
+  addWatchOnlyXpub,
+  removeStoredWallet,
   isWalletUnlocked,
   lockWallet,
   unlockWallet,
 } from "@/lib/tauri/wallet";
-import { initBitcoinFromCachedSeed, createBitcoinWatchWallet } from "@/lib/tauri/bitcoin";
+import {
+  initBitcoinFromCachedSeed,
+  createBitcoinWatchWallet,
+  getBitcoinNewAddress,
+} from "@/lib/tauri/bitcoin";
 import {
   type ChainFamily,
   getEnabledChainsByFamily,
 } from "@/lib/chains";
+import { useBitcoinStore } from "./bitcoinStore";
 
 // =============================================================================
 // Types
@@ -42,6 +51,8 @@ export interface WalletAddress {
   chainFamily: "secp256k1" | "ed25519" | "sr25519";
   address: string;
   derivationPath: string;
+  /** Derivation scheme ID ("default", "ledger_live", ...); absent for watch-only */
+  derivationScheme?: string;
   isPrimary: boolean;
 }
 
@@ -219,6 +230,8 @@ export const useWalletStore = create<WalletState>()(
         lock: async () => {
           try {
             await lockWallet();
+            // Locking stops Bitcoin push updates
+            useBitcoinStore.getState().resetWatching();
             set({ isUnlocked: false });
           } catch (error) {
             console.error("Failed to lock wallet:", error);
@@ -301,6 +314,7 @@ export const useWalletStore = create<WalletState>()(
                 chainFamily: addr.chain_family,
                 address: addr.address,
                 derivationPath: addr.derivation_path,
+                derivationScheme: addr.derivation_scheme,
                 isPrimary: true,
               })),
             };
@@ -389,6 +403,7 @@ export const useWalletStore = create<WalletState>()(
                 chainFamily: addr.chain_family,
                 address: addr.address,
                 derivationPath: addr.derivation_path,
+                derivationScheme: addr.derivation_scheme,
                 isPrimary: true,
               })),
             };
@@ -411,15 +426,39 @@ export const useWalletStore = create<WalletState>()(
         addWatchOnlyAddress: async (name, chainId, address) => {
           const chain = useWalletStore.getState().supportedChains.find((c) => c.id === chainId);
 
-          const walletId = `watch_${Date.now()}_${Math.random().toString(36).substr(2, 9)}`;
+          let walletId = `watch_${Date.now()}_${Math.random().toString(36).substr(2, 9)}`;
+
+          // Account xpubs derive their receive addresses without secrets.
+          // The backend only attaches them to wallets it knows are watch-only.
+          let primary = { address, derivationPath: "" };
+          const inspection = await inspectAddress(address);
+          if (inspection.kind === "extended_public_key") {
+            walletId = (await addWatchOnlyWallet(name, chainId, address)).id;
+            try {
+              const derived = await addWatchOnlyXpub(walletId, chainId, address);
+              primary = { address: derived.address, derivationPath: derived.derivation_path };
+            } catch (xpubError) {
+              // Don't leave an empty wallet behind for a rejected key
+              await removeStoredWallet(walletId).catch((e) =>
+                console.error("[WalletStore] Failed to remove watch-only wallet:", e)
+              );
+              throw xpubError;
+            }
+          }
 
           // Initialize BDK wallet for Bitcoin watch-only addresses
           if (chainId === "bitcoin") {
             try {
               await createBitcoinWatchWallet(walletId, address);
               console.log("[WalletStore] Bitcoin watch wallet initialized");
+              // Descriptors have no address of their own; show the first receive address
+              if (inspection.kind === "descriptor") {
+                primary = { address: await getBitcoinNewAddress(walletId), derivationPath: "" };
+              }
             } catch (btcError) {
               console.error("[WalletStore] Failed to init Bitcoin watch wallet:", btcError);
+              // A descriptor is only usable through its BDK wallet
+              if (inspection.kind === "descriptor") throw btcError;
               // Still create the wallet entry, sync will just fail
             }
           }
@@ -434,8 +473,8 @@ export const useWalletStore = create<WalletState>()(
               {
                 chain: chainId,
                 chainFamily: chain?.family || "secp256k1",
-                address,
-                derivationPath: "",
+                address: primary.address,
+                derivationPath: primary.derivationPath,
                 isPrimary: true,
               },
             ],